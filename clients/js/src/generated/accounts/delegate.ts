@@ -0,0 +1,166 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u128,
+  u16,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type Delegate = Account<DelegateAccountData>;
+
+export type DelegateAccountData = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  indexWithPrecision: Array<bigint>;
+  delegatorsIndexWithPrecision: Array<bigint>;
+  delegatedStake: bigint;
+  commissionBps: number;
+  bump: number;
+  data: Array<number>;
+};
+
+export type DelegateAccountDataArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  indexWithPrecision: Array<number | bigint>;
+  delegatorsIndexWithPrecision: Array<number | bigint>;
+  delegatedStake: number | bigint;
+  commissionBps: number;
+  bump: number;
+  data: Array<number>;
+};
+
+export function getDelegateAccountDataSerializer(): Serializer<
+  DelegateAccountDataArgs,
+  DelegateAccountData
+> {
+  return struct<DelegateAccountData>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['indexWithPrecision', array(u128(), { size: 3 })],
+      ['delegatorsIndexWithPrecision', array(u128(), { size: 3 })],
+      ['delegatedStake', u64()],
+      ['commissionBps', u16()],
+      ['bump', u8()],
+      ['data', array(u8(), { size: 5 })],
+    ],
+    { description: 'DelegateAccountData' }
+  ) as Serializer<DelegateAccountDataArgs, DelegateAccountData>;
+}
+
+export function deserializeDelegate(rawAccount: RpcAccount): Delegate {
+  return deserializeAccount(rawAccount, getDelegateAccountDataSerializer());
+}
+
+export async function fetchDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Delegate> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'Delegate');
+  return deserializeDelegate(maybeAccount);
+}
+
+export async function safeFetchDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Delegate | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeDelegate(maybeAccount) : null;
+}
+
+export async function fetchAllDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Delegate[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'Delegate');
+    return deserializeDelegate(maybeAccount);
+  });
+}
+
+export async function safeFetchAllDelegate(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Delegate[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeDelegate(maybeAccount as RpcAccount));
+}
+
+export function getDelegateGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      rewardPool: PublicKey;
+      mining: PublicKey;
+      indexWithPrecision: Array<number | bigint>;
+      delegatorsIndexWithPrecision: Array<number | bigint>;
+      delegatedStake: number | bigint;
+      commissionBps: number;
+      bump: number;
+      data: Array<number>;
+    }>({
+      rewardPool: [0, publicKeySerializer()],
+      mining: [32, publicKeySerializer()],
+      indexWithPrecision: [64, array(u128(), { size: 3 })],
+      delegatorsIndexWithPrecision: [112, array(u128(), { size: 3 })],
+      delegatedStake: [160, u64()],
+      commissionBps: [168, u16()],
+      bump: [170, u8()],
+      data: [171, array(u8(), { size: 5 })],
+    })
+    .deserializeUsing<Delegate>((account) => deserializeDelegate(account));
+}
+
+export function getDelegateSize(): number {
+  return 176;
+}
@@ -0,0 +1,160 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u128,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type Delegation = Account<DelegationAccountData>;
+
+export type DelegationAccountData = {
+  rewardPool: PublicKey;
+  delegateMining: PublicKey;
+  mining: PublicKey;
+  indexWithPrecision: Array<bigint>;
+  delegatedAmount: bigint;
+  bump: number;
+  data: Array<number>;
+};
+
+export type DelegationAccountDataArgs = {
+  rewardPool: PublicKey;
+  delegateMining: PublicKey;
+  mining: PublicKey;
+  indexWithPrecision: Array<number | bigint>;
+  delegatedAmount: number | bigint;
+  bump: number;
+  data: Array<number>;
+};
+
+export function getDelegationAccountDataSerializer(): Serializer<
+  DelegationAccountDataArgs,
+  DelegationAccountData
+> {
+  return struct<DelegationAccountData>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['delegateMining', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['indexWithPrecision', array(u128(), { size: 3 })],
+      ['delegatedAmount', u64()],
+      ['bump', u8()],
+      ['data', array(u8(), { size: 7 })],
+    ],
+    { description: 'DelegationAccountData' }
+  ) as Serializer<DelegationAccountDataArgs, DelegationAccountData>;
+}
+
+export function deserializeDelegation(rawAccount: RpcAccount): Delegation {
+  return deserializeAccount(rawAccount, getDelegationAccountDataSerializer());
+}
+
+export async function fetchDelegation(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Delegation> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'Delegation');
+  return deserializeDelegation(maybeAccount);
+}
+
+export async function safeFetchDelegation(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Delegation | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeDelegation(maybeAccount) : null;
+}
+
+export async function fetchAllDelegation(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Delegation[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'Delegation');
+    return deserializeDelegation(maybeAccount);
+  });
+}
+
+export async function safeFetchAllDelegation(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Delegation[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeDelegation(maybeAccount as RpcAccount));
+}
+
+export function getDelegationGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      rewardPool: PublicKey;
+      delegateMining: PublicKey;
+      mining: PublicKey;
+      indexWithPrecision: Array<number | bigint>;
+      delegatedAmount: number | bigint;
+      bump: number;
+      data: Array<number>;
+    }>({
+      rewardPool: [0, publicKeySerializer()],
+      delegateMining: [32, publicKeySerializer()],
+      mining: [64, publicKeySerializer()],
+      indexWithPrecision: [96, array(u128(), { size: 3 })],
+      delegatedAmount: [144, u64()],
+      bump: [152, u8()],
+      data: [153, array(u8(), { size: 7 })],
+    })
+    .deserializeUsing<Delegation>((account) => deserializeDelegation(account));
+}
+
+export function getDelegationSize(): number {
+  return 160;
+}
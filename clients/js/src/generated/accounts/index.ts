@@ -6,5 +6,8 @@
  * @see https://github.com/trezoaplex-foundation/kinobi
  */
 
+export * from './delegate';
+export * from './delegation';
 export * from './mining';
 export * from './rewardPool';
+export * from './vesting';
@@ -25,34 +25,42 @@ import {
   publicKey as publicKeySerializer,
   struct,
   u128,
+  u16,
   u64,
   u8,
 } from '@trezoaplex-foundation/umi/serializers';
+import {
+  MergedWeightedStakeDiff,
+  MergedWeightedStakeDiffArgs,
+  getMergedWeightedStakeDiffSerializer,
+} from '../types';
 
 export type Mining = Account<MiningAccountData>;
 
 export type MiningAccountData = {
   rewardPool: PublicKey;
   owner: PublicKey;
-  rewardMint: PublicKey;
-  indexWithPrecision: bigint;
+  indexWithPrecision: Array<bigint>;
   share: bigint;
-  unclaimedRewards: bigint;
+  unclaimedRewards: Array<bigint>;
   stakeFromOthers: bigint;
   bump: number;
   data: Array<number>;
+  modifiersTreeCapacity: number;
+  mergedWeightedStakeDiffs: Array<MergedWeightedStakeDiff>;
 };
 
 export type MiningAccountDataArgs = {
   rewardPool: PublicKey;
   owner: PublicKey;
-  rewardMint: PublicKey;
-  indexWithPrecision: number | bigint;
+  indexWithPrecision: Array<number | bigint>;
   share: number | bigint;
-  unclaimedRewards: number | bigint;
+  unclaimedRewards: Array<number | bigint>;
   stakeFromOthers: number | bigint;
   bump: number;
   data: Array<number>;
+  modifiersTreeCapacity: number;
+  mergedWeightedStakeDiffs: Array<MergedWeightedStakeDiffArgs>;
 };
 
 export function getMiningAccountDataSerializer(): Serializer<
@@ -63,13 +71,17 @@ export function getMiningAccountDataSerializer(): Serializer<
     [
       ['rewardPool', publicKeySerializer()],
       ['owner', publicKeySerializer()],
-      ['rewardMint', publicKeySerializer()],
-      ['indexWithPrecision', u128()],
+      ['indexWithPrecision', array(u128(), { size: 3 })],
       ['share', u64()],
-      ['unclaimedRewards', u64()],
+      ['unclaimedRewards', array(u64(), { size: 3 })],
       ['stakeFromOthers', u64()],
       ['bump', u8()],
-      ['data', array(u8(), { size: 7 })],
+      ['data', array(u8(), { size: 5 })],
+      ['modifiersTreeCapacity', u16()],
+      [
+        'mergedWeightedStakeDiffs',
+        array(getMergedWeightedStakeDiffSerializer(), { size: 4 })
+      ],
     ],
     { description: 'MiningAccountData' }
   ) as Serializer<MiningAccountDataArgs, MiningAccountData>;
@@ -144,27 +156,32 @@ export function getMiningGpaBuilder(
     .registerFields<{
       rewardPool: PublicKey;
       owner: PublicKey;
-      rewardMint: PublicKey;
-      indexWithPrecision: number | bigint;
+      indexWithPrecision: Array<number | bigint>;
       share: number | bigint;
-      unclaimedRewards: number | bigint;
+      unclaimedRewards: Array<number | bigint>;
       stakeFromOthers: number | bigint;
       bump: number;
       data: Array<number>;
+      modifiersTreeCapacity: number;
+      mergedWeightedStakeDiffs: Array<MergedWeightedStakeDiffArgs>;
     }>({
       rewardPool: [0, publicKeySerializer()],
       owner: [32, publicKeySerializer()],
-      rewardMint: [64, publicKeySerializer()],
-      indexWithPrecision: [96, u128()],
+      indexWithPrecision: [64, array(u128(), { size: 3 })],
       share: [112, u64()],
-      unclaimedRewards: [120, u64()],
-      stakeFromOthers: [128, u64()],
-      bump: [136, u8()],
-      data: [137, array(u8(), { size: 7 })],
+      unclaimedRewards: [120, array(u64(), { size: 3 })],
+      stakeFromOthers: [144, u64()],
+      bump: [152, u8()],
+      data: [153, array(u8(), { size: 5 })],
+      modifiersTreeCapacity: [158, u16()],
+      mergedWeightedStakeDiffs: [
+        160,
+        array(getMergedWeightedStakeDiffSerializer(), { size: 4 }),
+      ],
     })
     .deserializeUsing<Mining>((account) => deserializeMining(account));
 }
 
 export function getMiningSize(): number {
-  return 144;
+  return 256;
 }
@@ -24,10 +24,17 @@ import {
   array,
   publicKey as publicKeySerializer,
   struct,
-  u128,
   u64,
   u8,
 } from '@trezoaplex-foundation/umi/serializers';
+import {
+  LockupTier,
+  LockupTierArgs,
+  RewardToken,
+  RewardTokenArgs,
+  getLockupTierSerializer,
+  getRewardTokenSerializer,
+} from '../types';
 
 export type RewardPool = Account<RewardPoolAccountData>;
 
@@ -35,26 +42,68 @@ export type RewardPoolAccountData = {
   depositAuthority: PublicKey;
   distributeAuthority: PublicKey;
   fillAuthority: PublicKey;
-  rewardMint: PublicKey;
-  indexWithPrecision: bigint;
+  rewardTokens: Array<RewardToken>;
   totalShare: bigint;
-  distributionEndsAt: bigint;
-  tokensAvailableForDistribution: bigint;
-  tokenAccountBump: number;
   data: Array<number>;
+  admin: PublicKey;
+  lockupTiers: Array<LockupTier>;
+  epochLengthSecs: bigint;
+  epochOffsetSecs: bigint;
+  lastAccruedAt: bigint;
+  distributionMode: number;
+  permissionlessDistribution: number;
+  crankBountyKind: number;
+  pausedOperations: number;
+  vaultAuthorityMigrated: number;
+  vaultAuthorityBump: number;
+  directClaims: number;
+  emissionCurveKind: number;
+  crankBountyValue: bigint;
+  lastDistributionAt: bigint;
+  pendingAuthorities: Array<PublicKey>;
+  guardian: PublicKey;
+  openMinings: bigint;
+  vestingCliffSecs: bigint;
+  vestingDurationSecs: bigint;
+  emissionCurveEpochs: bigint;
+  miningsCheckpoint: bigint;
+  pendingMiningsCheckpoint: bigint;
+  miningsBehindCheckpoint: bigint;
+  vestingRewards: Array<bigint>;
 };
 
 export type RewardPoolAccountDataArgs = {
   depositAuthority: PublicKey;
   distributeAuthority: PublicKey;
   fillAuthority: PublicKey;
-  rewardMint: PublicKey;
-  indexWithPrecision: number | bigint;
+  rewardTokens: Array<RewardTokenArgs>;
   totalShare: number | bigint;
-  distributionEndsAt: number | bigint;
-  tokensAvailableForDistribution: number | bigint;
-  tokenAccountBump: number;
   data: Array<number>;
+  admin: PublicKey;
+  lockupTiers: Array<LockupTierArgs>;
+  epochLengthSecs: number | bigint;
+  epochOffsetSecs: number | bigint;
+  lastAccruedAt: number | bigint;
+  distributionMode: number;
+  permissionlessDistribution: number;
+  crankBountyKind: number;
+  pausedOperations: number;
+  vaultAuthorityMigrated: number;
+  vaultAuthorityBump: number;
+  directClaims: number;
+  emissionCurveKind: number;
+  crankBountyValue: number | bigint;
+  lastDistributionAt: number | bigint;
+  pendingAuthorities: Array<PublicKey>;
+  guardian: PublicKey;
+  openMinings: number | bigint;
+  vestingCliffSecs: number | bigint;
+  vestingDurationSecs: number | bigint;
+  emissionCurveEpochs: number | bigint;
+  miningsCheckpoint: number | bigint;
+  pendingMiningsCheckpoint: number | bigint;
+  miningsBehindCheckpoint: number | bigint;
+  vestingRewards: Array<number | bigint>;
 };
 
 export function getRewardPoolAccountDataSerializer(): Serializer<
@@ -66,13 +115,34 @@ export function getRewardPoolAccountDataSerializer(): Serializer<
       ['depositAuthority', publicKeySerializer()],
       ['distributeAuthority', publicKeySerializer()],
       ['fillAuthority', publicKeySerializer()],
-      ['rewardMint', publicKeySerializer()],
-      ['indexWithPrecision', u128()],
+      ['rewardTokens', array(getRewardTokenSerializer(), { size: 3 })],
       ['totalShare', u64()],
-      ['distributionEndsAt', u64()],
-      ['tokensAvailableForDistribution', u64()],
-      ['tokenAccountBump', u8()],
-      ['data', array(u8(), { size: 7 })],
+      ['data', array(u8(), { size: 8 })],
+      ['admin', publicKeySerializer()],
+      ['lockupTiers', array(getLockupTierSerializer(), { size: 8 })],
+      ['epochLengthSecs', u64()],
+      ['epochOffsetSecs', u64()],
+      ['lastAccruedAt', u64()],
+      ['distributionMode', u8()],
+      ['permissionlessDistribution', u8()],
+      ['crankBountyKind', u8()],
+      ['pausedOperations', u8()],
+      ['vaultAuthorityMigrated', u8()],
+      ['vaultAuthorityBump', u8()],
+      ['directClaims', u8()],
+      ['emissionCurveKind', u8()],
+      ['crankBountyValue', u64()],
+      ['lastDistributionAt', u64()],
+      ['pendingAuthorities', array(publicKeySerializer(), { size: 5 })],
+      ['guardian', publicKeySerializer()],
+      ['openMinings', u64()],
+      ['vestingCliffSecs', u64()],
+      ['vestingDurationSecs', u64()],
+      ['emissionCurveEpochs', u64()],
+      ['miningsCheckpoint', u64()],
+      ['pendingMiningsCheckpoint', u64()],
+      ['miningsBehindCheckpoint', u64()],
+      ['vestingRewards', array(u64(), { size: 3 })],
     ],
     { description: 'RewardPoolAccountData' }
   ) as Serializer<RewardPoolAccountDataArgs, RewardPoolAccountData>;
@@ -148,28 +218,70 @@ export function getRewardPoolGpaBuilder(
       depositAuthority: PublicKey;
       distributeAuthority: PublicKey;
       fillAuthority: PublicKey;
-      rewardMint: PublicKey;
-      indexWithPrecision: number | bigint;
+      rewardTokens: Array<RewardTokenArgs>;
       totalShare: number | bigint;
-      distributionEndsAt: number | bigint;
-      tokensAvailableForDistribution: number | bigint;
-      tokenAccountBump: number;
       data: Array<number>;
+      admin: PublicKey;
+      lockupTiers: Array<LockupTierArgs>;
+      epochLengthSecs: number | bigint;
+      epochOffsetSecs: number | bigint;
+      lastAccruedAt: number | bigint;
+      distributionMode: number;
+      permissionlessDistribution: number;
+      crankBountyKind: number;
+      pausedOperations: number;
+      vaultAuthorityMigrated: number;
+      vaultAuthorityBump: number;
+      directClaims: number;
+      emissionCurveKind: number;
+      crankBountyValue: number | bigint;
+      lastDistributionAt: number | bigint;
+      pendingAuthorities: Array<PublicKey>;
+      guardian: PublicKey;
+      openMinings: number | bigint;
+      vestingCliffSecs: number | bigint;
+      vestingDurationSecs: number | bigint;
+      emissionCurveEpochs: number | bigint;
+      miningsCheckpoint: number | bigint;
+      pendingMiningsCheckpoint: number | bigint;
+      miningsBehindCheckpoint: number | bigint;
+      vestingRewards: Array<number | bigint>;
     }>({
       depositAuthority: [0, publicKeySerializer()],
       distributeAuthority: [32, publicKeySerializer()],
       fillAuthority: [64, publicKeySerializer()],
-      rewardMint: [96, publicKeySerializer()],
-      indexWithPrecision: [128, u128()],
-      totalShare: [144, u64()],
-      distributionEndsAt: [152, u64()],
-      tokensAvailableForDistribution: [160, u64()],
-      tokenAccountBump: [168, u8()],
-      data: [169, array(u8(), { size: 7 })],
+      rewardTokens: [96, array(getRewardTokenSerializer(), { size: 3 })],
+      totalShare: [336, u64()],
+      data: [344, array(u8(), { size: 8 })],
+      admin: [352, publicKeySerializer()],
+      lockupTiers: [384, array(getLockupTierSerializer(), { size: 8 })],
+      epochLengthSecs: [576, u64()],
+      epochOffsetSecs: [584, u64()],
+      lastAccruedAt: [592, u64()],
+      distributionMode: [600, u8()],
+      permissionlessDistribution: [601, u8()],
+      crankBountyKind: [602, u8()],
+      pausedOperations: [603, u8()],
+      vaultAuthorityMigrated: [604, u8()],
+      vaultAuthorityBump: [605, u8()],
+      directClaims: [606, u8()],
+      emissionCurveKind: [607, u8()],
+      crankBountyValue: [608, u64()],
+      lastDistributionAt: [616, u64()],
+      pendingAuthorities: [624, array(publicKeySerializer(), { size: 5 })],
+      guardian: [784, publicKeySerializer()],
+      openMinings: [816, u64()],
+      vestingCliffSecs: [824, u64()],
+      vestingDurationSecs: [832, u64()],
+      emissionCurveEpochs: [840, u64()],
+      miningsCheckpoint: [848, u64()],
+      pendingMiningsCheckpoint: [856, u64()],
+      miningsBehindCheckpoint: [864, u64()],
+      vestingRewards: [872, array(u64(), { size: 3 })],
     })
     .deserializeUsing<RewardPool>((account) => deserializeRewardPool(account));
 }
 
 export function getRewardPoolSize(): number {
-  return 176;
+  return 896;
 }
@@ -0,0 +1,164 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  VestingTranche,
+  VestingTrancheArgs,
+  getVestingTrancheSerializer,
+} from '../types';
+
+export type Vesting = Account<VestingAccountData>;
+
+export type VestingAccountData = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  owner: PublicKey;
+  unlockedAmounts: Array<bigint>;
+  tranches: Array<VestingTranche>;
+  bump: number;
+  data: Array<number>;
+};
+
+export type VestingAccountDataArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  owner: PublicKey;
+  unlockedAmounts: Array<number | bigint>;
+  tranches: Array<VestingTrancheArgs>;
+  bump: number;
+  data: Array<number>;
+};
+
+export function getVestingAccountDataSerializer(): Serializer<
+  VestingAccountDataArgs,
+  VestingAccountData
+> {
+  return struct<VestingAccountData>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['owner', publicKeySerializer()],
+      ['unlockedAmounts', array(u64(), { size: 3 })],
+      ['tranches', array(getVestingTrancheSerializer(), { size: 8 })],
+      ['bump', u8()],
+      ['data', array(u8(), { size: 7 })],
+    ],
+    { description: 'VestingAccountData' }
+  ) as Serializer<VestingAccountDataArgs, VestingAccountData>;
+}
+
+export function deserializeVesting(rawAccount: RpcAccount): Vesting {
+  return deserializeAccount(rawAccount, getVestingAccountDataSerializer());
+}
+
+export async function fetchVesting(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Vesting> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'Vesting');
+  return deserializeVesting(maybeAccount);
+}
+
+export async function safeFetchVesting(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<Vesting | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeVesting(maybeAccount) : null;
+}
+
+export async function fetchAllVesting(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Vesting[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'Vesting');
+    return deserializeVesting(maybeAccount);
+  });
+}
+
+export async function safeFetchAllVesting(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<Vesting[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeVesting(maybeAccount as RpcAccount));
+}
+
+export function getVestingGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      rewardPool: PublicKey;
+      mining: PublicKey;
+      owner: PublicKey;
+      unlockedAmounts: Array<number | bigint>;
+      tranches: Array<VestingTrancheArgs>;
+      bump: number;
+      data: Array<number>;
+    }>({
+      rewardPool: [0, publicKeySerializer()],
+      mining: [32, publicKeySerializer()],
+      owner: [64, publicKeySerializer()],
+      unlockedAmounts: [96, array(u64(), { size: 3 })],
+      tranches: [120, array(getVestingTrancheSerializer(), { size: 8 })],
+      bump: [696, u8()],
+      data: [697, array(u8(), { size: 7 })],
+    })
+    .deserializeUsing<Vesting>((account) => deserializeVesting(account));
+}
+
+export function getVestingSize(): number {
+  return 704;
+}
@@ -255,6 +255,396 @@ export class DecreaseRewardsTooBigError extends ProgramError {
 codeToErrorMap.set(0xf, DecreaseRewardsTooBigError);
 nameToErrorMap.set('DecreaseRewardsTooBig', DecreaseRewardsTooBigError);
 
+/** LockupTierIsDisabled: Rewards: lockup tier is disabled */
+export class LockupTierIsDisabledError extends ProgramError {
+  override readonly name: string = 'LockupTierIsDisabled';
+
+  readonly code: number = 0x10; // 16
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: lockup tier is disabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x10, LockupTierIsDisabledError);
+nameToErrorMap.set('LockupTierIsDisabled', LockupTierIsDisabledError);
+
+/** InvalidLockupTier: Rewards: lockup tier is invalid */
+export class InvalidLockupTierError extends ProgramError {
+  override readonly name: string = 'InvalidLockupTier';
+
+  readonly code: number = 0x11; // 17
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: lockup tier is invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x11, InvalidLockupTierError);
+nameToErrorMap.set('InvalidLockupTier', InvalidLockupTierError);
+
+/** LockupTierIsImmutable: Rewards: lockup tier duration and multiplier can't be changed once configured */
+export class LockupTierIsImmutableError extends ProgramError {
+  override readonly name: string = 'LockupTierIsImmutable';
+
+  readonly code: number = 0x12; // 18
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Rewards: lockup tier duration and multiplier can't be changed once configured",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x12, LockupTierIsImmutableError);
+nameToErrorMap.set('LockupTierIsImmutable', LockupTierIsImmutableError);
+
+/** UnknownRewardMint: Rewards: the mint isn't one of the pool's reward tokens */
+export class UnknownRewardMintError extends ProgramError {
+  override readonly name: string = 'UnknownRewardMint';
+
+  readonly code: number = 0x13; // 19
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Rewards: the mint isn't one of the pool's reward tokens",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x13, UnknownRewardMintError);
+nameToErrorMap.set('UnknownRewardMint', UnknownRewardMintError);
+
+/** RewardMintAlreadyAdded: Rewards: the mint has already been added to the pool */
+export class RewardMintAlreadyAddedError extends ProgramError {
+  override readonly name: string = 'RewardMintAlreadyAdded';
+
+  readonly code: number = 0x14; // 20
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: the mint has already been added to the pool',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x14, RewardMintAlreadyAddedError);
+nameToErrorMap.set('RewardMintAlreadyAdded', RewardMintAlreadyAddedError);
+
+/** RewardTokensLimitReached: Rewards: the pool distributes max number of reward tokens already */
+export class RewardTokensLimitReachedError extends ProgramError {
+  override readonly name: string = 'RewardTokensLimitReached';
+
+  readonly code: number = 0x15; // 21
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: the pool distributes max number of reward tokens already',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x15, RewardTokensLimitReachedError);
+nameToErrorMap.set('RewardTokensLimitReached', RewardTokensLimitReachedError);
+
+/** IndexHistoryIsFull: Rewards: index history is full, it has to be compacted */
+export class IndexHistoryIsFullError extends ProgramError {
+  override readonly name: string = 'IndexHistoryIsFull';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: index history is full, it has to be compacted',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x16, IndexHistoryIsFullError);
+nameToErrorMap.set('IndexHistoryIsFull', IndexHistoryIsFullError);
+
+/** PoolModifiersTreeIsFull: Rewards: pool's weighted stake modifiers tree is full */
+export class PoolModifiersTreeIsFullError extends ProgramError {
+  override readonly name: string = 'PoolModifiersTreeIsFull';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Rewards: pool's weighted stake modifiers tree is full",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17, PoolModifiersTreeIsFullError);
+nameToErrorMap.set('PoolModifiersTreeIsFull', PoolModifiersTreeIsFullError);
+
+/** MiningModifiersTreeIsFull: Rewards: mining's weighted stake modifiers tree is full */
+export class MiningModifiersTreeIsFullError extends ProgramError {
+  override readonly name: string = 'MiningModifiersTreeIsFull';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Rewards: mining's weighted stake modifiers tree is full",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x18, MiningModifiersTreeIsFullError);
+nameToErrorMap.set('MiningModifiersTreeIsFull', MiningModifiersTreeIsFullError);
+
+/** InvalidModifiersTreeCapacity: Rewards: unsupported mining's weighted stake modifiers tree capacity */
+export class InvalidModifiersTreeCapacityError extends ProgramError {
+  override readonly name: string = 'InvalidModifiersTreeCapacity';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Rewards: unsupported mining's weighted stake modifiers tree capacity",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x19, InvalidModifiersTreeCapacityError);
+nameToErrorMap.set(
+  'InvalidModifiersTreeCapacity',
+  InvalidModifiersTreeCapacityError
+);
+
+/** InvalidEpoch: Rewards: invalid distribution epoch configuration */
+export class InvalidEpochError extends ProgramError {
+  override readonly name: string = 'InvalidEpoch';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: invalid distribution epoch configuration', program, cause);
+  }
+}
+codeToErrorMap.set(0x1a, InvalidEpochError);
+nameToErrorMap.set('InvalidEpoch', InvalidEpochError);
+
+/** InvalidCrankBounty: Rewards: invalid crank bounty */
+export class InvalidCrankBountyError extends ProgramError {
+  override readonly name: string = 'InvalidCrankBounty';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: invalid crank bounty', program, cause);
+  }
+}
+codeToErrorMap.set(0x1b, InvalidCrankBountyError);
+nameToErrorMap.set('InvalidCrankBounty', InvalidCrankBountyError);
+
+/** NoPendingAuthority: Rewards: no such pending authority */
+export class NoPendingAuthorityError extends ProgramError {
+  override readonly name: string = 'NoPendingAuthority';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: no such pending authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x1c, NoPendingAuthorityError);
+nameToErrorMap.set('NoPendingAuthority', NoPendingAuthorityError);
+
+/** PoolPaused: Rewards: the operation is paused for the pool */
+export class PoolPausedError extends ProgramError {
+  override readonly name: string = 'PoolPaused';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: the operation is paused for the pool', program, cause);
+  }
+}
+codeToErrorMap.set(0x1d, PoolPausedError);
+nameToErrorMap.set('PoolPaused', PoolPausedError);
+
+/** NotEnoughUndistributedRewards: Rewards: not enough undistributed rewards in the vault */
+export class NotEnoughUndistributedRewardsError extends ProgramError {
+  override readonly name: string = 'NotEnoughUndistributedRewards';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: not enough undistributed rewards in the vault',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1e, NotEnoughUndistributedRewardsError);
+nameToErrorMap.set(
+  'NotEnoughUndistributedRewards',
+  NotEnoughUndistributedRewardsError
+);
+
+/** PoolIsInUse: Rewards: the pool is still in use and cannot be closed */
+export class PoolIsInUseError extends ProgramError {
+  override readonly name: string = 'PoolIsInUse';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: the pool is still in use and cannot be closed',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1f, PoolIsInUseError);
+nameToErrorMap.set('PoolIsInUse', PoolIsInUseError);
+
+/** VaultAuthorityNotMigrated: Rewards: vault authority is not migrated to the PDA */
+export class VaultAuthorityNotMigratedError extends ProgramError {
+  override readonly name: string = 'VaultAuthorityNotMigrated';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: vault authority is not migrated to the PDA',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x20, VaultAuthorityNotMigratedError);
+nameToErrorMap.set('VaultAuthorityNotMigrated', VaultAuthorityNotMigratedError);
+
+/** ClaimAmountExceedsRewards: Rewards: claim amount exceeds unclaimed rewards */
+export class ClaimAmountExceedsRewardsError extends ProgramError {
+  override readonly name: string = 'ClaimAmountExceedsRewards';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: claim amount exceeds unclaimed rewards', program, cause);
+  }
+}
+codeToErrorMap.set(0x21, ClaimAmountExceedsRewardsError);
+nameToErrorMap.set('ClaimAmountExceedsRewards', ClaimAmountExceedsRewardsError);
+
+/** InvalidVestingSchedule: Rewards: invalid vesting schedule */
+export class InvalidVestingScheduleError extends ProgramError {
+  override readonly name: string = 'InvalidVestingSchedule';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: invalid vesting schedule', program, cause);
+  }
+}
+codeToErrorMap.set(0x22, InvalidVestingScheduleError);
+nameToErrorMap.set('InvalidVestingSchedule', InvalidVestingScheduleError);
+
+/** InvalidCommission: Rewards: invalid delegate commission */
+export class InvalidCommissionError extends ProgramError {
+  override readonly name: string = 'InvalidCommission';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: invalid delegate commission', program, cause);
+  }
+}
+codeToErrorMap.set(0x23, InvalidCommissionError);
+nameToErrorMap.set('InvalidCommission', InvalidCommissionError);
+
+/** DelegationAccountsMissing: Rewards: delegate and delegation accounts are missing */
+export class DelegationAccountsMissingError extends ProgramError {
+  override readonly name: string = 'DelegationAccountsMissing';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: delegate and delegation accounts are missing',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x24, DelegationAccountsMissingError);
+nameToErrorMap.set('DelegationAccountsMissing', DelegationAccountsMissingError);
+
+/** InvalidEmissionCurve: Rewards: invalid emission curve */
+export class InvalidEmissionCurveError extends ProgramError {
+  override readonly name: string = 'InvalidEmissionCurve';
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: invalid emission curve', program, cause);
+  }
+}
+codeToErrorMap.set(0x25, InvalidEmissionCurveError);
+nameToErrorMap.set('InvalidEmissionCurve', InvalidEmissionCurveError);
+
+/** NotLegacyAccount: Rewards: the account isn't stored in the legacy layout */
+export class NotLegacyAccountError extends ProgramError {
+  override readonly name: string = 'NotLegacyAccount';
+
+  readonly code: number = 0x26; // 38
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      "Rewards: the account isn't stored in the legacy layout",
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x26, NotLegacyAccountError);
+nameToErrorMap.set('NotLegacyAccount', NotLegacyAccountError);
+
+/** MiningsCheckpointNotReached: Rewards: not every mining has been refreshed since the date */
+export class MiningsCheckpointNotReachedError extends ProgramError {
+  override readonly name: string = 'MiningsCheckpointNotReached';
+
+  readonly code: number = 0x27; // 39
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Rewards: not every mining has been refreshed since the date',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x27, MiningsCheckpointNotReachedError);
+nameToErrorMap.set(
+  'MiningsCheckpointNotReached',
+  MiningsCheckpointNotReachedError
+);
+
+/** VestingTranchesAreFull: Rewards: no room in the vesting for one more claim */
+export class VestingTranchesAreFullError extends ProgramError {
+  override readonly name: string = 'VestingTranchesAreFull';
+
+  readonly code: number = 0x28; // 40
+
+  constructor(program: Program, cause?: Error) {
+    super('Rewards: no room in the vesting for one more claim', program, cause);
+  }
+}
+codeToErrorMap.set(0x28, VestingTranchesAreFullError);
+nameToErrorMap.set('VestingTranchesAreFull', VestingTranchesAreFullError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -0,0 +1,127 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AuthorityRole,
+  AuthorityRoleArgs,
+  getAuthorityRoleSerializer,
+} from '../types';
+
+// Accounts.
+export type AcceptAuthorityInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The proposed authority */
+  newAuthority: Signer;
+};
+
+// Data.
+export type AcceptAuthorityInstructionData = {
+  discriminator: number;
+  role: AuthorityRole;
+};
+
+export type AcceptAuthorityInstructionDataArgs = { role: AuthorityRoleArgs };
+
+export function getAcceptAuthorityInstructionDataSerializer(): Serializer<
+  AcceptAuthorityInstructionDataArgs,
+  AcceptAuthorityInstructionData
+> {
+  return mapSerializer<
+    AcceptAuthorityInstructionDataArgs,
+    any,
+    AcceptAuthorityInstructionData
+  >(
+    struct<AcceptAuthorityInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['role', getAuthorityRoleSerializer()],
+      ],
+      { description: 'AcceptAuthorityInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 18 })
+  ) as Serializer<
+    AcceptAuthorityInstructionDataArgs,
+    AcceptAuthorityInstructionData
+  >;
+}
+
+// Args.
+export type AcceptAuthorityInstructionArgs = AcceptAuthorityInstructionDataArgs;
+
+// Instruction.
+export function acceptAuthority(
+  context: Pick<Context, 'programs'>,
+  input: AcceptAuthorityInstructionAccounts & AcceptAuthorityInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    newAuthority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.newAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AcceptAuthorityInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAcceptAuthorityInstructionDataSerializer().serialize(
+    resolvedArgs as AcceptAuthorityInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddRewardMintInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the reward mint */
+  rewardMint: PublicKey | Pda;
+  /** The address of the reward vault */
+  vault: PublicKey | Pda;
+  payer?: Signer;
+  /** Owner of the new vault: the deposit authority, or the vault authority PDA once the vaults are migrated */
+  depositAuthority: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+  /** The address of the Rent program */
+  rent?: PublicKey | Pda;
+  /** The address of the Token program where rewards are minted */
+  tokenProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddRewardMintInstructionData = { discriminator: number };
+
+export type AddRewardMintInstructionDataArgs = {};
+
+export function getAddRewardMintInstructionDataSerializer(): Serializer<
+  AddRewardMintInstructionDataArgs,
+  AddRewardMintInstructionData
+> {
+  return mapSerializer<
+    AddRewardMintInstructionDataArgs,
+    any,
+    AddRewardMintInstructionData
+  >(
+    struct<AddRewardMintInstructionData>([['discriminator', u8()]], {
+      description: 'AddRewardMintInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 13 })
+  ) as Serializer<
+    AddRewardMintInstructionDataArgs,
+    AddRewardMintInstructionData
+  >;
+}
+
+// Instruction.
+export function addRewardMint(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: AddRewardMintInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    rewardMint: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.rewardMint ?? null,
+    },
+    vault: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    depositAuthority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.depositAuthority ?? null,
+    },
+    admin: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+    rent: { index: 6, isWritable: false as boolean, value: input.rent ?? null },
+    tokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.rent.value) {
+    resolvedAccounts.rent.value = publicKey(
+      'SysvarRent111111111111111111111111111111111'
+    );
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddRewardMintInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
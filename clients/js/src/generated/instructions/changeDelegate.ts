@@ -42,6 +42,14 @@ export type ChangeDelegateInstructionAccounts = {
   oldDelegateMining: PublicKey | Pda;
   /** The address of the new delegate mining account */
   newDelegateMining: PublicKey | Pda;
+  /** The delegate account of the old delegate mining, required if the old delegate charges a commission */
+  oldDelegate?: PublicKey | Pda;
+  /** The delegation of the mining to the old delegate, required if the old delegate charges a commission */
+  oldDelegation?: PublicKey | Pda;
+  /** The delegate account of the new delegate mining, required if the new delegate charges a commission */
+  newDelegate?: PublicKey | Pda;
+  /** The delegation of the mining to the new delegate, required if the new delegate charges a commission */
+  newDelegation?: PublicKey | Pda;
 };
 
 // Data.
@@ -126,6 +134,26 @@ export function changeDelegate(
       isWritable: true as boolean,
       value: input.newDelegateMining ?? null,
     },
+    oldDelegate: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.oldDelegate ?? null,
+    },
+    oldDelegation: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.oldDelegation ?? null,
+    },
+    newDelegate: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.newDelegate ?? null,
+    },
+    newDelegation: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.newDelegation ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -139,7 +167,7 @@ export function changeDelegate(
   // Keys and Signers.
   const [keys, signers] = getAccountMetasAndSigners(
     orderedAccounts,
-    'programId',
+    'omitted',
     programId
   );
 
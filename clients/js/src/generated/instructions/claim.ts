@@ -38,12 +38,16 @@ export type ClaimInstructionAccounts = {
   mining: PublicKey | Pda;
   /** The end user the mining accounts belongs to */
   miningOwner: Signer;
-  /** The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs */
-  depositAuthority: Signer;
+  /** The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed */
+  depositAuthority: PublicKey | Pda | Signer;
   /** ATA where tokens will be claimed to */
   miningOwnerRewardTokenAccount: PublicKey | Pda;
   /** The address of the Token program where rewards are minted */
   tokenProgram?: PublicKey | Pda;
+  /** The vault authority PDA, required once the vaults are migrated to it */
+  vaultAuthority?: PublicKey | Pda;
+  /** The vesting account of the mining, required if the pool vests claimed rewards */
+  vesting?: PublicKey | Pda;
 };
 
 // Data.
@@ -78,7 +82,7 @@ export function claim(
   const resolvedAccounts = {
     rewardPool: {
       index: 0,
-      isWritable: false as boolean,
+      isWritable: true as boolean,
       value: input.rewardPool ?? null,
     },
     rewardMint: {
@@ -116,6 +120,16 @@ export function claim(
       isWritable: false as boolean,
       value: input.tokenProgram ?? null,
     },
+    vaultAuthority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.vaultAuthority ?? null,
+    },
+    vesting: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.vesting ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -135,7 +149,7 @@ export function claim(
   // Keys and Signers.
   const [keys, signers] = getAccountMetasAndSigners(
     orderedAccounts,
-    'programId',
+    'omitted',
     programId
   );
 
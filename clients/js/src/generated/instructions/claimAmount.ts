@@ -0,0 +1,185 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ClaimAmountInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the reward mint */
+  rewardMint: PublicKey | Pda;
+  /** The address of the reward vault */
+  vault: PublicKey | Pda;
+  /** The address of the mining account which belongs to the user and stores info about user's rewards */
+  mining: PublicKey | Pda;
+  /** The end user the mining accounts belongs to */
+  miningOwner: Signer;
+  /** The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed */
+  depositAuthority: PublicKey | Pda | Signer;
+  /** ATA where tokens will be claimed to */
+  miningOwnerRewardTokenAccount: PublicKey | Pda;
+  /** The address of the Token program where rewards are minted */
+  tokenProgram?: PublicKey | Pda;
+  /** The vault authority PDA, required once the vaults are migrated to it */
+  vaultAuthority?: PublicKey | Pda;
+  /** The vesting account of the mining, required if the pool vests claimed rewards */
+  vesting?: PublicKey | Pda;
+};
+
+// Data.
+export type ClaimAmountInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type ClaimAmountInstructionDataArgs = { amount: number | bigint };
+
+export function getClaimAmountInstructionDataSerializer(): Serializer<
+  ClaimAmountInstructionDataArgs,
+  ClaimAmountInstructionData
+> {
+  return mapSerializer<
+    ClaimAmountInstructionDataArgs,
+    any,
+    ClaimAmountInstructionData
+  >(
+    struct<ClaimAmountInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['amount', u64()],
+      ],
+      { description: 'ClaimAmountInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 24 })
+  ) as Serializer<ClaimAmountInstructionDataArgs, ClaimAmountInstructionData>;
+}
+
+// Args.
+export type ClaimAmountInstructionArgs = ClaimAmountInstructionDataArgs;
+
+// Instruction.
+export function claimAmount(
+  context: Pick<Context, 'programs'>,
+  input: ClaimAmountInstructionAccounts & ClaimAmountInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    rewardMint: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.rewardMint ?? null,
+    },
+    vault: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    mining: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.mining ?? null,
+    },
+    miningOwner: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.miningOwner ?? null,
+    },
+    depositAuthority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.depositAuthority ?? null,
+    },
+    miningOwnerRewardTokenAccount: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.miningOwnerRewardTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    vaultAuthority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.vaultAuthority ?? null,
+    },
+    vesting: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.vesting ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ClaimAmountInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'omitted',
+    programId
+  );
+
+  // Data.
+  const data = getClaimAmountInstructionDataSerializer().serialize(
+    resolvedArgs as ClaimAmountInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
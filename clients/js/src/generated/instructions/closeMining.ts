@@ -37,6 +37,8 @@ export type CloseMiningInstructionAccounts = {
   depositAuthority: Signer;
   /** The address of the reward pool */
   rewardPool: PublicKey | Pda;
+  /** The address of the delegate account of the mining, it doesn't exist unless the mining charges a commission */
+  delegate: PublicKey | Pda;
 };
 
 // Data.
@@ -98,6 +100,11 @@ export function closeMining(
       isWritable: true as boolean,
       value: input.rewardPool ?? null,
     },
+    delegate: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.delegate ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Accounts in order.
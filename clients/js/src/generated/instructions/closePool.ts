@@ -0,0 +1,134 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ClosePoolInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+  /** The deposit authority, or the vault authority PDA once the vaults are migrated to it */
+  vaultOwner: PublicKey | Pda | Signer;
+  /** The address where lamports of the pool and the vaults will be transferred to */
+  targetAccount: PublicKey | Pda;
+  /** The address of the Token program where rewards are minted */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ClosePoolInstructionData = { discriminator: number };
+
+export type ClosePoolInstructionDataArgs = {};
+
+export function getClosePoolInstructionDataSerializer(): Serializer<
+  ClosePoolInstructionDataArgs,
+  ClosePoolInstructionData
+> {
+  return mapSerializer<
+    ClosePoolInstructionDataArgs,
+    any,
+    ClosePoolInstructionData
+  >(
+    struct<ClosePoolInstructionData>([['discriminator', u8()]], {
+      description: 'ClosePoolInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 21 })
+  ) as Serializer<ClosePoolInstructionDataArgs, ClosePoolInstructionData>;
+}
+
+// Instruction.
+export function closePool(
+  context: Pick<Context, 'programs'>,
+  input: ClosePoolInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+    vaultOwner: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.vaultOwner ?? null,
+    },
+    targetAccount: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.targetAccount ?? null,
+    },
+    tokenProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getClosePoolInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
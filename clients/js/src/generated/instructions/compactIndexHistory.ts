@@ -0,0 +1,127 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CompactIndexHistoryInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of Authority who is eligble for distributiong rewards for users */
+  distributeAuthority: Signer;
+};
+
+// Data.
+export type CompactIndexHistoryInstructionData = {
+  discriminator: number;
+  compactBefore: bigint;
+};
+
+export type CompactIndexHistoryInstructionDataArgs = {
+  compactBefore: number | bigint;
+};
+
+export function getCompactIndexHistoryInstructionDataSerializer(): Serializer<
+  CompactIndexHistoryInstructionDataArgs,
+  CompactIndexHistoryInstructionData
+> {
+  return mapSerializer<
+    CompactIndexHistoryInstructionDataArgs,
+    any,
+    CompactIndexHistoryInstructionData
+  >(
+    struct<CompactIndexHistoryInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['compactBefore', u64()],
+      ],
+      { description: 'CompactIndexHistoryInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 14 })
+  ) as Serializer<
+    CompactIndexHistoryInstructionDataArgs,
+    CompactIndexHistoryInstructionData
+  >;
+}
+
+// Args.
+export type CompactIndexHistoryInstructionArgs =
+  CompactIndexHistoryInstructionDataArgs;
+
+// Instruction.
+export function compactIndexHistory(
+  context: Pick<Context, 'programs'>,
+  input: CompactIndexHistoryInstructionAccounts &
+    CompactIndexHistoryInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    distributeAuthority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.distributeAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CompactIndexHistoryInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCompactIndexHistoryInstructionDataSerializer().serialize(
+    resolvedArgs as CompactIndexHistoryInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
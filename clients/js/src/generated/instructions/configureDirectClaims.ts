@@ -0,0 +1,127 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ConfigureDirectClaimsInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+};
+
+// Data.
+export type ConfigureDirectClaimsInstructionData = {
+  discriminator: number;
+  directClaims: boolean;
+};
+
+export type ConfigureDirectClaimsInstructionDataArgs = {
+  directClaims: boolean;
+};
+
+export function getConfigureDirectClaimsInstructionDataSerializer(): Serializer<
+  ConfigureDirectClaimsInstructionDataArgs,
+  ConfigureDirectClaimsInstructionData
+> {
+  return mapSerializer<
+    ConfigureDirectClaimsInstructionDataArgs,
+    any,
+    ConfigureDirectClaimsInstructionData
+  >(
+    struct<ConfigureDirectClaimsInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['directClaims', bool()],
+      ],
+      { description: 'ConfigureDirectClaimsInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 23 })
+  ) as Serializer<
+    ConfigureDirectClaimsInstructionDataArgs,
+    ConfigureDirectClaimsInstructionData
+  >;
+}
+
+// Args.
+export type ConfigureDirectClaimsInstructionArgs =
+  ConfigureDirectClaimsInstructionDataArgs;
+
+// Instruction.
+export function configureDirectClaims(
+  context: Pick<Context, 'programs'>,
+  input: ConfigureDirectClaimsInstructionAccounts &
+    ConfigureDirectClaimsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ConfigureDirectClaimsInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getConfigureDirectClaimsInstructionDataSerializer().serialize(
+    resolvedArgs as ConfigureDirectClaimsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,135 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  CrankBounty,
+  CrankBountyArgs,
+  getCrankBountySerializer,
+} from '../types';
+
+// Accounts.
+export type ConfigureDistributionCrankInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+};
+
+// Data.
+export type ConfigureDistributionCrankInstructionData = {
+  discriminator: number;
+  permissionlessDistribution: boolean;
+  crankBounty: CrankBounty;
+};
+
+export type ConfigureDistributionCrankInstructionDataArgs = {
+  permissionlessDistribution: boolean;
+  crankBounty: CrankBountyArgs;
+};
+
+export function getConfigureDistributionCrankInstructionDataSerializer(): Serializer<
+  ConfigureDistributionCrankInstructionDataArgs,
+  ConfigureDistributionCrankInstructionData
+> {
+  return mapSerializer<
+    ConfigureDistributionCrankInstructionDataArgs,
+    any,
+    ConfigureDistributionCrankInstructionData
+  >(
+    struct<ConfigureDistributionCrankInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['permissionlessDistribution', bool()],
+        ['crankBounty', getCrankBountySerializer()],
+      ],
+      { description: 'ConfigureDistributionCrankInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 16 })
+  ) as Serializer<
+    ConfigureDistributionCrankInstructionDataArgs,
+    ConfigureDistributionCrankInstructionData
+  >;
+}
+
+// Args.
+export type ConfigureDistributionCrankInstructionArgs =
+  ConfigureDistributionCrankInstructionDataArgs;
+
+// Instruction.
+export function configureDistributionCrank(
+  context: Pick<Context, 'programs'>,
+  input: ConfigureDistributionCrankInstructionAccounts &
+    ConfigureDistributionCrankInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ConfigureDistributionCrankInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getConfigureDistributionCrankInstructionDataSerializer().serialize(
+    resolvedArgs as ConfigureDistributionCrankInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,131 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  EmissionCurve,
+  EmissionCurveArgs,
+  getEmissionCurveSerializer,
+} from '../types';
+
+// Accounts.
+export type ConfigureEmissionCurveInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+};
+
+// Data.
+export type ConfigureEmissionCurveInstructionData = {
+  discriminator: number;
+  emissionCurve: EmissionCurve;
+};
+
+export type ConfigureEmissionCurveInstructionDataArgs = {
+  emissionCurve: EmissionCurveArgs;
+};
+
+export function getConfigureEmissionCurveInstructionDataSerializer(): Serializer<
+  ConfigureEmissionCurveInstructionDataArgs,
+  ConfigureEmissionCurveInstructionData
+> {
+  return mapSerializer<
+    ConfigureEmissionCurveInstructionDataArgs,
+    any,
+    ConfigureEmissionCurveInstructionData
+  >(
+    struct<ConfigureEmissionCurveInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['emissionCurve', getEmissionCurveSerializer()],
+      ],
+      { description: 'ConfigureEmissionCurveInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 32 })
+  ) as Serializer<
+    ConfigureEmissionCurveInstructionDataArgs,
+    ConfigureEmissionCurveInstructionData
+  >;
+}
+
+// Args.
+export type ConfigureEmissionCurveInstructionArgs =
+  ConfigureEmissionCurveInstructionDataArgs;
+
+// Instruction.
+export function configureEmissionCurve(
+  context: Pick<Context, 'programs'>,
+  input: ConfigureEmissionCurveInstructionAccounts &
+    ConfigureEmissionCurveInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ConfigureEmissionCurveInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getConfigureEmissionCurveInstructionDataSerializer().serialize(
+    resolvedArgs as ConfigureEmissionCurveInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,130 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { LockupTier, LockupTierArgs, getLockupTierSerializer } from '../types';
+
+// Accounts.
+export type ConfigureLockupTierInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+};
+
+// Data.
+export type ConfigureLockupTierInstructionData = {
+  discriminator: number;
+  tierIndex: number;
+  lockupTier: LockupTier;
+};
+
+export type ConfigureLockupTierInstructionDataArgs = {
+  tierIndex: number;
+  lockupTier: LockupTierArgs;
+};
+
+export function getConfigureLockupTierInstructionDataSerializer(): Serializer<
+  ConfigureLockupTierInstructionDataArgs,
+  ConfigureLockupTierInstructionData
+> {
+  return mapSerializer<
+    ConfigureLockupTierInstructionDataArgs,
+    any,
+    ConfigureLockupTierInstructionData
+  >(
+    struct<ConfigureLockupTierInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['tierIndex', u8()],
+        ['lockupTier', getLockupTierSerializer()],
+      ],
+      { description: 'ConfigureLockupTierInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 12 })
+  ) as Serializer<
+    ConfigureLockupTierInstructionDataArgs,
+    ConfigureLockupTierInstructionData
+  >;
+}
+
+// Args.
+export type ConfigureLockupTierInstructionArgs =
+  ConfigureLockupTierInstructionDataArgs;
+
+// Instruction.
+export function configureLockupTier(
+  context: Pick<Context, 'programs'>,
+  input: ConfigureLockupTierInstructionAccounts &
+    ConfigureLockupTierInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ConfigureLockupTierInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getConfigureLockupTierInstructionDataSerializer().serialize(
+    resolvedArgs as ConfigureLockupTierInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
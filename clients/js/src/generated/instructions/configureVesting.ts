@@ -0,0 +1,129 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ConfigureVestingInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+};
+
+// Data.
+export type ConfigureVestingInstructionData = {
+  discriminator: number;
+  cliffSecs: bigint;
+  durationSecs: bigint;
+};
+
+export type ConfigureVestingInstructionDataArgs = {
+  cliffSecs: number | bigint;
+  durationSecs: number | bigint;
+};
+
+export function getConfigureVestingInstructionDataSerializer(): Serializer<
+  ConfigureVestingInstructionDataArgs,
+  ConfigureVestingInstructionData
+> {
+  return mapSerializer<
+    ConfigureVestingInstructionDataArgs,
+    any,
+    ConfigureVestingInstructionData
+  >(
+    struct<ConfigureVestingInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['cliffSecs', u64()],
+        ['durationSecs', u64()],
+      ],
+      { description: 'ConfigureVestingInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 25 })
+  ) as Serializer<
+    ConfigureVestingInstructionDataArgs,
+    ConfigureVestingInstructionData
+  >;
+}
+
+// Args.
+export type ConfigureVestingInstructionArgs =
+  ConfigureVestingInstructionDataArgs;
+
+// Instruction.
+export function configureVesting(
+  context: Pick<Context, 'programs'>,
+  input: ConfigureVestingInstructionAccounts & ConfigureVestingInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ConfigureVestingInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getConfigureVestingInstructionDataSerializer().serialize(
+    resolvedArgs as ConfigureVestingInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,172 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  option,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type DefundVaultInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the reward mint */
+  rewardMint: PublicKey | Pda;
+  /** The address of the reward vault */
+  vault: PublicKey | Pda;
+  /** The address of the wallet who is responsible for filling pool's vault with rewards */
+  fillAuthority: Signer;
+  /** The deposit authority, or the vault authority PDA once the vaults are migrated to it */
+  vaultOwner: PublicKey | Pda | Signer;
+  /** The address of the TA the tokens will be transferred to */
+  destinationTokenAccount: PublicKey | Pda;
+  /** The address of the Token program where rewards are minted */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type DefundVaultInstructionData = {
+  discriminator: number;
+  amount: bigint;
+  distributionEndsAt: Option<bigint>;
+};
+
+export type DefundVaultInstructionDataArgs = {
+  amount: number | bigint;
+  distributionEndsAt: OptionOrNullable<number | bigint>;
+};
+
+export function getDefundVaultInstructionDataSerializer(): Serializer<
+  DefundVaultInstructionDataArgs,
+  DefundVaultInstructionData
+> {
+  return mapSerializer<
+    DefundVaultInstructionDataArgs,
+    any,
+    DefundVaultInstructionData
+  >(
+    struct<DefundVaultInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['amount', u64()],
+        ['distributionEndsAt', option(u64())],
+      ],
+      { description: 'DefundVaultInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 20 })
+  ) as Serializer<DefundVaultInstructionDataArgs, DefundVaultInstructionData>;
+}
+
+// Args.
+export type DefundVaultInstructionArgs = DefundVaultInstructionDataArgs;
+
+// Instruction.
+export function defundVault(
+  context: Pick<Context, 'programs'>,
+  input: DefundVaultInstructionAccounts & DefundVaultInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    rewardMint: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.rewardMint ?? null,
+    },
+    vault: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    fillAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.fillAuthority ?? null,
+    },
+    vaultOwner: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.vaultOwner ?? null,
+    },
+    destinationTokenAccount: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.destinationTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: DefundVaultInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getDefundVaultInstructionDataSerializer().serialize(
+    resolvedArgs as DefundVaultInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
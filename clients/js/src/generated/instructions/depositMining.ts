@@ -43,6 +43,10 @@ export type DepositMiningInstructionAccounts = {
   depositAuthority: Signer;
   /** The address of Mining Account that might be used as a delegate in delegated staking model */
   delegateMining: PublicKey | Pda;
+  /** The delegate account of the delegate mining, required if the delegate charges a commission */
+  delegate?: PublicKey | Pda;
+  /** The delegation of the mining to the delegate, required if the delegate charges a commission */
+  delegation?: PublicKey | Pda;
 };
 
 // Data.
@@ -123,6 +127,16 @@ export function depositMining(
       isWritable: false as boolean,
       value: input.delegateMining ?? null,
     },
+    delegate: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.delegate ?? null,
+    },
+    delegation: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.delegation ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -136,7 +150,7 @@ export function depositMining(
   // Keys and Signers.
   const [keys, signers] = getAccountMetasAndSigners(
     orderedAccounts,
-    'programId',
+    'omitted',
     programId
   );
 
@@ -30,8 +30,10 @@ import {
 export type DistributeRewardsInstructionAccounts = {
   /** The address of the reward pool */
   rewardPool: PublicKey | Pda;
-  /** The address of Authority who is eligble for distributiong rewards for users */
+  /** The address of Authority who is eligble for distributiong rewards for users, anybody if the distribution is permissionless */
   distributeAuthority: Signer;
+  /** The mining of the distributing wallet the crank bounty is credited to */
+  keeperMining?: PublicKey | Pda;
 };
 
 // Data.
@@ -81,6 +83,11 @@ export function distributeRewards(
       isWritable: false as boolean,
       value: input.distributeAuthority ?? null,
     },
+    keeperMining: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.keeperMining ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Accounts in order.
@@ -91,7 +98,7 @@ export function distributeRewards(
   // Keys and Signers.
   const [keys, signers] = getAccountMetasAndSigners(
     orderedAccounts,
-    'programId',
+    'omitted',
     programId
   );
 
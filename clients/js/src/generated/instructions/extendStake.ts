@@ -45,6 +45,10 @@ export type ExtendStakeInstructionAccounts = {
   depositAuthority: Signer;
   /** The address of Mining Account that might be used as a delegate in delegated staking model */
   delegateMining: PublicKey | Pda;
+  /** The delegate account of the delegate mining, required if the delegate charges a commission */
+  delegate?: PublicKey | Pda;
+  /** The delegation of the mining to the delegate, required if the delegate charges a commission */
+  delegation?: PublicKey | Pda;
 };
 
 // Data.
@@ -136,6 +140,16 @@ export function extendStake(
       isWritable: false as boolean,
       value: input.delegateMining ?? null,
     },
+    delegate: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.delegate ?? null,
+    },
+    delegation: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.delegation ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -149,7 +163,7 @@ export function extendStake(
   // Keys and Signers.
   const [keys, signers] = getAccountMetasAndSigners(
     orderedAccounts,
-    'programId',
+    'omitted',
     programId
   );
 
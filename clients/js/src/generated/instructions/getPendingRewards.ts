@@ -0,0 +1,106 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetPendingRewardsInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the mining account which belongs to the user and stores info about user's rewards */
+  mining: PublicKey | Pda;
+};
+
+// Data.
+export type GetPendingRewardsInstructionData = { discriminator: number };
+
+export type GetPendingRewardsInstructionDataArgs = {};
+
+export function getGetPendingRewardsInstructionDataSerializer(): Serializer<
+  GetPendingRewardsInstructionDataArgs,
+  GetPendingRewardsInstructionData
+> {
+  return mapSerializer<
+    GetPendingRewardsInstructionDataArgs,
+    any,
+    GetPendingRewardsInstructionData
+  >(
+    struct<GetPendingRewardsInstructionData>([['discriminator', u8()]], {
+      description: 'GetPendingRewardsInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 33 })
+  ) as Serializer<
+    GetPendingRewardsInstructionDataArgs,
+    GetPendingRewardsInstructionData
+  >;
+}
+
+// Instruction.
+export function getPendingRewards(
+  context: Pick<Context, 'programs'>,
+  input: GetPendingRewardsInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.rewardPool ?? null,
+    },
+    mining: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.mining ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetPendingRewardsInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
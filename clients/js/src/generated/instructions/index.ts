@@ -6,15 +6,39 @@
  * @see https://github.com/trezoaplex-foundation/kinobi
  */
 
+export * from './acceptAuthority';
+export * from './addRewardMint';
 export * from './changeDelegate';
 export * from './claim';
+export * from './claimAmount';
 export * from './closeMining';
+export * from './closePool';
+export * from './compactIndexHistory';
+export * from './configureDirectClaims';
+export * from './configureDistributionCrank';
+export * from './configureEmissionCurve';
+export * from './configureLockupTier';
+export * from './configureVesting';
 export * from './decreaseRewards';
+export * from './defundVault';
 export * from './depositMining';
 export * from './distributeRewards';
 export * from './extendStake';
 export * from './fillVault';
+export * from './getPendingRewards';
+export * from './initializeDelegate';
+export * from './initializeDelegation';
 export * from './initializeMining';
 export * from './initializePool';
+export * from './initializeVesting';
+export * from './migrateMining';
+export * from './migrateRewardPool';
+export * from './migrateVaultAuthority';
+export * from './proposeAuthority';
+export * from './resizeMining';
+export * from './setDelegateCommission';
+export * from './setPausedOperations';
+export * from './settleDelegationRewards';
 export * from './slash';
 export * from './withdrawMining';
+export * from './withdrawVested';
@@ -0,0 +1,164 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u16,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeDelegateInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the delegate mining account */
+  mining: PublicKey | Pda;
+  /** The address of the delegate account of the mining */
+  delegate: PublicKey | Pda;
+  /** The end user the mining accounts belongs to */
+  miningOwner: Signer;
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeDelegateInstructionData = {
+  discriminator: number;
+  commissionBps: number;
+};
+
+export type InitializeDelegateInstructionDataArgs = { commissionBps: number };
+
+export function getInitializeDelegateInstructionDataSerializer(): Serializer<
+  InitializeDelegateInstructionDataArgs,
+  InitializeDelegateInstructionData
+> {
+  return mapSerializer<
+    InitializeDelegateInstructionDataArgs,
+    any,
+    InitializeDelegateInstructionData
+  >(
+    struct<InitializeDelegateInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['commissionBps', u16()],
+      ],
+      { description: 'InitializeDelegateInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 28 })
+  ) as Serializer<
+    InitializeDelegateInstructionDataArgs,
+    InitializeDelegateInstructionData
+  >;
+}
+
+// Args.
+export type InitializeDelegateInstructionArgs =
+  InitializeDelegateInstructionDataArgs;
+
+// Instruction.
+export function initializeDelegate(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: InitializeDelegateInstructionAccounts &
+    InitializeDelegateInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    mining: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.mining ?? null,
+    },
+    delegate: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.delegate ?? null,
+    },
+    miningOwner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.miningOwner ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitializeDelegateInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitializeDelegateInstructionDataSerializer().serialize(
+    resolvedArgs as InitializeDelegateInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
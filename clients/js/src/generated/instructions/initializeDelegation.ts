@@ -0,0 +1,180 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeDelegationInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the delegate mining account */
+  delegateMining: PublicKey | Pda;
+  /** The address of the delegate account of the delegate mining */
+  delegate: PublicKey | Pda;
+  /** The address of the mining account which belongs to the user and stores info about user's rewards */
+  mining: PublicKey | Pda;
+  /** The address of the delegation of the mining to the delegate */
+  delegation: PublicKey | Pda;
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The address of the Staking program's Registrar, required if any stake is delegated already */
+  depositAuthority?: Signer;
+};
+
+// Data.
+export type InitializeDelegationInstructionData = {
+  discriminator: number;
+  delegatedAmount: bigint;
+};
+
+export type InitializeDelegationInstructionDataArgs = {
+  delegatedAmount: number | bigint;
+};
+
+export function getInitializeDelegationInstructionDataSerializer(): Serializer<
+  InitializeDelegationInstructionDataArgs,
+  InitializeDelegationInstructionData
+> {
+  return mapSerializer<
+    InitializeDelegationInstructionDataArgs,
+    any,
+    InitializeDelegationInstructionData
+  >(
+    struct<InitializeDelegationInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['delegatedAmount', u64()],
+      ],
+      { description: 'InitializeDelegationInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 30 })
+  ) as Serializer<
+    InitializeDelegationInstructionDataArgs,
+    InitializeDelegationInstructionData
+  >;
+}
+
+// Args.
+export type InitializeDelegationInstructionArgs =
+  InitializeDelegationInstructionDataArgs;
+
+// Instruction.
+export function initializeDelegation(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: InitializeDelegationInstructionAccounts &
+    InitializeDelegationInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    delegateMining: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.delegateMining ?? null,
+    },
+    delegate: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.delegate ?? null,
+    },
+    mining: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mining ?? null,
+    },
+    delegation: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.delegation ?? null,
+    },
+    payer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    depositAuthority: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.depositAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitializeDelegationInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitializeDelegationInstructionDataSerializer().serialize(
+    resolvedArgs as InitializeDelegationInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
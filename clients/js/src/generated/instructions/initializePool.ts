@@ -17,9 +17,11 @@ import {
 } from '@trezoaplex-foundation/umi';
 import {
   Serializer,
+  array,
   mapSerializer,
   publicKey as publicKeySerializer,
   struct,
+  u64,
   u8,
 } from '@trezoaplex-foundation/umi/serializers';
 import {
@@ -27,6 +29,14 @@ import {
   ResolvedAccountsWithIndices,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  DistributionMode,
+  DistributionModeArgs,
+  LockupTier,
+  LockupTierArgs,
+  getDistributionModeSerializer,
+  getLockupTierSerializer,
+} from '../types';
 
 // Accounts.
 export type InitializePoolInstructionAccounts = {
@@ -52,11 +62,21 @@ export type InitializePoolInstructionData = {
   discriminator: number;
   fillAuthority: PublicKey;
   distributeAuthority: PublicKey;
+  admin: PublicKey;
+  lockupTiers: Array<LockupTier>;
+  epochLengthSecs: bigint;
+  epochOffsetSecs: bigint;
+  distributionMode: DistributionMode;
 };
 
 export type InitializePoolInstructionDataArgs = {
   fillAuthority: PublicKey;
   distributeAuthority: PublicKey;
+  admin: PublicKey;
+  lockupTiers: Array<LockupTierArgs>;
+  epochLengthSecs: number | bigint;
+  epochOffsetSecs: number | bigint;
+  distributionMode: DistributionModeArgs;
 };
 
 export function getInitializePoolInstructionDataSerializer(): Serializer<
@@ -73,6 +93,11 @@ export function getInitializePoolInstructionDataSerializer(): Serializer<
         ['discriminator', u8()],
         ['fillAuthority', publicKeySerializer()],
         ['distributeAuthority', publicKeySerializer()],
+        ['admin', publicKeySerializer()],
+        ['lockupTiers', array(getLockupTierSerializer())],
+        ['epochLengthSecs', u64()],
+        ['epochOffsetSecs', u64()],
+        ['distributionMode', getDistributionModeSerializer()],
       ],
       { description: 'InitializePoolInstructionData' }
     ),
@@ -0,0 +1,139 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeVestingInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the mining account which belongs to the user and stores info about user's rewards */
+  mining: PublicKey | Pda;
+  /** The address of the vesting account of the mining */
+  vesting: PublicKey | Pda;
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeVestingInstructionData = { discriminator: number };
+
+export type InitializeVestingInstructionDataArgs = {};
+
+export function getInitializeVestingInstructionDataSerializer(): Serializer<
+  InitializeVestingInstructionDataArgs,
+  InitializeVestingInstructionData
+> {
+  return mapSerializer<
+    InitializeVestingInstructionDataArgs,
+    any,
+    InitializeVestingInstructionData
+  >(
+    struct<InitializeVestingInstructionData>([['discriminator', u8()]], {
+      description: 'InitializeVestingInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 26 })
+  ) as Serializer<
+    InitializeVestingInstructionDataArgs,
+    InitializeVestingInstructionData
+  >;
+}
+
+// Instruction.
+export function initializeVesting(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: InitializeVestingInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.rewardPool ?? null,
+    },
+    mining: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.mining ?? null,
+    },
+    vesting: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.vesting ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitializeVestingInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,156 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateRewardPoolInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's distribute authority */
+  distributeAuthority: Signer;
+  /** The address paying for the extra rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateRewardPoolInstructionData = {
+  discriminator: number;
+  admin: PublicKey;
+  legacyMinings: bigint;
+};
+
+export type MigrateRewardPoolInstructionDataArgs = {
+  admin: PublicKey;
+  legacyMinings: number | bigint;
+};
+
+export function getMigrateRewardPoolInstructionDataSerializer(): Serializer<
+  MigrateRewardPoolInstructionDataArgs,
+  MigrateRewardPoolInstructionData
+> {
+  return mapSerializer<
+    MigrateRewardPoolInstructionDataArgs,
+    any,
+    MigrateRewardPoolInstructionData
+  >(
+    struct<MigrateRewardPoolInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['admin', publicKeySerializer()],
+        ['legacyMinings', u64()],
+      ],
+      { description: 'MigrateRewardPoolInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 34 })
+  ) as Serializer<
+    MigrateRewardPoolInstructionDataArgs,
+    MigrateRewardPoolInstructionData
+  >;
+}
+
+// Args.
+export type MigrateRewardPoolInstructionArgs =
+  MigrateRewardPoolInstructionDataArgs;
+
+// Instruction.
+export function migrateRewardPool(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: MigrateRewardPoolInstructionAccounts & MigrateRewardPoolInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    distributeAuthority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.distributeAuthority ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MigrateRewardPoolInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateRewardPoolInstructionDataSerializer().serialize(
+    resolvedArgs as MigrateRewardPoolInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,139 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateVaultAuthorityInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+  /** The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs */
+  depositAuthority: Signer;
+  /** The vault authority PDA the vaults are transferred to */
+  vaultAuthority: PublicKey | Pda;
+  /** The address of the Token program where rewards are minted */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateVaultAuthorityInstructionData = { discriminator: number };
+
+export type MigrateVaultAuthorityInstructionDataArgs = {};
+
+export function getMigrateVaultAuthorityInstructionDataSerializer(): Serializer<
+  MigrateVaultAuthorityInstructionDataArgs,
+  MigrateVaultAuthorityInstructionData
+> {
+  return mapSerializer<
+    MigrateVaultAuthorityInstructionDataArgs,
+    any,
+    MigrateVaultAuthorityInstructionData
+  >(
+    struct<MigrateVaultAuthorityInstructionData>([['discriminator', u8()]], {
+      description: 'MigrateVaultAuthorityInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 22 })
+  ) as Serializer<
+    MigrateVaultAuthorityInstructionDataArgs,
+    MigrateVaultAuthorityInstructionData
+  >;
+}
+
+// Instruction.
+export function migrateVaultAuthority(
+  context: Pick<Context, 'programs'>,
+  input: MigrateVaultAuthorityInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+    depositAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.depositAuthority ?? null,
+    },
+    vaultAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.vaultAuthority ?? null,
+    },
+    tokenProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateVaultAuthorityInstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,134 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AuthorityRole,
+  AuthorityRoleArgs,
+  getAuthorityRoleSerializer,
+} from '../types';
+
+// Accounts.
+export type ProposeAuthorityInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the pool's admin */
+  admin: Signer;
+};
+
+// Data.
+export type ProposeAuthorityInstructionData = {
+  discriminator: number;
+  role: AuthorityRole;
+  newAuthority: PublicKey;
+};
+
+export type ProposeAuthorityInstructionDataArgs = {
+  role: AuthorityRoleArgs;
+  newAuthority: PublicKey;
+};
+
+export function getProposeAuthorityInstructionDataSerializer(): Serializer<
+  ProposeAuthorityInstructionDataArgs,
+  ProposeAuthorityInstructionData
+> {
+  return mapSerializer<
+    ProposeAuthorityInstructionDataArgs,
+    any,
+    ProposeAuthorityInstructionData
+  >(
+    struct<ProposeAuthorityInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['role', getAuthorityRoleSerializer()],
+        ['newAuthority', publicKeySerializer()],
+      ],
+      { description: 'ProposeAuthorityInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 17 })
+  ) as Serializer<
+    ProposeAuthorityInstructionDataArgs,
+    ProposeAuthorityInstructionData
+  >;
+}
+
+// Args.
+export type ProposeAuthorityInstructionArgs =
+  ProposeAuthorityInstructionDataArgs;
+
+// Instruction.
+export function proposeAuthority(
+  context: Pick<Context, 'programs'>,
+  input: ProposeAuthorityInstructionAccounts & ProposeAuthorityInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ProposeAuthorityInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getProposeAuthorityInstructionDataSerializer().serialize(
+    resolvedArgs as ProposeAuthorityInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
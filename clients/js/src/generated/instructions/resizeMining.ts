@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u16,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ResizeMiningInstructionAccounts = {
+  /** The address of the mining account which belongs to the user and stores info about user's rewards */
+  mining: PublicKey | Pda;
+  /** The end user the mining accounts belongs to */
+  miningOwner: Signer;
+  /** The address paying for the extra rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ResizeMiningInstructionData = {
+  discriminator: number;
+  modifiersTreeCapacity: number;
+};
+
+export type ResizeMiningInstructionDataArgs = { modifiersTreeCapacity: number };
+
+export function getResizeMiningInstructionDataSerializer(): Serializer<
+  ResizeMiningInstructionDataArgs,
+  ResizeMiningInstructionData
+> {
+  return mapSerializer<
+    ResizeMiningInstructionDataArgs,
+    any,
+    ResizeMiningInstructionData
+  >(
+    struct<ResizeMiningInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['modifiersTreeCapacity', u16()],
+      ],
+      { description: 'ResizeMiningInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 15 })
+  ) as Serializer<ResizeMiningInstructionDataArgs, ResizeMiningInstructionData>;
+}
+
+// Args.
+export type ResizeMiningInstructionArgs = ResizeMiningInstructionDataArgs;
+
+// Instruction.
+export function resizeMining(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: ResizeMiningInstructionAccounts & ResizeMiningInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    mining: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.mining ?? null,
+    },
+    miningOwner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.miningOwner ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ResizeMiningInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getResizeMiningInstructionDataSerializer().serialize(
+    resolvedArgs as ResizeMiningInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,141 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u16,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetDelegateCommissionInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the delegate mining account */
+  mining: PublicKey | Pda;
+  /** The address of the delegate account of the mining */
+  delegate: PublicKey | Pda;
+  /** The end user the mining accounts belongs to */
+  miningOwner: Signer;
+};
+
+// Data.
+export type SetDelegateCommissionInstructionData = {
+  discriminator: number;
+  commissionBps: number;
+};
+
+export type SetDelegateCommissionInstructionDataArgs = {
+  commissionBps: number;
+};
+
+export function getSetDelegateCommissionInstructionDataSerializer(): Serializer<
+  SetDelegateCommissionInstructionDataArgs,
+  SetDelegateCommissionInstructionData
+> {
+  return mapSerializer<
+    SetDelegateCommissionInstructionDataArgs,
+    any,
+    SetDelegateCommissionInstructionData
+  >(
+    struct<SetDelegateCommissionInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['commissionBps', u16()],
+      ],
+      { description: 'SetDelegateCommissionInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 29 })
+  ) as Serializer<
+    SetDelegateCommissionInstructionDataArgs,
+    SetDelegateCommissionInstructionData
+  >;
+}
+
+// Args.
+export type SetDelegateCommissionInstructionArgs =
+  SetDelegateCommissionInstructionDataArgs;
+
+// Instruction.
+export function setDelegateCommission(
+  context: Pick<Context, 'programs'>,
+  input: SetDelegateCommissionInstructionAccounts &
+    SetDelegateCommissionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    mining: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.mining ?? null,
+    },
+    delegate: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.delegate ?? null,
+    },
+    miningOwner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.miningOwner ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetDelegateCommissionInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetDelegateCommissionInstructionDataSerializer().serialize(
+    resolvedArgs as SetDelegateCommissionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,126 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetPausedOperationsInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The pool's guardian or admin */
+  pauser: Signer;
+};
+
+// Data.
+export type SetPausedOperationsInstructionData = {
+  discriminator: number;
+  pausedOperations: number;
+};
+
+export type SetPausedOperationsInstructionDataArgs = {
+  pausedOperations: number;
+};
+
+export function getSetPausedOperationsInstructionDataSerializer(): Serializer<
+  SetPausedOperationsInstructionDataArgs,
+  SetPausedOperationsInstructionData
+> {
+  return mapSerializer<
+    SetPausedOperationsInstructionDataArgs,
+    any,
+    SetPausedOperationsInstructionData
+  >(
+    struct<SetPausedOperationsInstructionData>(
+      [
+        ['discriminator', u8()],
+        ['pausedOperations', u8()],
+      ],
+      { description: 'SetPausedOperationsInstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 19 })
+  ) as Serializer<
+    SetPausedOperationsInstructionDataArgs,
+    SetPausedOperationsInstructionData
+  >;
+}
+
+// Args.
+export type SetPausedOperationsInstructionArgs =
+  SetPausedOperationsInstructionDataArgs;
+
+// Instruction.
+export function setPausedOperations(
+  context: Pick<Context, 'programs'>,
+  input: SetPausedOperationsInstructionAccounts &
+    SetPausedOperationsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    pauser: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.pauser ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetPausedOperationsInstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetPausedOperationsInstructionDataSerializer().serialize(
+    resolvedArgs as SetPausedOperationsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,129 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SettleDelegationRewardsInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the mining account which belongs to the user and stores info about user's rewards */
+  mining: PublicKey | Pda;
+  /** The address of the delegate mining account */
+  delegateMining: PublicKey | Pda;
+  /** The address of the delegate account of the delegate mining */
+  delegate: PublicKey | Pda;
+  /** The address of the delegation of the mining to the delegate */
+  delegation: PublicKey | Pda;
+};
+
+// Data.
+export type SettleDelegationRewardsInstructionData = { discriminator: number };
+
+export type SettleDelegationRewardsInstructionDataArgs = {};
+
+export function getSettleDelegationRewardsInstructionDataSerializer(): Serializer<
+  SettleDelegationRewardsInstructionDataArgs,
+  SettleDelegationRewardsInstructionData
+> {
+  return mapSerializer<
+    SettleDelegationRewardsInstructionDataArgs,
+    any,
+    SettleDelegationRewardsInstructionData
+  >(
+    struct<SettleDelegationRewardsInstructionData>([['discriminator', u8()]], {
+      description: 'SettleDelegationRewardsInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 31 })
+  ) as Serializer<
+    SettleDelegationRewardsInstructionDataArgs,
+    SettleDelegationRewardsInstructionData
+  >;
+}
+
+// Instruction.
+export function settleDelegationRewards(
+  context: Pick<Context, 'programs'>,
+  input: SettleDelegationRewardsInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.rewardPool ?? null,
+    },
+    mining: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.mining ?? null,
+    },
+    delegateMining: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.delegateMining ?? null,
+    },
+    delegate: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.delegate ?? null,
+    },
+    delegation: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.delegation ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSettleDelegationRewardsInstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
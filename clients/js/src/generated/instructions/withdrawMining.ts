@@ -38,6 +38,10 @@ export type WithdrawMiningInstructionAccounts = {
   depositAuthority: Signer;
   /** The address of Mining Account that might be used as a delegate in delegated staking model */
   delegateMining: PublicKey | Pda;
+  /** The delegate account of the delegate mining, required if the delegate charges a commission */
+  delegate?: PublicKey | Pda;
+  /** The delegation of the mining to the delegate, required if the delegate charges a commission */
+  delegation?: PublicKey | Pda;
 };
 
 // Data.
@@ -115,6 +119,16 @@ export function withdrawMining(
       isWritable: false as boolean,
       value: input.delegateMining ?? null,
     },
+    delegate: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.delegate ?? null,
+    },
+    delegation: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.delegation ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -128,7 +142,7 @@ export function withdrawMining(
   // Keys and Signers.
   const [keys, signers] = getAccountMetasAndSigners(
     orderedAccounts,
-    'programId',
+    'omitted',
     programId
   );
 
@@ -0,0 +1,158 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawVestedInstructionAccounts = {
+  /** The address of the reward pool */
+  rewardPool: PublicKey | Pda;
+  /** The address of the reward mint */
+  rewardMint: PublicKey | Pda;
+  /** The address of the reward vault */
+  vault: PublicKey | Pda;
+  /** The address of the vesting account of the mining */
+  vesting: PublicKey | Pda;
+  /** The end user the mining accounts belongs to */
+  miningOwner: Signer;
+  /** The deposit authority, or the vault authority PDA once the vaults are migrated to it */
+  vaultOwner: PublicKey | Pda | Signer;
+  /** ATA where tokens will be withdrawn to */
+  miningOwnerRewardTokenAccount: PublicKey | Pda;
+  /** The address of the Token program where rewards are minted */
+  tokenProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawVestedInstructionData = { discriminator: number };
+
+export type WithdrawVestedInstructionDataArgs = {};
+
+export function getWithdrawVestedInstructionDataSerializer(): Serializer<
+  WithdrawVestedInstructionDataArgs,
+  WithdrawVestedInstructionData
+> {
+  return mapSerializer<
+    WithdrawVestedInstructionDataArgs,
+    any,
+    WithdrawVestedInstructionData
+  >(
+    struct<WithdrawVestedInstructionData>([['discriminator', u8()]], {
+      description: 'WithdrawVestedInstructionData',
+    }),
+    (value) => ({ ...value, discriminator: 27 })
+  ) as Serializer<
+    WithdrawVestedInstructionDataArgs,
+    WithdrawVestedInstructionData
+  >;
+}
+
+// Instruction.
+export function withdrawVested(
+  context: Pick<Context, 'programs'>,
+  input: WithdrawVestedInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'trzRewards',
+    'BF5PatmRTQDgEKoXR7iHRbkibEEi83nVM38cUKWzQcTR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    rewardPool: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.rewardPool ?? null,
+    },
+    rewardMint: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.rewardMint ?? null,
+    },
+    vault: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    vesting: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.vesting ?? null,
+    },
+    miningOwner: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.miningOwner ?? null,
+    },
+    vaultOwner: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.vaultOwner ?? null,
+    },
+    miningOwnerRewardTokenAccount: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.miningOwnerRewardTokenAccount ?? null,
+    },
+    tokenProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawVestedInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,52 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  AuthorityRole,
+  AuthorityRoleArgs,
+  getAuthorityRoleSerializer,
+} from '.';
+
+export type AcceptAuthorityEvent = {
+  rewardPool: PublicKey;
+  role: AuthorityRole;
+  oldAuthority: PublicKey;
+  newAuthority: PublicKey;
+  timestamp: bigint;
+};
+
+export type AcceptAuthorityEventArgs = {
+  rewardPool: PublicKey;
+  role: AuthorityRoleArgs;
+  oldAuthority: PublicKey;
+  newAuthority: PublicKey;
+  timestamp: number | bigint;
+};
+
+export function getAcceptAuthorityEventSerializer(): Serializer<
+  AcceptAuthorityEventArgs,
+  AcceptAuthorityEvent
+> {
+  return struct<AcceptAuthorityEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['role', getAuthorityRoleSerializer()],
+      ['oldAuthority', publicKeySerializer()],
+      ['newAuthority', publicKeySerializer()],
+      ['timestamp', u64()],
+    ],
+    { description: 'AcceptAuthorityEvent' }
+  ) as Serializer<AcceptAuthorityEventArgs, AcceptAuthorityEvent>;
+}
@@ -12,6 +12,9 @@ export enum AccountType {
   Uninitialized,
   RewardPool,
   Mining,
+  Vesting,
+  Delegate,
+  Delegation,
 }
 
 export type AccountTypeArgs = AccountType;
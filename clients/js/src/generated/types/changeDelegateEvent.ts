@@ -0,0 +1,58 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Option,
+  OptionOrNullable,
+  PublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type ChangeDelegateEvent = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  oldDelegateMining: Option<PublicKey>;
+  newDelegateMining: Option<PublicKey>;
+  stakedAmount: bigint;
+  totalShare: bigint;
+  timestamp: bigint;
+};
+
+export type ChangeDelegateEventArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  oldDelegateMining: OptionOrNullable<PublicKey>;
+  newDelegateMining: OptionOrNullable<PublicKey>;
+  stakedAmount: number | bigint;
+  totalShare: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getChangeDelegateEventSerializer(): Serializer<
+  ChangeDelegateEventArgs,
+  ChangeDelegateEvent
+> {
+  return struct<ChangeDelegateEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['oldDelegateMining', option(publicKeySerializer())],
+      ['newDelegateMining', option(publicKeySerializer())],
+      ['stakedAmount', u64()],
+      ['totalShare', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'ChangeDelegateEvent' }
+  ) as Serializer<ChangeDelegateEventArgs, ChangeDelegateEvent>;
+}
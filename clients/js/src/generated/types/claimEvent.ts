@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type ClaimEvent = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  rewardMint: PublicKey;
+  amount: bigint;
+  vested: boolean;
+  timestamp: bigint;
+};
+
+export type ClaimEventArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  rewardMint: PublicKey;
+  amount: number | bigint;
+  vested: boolean;
+  timestamp: number | bigint;
+};
+
+export function getClaimEventSerializer(): Serializer<
+  ClaimEventArgs,
+  ClaimEvent
+> {
+  return struct<ClaimEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['rewardMint', publicKeySerializer()],
+      ['amount', u64()],
+      ['vested', bool()],
+      ['timestamp', u64()],
+    ],
+    { description: 'ClaimEvent' }
+  ) as Serializer<ClaimEventArgs, ClaimEvent>;
+}
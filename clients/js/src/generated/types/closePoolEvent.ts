@@ -0,0 +1,50 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  VaultLeftovers,
+  VaultLeftoversArgs,
+  getVaultLeftoversSerializer,
+} from '.';
+
+export type ClosePoolEvent = {
+  rewardPool: PublicKey;
+  leftovers: Array<VaultLeftovers>;
+  rentReceiver: PublicKey;
+  timestamp: bigint;
+};
+
+export type ClosePoolEventArgs = {
+  rewardPool: PublicKey;
+  leftovers: Array<VaultLeftoversArgs>;
+  rentReceiver: PublicKey;
+  timestamp: number | bigint;
+};
+
+export function getClosePoolEventSerializer(): Serializer<
+  ClosePoolEventArgs,
+  ClosePoolEvent
+> {
+  return struct<ClosePoolEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['leftovers', array(getVaultLeftoversSerializer())],
+      ['rentReceiver', publicKeySerializer()],
+      ['timestamp', u64()],
+    ],
+    { description: 'ClosePoolEvent' }
+  ) as Serializer<ClosePoolEventArgs, ClosePoolEvent>;
+}
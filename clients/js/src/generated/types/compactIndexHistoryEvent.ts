@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type CompactIndexHistoryEvent = {
+  rewardPool: PublicKey;
+  compactBefore: bigint;
+  removedRecords: bigint;
+  pendingMiningsCheckpoint: bigint;
+  timestamp: bigint;
+};
+
+export type CompactIndexHistoryEventArgs = {
+  rewardPool: PublicKey;
+  compactBefore: number | bigint;
+  removedRecords: number | bigint;
+  pendingMiningsCheckpoint: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getCompactIndexHistoryEventSerializer(): Serializer<
+  CompactIndexHistoryEventArgs,
+  CompactIndexHistoryEvent
+> {
+  return struct<CompactIndexHistoryEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['compactBefore', u64()],
+      ['removedRecords', u64()],
+      ['pendingMiningsCheckpoint', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'CompactIndexHistoryEvent' }
+  ) as Serializer<CompactIndexHistoryEventArgs, CompactIndexHistoryEvent>;
+}
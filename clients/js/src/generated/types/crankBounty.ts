@@ -0,0 +1,80 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  array,
+  dataEnum,
+  struct,
+  tuple,
+  u64,
+  unit,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type CrankBounty =
+  | { __kind: 'None' }
+  | { __kind: 'FixedAmount'; fields: [Array<bigint>] }
+  | { __kind: 'BasisPoints'; fields: [bigint] };
+
+export type CrankBountyArgs =
+  | { __kind: 'None' }
+  | { __kind: 'FixedAmount'; fields: [Array<number | bigint>] }
+  | { __kind: 'BasisPoints'; fields: [number | bigint] };
+
+export function getCrankBountySerializer(): Serializer<
+  CrankBountyArgs,
+  CrankBounty
+> {
+  return dataEnum<CrankBounty>(
+    [
+      ['None', unit()],
+      [
+        'FixedAmount',
+        struct<GetDataEnumKindContent<CrankBounty, 'FixedAmount'>>([
+          ['fields', tuple([array(u64(), { size: 3 })])],
+        ]),
+      ],
+      [
+        'BasisPoints',
+        struct<GetDataEnumKindContent<CrankBounty, 'BasisPoints'>>([
+          ['fields', tuple([u64()])],
+        ]),
+      ],
+    ],
+    { description: 'CrankBounty' }
+  ) as Serializer<CrankBountyArgs, CrankBounty>;
+}
+
+// Data Enum Helpers.
+export function crankBounty(
+  kind: 'None'
+): GetDataEnumKind<CrankBountyArgs, 'None'>;
+export function crankBounty(
+  kind: 'FixedAmount',
+  data: GetDataEnumKindContent<CrankBountyArgs, 'FixedAmount'>['fields']
+): GetDataEnumKind<CrankBountyArgs, 'FixedAmount'>;
+export function crankBounty(
+  kind: 'BasisPoints',
+  data: GetDataEnumKindContent<CrankBountyArgs, 'BasisPoints'>['fields']
+): GetDataEnumKind<CrankBountyArgs, 'BasisPoints'>;
+export function crankBounty<K extends CrankBountyArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<CrankBountyArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isCrankBounty<K extends CrankBounty['__kind']>(
+  kind: K,
+  value: CrankBounty
+): value is CrankBounty & { __kind: K } {
+  return value.__kind === kind;
+}
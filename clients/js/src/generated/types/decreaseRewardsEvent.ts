@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type DecreaseRewardsEvent = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  decreasedWeightedStakeNumber: bigint;
+  share: bigint;
+  timestamp: bigint;
+};
+
+export type DecreaseRewardsEventArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  decreasedWeightedStakeNumber: number | bigint;
+  share: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getDecreaseRewardsEventSerializer(): Serializer<
+  DecreaseRewardsEventArgs,
+  DecreaseRewardsEvent
+> {
+  return struct<DecreaseRewardsEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['decreasedWeightedStakeNumber', u64()],
+      ['share', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'DecreaseRewardsEvent' }
+  ) as Serializer<DecreaseRewardsEventArgs, DecreaseRewardsEvent>;
+}
@@ -0,0 +1,62 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Option,
+  OptionOrNullable,
+  PublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+import { LockupPeriod, LockupPeriodArgs, getLockupPeriodSerializer } from '.';
+
+export type DepositEvent = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  amount: bigint;
+  lockupPeriod: LockupPeriod;
+  delegateMining: Option<PublicKey>;
+  share: bigint;
+  totalShare: bigint;
+  timestamp: bigint;
+};
+
+export type DepositEventArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  amount: number | bigint;
+  lockupPeriod: LockupPeriodArgs;
+  delegateMining: OptionOrNullable<PublicKey>;
+  share: number | bigint;
+  totalShare: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getDepositEventSerializer(): Serializer<
+  DepositEventArgs,
+  DepositEvent
+> {
+  return struct<DepositEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['amount', u64()],
+      ['lockupPeriod', getLockupPeriodSerializer()],
+      ['delegateMining', option(publicKeySerializer())],
+      ['share', u64()],
+      ['totalShare', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'DepositEvent' }
+  ) as Serializer<DepositEventArgs, DepositEvent>;
+}
@@ -0,0 +1,57 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u128,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type DistributeEvent = {
+  rewardPool: PublicKey;
+  rewardMint: PublicKey;
+  day: bigint;
+  amount: bigint;
+  crankBounty: bigint;
+  totalShare: bigint;
+  indexWithPrecision: bigint;
+  timestamp: bigint;
+};
+
+export type DistributeEventArgs = {
+  rewardPool: PublicKey;
+  rewardMint: PublicKey;
+  day: number | bigint;
+  amount: number | bigint;
+  crankBounty: number | bigint;
+  totalShare: number | bigint;
+  indexWithPrecision: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getDistributeEventSerializer(): Serializer<
+  DistributeEventArgs,
+  DistributeEvent
+> {
+  return struct<DistributeEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['rewardMint', publicKeySerializer()],
+      ['day', u64()],
+      ['amount', u64()],
+      ['crankBounty', u64()],
+      ['totalShare', u64()],
+      ['indexWithPrecision', u128()],
+      ['timestamp', u64()],
+    ],
+    { description: 'DistributeEvent' }
+  ) as Serializer<DistributeEventArgs, DistributeEvent>;
+}
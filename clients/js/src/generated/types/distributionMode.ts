@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@trezoaplex-foundation/umi/serializers';
+
+export enum DistributionMode {
+  Epochs,
+  Streaming,
+}
+
+export type DistributionModeArgs = DistributionMode;
+
+export function getDistributionModeSerializer(): Serializer<
+  DistributionModeArgs,
+  DistributionMode
+> {
+  return scalarEnum<DistributionMode>(DistributionMode, {
+    description: 'DistributionMode',
+  }) as Serializer<DistributionModeArgs, DistributionMode>;
+}
@@ -0,0 +1,85 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+  u64,
+  unit,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type EmissionCurve =
+  | { __kind: 'Even' }
+  | { __kind: 'LinearDecay' }
+  | { __kind: 'Halving'; fields: [bigint] }
+  | { __kind: 'Stepped'; fields: [bigint] };
+
+export type EmissionCurveArgs =
+  | { __kind: 'Even' }
+  | { __kind: 'LinearDecay' }
+  | { __kind: 'Halving'; fields: [number | bigint] }
+  | { __kind: 'Stepped'; fields: [number | bigint] };
+
+export function getEmissionCurveSerializer(): Serializer<
+  EmissionCurveArgs,
+  EmissionCurve
+> {
+  return dataEnum<EmissionCurve>(
+    [
+      ['Even', unit()],
+      ['LinearDecay', unit()],
+      [
+        'Halving',
+        struct<GetDataEnumKindContent<EmissionCurve, 'Halving'>>([
+          ['fields', tuple([u64()])],
+        ]),
+      ],
+      [
+        'Stepped',
+        struct<GetDataEnumKindContent<EmissionCurve, 'Stepped'>>([
+          ['fields', tuple([u64()])],
+        ]),
+      ],
+    ],
+    { description: 'EmissionCurve' }
+  ) as Serializer<EmissionCurveArgs, EmissionCurve>;
+}
+
+// Data Enum Helpers.
+export function emissionCurve(
+  kind: 'Even'
+): GetDataEnumKind<EmissionCurveArgs, 'Even'>;
+export function emissionCurve(
+  kind: 'LinearDecay'
+): GetDataEnumKind<EmissionCurveArgs, 'LinearDecay'>;
+export function emissionCurve(
+  kind: 'Halving',
+  data: GetDataEnumKindContent<EmissionCurveArgs, 'Halving'>['fields']
+): GetDataEnumKind<EmissionCurveArgs, 'Halving'>;
+export function emissionCurve(
+  kind: 'Stepped',
+  data: GetDataEnumKindContent<EmissionCurveArgs, 'Stepped'>['fields']
+): GetDataEnumKind<EmissionCurveArgs, 'Stepped'>;
+export function emissionCurve<K extends EmissionCurveArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<EmissionCurveArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isEmissionCurve<K extends EmissionCurve['__kind']>(
+  kind: K,
+  value: EmissionCurve
+): value is EmissionCurve & { __kind: K } {
+  return value.__kind === kind;
+}
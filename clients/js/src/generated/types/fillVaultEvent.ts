@@ -0,0 +1,50 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type FillVaultEvent = {
+  rewardPool: PublicKey;
+  rewardMint: PublicKey;
+  amount: bigint;
+  distributionEndsAt: bigint;
+  tokensAvailableForDistribution: bigint;
+  timestamp: bigint;
+};
+
+export type FillVaultEventArgs = {
+  rewardPool: PublicKey;
+  rewardMint: PublicKey;
+  amount: number | bigint;
+  distributionEndsAt: number | bigint;
+  tokensAvailableForDistribution: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getFillVaultEventSerializer(): Serializer<
+  FillVaultEventArgs,
+  FillVaultEvent
+> {
+  return struct<FillVaultEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['rewardMint', publicKeySerializer()],
+      ['amount', u64()],
+      ['distributionEndsAt', u64()],
+      ['tokensAvailableForDistribution', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'FillVaultEvent' }
+  ) as Serializer<FillVaultEventArgs, FillVaultEvent>;
+}
@@ -6,5 +6,31 @@
  * @see https://github.com/trezoaplex-foundation/kinobi
  */
 
+export * from './acceptAuthorityEvent';
 export * from './accountType';
+export * from './authorityRole';
+export * from './changeDelegateEvent';
+export * from './claimEvent';
+export * from './closePoolEvent';
+export * from './compactIndexHistoryEvent';
+export * from './crankBounty';
+export * from './decreaseRewardsEvent';
+export * from './defundVaultEvent';
+export * from './depositEvent';
+export * from './distributeEvent';
+export * from './distributionMode';
+export * from './emissionCurve';
+export * from './fillVaultEvent';
 export * from './lockupPeriod';
+export * from './lockupTier';
+export * from './mergedWeightedStakeDiff';
+export * from './pendingRewards';
+export * from './proposeAuthorityEvent';
+export * from './rewardToken';
+export * from './rewardsEvent';
+export * from './setPausedOperationsEvent';
+export * from './slashEvent';
+export * from './vaultLeftovers';
+export * from './vestingTranche';
+export * from './withdrawEvent';
+export * from './withdrawVestedEvent';
@@ -6,23 +6,86 @@
  * @see https://github.com/trezoaplex-foundation/kinobi
  */
 
-import { Serializer, scalarEnum } from '@trezoaplex-foundation/umi/serializers';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+  u8,
+  unit,
+} from '@trezoaplex-foundation/umi/serializers';
 
-export enum LockupPeriod {
-  None,
-  Flex,
-  ThreeMonths,
-  SixMonths,
-  OneYear,
-}
+export type LockupPeriod =
+  | { __kind: 'None' }
+  | { __kind: 'Flex' }
+  | { __kind: 'ThreeMonths' }
+  | { __kind: 'SixMonths' }
+  | { __kind: 'OneYear' }
+  | { __kind: 'Tier'; fields: [number] };
 
-export type LockupPeriodArgs = LockupPeriod;
+export type LockupPeriodArgs =
+  | { __kind: 'None' }
+  | { __kind: 'Flex' }
+  | { __kind: 'ThreeMonths' }
+  | { __kind: 'SixMonths' }
+  | { __kind: 'OneYear' }
+  | { __kind: 'Tier'; fields: [number] };
 
 export function getLockupPeriodSerializer(): Serializer<
   LockupPeriodArgs,
   LockupPeriod
 > {
-  return scalarEnum<LockupPeriod>(LockupPeriod, {
-    description: 'LockupPeriod',
-  }) as Serializer<LockupPeriodArgs, LockupPeriod>;
+  return dataEnum<LockupPeriod>(
+    [
+      ['None', unit()],
+      ['Flex', unit()],
+      ['ThreeMonths', unit()],
+      ['SixMonths', unit()],
+      ['OneYear', unit()],
+      [
+        'Tier',
+        struct<GetDataEnumKindContent<LockupPeriod, 'Tier'>>([
+          ['fields', tuple([u8()])],
+        ]),
+      ],
+    ],
+    { description: 'LockupPeriod' }
+  ) as Serializer<LockupPeriodArgs, LockupPeriod>;
+}
+
+// Data Enum Helpers.
+export function lockupPeriod(
+  kind: 'None'
+): GetDataEnumKind<LockupPeriodArgs, 'None'>;
+export function lockupPeriod(
+  kind: 'Flex'
+): GetDataEnumKind<LockupPeriodArgs, 'Flex'>;
+export function lockupPeriod(
+  kind: 'ThreeMonths'
+): GetDataEnumKind<LockupPeriodArgs, 'ThreeMonths'>;
+export function lockupPeriod(
+  kind: 'SixMonths'
+): GetDataEnumKind<LockupPeriodArgs, 'SixMonths'>;
+export function lockupPeriod(
+  kind: 'OneYear'
+): GetDataEnumKind<LockupPeriodArgs, 'OneYear'>;
+export function lockupPeriod(
+  kind: 'Tier',
+  data: GetDataEnumKindContent<LockupPeriodArgs, 'Tier'>['fields']
+): GetDataEnumKind<LockupPeriodArgs, 'Tier'>;
+export function lockupPeriod<K extends LockupPeriodArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<LockupPeriodArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isLockupPeriod<K extends LockupPeriod['__kind']>(
+  kind: K,
+  value: LockupPeriod
+): value is LockupPeriod & { __kind: K } {
+  return value.__kind === kind;
 }
@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type LockupTier = {
+  days: bigint;
+  multiplier: bigint;
+  enabled: number;
+  padding: Array<number>;
+};
+
+export type LockupTierArgs = {
+  days: number | bigint;
+  multiplier: number | bigint;
+  enabled: number;
+  padding: Array<number>;
+};
+
+export function getLockupTierSerializer(): Serializer<
+  LockupTierArgs,
+  LockupTier
+> {
+  return struct<LockupTier>(
+    [
+      ['days', u64()],
+      ['multiplier', u64()],
+      ['enabled', u8()],
+      ['padding', array(u8(), { size: 7 })],
+    ],
+    { description: 'LockupTier' }
+  ) as Serializer<LockupTierArgs, LockupTier>;
+}
@@ -0,0 +1,39 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type MergedWeightedStakeDiff = {
+  expiresAt: bigint;
+  mergedInto: bigint;
+  diff: bigint;
+};
+
+export type MergedWeightedStakeDiffArgs = {
+  expiresAt: number | bigint;
+  mergedInto: number | bigint;
+  diff: number | bigint;
+};
+
+export function getMergedWeightedStakeDiffSerializer(): Serializer<
+  MergedWeightedStakeDiffArgs,
+  MergedWeightedStakeDiff
+> {
+  return struct<MergedWeightedStakeDiff>(
+    [
+      ['expiresAt', u64()],
+      ['mergedInto', u64()],
+      ['diff', u64()],
+    ],
+    { description: 'MergedWeightedStakeDiff' }
+  ) as Serializer<MergedWeightedStakeDiffArgs, MergedWeightedStakeDiff>;
+}
@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  option,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type PendingRewards = {
+  unclaimedRewards: Array<bigint>;
+  share: bigint;
+  nextModifierAt: Option<bigint>;
+};
+
+export type PendingRewardsArgs = {
+  unclaimedRewards: Array<number | bigint>;
+  share: number | bigint;
+  nextModifierAt: OptionOrNullable<number | bigint>;
+};
+
+export function getPendingRewardsSerializer(): Serializer<
+  PendingRewardsArgs,
+  PendingRewards
+> {
+  return struct<PendingRewards>(
+    [
+      ['unclaimedRewards', array(u64(), { size: 3 })],
+      ['share', u64()],
+      ['nextModifierAt', option(u64())],
+    ],
+    { description: 'PendingRewards' }
+  ) as Serializer<PendingRewardsArgs, PendingRewards>;
+}
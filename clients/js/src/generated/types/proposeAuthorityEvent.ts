@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  AuthorityRole,
+  AuthorityRoleArgs,
+  getAuthorityRoleSerializer,
+} from '.';
+
+export type ProposeAuthorityEvent = {
+  rewardPool: PublicKey;
+  role: AuthorityRole;
+  newAuthority: PublicKey;
+  timestamp: bigint;
+};
+
+export type ProposeAuthorityEventArgs = {
+  rewardPool: PublicKey;
+  role: AuthorityRoleArgs;
+  newAuthority: PublicKey;
+  timestamp: number | bigint;
+};
+
+export function getProposeAuthorityEventSerializer(): Serializer<
+  ProposeAuthorityEventArgs,
+  ProposeAuthorityEvent
+> {
+  return struct<ProposeAuthorityEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['role', getAuthorityRoleSerializer()],
+      ['newAuthority', publicKeySerializer()],
+      ['timestamp', u64()],
+    ],
+    { description: 'ProposeAuthorityEvent' }
+  ) as Serializer<ProposeAuthorityEventArgs, ProposeAuthorityEvent>;
+}
@@ -0,0 +1,56 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  publicKey as publicKeySerializer,
+  struct,
+  u128,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type RewardToken = {
+  mint: PublicKey;
+  indexWithPrecision: bigint;
+  distributionEndsAt: bigint;
+  tokensAvailableForDistribution: bigint;
+  tokenAccountBump: number;
+  padding: Array<number>;
+  crankBountyAmount: bigint;
+};
+
+export type RewardTokenArgs = {
+  mint: PublicKey;
+  indexWithPrecision: number | bigint;
+  distributionEndsAt: number | bigint;
+  tokensAvailableForDistribution: number | bigint;
+  tokenAccountBump: number;
+  padding: Array<number>;
+  crankBountyAmount: number | bigint;
+};
+
+export function getRewardTokenSerializer(): Serializer<
+  RewardTokenArgs,
+  RewardToken
+> {
+  return struct<RewardToken>(
+    [
+      ['mint', publicKeySerializer()],
+      ['indexWithPrecision', u128()],
+      ['distributionEndsAt', u64()],
+      ['tokensAvailableForDistribution', u64()],
+      ['tokenAccountBump', u8()],
+      ['padding', array(u8(), { size: 7 })],
+      ['crankBountyAmount', u64()],
+    ],
+    { description: 'RewardToken' }
+  ) as Serializer<RewardTokenArgs, RewardToken>;
+}
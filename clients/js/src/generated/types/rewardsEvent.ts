@@ -0,0 +1,274 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  struct,
+  tuple,
+} from '@trezoaplex-foundation/umi/serializers';
+import {
+  AcceptAuthorityEvent,
+  AcceptAuthorityEventArgs,
+  ChangeDelegateEvent,
+  ChangeDelegateEventArgs,
+  ClaimEvent,
+  ClaimEventArgs,
+  ClosePoolEvent,
+  ClosePoolEventArgs,
+  CompactIndexHistoryEvent,
+  CompactIndexHistoryEventArgs,
+  DecreaseRewardsEvent,
+  DecreaseRewardsEventArgs,
+  DefundVaultEvent,
+  DefundVaultEventArgs,
+  DepositEvent,
+  DepositEventArgs,
+  DistributeEvent,
+  DistributeEventArgs,
+  FillVaultEvent,
+  FillVaultEventArgs,
+  ProposeAuthorityEvent,
+  ProposeAuthorityEventArgs,
+  SetPausedOperationsEvent,
+  SetPausedOperationsEventArgs,
+  SlashEvent,
+  SlashEventArgs,
+  WithdrawEvent,
+  WithdrawEventArgs,
+  WithdrawVestedEvent,
+  WithdrawVestedEventArgs,
+  getAcceptAuthorityEventSerializer,
+  getChangeDelegateEventSerializer,
+  getClaimEventSerializer,
+  getClosePoolEventSerializer,
+  getCompactIndexHistoryEventSerializer,
+  getDecreaseRewardsEventSerializer,
+  getDefundVaultEventSerializer,
+  getDepositEventSerializer,
+  getDistributeEventSerializer,
+  getFillVaultEventSerializer,
+  getProposeAuthorityEventSerializer,
+  getSetPausedOperationsEventSerializer,
+  getSlashEventSerializer,
+  getWithdrawEventSerializer,
+  getWithdrawVestedEventSerializer,
+} from '.';
+
+export type RewardsEvent =
+  | { __kind: 'Deposit'; fields: [DepositEvent] }
+  | { __kind: 'Withdraw'; fields: [WithdrawEvent] }
+  | { __kind: 'Claim'; fields: [ClaimEvent] }
+  | { __kind: 'Distribute'; fields: [DistributeEvent] }
+  | { __kind: 'Slash'; fields: [SlashEvent] }
+  | { __kind: 'DecreaseRewards'; fields: [DecreaseRewardsEvent] }
+  | { __kind: 'ChangeDelegate'; fields: [ChangeDelegateEvent] }
+  | { __kind: 'FillVault'; fields: [FillVaultEvent] }
+  | { __kind: 'DefundVault'; fields: [DefundVaultEvent] }
+  | { __kind: 'ClosePool'; fields: [ClosePoolEvent] }
+  | { __kind: 'WithdrawVested'; fields: [WithdrawVestedEvent] }
+  | { __kind: 'CompactIndexHistory'; fields: [CompactIndexHistoryEvent] }
+  | { __kind: 'ProposeAuthority'; fields: [ProposeAuthorityEvent] }
+  | { __kind: 'AcceptAuthority'; fields: [AcceptAuthorityEvent] }
+  | { __kind: 'SetPausedOperations'; fields: [SetPausedOperationsEvent] };
+
+export type RewardsEventArgs =
+  | { __kind: 'Deposit'; fields: [DepositEventArgs] }
+  | { __kind: 'Withdraw'; fields: [WithdrawEventArgs] }
+  | { __kind: 'Claim'; fields: [ClaimEventArgs] }
+  | { __kind: 'Distribute'; fields: [DistributeEventArgs] }
+  | { __kind: 'Slash'; fields: [SlashEventArgs] }
+  | { __kind: 'DecreaseRewards'; fields: [DecreaseRewardsEventArgs] }
+  | { __kind: 'ChangeDelegate'; fields: [ChangeDelegateEventArgs] }
+  | { __kind: 'FillVault'; fields: [FillVaultEventArgs] }
+  | { __kind: 'DefundVault'; fields: [DefundVaultEventArgs] }
+  | { __kind: 'ClosePool'; fields: [ClosePoolEventArgs] }
+  | { __kind: 'WithdrawVested'; fields: [WithdrawVestedEventArgs] }
+  | { __kind: 'CompactIndexHistory'; fields: [CompactIndexHistoryEventArgs] }
+  | { __kind: 'ProposeAuthority'; fields: [ProposeAuthorityEventArgs] }
+  | { __kind: 'AcceptAuthority'; fields: [AcceptAuthorityEventArgs] }
+  | { __kind: 'SetPausedOperations'; fields: [SetPausedOperationsEventArgs] };
+
+export function getRewardsEventSerializer(): Serializer<
+  RewardsEventArgs,
+  RewardsEvent
+> {
+  return dataEnum<RewardsEvent>(
+    [
+      [
+        'Deposit',
+        struct<GetDataEnumKindContent<RewardsEvent, 'Deposit'>>([
+          ['fields', tuple([getDepositEventSerializer()])],
+        ]),
+      ],
+      [
+        'Withdraw',
+        struct<GetDataEnumKindContent<RewardsEvent, 'Withdraw'>>([
+          ['fields', tuple([getWithdrawEventSerializer()])],
+        ]),
+      ],
+      [
+        'Claim',
+        struct<GetDataEnumKindContent<RewardsEvent, 'Claim'>>([
+          ['fields', tuple([getClaimEventSerializer()])],
+        ]),
+      ],
+      [
+        'Distribute',
+        struct<GetDataEnumKindContent<RewardsEvent, 'Distribute'>>([
+          ['fields', tuple([getDistributeEventSerializer()])],
+        ]),
+      ],
+      [
+        'Slash',
+        struct<GetDataEnumKindContent<RewardsEvent, 'Slash'>>([
+          ['fields', tuple([getSlashEventSerializer()])],
+        ]),
+      ],
+      [
+        'DecreaseRewards',
+        struct<GetDataEnumKindContent<RewardsEvent, 'DecreaseRewards'>>([
+          ['fields', tuple([getDecreaseRewardsEventSerializer()])],
+        ]),
+      ],
+      [
+        'ChangeDelegate',
+        struct<GetDataEnumKindContent<RewardsEvent, 'ChangeDelegate'>>([
+          ['fields', tuple([getChangeDelegateEventSerializer()])],
+        ]),
+      ],
+      [
+        'FillVault',
+        struct<GetDataEnumKindContent<RewardsEvent, 'FillVault'>>([
+          ['fields', tuple([getFillVaultEventSerializer()])],
+        ]),
+      ],
+      [
+        'DefundVault',
+        struct<GetDataEnumKindContent<RewardsEvent, 'DefundVault'>>([
+          ['fields', tuple([getDefundVaultEventSerializer()])],
+        ]),
+      ],
+      [
+        'ClosePool',
+        struct<GetDataEnumKindContent<RewardsEvent, 'ClosePool'>>([
+          ['fields', tuple([getClosePoolEventSerializer()])],
+        ]),
+      ],
+      [
+        'WithdrawVested',
+        struct<GetDataEnumKindContent<RewardsEvent, 'WithdrawVested'>>([
+          ['fields', tuple([getWithdrawVestedEventSerializer()])],
+        ]),
+      ],
+      [
+        'CompactIndexHistory',
+        struct<GetDataEnumKindContent<RewardsEvent, 'CompactIndexHistory'>>([
+          ['fields', tuple([getCompactIndexHistoryEventSerializer()])],
+        ]),
+      ],
+      [
+        'ProposeAuthority',
+        struct<GetDataEnumKindContent<RewardsEvent, 'ProposeAuthority'>>([
+          ['fields', tuple([getProposeAuthorityEventSerializer()])],
+        ]),
+      ],
+      [
+        'AcceptAuthority',
+        struct<GetDataEnumKindContent<RewardsEvent, 'AcceptAuthority'>>([
+          ['fields', tuple([getAcceptAuthorityEventSerializer()])],
+        ]),
+      ],
+      [
+        'SetPausedOperations',
+        struct<GetDataEnumKindContent<RewardsEvent, 'SetPausedOperations'>>([
+          ['fields', tuple([getSetPausedOperationsEventSerializer()])],
+        ]),
+      ],
+    ],
+    { description: 'RewardsEvent' }
+  ) as Serializer<RewardsEventArgs, RewardsEvent>;
+}
+
+// Data Enum Helpers.
+export function rewardsEvent(
+  kind: 'Deposit',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'Deposit'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'Deposit'>;
+export function rewardsEvent(
+  kind: 'Withdraw',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'Withdraw'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'Withdraw'>;
+export function rewardsEvent(
+  kind: 'Claim',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'Claim'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'Claim'>;
+export function rewardsEvent(
+  kind: 'Distribute',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'Distribute'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'Distribute'>;
+export function rewardsEvent(
+  kind: 'Slash',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'Slash'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'Slash'>;
+export function rewardsEvent(
+  kind: 'DecreaseRewards',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'DecreaseRewards'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'DecreaseRewards'>;
+export function rewardsEvent(
+  kind: 'ChangeDelegate',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'ChangeDelegate'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'ChangeDelegate'>;
+export function rewardsEvent(
+  kind: 'FillVault',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'FillVault'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'FillVault'>;
+export function rewardsEvent(
+  kind: 'DefundVault',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'DefundVault'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'DefundVault'>;
+export function rewardsEvent(
+  kind: 'ClosePool',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'ClosePool'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'ClosePool'>;
+export function rewardsEvent(
+  kind: 'WithdrawVested',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'WithdrawVested'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'WithdrawVested'>;
+export function rewardsEvent(
+  kind: 'CompactIndexHistory',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'CompactIndexHistory'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'CompactIndexHistory'>;
+export function rewardsEvent(
+  kind: 'ProposeAuthority',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'ProposeAuthority'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'ProposeAuthority'>;
+export function rewardsEvent(
+  kind: 'AcceptAuthority',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'AcceptAuthority'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'AcceptAuthority'>;
+export function rewardsEvent(
+  kind: 'SetPausedOperations',
+  data: GetDataEnumKindContent<RewardsEventArgs, 'SetPausedOperations'>['fields']
+): GetDataEnumKind<RewardsEventArgs, 'SetPausedOperations'>;
+export function rewardsEvent<K extends RewardsEventArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<RewardsEventArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isRewardsEvent<K extends RewardsEvent['__kind']>(
+  kind: K,
+  value: RewardsEvent
+): value is RewardsEvent & { __kind: K } {
+  return value.__kind === kind;
+}
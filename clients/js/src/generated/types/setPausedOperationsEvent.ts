@@ -0,0 +1,42 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type SetPausedOperationsEvent = {
+  rewardPool: PublicKey;
+  pausedOperations: number;
+  timestamp: bigint;
+};
+
+export type SetPausedOperationsEventArgs = {
+  rewardPool: PublicKey;
+  pausedOperations: number;
+  timestamp: number | bigint;
+};
+
+export function getSetPausedOperationsEventSerializer(): Serializer<
+  SetPausedOperationsEventArgs,
+  SetPausedOperationsEvent
+> {
+  return struct<SetPausedOperationsEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['pausedOperations', u8()],
+      ['timestamp', u64()],
+    ],
+    { description: 'SetPausedOperationsEvent' }
+  ) as Serializer<SetPausedOperationsEventArgs, SetPausedOperationsEvent>;
+}
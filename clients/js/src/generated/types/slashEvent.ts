@@ -0,0 +1,61 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Option,
+  OptionOrNullable,
+  PublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type SlashEvent = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  slashAmountInNative: bigint;
+  slashAmountMultipliedByPeriod: bigint;
+  stakeExpirationDate: Option<bigint>;
+  share: bigint;
+  totalShare: bigint;
+  timestamp: bigint;
+};
+
+export type SlashEventArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  slashAmountInNative: number | bigint;
+  slashAmountMultipliedByPeriod: number | bigint;
+  stakeExpirationDate: OptionOrNullable<number | bigint>;
+  share: number | bigint;
+  totalShare: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getSlashEventSerializer(): Serializer<
+  SlashEventArgs,
+  SlashEvent
+> {
+  return struct<SlashEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['slashAmountInNative', u64()],
+      ['slashAmountMultipliedByPeriod', u64()],
+      ['stakeExpirationDate', option(u64())],
+      ['share', u64()],
+      ['totalShare', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'SlashEvent' }
+  ) as Serializer<SlashEventArgs, SlashEvent>;
+}
@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type VaultLeftovers = {
+  rewardMint: PublicKey;
+  treasury: PublicKey;
+  amount: bigint;
+};
+
+export type VaultLeftoversArgs = {
+  rewardMint: PublicKey;
+  treasury: PublicKey;
+  amount: number | bigint;
+};
+
+export function getVaultLeftoversSerializer(): Serializer<
+  VaultLeftoversArgs,
+  VaultLeftovers
+> {
+  return struct<VaultLeftovers>(
+    [
+      ['rewardMint', publicKeySerializer()],
+      ['treasury', publicKeySerializer()],
+      ['amount', u64()],
+    ],
+    { description: 'VaultLeftovers' }
+  ) as Serializer<VaultLeftoversArgs, VaultLeftovers>;
+}
@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type VestingTranche = {
+  vestingAmounts: Array<bigint>;
+  lockedAmounts: Array<bigint>;
+  vestingStart: bigint;
+  cliffSecs: bigint;
+  durationSecs: bigint;
+};
+
+export type VestingTrancheArgs = {
+  vestingAmounts: Array<number | bigint>;
+  lockedAmounts: Array<number | bigint>;
+  vestingStart: number | bigint;
+  cliffSecs: number | bigint;
+  durationSecs: number | bigint;
+};
+
+export function getVestingTrancheSerializer(): Serializer<
+  VestingTrancheArgs,
+  VestingTranche
+> {
+  return struct<VestingTranche>(
+    [
+      ['vestingAmounts', array(u64(), { size: 3 })],
+      ['lockedAmounts', array(u64(), { size: 3 })],
+      ['vestingStart', u64()],
+      ['cliffSecs', u64()],
+      ['durationSecs', u64()],
+    ],
+    { description: 'VestingTranche' }
+  ) as Serializer<VestingTrancheArgs, VestingTranche>;
+}
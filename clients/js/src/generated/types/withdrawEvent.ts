@@ -0,0 +1,58 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import {
+  Option,
+  OptionOrNullable,
+  PublicKey,
+} from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type WithdrawEvent = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  amount: bigint;
+  delegateMining: Option<PublicKey>;
+  share: bigint;
+  totalShare: bigint;
+  timestamp: bigint;
+};
+
+export type WithdrawEventArgs = {
+  rewardPool: PublicKey;
+  mining: PublicKey;
+  amount: number | bigint;
+  delegateMining: OptionOrNullable<PublicKey>;
+  share: number | bigint;
+  totalShare: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getWithdrawEventSerializer(): Serializer<
+  WithdrawEventArgs,
+  WithdrawEvent
+> {
+  return struct<WithdrawEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['mining', publicKeySerializer()],
+      ['amount', u64()],
+      ['delegateMining', option(publicKeySerializer())],
+      ['share', u64()],
+      ['totalShare', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'WithdrawEvent' }
+  ) as Serializer<WithdrawEventArgs, WithdrawEvent>;
+}
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/trezoaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@trezoaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@trezoaplex-foundation/umi/serializers';
+
+export type WithdrawVestedEvent = {
+  rewardPool: PublicKey;
+  vesting: PublicKey;
+  rewardMint: PublicKey;
+  amount: bigint;
+  timestamp: bigint;
+};
+
+export type WithdrawVestedEventArgs = {
+  rewardPool: PublicKey;
+  vesting: PublicKey;
+  rewardMint: PublicKey;
+  amount: number | bigint;
+  timestamp: number | bigint;
+};
+
+export function getWithdrawVestedEventSerializer(): Serializer<
+  WithdrawVestedEventArgs,
+  WithdrawVestedEvent
+> {
+  return struct<WithdrawVestedEvent>(
+    [
+      ['rewardPool', publicKeySerializer()],
+      ['vesting', publicKeySerializer()],
+      ['rewardMint', publicKeySerializer()],
+      ['amount', u64()],
+      ['timestamp', u64()],
+    ],
+    { description: 'WithdrawVestedEvent' }
+  ) as Serializer<WithdrawVestedEventArgs, WithdrawVestedEvent>;
+}
@@ -0,0 +1,368 @@
+//! High-level client performing the common flows of the program over a pluggable RPC
+
+use crate::{
+    decoders::{DecodedMining, DecodedRewardPool, DecodedRewardToken},
+    pda::{find_mining_address, find_vault_address, find_vesting_address},
+};
+use std::future::Future;
+use tpl_associated_token_account::get_associated_token_address_with_program_id;
+use trezoa_program::{
+    borsh1::try_from_slice_unchecked,
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction, sysvar,
+};
+use trezoa_sdk::{
+    account::{from_account, Account},
+    signature::{Keypair, Signer},
+};
+use trz_rewards::instruction::with_token_program;
+use trz_rewards_core::{
+    state::{DistributionMode, LockupTier, PendingRewards, WrappedRewardPool},
+    utils::LockupPeriod,
+};
+
+/// RPC the client reaches the cluster through, e.g. the RPC client of a node
+/// or the banks client of a local test validator
+pub trait RewardsRpc {
+    type Error;
+
+    /// Address paying for the transactions and the accounts they create
+    fn payer(&self) -> Pubkey;
+
+    /// The account, none if it doesn't exist
+    fn get_account(
+        &mut self,
+        address: &Pubkey,
+    ) -> itpl Future<Output = Result<Option<Account>, Self::Error>>;
+
+    /// Lamports an account of the given size has to hold to be rent exempt
+    fn minimum_balance_for_rent_exemption(
+        &mut self,
+        data_len: usize,
+    ) -> itpl Future<Output = Result<u64, Self::Error>>;
+
+    /// Sends the transaction signed by the payer and the signers, waiting for its confirmation
+    fn send_transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> itpl Future<Output = Result<(), Self::Error>>;
+
+    /// Simulates the transaction paid by the payer, returning the data the program has returned
+    fn simulate_return_data(
+        &mut self,
+        instructions: &[Instruction],
+    ) -> itpl Future<Output = Result<Option<Vec<u8>>, Self::Error>>;
+}
+
+/// Failure of the client's flow
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError<E> {
+    /// The RPC request has failed
+    #[error("RPC request failed: {0:?}")]
+    Rpc(E),
+    /// The account the flow relies on doesn't exist
+    #[error("Account {0} not found")]
+    AccountNotFound(Pubkey),
+    /// The account or the returned data couldn't be decoded
+    #[error("Decoding failed: {0}")]
+    Decoding(#[from] ProgramError),
+    /// The program has returned no data
+    #[error("No data returned")]
+    NoReturnData,
+}
+
+/// Configuration of the reward pool to create
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    pub fill_authority: Pubkey,
+    pub distribute_authority: Pubkey,
+    pub admin: Pubkey,
+    /// Lockup tiers of the pool, empty means the default schedule
+    pub lockup_tiers: Vec<LockupTier>,
+    /// Length of the distribution epoch, zero means a day
+    pub epoch_length_secs: u64,
+    pub epoch_offset_secs: u64,
+    pub distribution_mode: DistributionMode,
+}
+
+/// Summary of the reward pool's state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Weighted stake of all the minings
+    pub total_share: u64,
+    pub open_minings: u64,
+    /// Beginning of the last distributed epoch, zero if it isn't tracked
+    pub last_distribution_at: u64,
+    pub reward_tokens: Vec<DecodedRewardToken>,
+    /// Date the next weighted stake modifier, e.g. of an expiring lockup, is applied at.
+    /// The modifiers dated before the current time are already due, so they're skipped.
+    pub next_modifier_at: Option<u64>,
+    /// Number of the index history records stored
+    pub index_history_len: usize,
+}
+
+/// Client of the program performing the common flows in as few transactions as possible
+pub struct RewardsClient<R> {
+    rpc: R,
+}
+
+itpl<R: RewardsRpc> RewardsClient<R> {
+    pub fn new(rpc: R) -> Self {
+        Self { rpc }
+    }
+
+    /// RPC the client sends the requests through
+    pub fn rpc(&mut self) -> &mut R {
+        &mut self.rpc
+    }
+
+    /// Creates the reward pool account and initializes it along with the vault of the reward mint.
+    /// The mint might belong to either of the token programs.
+    pub async fn create_pool(
+        &mut self,
+        reward_pool: &Keypair,
+        reward_mint: &Pubkey,
+        deposit_authority: &Keypair,
+        config: PoolConfig,
+    ) -> Result<(), ClientError<R::Error>> {
+        let token_program = self.account_owner(reward_mint).await?;
+        let lamports = self
+            .rpc
+            .minimum_balance_for_rent_exemption(WrappedRewardPool::LEN)
+            .await
+            .map_err(ClientError::Rpc)?;
+        let payer = self.rpc.payer();
+
+        let instructions = [
+            system_instruction::create_account(
+                &payer,
+                &reward_pool.pubkey(),
+                lamports,
+                WrappedRewardPool::LEN as u64,
+                &crate::ID,
+            ),
+            with_token_program(
+                trz_rewards::instruction::initialize_pool(
+                    &crate::ID,
+                    &reward_pool.pubkey(),
+                    reward_mint,
+                    &find_vault_address(&reward_pool.pubkey(), reward_mint).0,
+                    &payer,
+                    &deposit_authority.pubkey(),
+                    &config.fill_authority,
+                    &config.distribute_authority,
+                    &config.admin,
+                    config.lockup_tiers,
+                    config.epoch_length_secs,
+                    config.epoch_offset_secs,
+                    config.distribution_mode,
+                ),
+                &token_program,
+            ),
+        ];
+
+        self.rpc
+            .send_transaction(&instructions, &[reward_pool, deposit_authority])
+            .await
+            .map_err(ClientError::Rpc)
+    }
+
+    /// Deposits the stake to the owner's mining, initializing the mining first if there is none.
+    /// The stake isn't delegated. Returns the address of the mining.
+    pub async fn open_mining_and_deposit(
+        &mut self,
+        reward_pool: &Pubkey,
+        mining_owner: &Pubkey,
+        deposit_authority: &Keypair,
+        amount: u64,
+        lockup_period: LockupPeriod,
+    ) -> Result<Pubkey, ClientError<R::Error>> {
+        let (mining, _) = find_mining_address(reward_pool, mining_owner);
+
+        let mut instructions = vec![];
+        let mining_account = self
+            .rpc
+            .get_account(&mining)
+            .await
+            .map_err(ClientError::Rpc)?;
+        if mining_account.is_none() {
+            instructions.push(trz_rewards::instruction::initialize_mining(
+                &crate::ID,
+                reward_pool,
+                &mining,
+                &self.rpc.payer(),
+                mining_owner,
+            ));
+        }
+        instructions.push(trz_rewards::instruction::deposit_mining(
+            &crate::ID,
+            reward_pool,
+            &mining,
+            &deposit_authority.pubkey(),
+            &mining,
+            amount,
+            lockup_period,
+            mining_owner,
+            mining_owner,
+        ));
+
+        self.rpc
+            .send_transaction(&instructions, &[deposit_authority])
+            .await
+            .map_err(ClientError::Rpc)?;
+
+        Ok(mining)
+    }
+
+    /// Claims the rewards of every reward token having some to the owner's associated token
+    /// accounts, which have to exist. The deposit authority has to sign unless the pool allows
+    /// direct claims. Claimed rewards are locked in the mining's vesting if the pool vests them.
+    /// Returns the rewards of every reward token pending before the claim.
+    pub async fn claim_all(
+        &mut self,
+        reward_pool: &Pubkey,
+        mining_owner: &Keypair,
+        deposit_authority: Option<&Keypair>,
+    ) -> Result<PendingRewards, ClientError<R::Error>> {
+        let pool = self.reward_pool(reward_pool).await?;
+        let (mining, _) = find_mining_address(reward_pool, &mining_owner.pubkey());
+        let pending_rewards = self.pending_rewards(reward_pool, &mining).await?;
+
+        let mut instructions = vec![];
+        for (reward_token, unclaimed_rewards) in pool
+            .reward_tokens
+            .iter()
+            .zip(pending_rewards.unclaimed_rewards)
+        {
+            if unclaimed_rewards == 0 {
+                continue;
+            }
+
+            let token_program = self.account_owner(&reward_token.mint).await?;
+            let (vault, _) = find_vault_address(reward_pool, &reward_token.mint);
+            let mining_owner_reward_token = get_associated_token_address_with_program_id(
+                &mining_owner.pubkey(),
+                &reward_token.mint,
+                &token_program,
+            );
+            let mut instruction = match deposit_authority {
+                Some(deposit_authority) => trz_rewards::instruction::claim(
+                    &crate::ID,
+                    reward_pool,
+                    &reward_token.mint,
+                    &vault,
+                    &mining,
+                    &mining_owner.pubkey(),
+                    &deposit_authority.pubkey(),
+                    &mining_owner_reward_token,
+                ),
+                None => trz_rewards::instruction::claim_direct(
+                    &crate::ID,
+                    reward_pool,
+                    &reward_token.mint,
+                    &vault,
+                    &mining,
+                    &mining_owner.pubkey(),
+                    &mining_owner_reward_token,
+                ),
+            };
+            if pool.vesting_duration_secs != 0 {
+                let (vesting, _) = find_vesting_address(&mining);
+                instruction.accounts.push(AccountMeta::new(vesting, false));
+            }
+            instructions.push(with_token_program(instruction, &token_program));
+        }
+        if instructions.is_empty() {
+            return Ok(pending_rewards);
+        }
+
+        let mut signers = vec![mining_owner];
+        signers.extend(deposit_authority);
+        self.rpc
+            .send_transaction(&instructions, &signers)
+            .await
+            .map_err(ClientError::Rpc)?;
+
+        Ok(pending_rewards)
+    }
+
+    /// Rewards of the mining pending to be claimed at the moment, as the program calculates them
+    pub async fn pending_rewards(
+        &mut self,
+        reward_pool: &Pubkey,
+        mining: &Pubkey,
+    ) -> Result<PendingRewards, ClientError<R::Error>> {
+        let instruction =
+            trz_rewards::instruction::get_pending_rewards(&crate::ID, reward_pool, mining);
+        let return_data = self
+            .rpc
+            .simulate_return_data(&[instruction])
+            .await
+            .map_err(ClientError::Rpc)?
+            .ok_or(ClientError::NoReturnData)?;
+
+        Ok(try_from_slice_unchecked(&return_data).map_err(ProgramError::from)?)
+    }
+
+    /// Summary of the reward pool's state
+    pub async fn pool_stats(
+        &mut self,
+        reward_pool: &Pubkey,
+    ) -> Result<PoolStats, ClientError<R::Error>> {
+        let pool = self.reward_pool(reward_pool).await?;
+        let curr_ts = self.unix_timestamp().await?;
+
+        Ok(PoolStats {
+            total_share: pool.total_share,
+            open_minings: pool.open_minings,
+            last_distribution_at: pool.last_distribution_at,
+            next_modifier_at: pool
+                .upcoming_weighted_stake_diffs(curr_ts)
+                .first()
+                .map(|weighted_stake_diff| weighted_stake_diff.date),
+            index_history_len: pool.cumulative_index.len(),
+            reward_tokens: pool.reward_tokens,
+        })
+    }
+
+    /// The reward pool account decoded along with its trees
+    pub async fn reward_pool(
+        &mut self,
+        reward_pool: &Pubkey,
+    ) -> Result<DecodedRewardPool, ClientError<R::Error>> {
+        let account = self.account(reward_pool).await?;
+        Ok(DecodedRewardPool::from_account_data(&account.data)?)
+    }
+
+    /// The mining account decoded along with its weighted stake modifiers
+    pub async fn mining(
+        &mut self,
+        mining: &Pubkey,
+    ) -> Result<DecodedMining, ClientError<R::Error>> {
+        let account = self.account(mining).await?;
+        Ok(DecodedMining::from_account_data(&account.data)?)
+    }
+
+    async fn account(&mut self, address: &Pubkey) -> Result<Account, ClientError<R::Error>> {
+        self.rpc
+            .get_account(address)
+            .await
+            .map_err(ClientError::Rpc)?
+            .ok_or(ClientError::AccountNotFound(*address))
+    }
+
+    async fn account_owner(&mut self, address: &Pubkey) -> Result<Pubkey, ClientError<R::Error>> {
+        Ok(self.account(address).await?.owner)
+    }
+
+    /// The cluster's time, the way the program gets it
+    async fn unix_timestamp(&mut self) -> Result<u64, ClientError<R::Error>> {
+        let clock = self.account(&sysvar::clock::id()).await?;
+        let clock: Clock = from_account(&clock).ok_or(ProgramError::InvalidAccountData)?;
+        Ok(clock.unix_timestamp as u64)
+    }
+}
@@ -0,0 +1,314 @@
+//! Decoders of the whole mining and reward pool accounts. Unlike the generated accounts,
+//! they decode the weighted stake modifiers and index history trees stored after the headers.
+
+use crate::types::{CrankBounty, DistributionMode, EmissionCurve};
+use trezoa_program::{program_error::ProgramError, pubkey::Pubkey};
+use trz_rewards_core::{
+    state::{
+        self, LockupTier, Mining, RewardToken, WrappedImmutableMining, WrappedImmutableRewardPool,
+        WrappedRewardPool, AUTHORITY_ROLES, MAX_LOCKUP_TIERS, MAX_REWARD_TOKENS,
+    },
+    AlignedAccountData,
+};
+
+/// Weighted stake modifier applied at the date, e.g. when a lockup expires
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedStakeDiff {
+    pub date: u64,
+    /// Weighted stake subtracted at the date
+    pub diff: u64,
+}
+
+/// Index history record of the pool
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexRecord {
+    pub date: u64,
+    /// Indexes of every reward token at the date, ordered as the pool's reward tokens are
+    pub index_with_precision: [u128; MAX_REWARD_TOKENS],
+}
+
+/// Reward token the pool distributes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodedRewardToken {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    pub index_with_precision: u128,
+    pub distribution_ends_at: u64,
+    pub tokens_available_for_distribution: u64,
+    /// Bump of the vault the token is stored on
+    pub token_account_bump: u8,
+    /// Claimed rewards kept in the vault till they're withdrawn from the vestings
+    pub vesting_rewards: u64,
+}
+
+/// Lockup tier deposits might be made for
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodedLockupTier {
+    pub days: u64,
+    pub multiplier: u64,
+    pub enabled: bool,
+}
+
+itpl DecodedRewardToken {
+    fn new(reward_token: &RewardToken, vesting_rewards: u64) -> Self {
+        Self {
+            mint: reward_token.mint,
+            index_with_precision: reward_token.index_with_precision,
+            distribution_ends_at: reward_token.distribution_ends_at,
+            tokens_available_for_distribution: reward_token.tokens_available_for_distribution,
+            token_account_bump: reward_token.token_account_bump,
+            vesting_rewards,
+        }
+    }
+}
+
+itpl From<LockupTier> for DecodedLockupTier {
+    fn from(lockup_tier: LockupTier) -> Self {
+        Self {
+            days: lockup_tier.days,
+            multiplier: lockup_tier.multiplier,
+            enabled: lockup_tier.is_enabled(),
+        }
+    }
+}
+
+/// Mining account along with its weighted stake modifiers
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodedMining {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub reward_pool: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub owner: Pubkey,
+    pub index_with_precision: [u128; MAX_REWARD_TOKENS],
+    pub share: u64,
+    pub unclaimed_rewards: [u64; MAX_REWARD_TOKENS],
+    pub stake_from_others: u64,
+    pub modifiers_tree_capacity: u16,
+    /// Modifiers ordered by their dates, the earliest first
+    pub weighted_stake_diffs: Vec<WeightedStakeDiff>,
+}
+
+itpl DecodedMining {
+    /// Decodes the mining from the account data as it's returned by RPC
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Mining::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let data = AlignedAccountData::new(data);
+        let wrapped_mining = WrappedImmutableMining::from_bytes(data.bytes())?;
+        let mining = wrapped_mining.mining;
+        if !mining.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(Self {
+            reward_pool: mining.reward_pool,
+            owner: mining.owner,
+            index_with_precision: mining.index_with_precision,
+            share: mining.share,
+            unclaimed_rewards: mining.unclaimed_rewards,
+            stake_from_others: mining.stake_from_others,
+            modifiers_tree_capacity: mining.modifiers_tree_capacity,
+            weighted_stake_diffs: wrapped_mining
+                .weighted_stake_diffs
+                .iter()
+                .map(|(date, diff)| WeightedStakeDiff {
+                    date: *date,
+                    diff: *diff,
+                })
+                .collect(),
+        })
+    }
+
+    /// Modifiers applied after the given time, i.e. the upcoming lockup expirations
+    pub fn upcoming_weighted_stake_diffs(&self, after: u64) -> &[WeightedStakeDiff] {
+        let upcoming_from = self
+            .weighted_stake_diffs
+            .partition_point(|weighted_stake_diff| weighted_stake_diff.date <= after);
+        &self.weighted_stake_diffs[upcoming_from..]
+    }
+}
+
+/// Reward pool account along with its weighted stake modifiers and index history
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecodedRewardPool {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub deposit_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub distribute_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fill_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin: Pubkey,
+    /// Default pubkey means there is none
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub guardian: Pubkey,
+    /// Authorities proposed by the admin, indexed by `AuthorityRole`.
+    /// Default pubkey means there is no pending authority for the role.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::DisplayFromStr; AUTHORITY_ROLES]>")
+    )]
+    pub pending_authorities: [Pubkey; AUTHORITY_ROLES],
+    /// Reward tokens added to the pool, ordered the way the indexes are
+    pub reward_tokens: Vec<DecodedRewardToken>,
+    pub total_share: u64,
+    /// Lockup tiers indexed the way `LockupPeriod::Tier` addresses them, the first one is the base
+    pub lockup_tiers: [DecodedLockupTier; MAX_LOCKUP_TIERS],
+    pub epoch_length_secs: u64,
+    pub epoch_offset_secs: u64,
+    pub distribution_mode: DistributionMode,
+    pub emission_curve: EmissionCurve,
+    /// Whether anybody might distribute rewards, not only the distribute authority
+    pub permissionless_distribution: bool,
+    pub crank_bounty: CrankBounty,
+    /// Flags of the paused operations, see `PoolOperation`
+    pub paused_operations: u8,
+    /// Beginning of the last distributed epoch, zero if it isn't tracked
+    pub last_distribution_at: u64,
+    /// The time rewards have been streamed till in the streaming mode
+    pub last_accrued_at: u64,
+    pub open_minings: u64,
+    /// Whether the pool has been migrated from the legacy layout
+    pub migrated_from_legacy: bool,
+    /// Bump of the vault authority PDA owning the vaults, none if they aren't migrated to it
+    pub vault_authority_bump: Option<u8>,
+    /// Whether mining owners might claim without the deposit authority's signature
+    pub direct_claims: bool,
+    pub vesting_cliff_secs: u64,
+    /// Claimed rewards vest over the duration, zero means the vesting is disabled
+    pub vesting_duration_secs: u64,
+    /// Every open mining has been refreshed since the date, the index history before it
+    /// might be compacted
+    pub minings_checkpoint: u64,
+    /// The date the minings are being refreshed since to move the checkpoint to
+    pub pending_minings_checkpoint: u64,
+    /// Number of the open minings that haven't been refreshed since the pending checkpoint
+    pub minings_behind_checkpoint: u64,
+    /// Generation of the pending minings checkpoint, the minings refreshed since it have the same
+    pub checkpoint_generation: u8,
+    /// Modifiers ordered by their dates, the earliest first
+    pub weighted_stake_diffs: Vec<WeightedStakeDiff>,
+    /// Index history ordered by the dates, the earliest first
+    pub cumulative_index: Vec<IndexRecord>,
+}
+
+itpl DecodedRewardPool {
+    /// Decodes the reward pool from the account data as it's returned by RPC
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < WrappedRewardPool::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let data = AlignedAccountData::new(data);
+        let wrapped_reward_pool = WrappedImmutableRewardPool::from_bytes(data.bytes())?;
+        let pool = wrapped_reward_pool.pool;
+        if !pool.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(Self {
+            deposit_authority: pool.deposit_authority,
+            distribute_authority: pool.distribute_authority,
+            fill_authority: pool.fill_authority,
+            admin: pool.admin,
+            guardian: pool.guardian,
+            pending_authorities: pool.pending_authorities,
+            reward_tokens: pool
+                .reward_tokens
+                .iter()
+                .zip(pool.vesting_rewards)
+                .filter(|(reward_token, _)| reward_token.is_initialized())
+                .map(|(reward_token, vesting_rewards)| {
+                    DecodedRewardToken::new(reward_token, vesting_rewards)
+                })
+                .collect(),
+            total_share: pool.total_share,
+            lockup_tiers: pool.lockup_tiers.map(DecodedLockupTier::from),
+            epoch_length_secs: pool.epoch_length(),
+            epoch_offset_secs: pool.epoch_offset_secs,
+            distribution_mode: match pool.distribution_mode() {
+                state::DistributionMode::Epochs => DistributionMode::Epochs,
+                state::DistributionMode::Streaming => DistributionMode::Streaming,
+            },
+            emission_curve: match pool.emission_curve() {
+                state::EmissionCurve::Even => EmissionCurve::Even,
+                state::EmissionCurve::LinearDecay => EmissionCurve::LinearDecay,
+                state::EmissionCurve::Halving(epochs) => EmissionCurve::Halving(epochs),
+                state::EmissionCurve::Stepped(epochs) => EmissionCurve::Stepped(epochs),
+            },
+            permissionless_distribution: pool.is_distribution_permissionless(),
+            crank_bounty: match pool.crank_bounty() {
+                state::CrankBounty::None => CrankBounty::None,
+                state::CrankBounty::FixedAmount(amounts) => CrankBounty::FixedAmount(amounts),
+                state::CrankBounty::BasisPoints(bps) => CrankBounty::BasisPoints(bps),
+            },
+            paused_operations: pool.paused_operations,
+            last_distribution_at: pool.last_distribution_at,
+            last_accrued_at: pool.last_accrued_at,
+            open_minings: pool.open_minings,
+            migrated_from_legacy: pool.is_migrated_from_legacy(),
+            vault_authority_bump: pool.migrated_vault_authority_bump(),
+            direct_claims: pool.allows_direct_claims(),
+            vesting_cliff_secs: pool.vesting_cliff_secs,
+            vesting_duration_secs: pool.vesting_duration_secs,
+            minings_checkpoint: pool.minings_checkpoint,
+            pending_minings_checkpoint: pool.pending_minings_checkpoint,
+            minings_behind_checkpoint: pool.minings_behind_checkpoint,
+            checkpoint_generation: pool.checkpoint_generation(),
+            weighted_stake_diffs: wrapped_reward_pool
+                .weighted_stake_diffs
+                .iter()
+                .map(|(date, diff)| WeightedStakeDiff {
+                    date: *date,
+                    diff: *diff,
+                })
+                .collect(),
+            cumulative_index: wrapped_reward_pool
+                .cumulative_index
+                .iter()
+                .map(|(date, index_with_precision)| IndexRecord {
+                    date: *date,
+                    index_with_precision: *index_with_precision,
+                })
+                .collect(),
+        })
+    }
+
+    /// Modifiers applied after the given time, i.e. the upcoming lockup expirations
+    pub fn upcoming_weighted_stake_diffs(&self, after: u64) -> &[WeightedStakeDiff] {
+        let upcoming_from = self
+            .weighted_stake_diffs
+            .partition_point(|weighted_stake_diff| weighted_stake_diff.date <= after);
+        &self.weighted_stake_diffs[upcoming_from..]
+    }
+}
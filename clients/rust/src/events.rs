@@ -0,0 +1,45 @@
+//! Events the program logs with `sol_log_data` and their decoder.
+//! The events are the program's own, see `trz_rewards::events`.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::io::{Error, ErrorKind};
+pub use trz_rewards::{events::*, utils::LockupPeriod};
+
+const INVOKE_PREFIX: &str = "invoke [";
+const DATA_PREFIX: &str = "Program data: ";
+
+/// Decodes the events the program logged in the transaction, in the order they were logged.
+/// The data logged by other programs, including the ones the program invokes, is skipped.
+pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Result<Vec<RewardsEvent>, Error> {
+    let program_id = crate::ID.to_string();
+    let mut invoked_programs = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        let log = log.as_ref();
+        if let Some(data) = log.strip_prefix(DATA_PREFIX) {
+            if invoked_programs.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            for field in data.split(' ') {
+                let field = STANDARD
+                    .decode(field)
+                    .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+                events.push(RewardsEvent::decode(&field)?);
+            }
+        } else if let Some((program, status)) = log
+            .strip_prefix("Program ")
+            .and_then(|log| log.split_once(' '))
+        {
+            if status.starts_with(INVOKE_PREFIX) {
+                invoked_programs.push(program);
+            } else if (status == "success" || status.starts_with("failed"))
+                && invoked_programs.last() == Some(&program)
+            {
+                invoked_programs.pop();
+            }
+        }
+    }
+
+    Ok(events)
+}
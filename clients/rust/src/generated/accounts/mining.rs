@@ -5,6 +5,7 @@
 //! [https://github.com/trezoaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::MergedWeightedStakeDiff;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use trezoa_program::pubkey::Pubkey;
@@ -22,21 +23,18 @@ pub struct Mining {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub owner: Pubkey,
-    #[cfg_attr(
-        feature = "serde",
-        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
-    )]
-    pub reward_mint: Pubkey,
-    pub index_with_precision: u128,
+    pub index_with_precision: [u128; 3],
     pub share: u64,
-    pub unclaimed_rewards: u64,
+    pub unclaimed_rewards: [u64; 3],
     pub stake_from_others: u64,
     pub bump: u8,
-    pub data: [u8; 7],
+    pub data: [u8; 5],
+    pub modifiers_tree_capacity: u16,
+    pub merged_weighted_stake_diffs: [MergedWeightedStakeDiff; 4],
 }
 
 itpl Mining {
-    pub const LEN: usize = 144;
+    pub const LEN: usize = 256;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -5,8 +5,14 @@
 //! [https://github.com/trezoaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#delegate;
+pub(crate) mod r#delegation;
 pub(crate) mod r#mining;
 pub(crate) mod r#reward_pool;
+pub(crate) mod r#vesting;
 
+pub use self::r#delegate::*;
+pub use self::r#delegation::*;
 pub use self::r#mining::*;
 pub use self::r#reward_pool::*;
+pub use self::r#vesting::*;
@@ -5,6 +5,8 @@
 //! [https://github.com/trezoaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::LockupTier;
+use crate::generated::types::RewardToken;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use trezoa_program::pubkey::Pubkey;
@@ -27,21 +29,46 @@ pub struct RewardPool {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub fill_authority: Pubkey,
+    pub reward_tokens: [RewardToken; 3],
+    pub total_share: u64,
+    pub data: [u8; 8],
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
-    pub reward_mint: Pubkey,
-    pub index_with_precision: u128,
-    pub total_share: u64,
-    pub distribution_ends_at: u64,
-    pub tokens_available_for_distribution: u64,
-    pub token_account_bump: u8,
-    pub data: [u8; 7],
+    pub admin: Pubkey,
+    pub lockup_tiers: [LockupTier; 8],
+    pub epoch_length_secs: u64,
+    pub epoch_offset_secs: u64,
+    pub last_accrued_at: u64,
+    pub distribution_mode: u8,
+    pub permissionless_distribution: u8,
+    pub crank_bounty_kind: u8,
+    pub paused_operations: u8,
+    pub vault_authority_migrated: u8,
+    pub vault_authority_bump: u8,
+    pub direct_claims: u8,
+    pub emission_curve_kind: u8,
+    pub crank_bounty_value: u64,
+    pub last_distribution_at: u64,
+    pub pending_authorities: [Pubkey; 5],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub guardian: Pubkey,
+    pub open_minings: u64,
+    pub vesting_cliff_secs: u64,
+    pub vesting_duration_secs: u64,
+    pub emission_curve_epochs: u64,
+    pub minings_checkpoint: u64,
+    pub pending_minings_checkpoint: u64,
+    pub minings_behind_checkpoint: u64,
+    pub vesting_rewards: [u64; 3],
 }
 
 itpl RewardPool {
-    pub const LEN: usize = 176;
+    pub const LEN: usize = 896;
 
     #[inline(always)]
     pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
@@ -60,6 +60,81 @@ pub enum TrzRewardsError {
         "Rewards: Penalty is not apliable becase it's bigger than the mining's weighted stake"
     )]
     DecreaseRewardsTooBig,
+    /// 16 (0x10) - Rewards: lockup tier is disabled
+    #[error("Rewards: lockup tier is disabled")]
+    LockupTierIsDisabled,
+    /// 17 (0x11) - Rewards: lockup tier is invalid
+    #[error("Rewards: lockup tier is invalid")]
+    InvalidLockupTier,
+    /// 18 (0x12) - Rewards: lockup tier duration and multiplier can't be changed once configured
+    #[error("Rewards: lockup tier duration and multiplier can't be changed once configured")]
+    LockupTierIsImmutable,
+    /// 19 (0x13) - Rewards: the mint isn't one of the pool's reward tokens
+    #[error("Rewards: the mint isn't one of the pool's reward tokens")]
+    UnknownRewardMint,
+    /// 20 (0x14) - Rewards: the mint has already been added to the pool
+    #[error("Rewards: the mint has already been added to the pool")]
+    RewardMintAlreadyAdded,
+    /// 21 (0x15) - Rewards: the pool distributes max number of reward tokens already
+    #[error("Rewards: the pool distributes max number of reward tokens already")]
+    RewardTokensLimitReached,
+    /// 22 (0x16) - Rewards: index history is full, it has to be compacted
+    #[error("Rewards: index history is full, it has to be compacted")]
+    IndexHistoryIsFull,
+    /// 23 (0x17) - Rewards: pool's weighted stake modifiers tree is full
+    #[error("Rewards: pool's weighted stake modifiers tree is full")]
+    PoolModifiersTreeIsFull,
+    /// 24 (0x18) - Rewards: mining's weighted stake modifiers tree is full
+    #[error("Rewards: mining's weighted stake modifiers tree is full")]
+    MiningModifiersTreeIsFull,
+    /// 25 (0x19) - Rewards: unsupported mining's weighted stake modifiers tree capacity
+    #[error("Rewards: unsupported mining's weighted stake modifiers tree capacity")]
+    InvalidModifiersTreeCapacity,
+    /// 26 (0x1A) - Rewards: invalid distribution epoch configuration
+    #[error("Rewards: invalid distribution epoch configuration")]
+    InvalidEpoch,
+    /// 27 (0x1B) - Rewards: invalid crank bounty
+    #[error("Rewards: invalid crank bounty")]
+    InvalidCrankBounty,
+    /// 28 (0x1C) - Rewards: no such pending authority
+    #[error("Rewards: no such pending authority")]
+    NoPendingAuthority,
+    /// 29 (0x1D) - Rewards: the operation is paused for the pool
+    #[error("Rewards: the operation is paused for the pool")]
+    PoolPaused,
+    /// 30 (0x1E) - Rewards: not enough undistributed rewards in the vault
+    #[error("Rewards: not enough undistributed rewards in the vault")]
+    NotEnoughUndistributedRewards,
+    /// 31 (0x1F) - Rewards: the pool is still in use and cannot be closed
+    #[error("Rewards: the pool is still in use and cannot be closed")]
+    PoolIsInUse,
+    /// 32 (0x20) - Rewards: vault authority is not migrated to the PDA
+    #[error("Rewards: vault authority is not migrated to the PDA")]
+    VaultAuthorityNotMigrated,
+    /// 33 (0x21) - Rewards: claim amount exceeds unclaimed rewards
+    #[error("Rewards: claim amount exceeds unclaimed rewards")]
+    ClaimAmountExceedsRewards,
+    /// 34 (0x22) - Rewards: invalid vesting schedule
+    #[error("Rewards: invalid vesting schedule")]
+    InvalidVestingSchedule,
+    /// 35 (0x23) - Rewards: invalid delegate commission
+    #[error("Rewards: invalid delegate commission")]
+    InvalidCommission,
+    /// 36 (0x24) - Rewards: delegate and delegation accounts are missing
+    #[error("Rewards: delegate and delegation accounts are missing")]
+    DelegationAccountsMissing,
+    /// 37 (0x25) - Rewards: invalid emission curve
+    #[error("Rewards: invalid emission curve")]
+    InvalidEmissionCurve,
+    /// 38 (0x26) - Rewards: the account isn't stored in the legacy layout
+    #[error("Rewards: the account isn't stored in the legacy layout")]
+    NotLegacyAccount,
+    /// 39 (0x27) - Rewards: not every mining has been refreshed since the date
+    #[error("Rewards: not every mining has been refreshed since the date")]
+    MiningsCheckpointNotReached,
+    /// 40 (0x28) - Rewards: no room in the vesting for one more claim
+    #[error("Rewards: no room in the vesting for one more claim")]
+    VestingTranchesAreFull,
 }
 
 itpl trezoa_program::program_error::PrintProgramError for TrzRewardsError {
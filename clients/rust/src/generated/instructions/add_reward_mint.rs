@@ -0,0 +1,605 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/trezoaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct AddRewardMint {
+    /// The address of the reward pool
+    pub reward_pool: trezoa_program::pubkey::Pubkey,
+    /// The address of the reward mint
+    pub reward_mint: trezoa_program::pubkey::Pubkey,
+    /// The address of the reward vault
+    pub vault: trezoa_program::pubkey::Pubkey,
+
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// Owner of the new vault: the deposit authority, or the vault authority PDA once the vaults are migrated
+    pub deposit_authority: trezoa_program::pubkey::Pubkey,
+    /// The address of the pool's admin
+    pub admin: trezoa_program::pubkey::Pubkey,
+    /// The address of the Rent program
+    pub rent: trezoa_program::pubkey::Pubkey,
+    /// The address of the Token program where rewards are minted
+    pub token_program: trezoa_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: trezoa_program::pubkey::Pubkey,
+}
+
+itpl AddRewardMint {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.reward_pool,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.reward_mint,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.deposit_authority,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.admin, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.rent, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = AddRewardMintInstructionData::new().try_to_vec().unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct AddRewardMintInstructionData {
+    discriminator: u8,
+}
+
+itpl AddRewardMintInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 13 }
+    }
+}
+
+/// Instruction builder for `AddRewardMint`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[]` reward_mint
+///   2. `[writable]` vault
+///   3. `[writable, signer]` payer
+///   4. `[]` deposit_authority
+///   5. `[signer]` admin
+///   6. `[optional]` rent (default to `SysvarRent111111111111111111111111111111111`)
+///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct AddRewardMintBuilder {
+    reward_pool: Option<trezoa_program::pubkey::Pubkey>,
+    reward_mint: Option<trezoa_program::pubkey::Pubkey>,
+    vault: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
+    admin: Option<trezoa_program::pubkey::Pubkey>,
+    rent: Option<trezoa_program::pubkey::Pubkey>,
+    token_program: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+itpl AddRewardMintBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the reward mint
+    #[inline(always)]
+    pub fn reward_mint(&mut self, reward_mint: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_mint = Some(reward_mint);
+        self
+    }
+    /// The address of the reward vault
+    #[inline(always)]
+    pub fn vault(&mut self, vault: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Owner of the new vault: the deposit authority, or the vault authority PDA once the vaults are migrated
+    #[inline(always)]
+    pub fn deposit_authority(
+        &mut self,
+        deposit_authority: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.deposit_authority = Some(deposit_authority);
+        self
+    }
+    /// The address of the pool's admin
+    #[inline(always)]
+    pub fn admin(&mut self, admin: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    /// `[optional account, default to 'SysvarRent111111111111111111111111111111111']`
+    /// The address of the Rent program
+    #[inline(always)]
+    pub fn rent(&mut self, rent: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.rent = Some(rent);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = AddRewardMint {
+            reward_pool: self.reward_pool.expect("reward_pool is not set"),
+            reward_mint: self.reward_mint.expect("reward_mint is not set"),
+            vault: self.vault.expect("vault is not set"),
+            payer: self.payer.expect("payer is not set"),
+            deposit_authority: self
+                .deposit_authority
+                .expect("deposit_authority is not set"),
+            admin: self.admin.expect("admin is not set"),
+            rent: self.rent.unwrap_or(trezoa_program::pubkey!(
+                "SysvarRent111111111111111111111111111111111"
+            )),
+            token_program: self.token_program.unwrap_or(trezoa_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `add_reward_mint` CPI accounts.
+pub struct AddRewardMintCpiAccounts<'a, 'b> {
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward mint
+    pub reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward vault
+    pub vault: &'b trezoa_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Owner of the new vault: the deposit authority, or the vault authority PDA once the vaults are migrated
+    pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the pool's admin
+    pub admin: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Rent program
+    pub rent: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `add_reward_mint` CPI instruction.
+pub struct AddRewardMintCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward mint
+    pub reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward vault
+    pub vault: &'b trezoa_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// Owner of the new vault: the deposit authority, or the vault authority PDA once the vaults are migrated
+    pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the pool's admin
+    pub admin: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Rent program
+    pub rent: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+itpl<'a, 'b> AddRewardMintCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: AddRewardMintCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            reward_pool: accounts.reward_pool,
+            reward_mint: accounts.reward_mint,
+            vault: accounts.vault,
+            payer: accounts.payer,
+            deposit_authority: accounts.deposit_authority,
+            admin: accounts.admin,
+            rent: accounts.rent,
+            token_program: accounts.token_program,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.reward_pool.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.reward_mint.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.deposit_authority.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.admin.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.rent.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = AddRewardMintInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.reward_pool.clone());
+        account_infos.push(self.reward_mint.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.deposit_authority.clone());
+        account_infos.push(self.admin.clone());
+        account_infos.push(self.rent.clone());
+        account_infos.push(self.token_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AddRewardMint` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[]` reward_mint
+///   2. `[writable]` vault
+///   3. `[writable, signer]` payer
+///   4. `[]` deposit_authority
+///   5. `[signer]` admin
+///   6. `[]` rent
+///   7. `[]` token_program
+///   8. `[]` system_program
+pub struct AddRewardMintCpiBuilder<'a, 'b> {
+    instruction: Box<AddRewardMintCpiBuilderInstruction<'a, 'b>>,
+}
+
+itpl<'a, 'b> AddRewardMintCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AddRewardMintCpiBuilderInstruction {
+            __program: program,
+            reward_pool: None,
+            reward_mint: None,
+            vault: None,
+            payer: None,
+            deposit_authority: None,
+            admin: None,
+            rent: None,
+            token_program: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(
+        &mut self,
+        reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the reward mint
+    #[inline(always)]
+    pub fn reward_mint(
+        &mut self,
+        reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_mint = Some(reward_mint);
+        self
+    }
+    /// The address of the reward vault
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Owner of the new vault: the deposit authority, or the vault authority PDA once the vaults are migrated
+    #[inline(always)]
+    pub fn deposit_authority(
+        &mut self,
+        deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.deposit_authority = Some(deposit_authority);
+        self
+    }
+    /// The address of the pool's admin
+    #[inline(always)]
+    pub fn admin(&mut self, admin: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.admin = Some(admin);
+        self
+    }
+    /// The address of the Rent program
+    #[inline(always)]
+    pub fn rent(&mut self, rent: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.rent = Some(rent);
+        self
+    }
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = AddRewardMintCpi {
+            __program: self.instruction.__program,
+
+            reward_pool: self
+                .instruction
+                .reward_pool
+                .expect("reward_pool is not set"),
+
+            reward_mint: self
+                .instruction
+                .reward_mint
+                .expect("reward_mint is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            deposit_authority: self
+                .instruction
+                .deposit_authority
+                .expect("deposit_authority is not set"),
+
+            admin: self.instruction.admin.expect("admin is not set"),
+
+            rent: self.instruction.rent.expect("rent is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AddRewardMintCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    reward_pool: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    reward_mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    admin: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    rent: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -23,6 +23,14 @@ pub struct ChangeDelegate {
     pub old_delegate_mining: trezoa_program::pubkey::Pubkey,
     /// The address of the new delegate mining account
     pub new_delegate_mining: trezoa_program::pubkey::Pubkey,
+    /// The delegate account of the old delegate mining, required if the old delegate charges a commission
+    pub old_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// The delegation of the mining to the old delegate, required if the old delegate charges a commission
+    pub old_delegation: Option<trezoa_program::pubkey::Pubkey>,
+    /// The delegate account of the new delegate mining, required if the new delegate charges a commission
+    pub new_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// The delegation of the mining to the new delegate, required if the new delegate charges a commission
+    pub new_delegation: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 itpl ChangeDelegate {
@@ -38,7 +46,7 @@ itpl ChangeDelegate {
         args: ChangeDelegateInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.reward_pool,
             false,
@@ -63,6 +71,30 @@ itpl ChangeDelegate {
             self.new_delegate_mining,
             false,
         ));
+        if let Some(old_delegate) = self.old_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                old_delegate,
+                false,
+            ));
+        }
+        if let Some(old_delegation) = self.old_delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                old_delegation,
+                false,
+            ));
+        }
+        if let Some(new_delegate) = self.new_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                new_delegate,
+                false,
+            ));
+        }
+        if let Some(new_delegation) = self.new_delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                new_delegation,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ChangeDelegateInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -104,6 +136,10 @@ pub struct ChangeDelegateInstructionArgs {
 ///   3. `[signer]` mining_owner
 ///   4. `[writable]` old_delegate_mining
 ///   5. `[writable]` new_delegate_mining
+///   6. `[writable, optional]` old_delegate
+///   7. `[writable, optional]` old_delegation
+///   8. `[writable, optional]` new_delegate
+///   9. `[writable, optional]` new_delegation
 #[derive(Default)]
 pub struct ChangeDelegateBuilder {
     reward_pool: Option<trezoa_program::pubkey::Pubkey>,
@@ -112,6 +148,10 @@ pub struct ChangeDelegateBuilder {
     mining_owner: Option<trezoa_program::pubkey::Pubkey>,
     old_delegate_mining: Option<trezoa_program::pubkey::Pubkey>,
     new_delegate_mining: Option<trezoa_program::pubkey::Pubkey>,
+    old_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    old_delegation: Option<trezoa_program::pubkey::Pubkey>,
+    new_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    new_delegation: Option<trezoa_program::pubkey::Pubkey>,
     staked_amount: Option<u64>,
     new_delegate: Option<Pubkey>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
@@ -166,6 +206,46 @@ itpl ChangeDelegateBuilder {
         self.new_delegate_mining = Some(new_delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the old delegate mining, required if the old delegate charges a commission
+    #[inline(always)]
+    pub fn old_delegate(
+        &mut self,
+        old_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.old_delegate = old_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the old delegate, required if the old delegate charges a commission
+    #[inline(always)]
+    pub fn old_delegation(
+        &mut self,
+        old_delegation: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.old_delegation = old_delegation;
+        self
+    }
+    /// `[optional account]`
+    /// The delegate account of the new delegate mining, required if the new delegate charges a commission
+    #[inline(always)]
+    pub fn new_delegate(
+        &mut self,
+        new_delegate: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.new_delegate = new_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the new delegate, required if the new delegate charges a commission
+    #[inline(always)]
+    pub fn new_delegation(
+        &mut self,
+        new_delegation: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.new_delegation = new_delegation;
+        self
+    }
     #[inline(always)]
     pub fn staked_amount(&mut self, staked_amount: u64) -> &mut Self {
         self.staked_amount = Some(staked_amount);
@@ -209,6 +289,10 @@ itpl ChangeDelegateBuilder {
             new_delegate_mining: self
                 .new_delegate_mining
                 .expect("new_delegate_mining is not set"),
+            old_delegate: self.old_delegate,
+            old_delegation: self.old_delegation,
+            new_delegate: self.new_delegate,
+            new_delegation: self.new_delegation,
         };
         let args = ChangeDelegateInstructionArgs {
             staked_amount: self
@@ -236,6 +320,14 @@ pub struct ChangeDelegateCpiAccounts<'a, 'b> {
     pub old_delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of the new delegate mining account
     pub new_delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the old delegate mining, required if the old delegate charges a commission
+    pub old_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the old delegate, required if the old delegate charges a commission
+    pub old_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegate account of the new delegate mining, required if the new delegate charges a commission
+    pub new_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the new delegate, required if the new delegate charges a commission
+    pub new_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `change_delegate` CPI instruction.
@@ -254,6 +346,14 @@ pub struct ChangeDelegateCpi<'a, 'b> {
     pub old_delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of the new delegate mining account
     pub new_delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the old delegate mining, required if the old delegate charges a commission
+    pub old_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the old delegate, required if the old delegate charges a commission
+    pub old_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegate account of the new delegate mining, required if the new delegate charges a commission
+    pub new_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the new delegate, required if the new delegate charges a commission
+    pub new_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ChangeDelegateInstructionArgs,
 }
@@ -272,6 +372,10 @@ itpl<'a, 'b> ChangeDelegateCpi<'a, 'b> {
             mining_owner: accounts.mining_owner,
             old_delegate_mining: accounts.old_delegate_mining,
             new_delegate_mining: accounts.new_delegate_mining,
+            old_delegate: accounts.old_delegate,
+            old_delegation: accounts.old_delegation,
+            new_delegate: accounts.new_delegate,
+            new_delegation: accounts.new_delegation,
             __args: args,
         }
     }
@@ -308,7 +412,7 @@ itpl<'a, 'b> ChangeDelegateCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.reward_pool.key,
             false,
@@ -333,6 +437,30 @@ itpl<'a, 'b> ChangeDelegateCpi<'a, 'b> {
             *self.new_delegate_mining.key,
             false,
         ));
+        if let Some(old_delegate) = self.old_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *old_delegate.key,
+                false,
+            ));
+        }
+        if let Some(old_delegation) = self.old_delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *old_delegation.key,
+                false,
+            ));
+        }
+        if let Some(new_delegate) = self.new_delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *new_delegate.key,
+                false,
+            ));
+        }
+        if let Some(new_delegation) = self.new_delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *new_delegation.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -349,7 +477,7 @@ itpl<'a, 'b> ChangeDelegateCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.reward_pool.clone());
         account_infos.push(self.mining.clone());
@@ -357,6 +485,18 @@ itpl<'a, 'b> ChangeDelegateCpi<'a, 'b> {
         account_infos.push(self.mining_owner.clone());
         account_infos.push(self.old_delegate_mining.clone());
         account_infos.push(self.new_delegate_mining.clone());
+        if let Some(old_delegate) = self.old_delegate {
+            account_infos.push(old_delegate.clone());
+        }
+        if let Some(old_delegation) = self.old_delegation {
+            account_infos.push(old_delegation.clone());
+        }
+        if let Some(new_delegate) = self.new_delegate {
+            account_infos.push(new_delegate.clone());
+        }
+        if let Some(new_delegation) = self.new_delegation {
+            account_infos.push(new_delegation.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -379,6 +519,10 @@ itpl<'a, 'b> ChangeDelegateCpi<'a, 'b> {
 ///   3. `[signer]` mining_owner
 ///   4. `[writable]` old_delegate_mining
 ///   5. `[writable]` new_delegate_mining
+///   6. `[writable, optional]` old_delegate
+///   7. `[writable, optional]` old_delegation
+///   8. `[writable, optional]` new_delegate
+///   9. `[writable, optional]` new_delegation
 pub struct ChangeDelegateCpiBuilder<'a, 'b> {
     instruction: Box<ChangeDelegateCpiBuilderInstruction<'a, 'b>>,
 }
@@ -393,6 +537,10 @@ itpl<'a, 'b> ChangeDelegateCpiBuilder<'a, 'b> {
             mining_owner: None,
             old_delegate_mining: None,
             new_delegate_mining: None,
+            old_delegate: None,
+            old_delegation: None,
+            new_delegate: None,
+            new_delegation: None,
             staked_amount: None,
             new_delegate: None,
             __remaining_accounts: Vec::new(),
@@ -453,6 +601,46 @@ itpl<'a, 'b> ChangeDelegateCpiBuilder<'a, 'b> {
         self.instruction.new_delegate_mining = Some(new_delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the old delegate mining, required if the old delegate charges a commission
+    #[inline(always)]
+    pub fn old_delegate(
+        &mut self,
+        old_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.old_delegate = old_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the old delegate, required if the old delegate charges a commission
+    #[inline(always)]
+    pub fn old_delegation(
+        &mut self,
+        old_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.old_delegation = old_delegation;
+        self
+    }
+    /// `[optional account]`
+    /// The delegate account of the new delegate mining, required if the new delegate charges a commission
+    #[inline(always)]
+    pub fn new_delegate(
+        &mut self,
+        new_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.new_delegate = new_delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the new delegate, required if the new delegate charges a commission
+    #[inline(always)]
+    pub fn new_delegation(
+        &mut self,
+        new_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.new_delegation = new_delegation;
+        self
+    }
     #[inline(always)]
     pub fn staked_amount(&mut self, staked_amount: u64) -> &mut Self {
         self.instruction.staked_amount = Some(staked_amount);
@@ -545,6 +733,14 @@ itpl<'a, 'b> ChangeDelegateCpiBuilder<'a, 'b> {
                 .instruction
                 .new_delegate_mining
                 .expect("new_delegate_mining is not set"),
+
+            old_delegate: self.instruction.old_delegate,
+
+            old_delegation: self.instruction.old_delegation,
+
+            new_delegate: self.instruction.new_delegate,
+
+            new_delegation: self.instruction.new_delegation,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -562,6 +758,10 @@ struct ChangeDelegateCpiBuilderInstruction<'a, 'b> {
     mining_owner: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     old_delegate_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     new_delegate_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    old_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    old_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    new_delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    new_delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     staked_amount: Option<u64>,
     new_delegate: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
@@ -20,12 +20,16 @@ pub struct Claim {
     pub mining: trezoa_program::pubkey::Pubkey,
     /// The end user the mining accounts belongs to
     pub mining_owner: trezoa_program::pubkey::Pubkey,
-    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs
-    pub deposit_authority: trezoa_program::pubkey::Pubkey,
+    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed
+    pub deposit_authority: (trezoa_program::pubkey::Pubkey, bool),
     /// ATA where tokens will be claimed to
     pub mining_owner_reward_token_account: trezoa_program::pubkey::Pubkey,
     /// The address of the Token program where rewards are minted
     pub token_program: trezoa_program::pubkey::Pubkey,
+    /// The vault authority PDA, required once the vaults are migrated to it
+    pub vault_authority: Option<trezoa_program::pubkey::Pubkey>,
+    /// The vesting account of the mining, required if the pool vests claimed rewards
+    pub vesting: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 itpl Claim {
@@ -37,8 +41,8 @@ itpl Claim {
         &self,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
-        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.reward_pool,
             false,
         ));
@@ -58,8 +62,8 @@ itpl Claim {
             true,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-            self.deposit_authority,
-            true,
+            self.deposit_authority.0,
+            self.deposit_authority.1,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.mining_owner_reward_token_account,
@@ -69,6 +73,17 @@ itpl Claim {
             self.token_program,
             false,
         ));
+        if let Some(vault_authority) = self.vault_authority {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                vault_authority,
+                false,
+            ));
+        }
+        if let Some(vesting) = self.vesting {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                vesting, false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = ClaimInstructionData::new().try_to_vec().unwrap();
 
@@ -95,7 +110,7 @@ itpl ClaimInstructionData {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` reward_pool
+///   0. `[writable]` reward_pool
 ///   1. `[]` reward_mint
 ///   2. `[writable]` vault
 ///   3. `[writable]` mining
@@ -103,6 +118,8 @@ itpl ClaimInstructionData {
 ///   5. `[signer]` deposit_authority
 ///   6. `[writable]` mining_owner_reward_token_account
 ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+///   8. `[optional]` vault_authority
+///   9. `[writable, optional]` vesting
 #[derive(Default)]
 pub struct ClaimBuilder {
     reward_pool: Option<trezoa_program::pubkey::Pubkey>,
@@ -110,9 +127,11 @@ pub struct ClaimBuilder {
     vault: Option<trezoa_program::pubkey::Pubkey>,
     mining: Option<trezoa_program::pubkey::Pubkey>,
     mining_owner: Option<trezoa_program::pubkey::Pubkey>,
-    deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
+    deposit_authority: Option<(trezoa_program::pubkey::Pubkey, bool)>,
     mining_owner_reward_token_account: Option<trezoa_program::pubkey::Pubkey>,
     token_program: Option<trezoa_program::pubkey::Pubkey>,
+    vault_authority: Option<trezoa_program::pubkey::Pubkey>,
+    vesting: Option<trezoa_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
@@ -150,13 +169,14 @@ itpl ClaimBuilder {
         self.mining_owner = Some(mining_owner);
         self
     }
-    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs
+    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed
     #[inline(always)]
     pub fn deposit_authority(
         &mut self,
         deposit_authority: trezoa_program::pubkey::Pubkey,
+        as_signer: bool,
     ) -> &mut Self {
-        self.deposit_authority = Some(deposit_authority);
+        self.deposit_authority = Some((deposit_authority, as_signer));
         self
     }
     /// ATA where tokens will be claimed to
@@ -175,6 +195,23 @@ itpl ClaimBuilder {
         self.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// The vault authority PDA, required once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_authority(
+        &mut self,
+        vault_authority: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.vault_authority = vault_authority;
+        self
+    }
+    /// `[optional account]`
+    /// The vesting account of the mining, required if the pool vests claimed rewards
+    #[inline(always)]
+    pub fn vesting(&mut self, vesting: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.vesting = vesting;
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -210,6 +247,8 @@ itpl ClaimBuilder {
             token_program: self.token_program.unwrap_or(trezoa_program::pubkey!(
                 "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
             )),
+            vault_authority: self.vault_authority,
+            vesting: self.vesting,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -228,12 +267,16 @@ pub struct ClaimCpiAccounts<'a, 'b> {
     pub mining: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The end user the mining accounts belongs to
     pub mining_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs
-    pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed
+    pub deposit_authority: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
     /// ATA where tokens will be claimed to
     pub mining_owner_reward_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of the Token program where rewards are minted
     pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The vault authority PDA, required once the vaults are migrated to it
+    pub vault_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The vesting account of the mining, required if the pool vests claimed rewards
+    pub vesting: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `claim` CPI instruction.
@@ -250,12 +293,16 @@ pub struct ClaimCpi<'a, 'b> {
     pub mining: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The end user the mining accounts belongs to
     pub mining_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs
-    pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed
+    pub deposit_authority: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
     /// ATA where tokens will be claimed to
     pub mining_owner_reward_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of the Token program where rewards are minted
     pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The vault authority PDA, required once the vaults are migrated to it
+    pub vault_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The vesting account of the mining, required if the pool vests claimed rewards
+    pub vesting: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 itpl<'a, 'b> ClaimCpi<'a, 'b> {
@@ -273,6 +320,8 @@ itpl<'a, 'b> ClaimCpi<'a, 'b> {
             deposit_authority: accounts.deposit_authority,
             mining_owner_reward_token_account: accounts.mining_owner_reward_token_account,
             token_program: accounts.token_program,
+            vault_authority: accounts.vault_authority,
+            vesting: accounts.vesting,
         }
     }
     #[inline(always)]
@@ -308,8 +357,8 @@ itpl<'a, 'b> ClaimCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
-        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.reward_pool.key,
             false,
         ));
@@ -330,8 +379,8 @@ itpl<'a, 'b> ClaimCpi<'a, 'b> {
             true,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
-            *self.deposit_authority.key,
-            true,
+            *self.deposit_authority.0.key,
+            self.deposit_authority.1,
         ));
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.mining_owner_reward_token_account.key,
@@ -341,6 +390,18 @@ itpl<'a, 'b> ClaimCpi<'a, 'b> {
             *self.token_program.key,
             false,
         ));
+        if let Some(vault_authority) = self.vault_authority {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *vault_authority.key,
+                false,
+            ));
+        }
+        if let Some(vesting) = self.vesting {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *vesting.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -355,16 +416,22 @@ itpl<'a, 'b> ClaimCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.reward_pool.clone());
         account_infos.push(self.reward_mint.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.mining.clone());
         account_infos.push(self.mining_owner.clone());
-        account_infos.push(self.deposit_authority.clone());
+        account_infos.push(self.deposit_authority.0.clone());
         account_infos.push(self.mining_owner_reward_token_account.clone());
         account_infos.push(self.token_program.clone());
+        if let Some(vault_authority) = self.vault_authority {
+            account_infos.push(vault_authority.clone());
+        }
+        if let Some(vesting) = self.vesting {
+            account_infos.push(vesting.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -381,7 +448,7 @@ itpl<'a, 'b> ClaimCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` reward_pool
+///   0. `[writable]` reward_pool
 ///   1. `[]` reward_mint
 ///   2. `[writable]` vault
 ///   3. `[writable]` mining
@@ -389,6 +456,8 @@ itpl<'a, 'b> ClaimCpi<'a, 'b> {
 ///   5. `[signer]` deposit_authority
 ///   6. `[writable]` mining_owner_reward_token_account
 ///   7. `[]` token_program
+///   8. `[optional]` vault_authority
+///   9. `[writable, optional]` vesting
 pub struct ClaimCpiBuilder<'a, 'b> {
     instruction: Box<ClaimCpiBuilderInstruction<'a, 'b>>,
 }
@@ -405,6 +474,8 @@ itpl<'a, 'b> ClaimCpiBuilder<'a, 'b> {
             deposit_authority: None,
             mining_owner_reward_token_account: None,
             token_program: None,
+            vault_authority: None,
+            vesting: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -451,13 +522,14 @@ itpl<'a, 'b> ClaimCpiBuilder<'a, 'b> {
         self.instruction.mining_owner = Some(mining_owner);
         self
     }
-    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs
+    /// The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed
     #[inline(always)]
     pub fn deposit_authority(
         &mut self,
         deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
     ) -> &mut Self {
-        self.instruction.deposit_authority = Some(deposit_authority);
+        self.instruction.deposit_authority = Some((deposit_authority, as_signer));
         self
     }
     /// ATA where tokens will be claimed to
@@ -479,6 +551,26 @@ itpl<'a, 'b> ClaimCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    /// `[optional account]`
+    /// The vault authority PDA, required once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_authority(
+        &mut self,
+        vault_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault_authority = vault_authority;
+        self
+    }
+    /// `[optional account]`
+    /// The vesting account of the mining, required if the pool vests claimed rewards
+    #[inline(always)]
+    pub fn vesting(
+        &mut self,
+        vesting: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vesting = vesting;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -556,6 +648,10 @@ itpl<'a, 'b> ClaimCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+
+            vault_authority: self.instruction.vault_authority,
+
+            vesting: self.instruction.vesting,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -571,9 +667,11 @@ struct ClaimCpiBuilderInstruction<'a, 'b> {
     vault: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     mining_owner: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
-    deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    deposit_authority: Option<(&'b trezoa_program::account_info::AccountInfo<'a>, bool)>,
     mining_owner_reward_token_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vault_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vesting: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
@@ -20,6 +20,8 @@ pub struct CloseMining {
     pub deposit_authority: trezoa_program::pubkey::Pubkey,
     /// The address of the reward pool
     pub reward_pool: trezoa_program::pubkey::Pubkey,
+    /// The address of the delegate account of the mining, it doesn't exist unless the mining charges a commission
+    pub delegate: trezoa_program::pubkey::Pubkey,
 }
 
 itpl CloseMining {
@@ -31,7 +33,7 @@ itpl CloseMining {
         &self,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             self.mining,
             false,
@@ -52,6 +54,10 @@ itpl CloseMining {
             self.reward_pool,
             false,
         ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.delegate,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = CloseMiningInstructionData::new().try_to_vec().unwrap();
 
@@ -83,6 +89,7 @@ itpl CloseMiningInstructionData {
 ///   2. `[writable]` target_account
 ///   3. `[signer]` deposit_authority
 ///   4. `[writable]` reward_pool
+///   5. `[]` delegate
 #[derive(Default)]
 pub struct CloseMiningBuilder {
     mining: Option<trezoa_program::pubkey::Pubkey>,
@@ -90,6 +97,7 @@ pub struct CloseMiningBuilder {
     target_account: Option<trezoa_program::pubkey::Pubkey>,
     deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
     reward_pool: Option<trezoa_program::pubkey::Pubkey>,
+    delegate: Option<trezoa_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
@@ -129,6 +137,12 @@ itpl CloseMiningBuilder {
         self.reward_pool = Some(reward_pool);
         self
     }
+    /// The address of the delegate account of the mining, it doesn't exist unless the mining charges a commission
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.delegate = Some(delegate);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -157,6 +171,7 @@ itpl CloseMiningBuilder {
                 .deposit_authority
                 .expect("deposit_authority is not set"),
             reward_pool: self.reward_pool.expect("reward_pool is not set"),
+            delegate: self.delegate.expect("delegate is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -175,6 +190,8 @@ pub struct CloseMiningCpiAccounts<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of the reward pool
     pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegate account of the mining, it doesn't exist unless the mining charges a commission
+    pub delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
 }
 
 /// `close_mining` CPI instruction.
@@ -191,6 +208,8 @@ pub struct CloseMiningCpi<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of the reward pool
     pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegate account of the mining, it doesn't exist unless the mining charges a commission
+    pub delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
 }
 
 itpl<'a, 'b> CloseMiningCpi<'a, 'b> {
@@ -205,6 +224,7 @@ itpl<'a, 'b> CloseMiningCpi<'a, 'b> {
             target_account: accounts.target_account,
             deposit_authority: accounts.deposit_authority,
             reward_pool: accounts.reward_pool,
+            delegate: accounts.delegate,
         }
     }
     #[inline(always)]
@@ -240,7 +260,7 @@ itpl<'a, 'b> CloseMiningCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
             *self.mining.key,
             false,
@@ -261,6 +281,10 @@ itpl<'a, 'b> CloseMiningCpi<'a, 'b> {
             *self.reward_pool.key,
             false,
         ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.delegate.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -275,13 +299,14 @@ itpl<'a, 'b> CloseMiningCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.mining.clone());
         account_infos.push(self.mining_owner.clone());
         account_infos.push(self.target_account.clone());
         account_infos.push(self.deposit_authority.clone());
         account_infos.push(self.reward_pool.clone());
+        account_infos.push(self.delegate.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -303,6 +328,7 @@ itpl<'a, 'b> CloseMiningCpi<'a, 'b> {
 ///   2. `[writable]` target_account
 ///   3. `[signer]` deposit_authority
 ///   4. `[writable]` reward_pool
+///   5. `[]` delegate
 pub struct CloseMiningCpiBuilder<'a, 'b> {
     instruction: Box<CloseMiningCpiBuilderInstruction<'a, 'b>>,
 }
@@ -316,6 +342,7 @@ itpl<'a, 'b> CloseMiningCpiBuilder<'a, 'b> {
             target_account: None,
             deposit_authority: None,
             reward_pool: None,
+            delegate: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -364,6 +391,15 @@ itpl<'a, 'b> CloseMiningCpiBuilder<'a, 'b> {
         self.instruction.reward_pool = Some(reward_pool);
         self
     }
+    /// The address of the delegate account of the mining, it doesn't exist unless the mining charges a commission
+    #[inline(always)]
+    pub fn delegate(
+        &mut self,
+        delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.delegate = Some(delegate);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -429,6 +465,8 @@ itpl<'a, 'b> CloseMiningCpiBuilder<'a, 'b> {
                 .instruction
                 .reward_pool
                 .expect("reward_pool is not set"),
+
+            delegate: self.instruction.delegate.expect("delegate is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -444,6 +482,7 @@ struct CloseMiningCpiBuilderInstruction<'a, 'b> {
     target_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     reward_pool: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,454 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/trezoaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct ClosePool {
+    /// The address of the reward pool
+    pub reward_pool: trezoa_program::pubkey::Pubkey,
+    /// The address of the pool's admin
+    pub admin: trezoa_program::pubkey::Pubkey,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (trezoa_program::pubkey::Pubkey, bool),
+    /// The address where lamports of the pool and the vaults will be transferred to
+    pub target_account: trezoa_program::pubkey::Pubkey,
+    /// The address of the Token program where rewards are minted
+    pub token_program: trezoa_program::pubkey::Pubkey,
+}
+
+itpl ClosePool {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.reward_pool,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.admin, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.vault_owner.0,
+            self.vault_owner.1,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.target_account,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = ClosePoolInstructionData::new().try_to_vec().unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ClosePoolInstructionData {
+    discriminator: u8,
+}
+
+itpl ClosePoolInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 21 }
+    }
+}
+
+/// Instruction builder for `ClosePool`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[signer]` admin
+///   2. `[signer]` vault_owner
+///   3. `[writable]` target_account
+///   4. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+#[derive(Default)]
+pub struct ClosePoolBuilder {
+    reward_pool: Option<trezoa_program::pubkey::Pubkey>,
+    admin: Option<trezoa_program::pubkey::Pubkey>,
+    vault_owner: Option<(trezoa_program::pubkey::Pubkey, bool)>,
+    target_account: Option<trezoa_program::pubkey::Pubkey>,
+    token_program: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+itpl ClosePoolBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the pool's admin
+    #[inline(always)]
+    pub fn admin(&mut self, admin: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_owner(
+        &mut self,
+        vault_owner: trezoa_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.vault_owner = Some((vault_owner, as_signer));
+        self
+    }
+    /// The address where lamports of the pool and the vaults will be transferred to
+    #[inline(always)]
+    pub fn target_account(&mut self, target_account: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.target_account = Some(target_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = ClosePool {
+            reward_pool: self.reward_pool.expect("reward_pool is not set"),
+            admin: self.admin.expect("admin is not set"),
+            vault_owner: self.vault_owner.expect("vault_owner is not set"),
+            target_account: self.target_account.expect("target_account is not set"),
+            token_program: self.token_program.unwrap_or(trezoa_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `close_pool` CPI accounts.
+pub struct ClosePoolCpiAccounts<'a, 'b> {
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the pool's admin
+    pub admin: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
+    /// The address where lamports of the pool and the vaults will be transferred to
+    pub target_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `close_pool` CPI instruction.
+pub struct ClosePoolCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the pool's admin
+    pub admin: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
+    /// The address where lamports of the pool and the vaults will be transferred to
+    pub target_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+itpl<'a, 'b> ClosePoolCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: ClosePoolCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            reward_pool: accounts.reward_pool,
+            admin: accounts.admin,
+            vault_owner: accounts.vault_owner,
+            target_account: accounts.target_account,
+            token_program: accounts.token_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.reward_pool.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.admin.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.vault_owner.0.key,
+            self.vault_owner.1,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.target_account.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = ClosePoolInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.reward_pool.clone());
+        account_infos.push(self.admin.clone());
+        account_infos.push(self.vault_owner.0.clone());
+        account_infos.push(self.target_account.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ClosePool` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[signer]` admin
+///   2. `[signer]` vault_owner
+///   3. `[writable]` target_account
+///   4. `[]` token_program
+pub struct ClosePoolCpiBuilder<'a, 'b> {
+    instruction: Box<ClosePoolCpiBuilderInstruction<'a, 'b>>,
+}
+
+itpl<'a, 'b> ClosePoolCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ClosePoolCpiBuilderInstruction {
+            __program: program,
+            reward_pool: None,
+            admin: None,
+            vault_owner: None,
+            target_account: None,
+            token_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(
+        &mut self,
+        reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the pool's admin
+    #[inline(always)]
+    pub fn admin(&mut self, admin: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.admin = Some(admin);
+        self
+    }
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_owner(
+        &mut self,
+        vault_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.vault_owner = Some((vault_owner, as_signer));
+        self
+    }
+    /// The address where lamports of the pool and the vaults will be transferred to
+    #[inline(always)]
+    pub fn target_account(
+        &mut self,
+        target_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.target_account = Some(target_account);
+        self
+    }
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = ClosePoolCpi {
+            __program: self.instruction.__program,
+
+            reward_pool: self
+                .instruction
+                .reward_pool
+                .expect("reward_pool is not set"),
+
+            admin: self.instruction.admin.expect("admin is not set"),
+
+            vault_owner: self
+                .instruction
+                .vault_owner
+                .expect("vault_owner is not set"),
+
+            target_account: self
+                .instruction
+                .target_account
+                .expect("target_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct ClosePoolCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    reward_pool: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    admin: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vault_owner: Option<(&'b trezoa_program::account_info::AccountInfo<'a>, bool)>,
+    target_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,599 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/trezoaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct DefundVault {
+    /// The address of the reward pool
+    pub reward_pool: trezoa_program::pubkey::Pubkey,
+    /// The address of the reward mint
+    pub reward_mint: trezoa_program::pubkey::Pubkey,
+    /// The address of the reward vault
+    pub vault: trezoa_program::pubkey::Pubkey,
+    /// The address of the wallet who is responsible for filling pool's vault with rewards
+    pub fill_authority: trezoa_program::pubkey::Pubkey,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (trezoa_program::pubkey::Pubkey, bool),
+    /// The address of the TA the tokens will be transferred to
+    pub destination_token_account: trezoa_program::pubkey::Pubkey,
+    /// The address of the Token program where rewards are minted
+    pub token_program: trezoa_program::pubkey::Pubkey,
+}
+
+itpl DefundVault {
+    pub fn instruction(
+        &self,
+        args: DefundVaultInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: DefundVaultInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.reward_pool,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.reward_mint,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.fill_authority,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.vault_owner.0,
+            self.vault_owner.1,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.destination_token_account,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = DefundVaultInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct DefundVaultInstructionData {
+    discriminator: u8,
+}
+
+itpl DefundVaultInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 20 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefundVaultInstructionArgs {
+    pub amount: u64,
+    pub distribution_ends_at: Option<u64>,
+}
+
+/// Instruction builder for `DefundVault`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[]` reward_mint
+///   2. `[writable]` vault
+///   3. `[signer]` fill_authority
+///   4. `[signer]` vault_owner
+///   5. `[writable]` destination_token_account
+///   6. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+#[derive(Default)]
+pub struct DefundVaultBuilder {
+    reward_pool: Option<trezoa_program::pubkey::Pubkey>,
+    reward_mint: Option<trezoa_program::pubkey::Pubkey>,
+    vault: Option<trezoa_program::pubkey::Pubkey>,
+    fill_authority: Option<trezoa_program::pubkey::Pubkey>,
+    vault_owner: Option<(trezoa_program::pubkey::Pubkey, bool)>,
+    destination_token_account: Option<trezoa_program::pubkey::Pubkey>,
+    token_program: Option<trezoa_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    distribution_ends_at: Option<u64>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+itpl DefundVaultBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the reward mint
+    #[inline(always)]
+    pub fn reward_mint(&mut self, reward_mint: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_mint = Some(reward_mint);
+        self
+    }
+    /// The address of the reward vault
+    #[inline(always)]
+    pub fn vault(&mut self, vault: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// The address of the wallet who is responsible for filling pool's vault with rewards
+    #[inline(always)]
+    pub fn fill_authority(&mut self, fill_authority: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.fill_authority = Some(fill_authority);
+        self
+    }
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_owner(
+        &mut self,
+        vault_owner: trezoa_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.vault_owner = Some((vault_owner, as_signer));
+        self
+    }
+    /// The address of the TA the tokens will be transferred to
+    #[inline(always)]
+    pub fn destination_token_account(
+        &mut self,
+        destination_token_account: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.destination_token_account = Some(destination_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn distribution_ends_at(&mut self, distribution_ends_at: u64) -> &mut Self {
+        self.distribution_ends_at = Some(distribution_ends_at);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = DefundVault {
+            reward_pool: self.reward_pool.expect("reward_pool is not set"),
+            reward_mint: self.reward_mint.expect("reward_mint is not set"),
+            vault: self.vault.expect("vault is not set"),
+            fill_authority: self.fill_authority.expect("fill_authority is not set"),
+            vault_owner: self.vault_owner.expect("vault_owner is not set"),
+            destination_token_account: self
+                .destination_token_account
+                .expect("destination_token_account is not set"),
+            token_program: self.token_program.unwrap_or(trezoa_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        };
+        let args = DefundVaultInstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+            distribution_ends_at: self.distribution_ends_at.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `defund_vault` CPI accounts.
+pub struct DefundVaultCpiAccounts<'a, 'b> {
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward mint
+    pub reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward vault
+    pub vault: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the wallet who is responsible for filling pool's vault with rewards
+    pub fill_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
+    /// The address of the TA the tokens will be transferred to
+    pub destination_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `defund_vault` CPI instruction.
+pub struct DefundVaultCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward mint
+    pub reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward vault
+    pub vault: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the wallet who is responsible for filling pool's vault with rewards
+    pub fill_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
+    /// The address of the TA the tokens will be transferred to
+    pub destination_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: DefundVaultInstructionArgs,
+}
+
+itpl<'a, 'b> DefundVaultCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: DefundVaultCpiAccounts<'a, 'b>,
+        args: DefundVaultInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            reward_pool: accounts.reward_pool,
+            reward_mint: accounts.reward_mint,
+            vault: accounts.vault,
+            fill_authority: accounts.fill_authority,
+            vault_owner: accounts.vault_owner,
+            destination_token_account: accounts.destination_token_account,
+            token_program: accounts.token_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.reward_pool.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.reward_mint.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.fill_authority.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.vault_owner.0.key,
+            self.vault_owner.1,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.destination_token_account.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = DefundVaultInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.reward_pool.clone());
+        account_infos.push(self.reward_mint.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.fill_authority.clone());
+        account_infos.push(self.vault_owner.0.clone());
+        account_infos.push(self.destination_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `DefundVault` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[]` reward_mint
+///   2. `[writable]` vault
+///   3. `[signer]` fill_authority
+///   4. `[signer]` vault_owner
+///   5. `[writable]` destination_token_account
+///   6. `[]` token_program
+pub struct DefundVaultCpiBuilder<'a, 'b> {
+    instruction: Box<DefundVaultCpiBuilderInstruction<'a, 'b>>,
+}
+
+itpl<'a, 'b> DefundVaultCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(DefundVaultCpiBuilderInstruction {
+            __program: program,
+            reward_pool: None,
+            reward_mint: None,
+            vault: None,
+            fill_authority: None,
+            vault_owner: None,
+            destination_token_account: None,
+            token_program: None,
+            amount: None,
+            distribution_ends_at: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(
+        &mut self,
+        reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the reward mint
+    #[inline(always)]
+    pub fn reward_mint(
+        &mut self,
+        reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_mint = Some(reward_mint);
+        self
+    }
+    /// The address of the reward vault
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// The address of the wallet who is responsible for filling pool's vault with rewards
+    #[inline(always)]
+    pub fn fill_authority(
+        &mut self,
+        fill_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fill_authority = Some(fill_authority);
+        self
+    }
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_owner(
+        &mut self,
+        vault_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.vault_owner = Some((vault_owner, as_signer));
+        self
+    }
+    /// The address of the TA the tokens will be transferred to
+    #[inline(always)]
+    pub fn destination_token_account(
+        &mut self,
+        destination_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination_token_account = Some(destination_token_account);
+        self
+    }
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn distribution_ends_at(&mut self, distribution_ends_at: u64) -> &mut Self {
+        self.instruction.distribution_ends_at = Some(distribution_ends_at);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = DefundVaultInstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+            distribution_ends_at: self.instruction.distribution_ends_at.clone(),
+        };
+        let instruction = DefundVaultCpi {
+            __program: self.instruction.__program,
+
+            reward_pool: self
+                .instruction
+                .reward_pool
+                .expect("reward_pool is not set"),
+
+            reward_mint: self
+                .instruction
+                .reward_mint
+                .expect("reward_mint is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            fill_authority: self
+                .instruction
+                .fill_authority
+                .expect("fill_authority is not set"),
+
+            vault_owner: self
+                .instruction
+                .vault_owner
+                .expect("vault_owner is not set"),
+
+            destination_token_account: self
+                .instruction
+                .destination_token_account
+                .expect("destination_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct DefundVaultCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    reward_pool: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    reward_mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    fill_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vault_owner: Option<(&'b trezoa_program::account_info::AccountInfo<'a>, bool)>,
+    destination_token_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    distribution_ends_at: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
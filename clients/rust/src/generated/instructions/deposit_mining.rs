@@ -20,6 +20,10 @@ pub struct DepositMining {
     pub deposit_authority: trezoa_program::pubkey::Pubkey,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: trezoa_program::pubkey::Pubkey,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 itpl DepositMining {
@@ -35,7 +39,7 @@ itpl DepositMining {
         args: DepositMiningInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.reward_pool,
             false,
@@ -52,6 +56,16 @@ itpl DepositMining {
             self.delegate_mining,
             false,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                delegate, false,
+            ));
+        }
+        if let Some(delegation) = self.delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                delegation, false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = DepositMiningInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -93,12 +107,16 @@ pub struct DepositMiningInstructionArgs {
 ///   1. `[writable]` mining
 ///   2. `[signer]` deposit_authority
 ///   3. `[]` delegate_mining
+///   4. `[writable, optional]` delegate
+///   5. `[writable, optional]` delegation
 #[derive(Default)]
 pub struct DepositMiningBuilder {
     reward_pool: Option<trezoa_program::pubkey::Pubkey>,
     mining: Option<trezoa_program::pubkey::Pubkey>,
     deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
     delegate_mining: Option<trezoa_program::pubkey::Pubkey>,
+    delegate: Option<trezoa_program::pubkey::Pubkey>,
+    delegation: Option<trezoa_program::pubkey::Pubkey>,
     amount: Option<u64>,
     lockup_period: Option<LockupPeriod>,
     mining_owner: Option<Pubkey>,
@@ -140,6 +158,20 @@ itpl DepositMiningBuilder {
         self.delegate_mining = Some(delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.delegate = delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegation(&mut self, delegation: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.delegation = delegation;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -187,6 +219,8 @@ itpl DepositMiningBuilder {
                 .deposit_authority
                 .expect("deposit_authority is not set"),
             delegate_mining: self.delegate_mining.expect("delegate_mining is not set"),
+            delegate: self.delegate,
+            delegation: self.delegation,
         };
         let args = DepositMiningInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -212,6 +246,10 @@ pub struct DepositMiningCpiAccounts<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `deposit_mining` CPI instruction.
@@ -226,6 +264,10 @@ pub struct DepositMiningCpi<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: DepositMiningInstructionArgs,
 }
@@ -242,6 +284,8 @@ itpl<'a, 'b> DepositMiningCpi<'a, 'b> {
             mining: accounts.mining,
             deposit_authority: accounts.deposit_authority,
             delegate_mining: accounts.delegate_mining,
+            delegate: accounts.delegate,
+            delegation: accounts.delegation,
             __args: args,
         }
     }
@@ -278,7 +322,7 @@ itpl<'a, 'b> DepositMiningCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.reward_pool.key,
             false,
@@ -295,6 +339,18 @@ itpl<'a, 'b> DepositMiningCpi<'a, 'b> {
             *self.delegate_mining.key,
             false,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *delegate.key,
+                false,
+            ));
+        }
+        if let Some(delegation) = self.delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *delegation.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -311,12 +367,18 @@ itpl<'a, 'b> DepositMiningCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.reward_pool.clone());
         account_infos.push(self.mining.clone());
         account_infos.push(self.deposit_authority.clone());
         account_infos.push(self.delegate_mining.clone());
+        if let Some(delegate) = self.delegate {
+            account_infos.push(delegate.clone());
+        }
+        if let Some(delegation) = self.delegation {
+            account_infos.push(delegation.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -337,6 +399,8 @@ itpl<'a, 'b> DepositMiningCpi<'a, 'b> {
 ///   1. `[writable]` mining
 ///   2. `[signer]` deposit_authority
 ///   3. `[]` delegate_mining
+///   4. `[writable, optional]` delegate
+///   5. `[writable, optional]` delegation
 pub struct DepositMiningCpiBuilder<'a, 'b> {
     instruction: Box<DepositMiningCpiBuilderInstruction<'a, 'b>>,
 }
@@ -349,6 +413,8 @@ itpl<'a, 'b> DepositMiningCpiBuilder<'a, 'b> {
             mining: None,
             deposit_authority: None,
             delegate_mining: None,
+            delegate: None,
+            delegation: None,
             amount: None,
             lockup_period: None,
             mining_owner: None,
@@ -393,6 +459,26 @@ itpl<'a, 'b> DepositMiningCpiBuilder<'a, 'b> {
         self.instruction.delegate_mining = Some(delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegate(
+        &mut self,
+        delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegate = delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegation(
+        &mut self,
+        delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegation = delegation;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -491,6 +577,10 @@ itpl<'a, 'b> DepositMiningCpiBuilder<'a, 'b> {
                 .instruction
                 .delegate_mining
                 .expect("delegate_mining is not set"),
+
+            delegate: self.instruction.delegate,
+
+            delegation: self.instruction.delegation,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -506,6 +596,8 @@ struct DepositMiningCpiBuilderInstruction<'a, 'b> {
     mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     delegate_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     lockup_period: Option<LockupPeriod>,
     mining_owner: Option<Pubkey>,
@@ -12,8 +12,10 @@ use borsh::BorshSerialize;
 pub struct DistributeRewards {
     /// The address of the reward pool
     pub reward_pool: trezoa_program::pubkey::Pubkey,
-    /// The address of Authority who is eligble for distributiong rewards for users
+    /// The address of Authority who is eligble for distributiong rewards for users, anybody if the distribution is permissionless
     pub distribute_authority: trezoa_program::pubkey::Pubkey,
+    /// The mining of the distributing wallet the crank bounty is credited to
+    pub keeper_mining: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 itpl DistributeRewards {
@@ -25,7 +27,7 @@ itpl DistributeRewards {
         &self,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.reward_pool,
             false,
@@ -34,6 +36,12 @@ itpl DistributeRewards {
             self.distribute_authority,
             true,
         ));
+        if let Some(keeper_mining) = self.keeper_mining {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                keeper_mining,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = DistributeRewardsInstructionData::new()
             .try_to_vec()
@@ -64,10 +72,12 @@ itpl DistributeRewardsInstructionData {
 ///
 ///   0. `[writable]` reward_pool
 ///   1. `[signer]` distribute_authority
+///   2. `[writable, optional]` keeper_mining
 #[derive(Default)]
 pub struct DistributeRewardsBuilder {
     reward_pool: Option<trezoa_program::pubkey::Pubkey>,
     distribute_authority: Option<trezoa_program::pubkey::Pubkey>,
+    keeper_mining: Option<trezoa_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
@@ -81,7 +91,7 @@ itpl DistributeRewardsBuilder {
         self.reward_pool = Some(reward_pool);
         self
     }
-    /// The address of Authority who is eligble for distributiong rewards for users
+    /// The address of Authority who is eligble for distributiong rewards for users, anybody if the distribution is permissionless
     #[inline(always)]
     pub fn distribute_authority(
         &mut self,
@@ -90,6 +100,16 @@ itpl DistributeRewardsBuilder {
         self.distribute_authority = Some(distribute_authority);
         self
     }
+    /// `[optional account]`
+    /// The mining of the distributing wallet the crank bounty is credited to
+    #[inline(always)]
+    pub fn keeper_mining(
+        &mut self,
+        keeper_mining: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.keeper_mining = keeper_mining;
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -115,6 +135,7 @@ itpl DistributeRewardsBuilder {
             distribute_authority: self
                 .distribute_authority
                 .expect("distribute_authority is not set"),
+            keeper_mining: self.keeper_mining,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -125,8 +146,10 @@ itpl DistributeRewardsBuilder {
 pub struct DistributeRewardsCpiAccounts<'a, 'b> {
     /// The address of the reward pool
     pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// The address of Authority who is eligble for distributiong rewards for users
+    /// The address of Authority who is eligble for distributiong rewards for users, anybody if the distribution is permissionless
     pub distribute_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The mining of the distributing wallet the crank bounty is credited to
+    pub keeper_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `distribute_rewards` CPI instruction.
@@ -135,8 +158,10 @@ pub struct DistributeRewardsCpi<'a, 'b> {
     pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of the reward pool
     pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
-    /// The address of Authority who is eligble for distributiong rewards for users
+    /// The address of Authority who is eligble for distributiong rewards for users, anybody if the distribution is permissionless
     pub distribute_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The mining of the distributing wallet the crank bounty is credited to
+    pub keeper_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 itpl<'a, 'b> DistributeRewardsCpi<'a, 'b> {
@@ -148,6 +173,7 @@ itpl<'a, 'b> DistributeRewardsCpi<'a, 'b> {
             __program: program,
             reward_pool: accounts.reward_pool,
             distribute_authority: accounts.distribute_authority,
+            keeper_mining: accounts.keeper_mining,
         }
     }
     #[inline(always)]
@@ -183,7 +209,7 @@ itpl<'a, 'b> DistributeRewardsCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.reward_pool.key,
             false,
@@ -192,6 +218,12 @@ itpl<'a, 'b> DistributeRewardsCpi<'a, 'b> {
             *self.distribute_authority.key,
             true,
         ));
+        if let Some(keeper_mining) = self.keeper_mining {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *keeper_mining.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -208,10 +240,13 @@ itpl<'a, 'b> DistributeRewardsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.reward_pool.clone());
         account_infos.push(self.distribute_authority.clone());
+        if let Some(keeper_mining) = self.keeper_mining {
+            account_infos.push(keeper_mining.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -230,6 +265,7 @@ itpl<'a, 'b> DistributeRewardsCpi<'a, 'b> {
 ///
 ///   0. `[writable]` reward_pool
 ///   1. `[signer]` distribute_authority
+///   2. `[writable, optional]` keeper_mining
 pub struct DistributeRewardsCpiBuilder<'a, 'b> {
     instruction: Box<DistributeRewardsCpiBuilderInstruction<'a, 'b>>,
 }
@@ -240,6 +276,7 @@ itpl<'a, 'b> DistributeRewardsCpiBuilder<'a, 'b> {
             __program: program,
             reward_pool: None,
             distribute_authority: None,
+            keeper_mining: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -253,7 +290,7 @@ itpl<'a, 'b> DistributeRewardsCpiBuilder<'a, 'b> {
         self.instruction.reward_pool = Some(reward_pool);
         self
     }
-    /// The address of Authority who is eligble for distributiong rewards for users
+    /// The address of Authority who is eligble for distributiong rewards for users, anybody if the distribution is permissionless
     #[inline(always)]
     pub fn distribute_authority(
         &mut self,
@@ -262,6 +299,16 @@ itpl<'a, 'b> DistributeRewardsCpiBuilder<'a, 'b> {
         self.instruction.distribute_authority = Some(distribute_authority);
         self
     }
+    /// `[optional account]`
+    /// The mining of the distributing wallet the crank bounty is credited to
+    #[inline(always)]
+    pub fn keeper_mining(
+        &mut self,
+        keeper_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.keeper_mining = keeper_mining;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -315,6 +362,8 @@ itpl<'a, 'b> DistributeRewardsCpiBuilder<'a, 'b> {
                 .instruction
                 .distribute_authority
                 .expect("distribute_authority is not set"),
+
+            keeper_mining: self.instruction.keeper_mining,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -327,6 +376,7 @@ struct DistributeRewardsCpiBuilderInstruction<'a, 'b> {
     __program: &'b trezoa_program::account_info::AccountInfo<'a>,
     reward_pool: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     distribute_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    keeper_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
@@ -22,6 +22,10 @@ pub struct ExtendStake {
     pub deposit_authority: trezoa_program::pubkey::Pubkey,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: trezoa_program::pubkey::Pubkey,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 itpl ExtendStake {
@@ -37,7 +41,7 @@ itpl ExtendStake {
         args: ExtendStakeInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.reward_pool,
             false,
@@ -58,6 +62,16 @@ itpl ExtendStake {
             self.delegate_mining,
             false,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                delegate, false,
+            ));
+        }
+        if let Some(delegation) = self.delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                delegation, false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = ExtendStakeInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -103,6 +117,8 @@ pub struct ExtendStakeInstructionArgs {
 ///   2. `[]` reward_mint
 ///   3. `[signer]` deposit_authority
 ///   4. `[]` delegate_mining
+///   5. `[writable, optional]` delegate
+///   6. `[writable, optional]` delegation
 #[derive(Default)]
 pub struct ExtendStakeBuilder {
     reward_pool: Option<trezoa_program::pubkey::Pubkey>,
@@ -110,6 +126,8 @@ pub struct ExtendStakeBuilder {
     reward_mint: Option<trezoa_program::pubkey::Pubkey>,
     deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
     delegate_mining: Option<trezoa_program::pubkey::Pubkey>,
+    delegate: Option<trezoa_program::pubkey::Pubkey>,
+    delegation: Option<trezoa_program::pubkey::Pubkey>,
     old_lockup_period: Option<LockupPeriod>,
     new_lockup_period: Option<LockupPeriod>,
     deposit_start_ts: Option<u64>,
@@ -160,6 +178,20 @@ itpl ExtendStakeBuilder {
         self.delegate_mining = Some(delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.delegate = delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegation(&mut self, delegation: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.delegation = delegation;
+        self
+    }
     #[inline(always)]
     pub fn old_lockup_period(&mut self, old_lockup_period: LockupPeriod) -> &mut Self {
         self.old_lockup_period = Some(old_lockup_period);
@@ -223,6 +255,8 @@ itpl ExtendStakeBuilder {
                 .deposit_authority
                 .expect("deposit_authority is not set"),
             delegate_mining: self.delegate_mining.expect("delegate_mining is not set"),
+            delegate: self.delegate,
+            delegation: self.delegation,
         };
         let args = ExtendStakeInstructionArgs {
             old_lockup_period: self
@@ -262,6 +296,10 @@ pub struct ExtendStakeCpiAccounts<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `extend_stake` CPI instruction.
@@ -278,6 +316,10 @@ pub struct ExtendStakeCpi<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: ExtendStakeInstructionArgs,
 }
@@ -295,6 +337,8 @@ itpl<'a, 'b> ExtendStakeCpi<'a, 'b> {
             reward_mint: accounts.reward_mint,
             deposit_authority: accounts.deposit_authority,
             delegate_mining: accounts.delegate_mining,
+            delegate: accounts.delegate,
+            delegation: accounts.delegation,
             __args: args,
         }
     }
@@ -331,7 +375,7 @@ itpl<'a, 'b> ExtendStakeCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.reward_pool.key,
             false,
@@ -352,6 +396,18 @@ itpl<'a, 'b> ExtendStakeCpi<'a, 'b> {
             *self.delegate_mining.key,
             false,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *delegate.key,
+                false,
+            ));
+        }
+        if let Some(delegation) = self.delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *delegation.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -368,13 +424,19 @@ itpl<'a, 'b> ExtendStakeCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.reward_pool.clone());
         account_infos.push(self.mining.clone());
         account_infos.push(self.reward_mint.clone());
         account_infos.push(self.deposit_authority.clone());
         account_infos.push(self.delegate_mining.clone());
+        if let Some(delegate) = self.delegate {
+            account_infos.push(delegate.clone());
+        }
+        if let Some(delegation) = self.delegation {
+            account_infos.push(delegation.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -396,6 +458,8 @@ itpl<'a, 'b> ExtendStakeCpi<'a, 'b> {
 ///   2. `[]` reward_mint
 ///   3. `[signer]` deposit_authority
 ///   4. `[]` delegate_mining
+///   5. `[writable, optional]` delegate
+///   6. `[writable, optional]` delegation
 pub struct ExtendStakeCpiBuilder<'a, 'b> {
     instruction: Box<ExtendStakeCpiBuilderInstruction<'a, 'b>>,
 }
@@ -409,6 +473,8 @@ itpl<'a, 'b> ExtendStakeCpiBuilder<'a, 'b> {
             reward_mint: None,
             deposit_authority: None,
             delegate_mining: None,
+            delegate: None,
+            delegation: None,
             old_lockup_period: None,
             new_lockup_period: None,
             deposit_start_ts: None,
@@ -465,6 +531,26 @@ itpl<'a, 'b> ExtendStakeCpiBuilder<'a, 'b> {
         self.instruction.delegate_mining = Some(delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegate(
+        &mut self,
+        delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegate = delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegation(
+        &mut self,
+        delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegation = delegation;
+        self
+    }
     #[inline(always)]
     pub fn old_lockup_period(&mut self, old_lockup_period: LockupPeriod) -> &mut Self {
         self.instruction.old_lockup_period = Some(old_lockup_period);
@@ -602,6 +688,10 @@ itpl<'a, 'b> ExtendStakeCpiBuilder<'a, 'b> {
                 .instruction
                 .delegate_mining
                 .expect("delegate_mining is not set"),
+
+            delegate: self.instruction.delegate,
+
+            delegation: self.instruction.delegation,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -618,6 +708,8 @@ struct ExtendStakeCpiBuilderInstruction<'a, 'b> {
     reward_mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     delegate_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     old_lockup_period: Option<LockupPeriod>,
     new_lockup_period: Option<LockupPeriod>,
     deposit_start_ts: Option<u64>,
@@ -0,0 +1,624 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/trezoaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct InitializeDelegation {
+    /// The address of the reward pool
+    pub reward_pool: trezoa_program::pubkey::Pubkey,
+    /// The address of the delegate mining account
+    pub delegate_mining: trezoa_program::pubkey::Pubkey,
+    /// The address of the delegate account of the delegate mining
+    pub delegate: trezoa_program::pubkey::Pubkey,
+    /// The address of the mining account which belongs to the user and stores info about user's rewards
+    pub mining: trezoa_program::pubkey::Pubkey,
+    /// The address of the delegation of the mining to the delegate
+    pub delegation: trezoa_program::pubkey::Pubkey,
+
+    pub payer: trezoa_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: trezoa_program::pubkey::Pubkey,
+    /// The address of the Staking program's Registrar, required if any stake is delegated already
+    pub deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
+}
+
+itpl InitializeDelegation {
+    pub fn instruction(
+        &self,
+        args: InitializeDelegationInstructionArgs,
+    ) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: InitializeDelegationInstructionArgs,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.reward_pool,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.delegate_mining,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.delegate,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.mining,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.delegation,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        if let Some(deposit_authority) = self.deposit_authority {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                deposit_authority,
+                true,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = InitializeDelegationInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct InitializeDelegationInstructionData {
+    discriminator: u8,
+}
+
+itpl InitializeDelegationInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 30 }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitializeDelegationInstructionArgs {
+    pub delegated_amount: u64,
+}
+
+/// Instruction builder for `InitializeDelegation`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[writable]` delegate_mining
+///   2. `[writable]` delegate
+///   3. `[]` mining
+///   4. `[writable]` delegation
+///   5. `[writable, signer]` payer
+///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[signer, optional]` deposit_authority
+#[derive(Default)]
+pub struct InitializeDelegationBuilder {
+    reward_pool: Option<trezoa_program::pubkey::Pubkey>,
+    delegate_mining: Option<trezoa_program::pubkey::Pubkey>,
+    delegate: Option<trezoa_program::pubkey::Pubkey>,
+    mining: Option<trezoa_program::pubkey::Pubkey>,
+    delegation: Option<trezoa_program::pubkey::Pubkey>,
+    payer: Option<trezoa_program::pubkey::Pubkey>,
+    system_program: Option<trezoa_program::pubkey::Pubkey>,
+    deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
+    delegated_amount: Option<u64>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+itpl InitializeDelegationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the delegate mining account
+    #[inline(always)]
+    pub fn delegate_mining(
+        &mut self,
+        delegate_mining: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.delegate_mining = Some(delegate_mining);
+        self
+    }
+    /// The address of the delegate account of the delegate mining
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.delegate = Some(delegate);
+        self
+    }
+    /// The address of the mining account which belongs to the user and stores info about user's rewards
+    #[inline(always)]
+    pub fn mining(&mut self, mining: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.mining = Some(mining);
+        self
+    }
+    /// The address of the delegation of the mining to the delegate
+    #[inline(always)]
+    pub fn delegation(&mut self, delegation: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.delegation = Some(delegation);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the Staking program's Registrar, required if any stake is delegated already
+    #[inline(always)]
+    pub fn deposit_authority(
+        &mut self,
+        deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.deposit_authority = deposit_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn delegated_amount(&mut self, delegated_amount: u64) -> &mut Self {
+        self.delegated_amount = Some(delegated_amount);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = InitializeDelegation {
+            reward_pool: self.reward_pool.expect("reward_pool is not set"),
+            delegate_mining: self.delegate_mining.expect("delegate_mining is not set"),
+            delegate: self.delegate.expect("delegate is not set"),
+            mining: self.mining.expect("mining is not set"),
+            delegation: self.delegation.expect("delegation is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(trezoa_program::pubkey!("11111111111111111111111111111111")),
+            deposit_authority: self.deposit_authority,
+        };
+        let args = InitializeDelegationInstructionArgs {
+            delegated_amount: self
+                .delegated_amount
+                .clone()
+                .expect("delegated_amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `initialize_delegation` CPI accounts.
+pub struct InitializeDelegationCpiAccounts<'a, 'b> {
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegate mining account
+    pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegate account of the delegate mining
+    pub delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the mining account which belongs to the user and stores info about user's rewards
+    pub mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegation of the mining to the delegate
+    pub delegation: &'b trezoa_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Staking program's Registrar, required if any stake is delegated already
+    pub deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+}
+
+/// `initialize_delegation` CPI instruction.
+pub struct InitializeDelegationCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegate mining account
+    pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegate account of the delegate mining
+    pub delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the mining account which belongs to the user and stores info about user's rewards
+    pub mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the delegation of the mining to the delegate
+    pub delegation: &'b trezoa_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Staking program's Registrar, required if any stake is delegated already
+    pub deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: InitializeDelegationInstructionArgs,
+}
+
+itpl<'a, 'b> InitializeDelegationCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: InitializeDelegationCpiAccounts<'a, 'b>,
+        args: InitializeDelegationInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            reward_pool: accounts.reward_pool,
+            delegate_mining: accounts.delegate_mining,
+            delegate: accounts.delegate,
+            mining: accounts.mining,
+            delegation: accounts.delegation,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            deposit_authority: accounts.deposit_authority,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.reward_pool.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.delegate_mining.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.delegate.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.mining.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.delegation.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(deposit_authority) = self.deposit_authority {
+            accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+                *deposit_authority.key,
+                true,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = InitializeDelegationInstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.reward_pool.clone());
+        account_infos.push(self.delegate_mining.clone());
+        account_infos.push(self.delegate.clone());
+        account_infos.push(self.mining.clone());
+        account_infos.push(self.delegation.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        if let Some(deposit_authority) = self.deposit_authority {
+            account_infos.push(deposit_authority.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `InitializeDelegation` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reward_pool
+///   1. `[writable]` delegate_mining
+///   2. `[writable]` delegate
+///   3. `[]` mining
+///   4. `[writable]` delegation
+///   5. `[writable, signer]` payer
+///   6. `[]` system_program
+///   7. `[signer, optional]` deposit_authority
+pub struct InitializeDelegationCpiBuilder<'a, 'b> {
+    instruction: Box<InitializeDelegationCpiBuilderInstruction<'a, 'b>>,
+}
+
+itpl<'a, 'b> InitializeDelegationCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(InitializeDelegationCpiBuilderInstruction {
+            __program: program,
+            reward_pool: None,
+            delegate_mining: None,
+            delegate: None,
+            mining: None,
+            delegation: None,
+            payer: None,
+            system_program: None,
+            deposit_authority: None,
+            delegated_amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(
+        &mut self,
+        reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the delegate mining account
+    #[inline(always)]
+    pub fn delegate_mining(
+        &mut self,
+        delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.delegate_mining = Some(delegate_mining);
+        self
+    }
+    /// The address of the delegate account of the delegate mining
+    #[inline(always)]
+    pub fn delegate(
+        &mut self,
+        delegate: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.delegate = Some(delegate);
+        self
+    }
+    /// The address of the mining account which belongs to the user and stores info about user's rewards
+    #[inline(always)]
+    pub fn mining(
+        &mut self,
+        mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mining = Some(mining);
+        self
+    }
+    /// The address of the delegation of the mining to the delegate
+    #[inline(always)]
+    pub fn delegation(
+        &mut self,
+        delegation: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.delegation = Some(delegation);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the Staking program's Registrar, required if any stake is delegated already
+    #[inline(always)]
+    pub fn deposit_authority(
+        &mut self,
+        deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.deposit_authority = deposit_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn delegated_amount(&mut self, delegated_amount: u64) -> &mut Self {
+        self.instruction.delegated_amount = Some(delegated_amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let args = InitializeDelegationInstructionArgs {
+            delegated_amount: self
+                .instruction
+                .delegated_amount
+                .clone()
+                .expect("delegated_amount is not set"),
+        };
+        let instruction = InitializeDelegationCpi {
+            __program: self.instruction.__program,
+
+            reward_pool: self
+                .instruction
+                .reward_pool
+                .expect("reward_pool is not set"),
+
+            delegate_mining: self
+                .instruction
+                .delegate_mining
+                .expect("delegate_mining is not set"),
+
+            delegate: self.instruction.delegate.expect("delegate is not set"),
+
+            mining: self.instruction.mining.expect("mining is not set"),
+
+            delegation: self.instruction.delegation.expect("delegation is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            deposit_authority: self.instruction.deposit_authority,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct InitializeDelegationCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    reward_pool: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegated_amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -5,6 +5,8 @@
 //! [https://github.com/trezoaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::DistributionMode;
+use crate::generated::types::LockupTier;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use trezoa_program::pubkey::Pubkey;
@@ -101,6 +103,11 @@ itpl InitializePoolInstructionData {
 pub struct InitializePoolInstructionArgs {
     pub fill_authority: Pubkey,
     pub distribute_authority: Pubkey,
+    pub admin: Pubkey,
+    pub lockup_tiers: Vec<LockupTier>,
+    pub epoch_length_secs: u64,
+    pub epoch_offset_secs: u64,
+    pub distribution_mode: DistributionMode,
 }
 
 /// Instruction builder for `InitializePool`.
@@ -127,6 +134,11 @@ pub struct InitializePoolBuilder {
     system_program: Option<trezoa_program::pubkey::Pubkey>,
     fill_authority: Option<Pubkey>,
     distribute_authority: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    lockup_tiers: Option<Vec<LockupTier>>,
+    epoch_length_secs: Option<u64>,
+    epoch_offset_secs: Option<u64>,
+    distribution_mode: Option<DistributionMode>,
     __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
 }
 
@@ -197,6 +209,31 @@ itpl InitializePoolBuilder {
         self.distribute_authority = Some(distribute_authority);
         self
     }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: Pubkey) -> &mut Self {
+        self.admin = Some(admin);
+        self
+    }
+    #[inline(always)]
+    pub fn lockup_tiers(&mut self, lockup_tiers: Vec<LockupTier>) -> &mut Self {
+        self.lockup_tiers = Some(lockup_tiers);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch_length_secs(&mut self, epoch_length_secs: u64) -> &mut Self {
+        self.epoch_length_secs = Some(epoch_length_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch_offset_secs(&mut self, epoch_offset_secs: u64) -> &mut Self {
+        self.epoch_offset_secs = Some(epoch_offset_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn distribution_mode(&mut self, distribution_mode: DistributionMode) -> &mut Self {
+        self.distribution_mode = Some(distribution_mode);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -244,6 +281,20 @@ itpl InitializePoolBuilder {
                 .distribute_authority
                 .clone()
                 .expect("distribute_authority is not set"),
+            admin: self.admin.clone().expect("admin is not set"),
+            lockup_tiers: self.lockup_tiers.clone().expect("lockup_tiers is not set"),
+            epoch_length_secs: self
+                .epoch_length_secs
+                .clone()
+                .expect("epoch_length_secs is not set"),
+            epoch_offset_secs: self
+                .epoch_offset_secs
+                .clone()
+                .expect("epoch_offset_secs is not set"),
+            distribution_mode: self
+                .distribution_mode
+                .clone()
+                .expect("distribution_mode is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -447,6 +498,11 @@ itpl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
             system_program: None,
             fill_authority: None,
             distribute_authority: None,
+            admin: None,
+            lockup_tiers: None,
+            epoch_length_secs: None,
+            epoch_offset_secs: None,
+            distribution_mode: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -523,6 +579,31 @@ itpl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
         self.instruction.distribute_authority = Some(distribute_authority);
         self
     }
+    #[inline(always)]
+    pub fn admin(&mut self, admin: Pubkey) -> &mut Self {
+        self.instruction.admin = Some(admin);
+        self
+    }
+    #[inline(always)]
+    pub fn lockup_tiers(&mut self, lockup_tiers: Vec<LockupTier>) -> &mut Self {
+        self.instruction.lockup_tiers = Some(lockup_tiers);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch_length_secs(&mut self, epoch_length_secs: u64) -> &mut Self {
+        self.instruction.epoch_length_secs = Some(epoch_length_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn epoch_offset_secs(&mut self, epoch_offset_secs: u64) -> &mut Self {
+        self.instruction.epoch_offset_secs = Some(epoch_offset_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn distribution_mode(&mut self, distribution_mode: DistributionMode) -> &mut Self {
+        self.instruction.distribution_mode = Some(distribution_mode);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -575,6 +656,27 @@ itpl<'a, 'b> InitializePoolCpiBuilder<'a, 'b> {
                 .distribute_authority
                 .clone()
                 .expect("distribute_authority is not set"),
+            admin: self.instruction.admin.clone().expect("admin is not set"),
+            lockup_tiers: self
+                .instruction
+                .lockup_tiers
+                .clone()
+                .expect("lockup_tiers is not set"),
+            epoch_length_secs: self
+                .instruction
+                .epoch_length_secs
+                .clone()
+                .expect("epoch_length_secs is not set"),
+            epoch_offset_secs: self
+                .instruction
+                .epoch_offset_secs
+                .clone()
+                .expect("epoch_offset_secs is not set"),
+            distribution_mode: self
+                .instruction
+                .distribution_mode
+                .clone()
+                .expect("distribution_mode is not set"),
         };
         let instruction = InitializePoolCpi {
             __program: self.instruction.__program,
@@ -630,6 +732,11 @@ struct InitializePoolCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     fill_authority: Option<Pubkey>,
     distribute_authority: Option<Pubkey>,
+    admin: Option<Pubkey>,
+    lockup_tiers: Option<Vec<LockupTier>>,
+    epoch_length_secs: Option<u64>,
+    epoch_offset_secs: Option<u64>,
+    distribution_mode: Option<DistributionMode>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b trezoa_program::account_info::AccountInfo<'a>,
@@ -5,28 +5,76 @@
 //! [https://github.com/trezoaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_authority;
+pub(crate) mod r#add_reward_mint;
 pub(crate) mod r#change_delegate;
 pub(crate) mod r#claim;
+pub(crate) mod r#claim_amount;
 pub(crate) mod r#close_mining;
+pub(crate) mod r#close_pool;
+pub(crate) mod r#compact_index_history;
+pub(crate) mod r#configure_direct_claims;
+pub(crate) mod r#configure_distribution_crank;
+pub(crate) mod r#configure_emission_curve;
+pub(crate) mod r#configure_lockup_tier;
+pub(crate) mod r#configure_vesting;
 pub(crate) mod r#decrease_rewards;
+pub(crate) mod r#defund_vault;
 pub(crate) mod r#deposit_mining;
 pub(crate) mod r#distribute_rewards;
 pub(crate) mod r#extend_stake;
 pub(crate) mod r#fill_vault;
+pub(crate) mod r#get_pending_rewards;
+pub(crate) mod r#initialize_delegate;
+pub(crate) mod r#initialize_delegation;
 pub(crate) mod r#initialize_mining;
 pub(crate) mod r#initialize_pool;
+pub(crate) mod r#initialize_vesting;
+pub(crate) mod r#migrate_mining;
+pub(crate) mod r#migrate_reward_pool;
+pub(crate) mod r#migrate_vault_authority;
+pub(crate) mod r#propose_authority;
+pub(crate) mod r#resize_mining;
+pub(crate) mod r#set_delegate_commission;
+pub(crate) mod r#set_paused_operations;
+pub(crate) mod r#settle_delegation_rewards;
 pub(crate) mod r#slash;
 pub(crate) mod r#withdraw_mining;
+pub(crate) mod r#withdraw_vested;
 
+pub use self::r#accept_authority::*;
+pub use self::r#add_reward_mint::*;
 pub use self::r#change_delegate::*;
 pub use self::r#claim::*;
+pub use self::r#claim_amount::*;
 pub use self::r#close_mining::*;
+pub use self::r#close_pool::*;
+pub use self::r#compact_index_history::*;
+pub use self::r#configure_direct_claims::*;
+pub use self::r#configure_distribution_crank::*;
+pub use self::r#configure_emission_curve::*;
+pub use self::r#configure_lockup_tier::*;
+pub use self::r#configure_vesting::*;
 pub use self::r#decrease_rewards::*;
+pub use self::r#defund_vault::*;
 pub use self::r#deposit_mining::*;
 pub use self::r#distribute_rewards::*;
 pub use self::r#extend_stake::*;
 pub use self::r#fill_vault::*;
+pub use self::r#get_pending_rewards::*;
+pub use self::r#initialize_delegate::*;
+pub use self::r#initialize_delegation::*;
 pub use self::r#initialize_mining::*;
 pub use self::r#initialize_pool::*;
+pub use self::r#initialize_vesting::*;
+pub use self::r#migrate_mining::*;
+pub use self::r#migrate_reward_pool::*;
+pub use self::r#migrate_vault_authority::*;
+pub use self::r#propose_authority::*;
+pub use self::r#resize_mining::*;
+pub use self::r#set_delegate_commission::*;
+pub use self::r#set_paused_operations::*;
+pub use self::r#settle_delegation_rewards::*;
 pub use self::r#slash::*;
 pub use self::r#withdraw_mining::*;
+pub use self::r#withdraw_vested::*;
@@ -19,6 +19,10 @@ pub struct WithdrawMining {
     pub deposit_authority: trezoa_program::pubkey::Pubkey,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: trezoa_program::pubkey::Pubkey,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<trezoa_program::pubkey::Pubkey>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<trezoa_program::pubkey::Pubkey>,
 }
 
 itpl WithdrawMining {
@@ -34,7 +38,7 @@ itpl WithdrawMining {
         args: WithdrawMiningInstructionArgs,
         remaining_accounts: &[trezoa_program::instruction::AccountMeta],
     ) -> trezoa_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             self.reward_pool,
             false,
@@ -51,6 +55,16 @@ itpl WithdrawMining {
             self.delegate_mining,
             false,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                delegate, false,
+            ));
+        }
+        if let Some(delegation) = self.delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                delegation, false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = WithdrawMiningInstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -91,12 +105,16 @@ pub struct WithdrawMiningInstructionArgs {
 ///   1. `[writable]` mining
 ///   2. `[signer]` deposit_authority
 ///   3. `[]` delegate_mining
+///   4. `[writable, optional]` delegate
+///   5. `[writable, optional]` delegation
 #[derive(Default)]
 pub struct WithdrawMiningBuilder {
     reward_pool: Option<trezoa_program::pubkey::Pubkey>,
     mining: Option<trezoa_program::pubkey::Pubkey>,
     deposit_authority: Option<trezoa_program::pubkey::Pubkey>,
     delegate_mining: Option<trezoa_program::pubkey::Pubkey>,
+    delegate: Option<trezoa_program::pubkey::Pubkey>,
+    delegation: Option<trezoa_program::pubkey::Pubkey>,
     amount: Option<u64>,
     mining_owner: Option<Pubkey>,
     delegate: Option<Pubkey>,
@@ -137,6 +155,20 @@ itpl WithdrawMiningBuilder {
         self.delegate_mining = Some(delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegate(&mut self, delegate: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.delegate = delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegation(&mut self, delegation: Option<trezoa_program::pubkey::Pubkey>) -> &mut Self {
+        self.delegation = delegation;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
@@ -179,6 +211,8 @@ itpl WithdrawMiningBuilder {
                 .deposit_authority
                 .expect("deposit_authority is not set"),
             delegate_mining: self.delegate_mining.expect("delegate_mining is not set"),
+            delegate: self.delegate,
+            delegation: self.delegation,
         };
         let args = WithdrawMiningInstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -200,6 +234,10 @@ pub struct WithdrawMiningCpiAccounts<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
 }
 
 /// `withdraw_mining` CPI instruction.
@@ -214,6 +252,10 @@ pub struct WithdrawMiningCpi<'a, 'b> {
     pub deposit_authority: &'b trezoa_program::account_info::AccountInfo<'a>,
     /// The address of Mining Account that might be used as a delegate in delegated staking model
     pub delegate_mining: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    pub delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    pub delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: WithdrawMiningInstructionArgs,
 }
@@ -230,6 +272,8 @@ itpl<'a, 'b> WithdrawMiningCpi<'a, 'b> {
             mining: accounts.mining,
             deposit_authority: accounts.deposit_authority,
             delegate_mining: accounts.delegate_mining,
+            delegate: accounts.delegate,
+            delegation: accounts.delegation,
             __args: args,
         }
     }
@@ -266,7 +310,7 @@ itpl<'a, 'b> WithdrawMiningCpi<'a, 'b> {
             bool,
         )],
     ) -> trezoa_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(trezoa_program::instruction::AccountMeta::new(
             *self.reward_pool.key,
             false,
@@ -283,6 +327,18 @@ itpl<'a, 'b> WithdrawMiningCpi<'a, 'b> {
             *self.delegate_mining.key,
             false,
         ));
+        if let Some(delegate) = self.delegate {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *delegate.key,
+                false,
+            ));
+        }
+        if let Some(delegation) = self.delegation {
+            accounts.push(trezoa_program::instruction::AccountMeta::new(
+                *delegation.key,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(trezoa_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -299,12 +355,18 @@ itpl<'a, 'b> WithdrawMiningCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.reward_pool.clone());
         account_infos.push(self.mining.clone());
         account_infos.push(self.deposit_authority.clone());
         account_infos.push(self.delegate_mining.clone());
+        if let Some(delegate) = self.delegate {
+            account_infos.push(delegate.clone());
+        }
+        if let Some(delegation) = self.delegation {
+            account_infos.push(delegation.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -325,6 +387,8 @@ itpl<'a, 'b> WithdrawMiningCpi<'a, 'b> {
 ///   1. `[writable]` mining
 ///   2. `[signer]` deposit_authority
 ///   3. `[]` delegate_mining
+///   4. `[writable, optional]` delegate
+///   5. `[writable, optional]` delegation
 pub struct WithdrawMiningCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawMiningCpiBuilderInstruction<'a, 'b>>,
 }
@@ -337,6 +401,8 @@ itpl<'a, 'b> WithdrawMiningCpiBuilder<'a, 'b> {
             mining: None,
             deposit_authority: None,
             delegate_mining: None,
+            delegate: None,
+            delegation: None,
             amount: None,
             mining_owner: None,
             delegate: None,
@@ -380,6 +446,26 @@ itpl<'a, 'b> WithdrawMiningCpiBuilder<'a, 'b> {
         self.instruction.delegate_mining = Some(delegate_mining);
         self
     }
+    /// `[optional account]`
+    /// The delegate account of the delegate mining, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegate(
+        &mut self,
+        delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegate = delegate;
+        self
+    }
+    /// `[optional account]`
+    /// The delegation of the mining to the delegate, required if the delegate charges a commission
+    #[inline(always)]
+    pub fn delegation(
+        &mut self,
+        delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.delegation = delegation;
+        self
+    }
     #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
@@ -468,6 +554,10 @@ itpl<'a, 'b> WithdrawMiningCpiBuilder<'a, 'b> {
                 .instruction
                 .delegate_mining
                 .expect("delegate_mining is not set"),
+
+            delegate: self.instruction.delegate,
+
+            delegation: self.instruction.delegation,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -483,6 +573,8 @@ struct WithdrawMiningCpiBuilderInstruction<'a, 'b> {
     mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     deposit_authority: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     delegate_mining: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegate: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    delegation: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     mining_owner: Option<Pubkey>,
     delegate: Option<Pubkey>,
@@ -0,0 +1,583 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/trezoaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+/// Accounts.
+pub struct WithdrawVested {
+    /// The address of the reward pool
+    pub reward_pool: trezoa_program::pubkey::Pubkey,
+    /// The address of the reward mint
+    pub reward_mint: trezoa_program::pubkey::Pubkey,
+    /// The address of the reward vault
+    pub vault: trezoa_program::pubkey::Pubkey,
+    /// The address of the vesting account of the mining
+    pub vesting: trezoa_program::pubkey::Pubkey,
+    /// The end user the mining accounts belongs to
+    pub mining_owner: trezoa_program::pubkey::Pubkey,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (trezoa_program::pubkey::Pubkey, bool),
+    /// ATA where tokens will be withdrawn to
+    pub mining_owner_reward_token_account: trezoa_program::pubkey::Pubkey,
+    /// The address of the Token program where rewards are minted
+    pub token_program: trezoa_program::pubkey::Pubkey,
+}
+
+itpl WithdrawVested {
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> trezoa_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.reward_pool,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.reward_mint,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.vault, false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.vesting,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.mining_owner,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.vault_owner.0,
+            self.vault_owner.1,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            self.mining_owner_reward_token_account,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = WithdrawVestedInstructionData::new().try_to_vec().unwrap();
+
+        trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct WithdrawVestedInstructionData {
+    discriminator: u8,
+}
+
+itpl WithdrawVestedInstructionData {
+    pub fn new() -> Self {
+        Self { discriminator: 27 }
+    }
+}
+
+/// Instruction builder for `WithdrawVested`.
+///
+/// ### Accounts:
+///
+///   0. `[]` reward_pool
+///   1. `[]` reward_mint
+///   2. `[writable]` vault
+///   3. `[writable]` vesting
+///   4. `[signer]` mining_owner
+///   5. `[signer]` vault_owner
+///   6. `[writable]` mining_owner_reward_token_account
+///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+#[derive(Default)]
+pub struct WithdrawVestedBuilder {
+    reward_pool: Option<trezoa_program::pubkey::Pubkey>,
+    reward_mint: Option<trezoa_program::pubkey::Pubkey>,
+    vault: Option<trezoa_program::pubkey::Pubkey>,
+    vesting: Option<trezoa_program::pubkey::Pubkey>,
+    mining_owner: Option<trezoa_program::pubkey::Pubkey>,
+    vault_owner: Option<(trezoa_program::pubkey::Pubkey, bool)>,
+    mining_owner_reward_token_account: Option<trezoa_program::pubkey::Pubkey>,
+    token_program: Option<trezoa_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<trezoa_program::instruction::AccountMeta>,
+}
+
+itpl WithdrawVestedBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the reward mint
+    #[inline(always)]
+    pub fn reward_mint(&mut self, reward_mint: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.reward_mint = Some(reward_mint);
+        self
+    }
+    /// The address of the reward vault
+    #[inline(always)]
+    pub fn vault(&mut self, vault: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.vault = Some(vault);
+        self
+    }
+    /// The address of the vesting account of the mining
+    #[inline(always)]
+    pub fn vesting(&mut self, vesting: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.vesting = Some(vesting);
+        self
+    }
+    /// The end user the mining accounts belongs to
+    #[inline(always)]
+    pub fn mining_owner(&mut self, mining_owner: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.mining_owner = Some(mining_owner);
+        self
+    }
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_owner(
+        &mut self,
+        vault_owner: trezoa_program::pubkey::Pubkey,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.vault_owner = Some((vault_owner, as_signer));
+        self
+    }
+    /// ATA where tokens will be withdrawn to
+    #[inline(always)]
+    pub fn mining_owner_reward_token_account(
+        &mut self,
+        mining_owner_reward_token_account: trezoa_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mining_owner_reward_token_account = Some(mining_owner_reward_token_account);
+        self
+    }
+    /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: trezoa_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: trezoa_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[trezoa_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> trezoa_program::instruction::Instruction {
+        let accounts = WithdrawVested {
+            reward_pool: self.reward_pool.expect("reward_pool is not set"),
+            reward_mint: self.reward_mint.expect("reward_mint is not set"),
+            vault: self.vault.expect("vault is not set"),
+            vesting: self.vesting.expect("vesting is not set"),
+            mining_owner: self.mining_owner.expect("mining_owner is not set"),
+            vault_owner: self.vault_owner.expect("vault_owner is not set"),
+            mining_owner_reward_token_account: self
+                .mining_owner_reward_token_account
+                .expect("mining_owner_reward_token_account is not set"),
+            token_program: self.token_program.unwrap_or(trezoa_program::pubkey!(
+                "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            )),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `withdraw_vested` CPI accounts.
+pub struct WithdrawVestedCpiAccounts<'a, 'b> {
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward mint
+    pub reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward vault
+    pub vault: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the vesting account of the mining
+    pub vesting: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The end user the mining accounts belongs to
+    pub mining_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
+    /// ATA where tokens will be withdrawn to
+    pub mining_owner_reward_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+/// `withdraw_vested` CPI instruction.
+pub struct WithdrawVestedCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward pool
+    pub reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward mint
+    pub reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the reward vault
+    pub vault: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the vesting account of the mining
+    pub vesting: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The end user the mining accounts belongs to
+    pub mining_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    pub vault_owner: (&'b trezoa_program::account_info::AccountInfo<'a>, bool),
+    /// ATA where tokens will be withdrawn to
+    pub mining_owner_reward_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    /// The address of the Token program where rewards are minted
+    pub token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+}
+
+itpl<'a, 'b> WithdrawVestedCpi<'a, 'b> {
+    pub fn new(
+        program: &'b trezoa_program::account_info::AccountInfo<'a>,
+        accounts: WithdrawVestedCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            reward_pool: accounts.reward_pool,
+            reward_mint: accounts.reward_mint,
+            vault: accounts.vault,
+            vesting: accounts.vesting,
+            mining_owner: accounts.mining_owner,
+            vault_owner: accounts.vault_owner,
+            mining_owner_reward_token_account: accounts.mining_owner_reward_token_account,
+            token_program: accounts.token_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.reward_pool.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.reward_mint.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.vault.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.vesting.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.mining_owner.key,
+            true,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.vault_owner.0.key,
+            self.vault_owner.1,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new(
+            *self.mining_owner_reward_token_account.key,
+            false,
+        ));
+        accounts.push(trezoa_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(trezoa_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = WithdrawVestedInstructionData::new().try_to_vec().unwrap();
+
+        let instruction = trezoa_program::instruction::Instruction {
+            program_id: crate::TRZ_REWARDS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.reward_pool.clone());
+        account_infos.push(self.reward_mint.clone());
+        account_infos.push(self.vault.clone());
+        account_infos.push(self.vesting.clone());
+        account_infos.push(self.mining_owner.clone());
+        account_infos.push(self.vault_owner.0.clone());
+        account_infos.push(self.mining_owner_reward_token_account.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            trezoa_program::program::invoke(&instruction, &account_infos)
+        } else {
+            trezoa_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `WithdrawVested` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` reward_pool
+///   1. `[]` reward_mint
+///   2. `[writable]` vault
+///   3. `[writable]` vesting
+///   4. `[signer]` mining_owner
+///   5. `[signer]` vault_owner
+///   6. `[writable]` mining_owner_reward_token_account
+///   7. `[]` token_program
+pub struct WithdrawVestedCpiBuilder<'a, 'b> {
+    instruction: Box<WithdrawVestedCpiBuilderInstruction<'a, 'b>>,
+}
+
+itpl<'a, 'b> WithdrawVestedCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b trezoa_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(WithdrawVestedCpiBuilderInstruction {
+            __program: program,
+            reward_pool: None,
+            reward_mint: None,
+            vault: None,
+            vesting: None,
+            mining_owner: None,
+            vault_owner: None,
+            mining_owner_reward_token_account: None,
+            token_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The address of the reward pool
+    #[inline(always)]
+    pub fn reward_pool(
+        &mut self,
+        reward_pool: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_pool = Some(reward_pool);
+        self
+    }
+    /// The address of the reward mint
+    #[inline(always)]
+    pub fn reward_mint(
+        &mut self,
+        reward_mint: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reward_mint = Some(reward_mint);
+        self
+    }
+    /// The address of the reward vault
+    #[inline(always)]
+    pub fn vault(&mut self, vault: &'b trezoa_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.vault = Some(vault);
+        self
+    }
+    /// The address of the vesting account of the mining
+    #[inline(always)]
+    pub fn vesting(
+        &mut self,
+        vesting: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.vesting = Some(vesting);
+        self
+    }
+    /// The end user the mining accounts belongs to
+    #[inline(always)]
+    pub fn mining_owner(
+        &mut self,
+        mining_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mining_owner = Some(mining_owner);
+        self
+    }
+    /// The deposit authority, or the vault authority PDA once the vaults are migrated to it
+    #[inline(always)]
+    pub fn vault_owner(
+        &mut self,
+        vault_owner: &'b trezoa_program::account_info::AccountInfo<'a>,
+        as_signer: bool,
+    ) -> &mut Self {
+        self.instruction.vault_owner = Some((vault_owner, as_signer));
+        self
+    }
+    /// ATA where tokens will be withdrawn to
+    #[inline(always)]
+    pub fn mining_owner_reward_token_account(
+        &mut self,
+        mining_owner_reward_token_account: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mining_owner_reward_token_account =
+            Some(mining_owner_reward_token_account);
+        self
+    }
+    /// The address of the Token program where rewards are minted
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b trezoa_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b trezoa_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> trezoa_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> trezoa_program::entrypoint::ProgramResult {
+        let instruction = WithdrawVestedCpi {
+            __program: self.instruction.__program,
+
+            reward_pool: self
+                .instruction
+                .reward_pool
+                .expect("reward_pool is not set"),
+
+            reward_mint: self
+                .instruction
+                .reward_mint
+                .expect("reward_mint is not set"),
+
+            vault: self.instruction.vault.expect("vault is not set"),
+
+            vesting: self.instruction.vesting.expect("vesting is not set"),
+
+            mining_owner: self
+                .instruction
+                .mining_owner
+                .expect("mining_owner is not set"),
+
+            vault_owner: self
+                .instruction
+                .vault_owner
+                .expect("vault_owner is not set"),
+
+            mining_owner_reward_token_account: self
+                .instruction
+                .mining_owner_reward_token_account
+                .expect("mining_owner_reward_token_account is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct WithdrawVestedCpiBuilderInstruction<'a, 'b> {
+    __program: &'b trezoa_program::account_info::AccountInfo<'a>,
+    reward_pool: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    reward_mint: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vesting: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    mining_owner: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    vault_owner: Option<(&'b trezoa_program::account_info::AccountInfo<'a>, bool)>,
+    mining_owner_reward_token_account: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b trezoa_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b trezoa_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -17,4 +17,7 @@ pub enum AccountType {
     Uninitialized,
     RewardPool,
     Mining,
+    Vesting,
+    Delegate,
+    Delegation,
 }
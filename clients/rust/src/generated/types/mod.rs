@@ -5,8 +5,60 @@
 //! [https://github.com/trezoaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_authority_event;
 pub(crate) mod r#account_type;
+pub(crate) mod r#authority_role;
+pub(crate) mod r#change_delegate_event;
+pub(crate) mod r#claim_event;
+pub(crate) mod r#close_pool_event;
+pub(crate) mod r#compact_index_history_event;
+pub(crate) mod r#crank_bounty;
+pub(crate) mod r#decrease_rewards_event;
+pub(crate) mod r#defund_vault_event;
+pub(crate) mod r#deposit_event;
+pub(crate) mod r#distribute_event;
+pub(crate) mod r#distribution_mode;
+pub(crate) mod r#emission_curve;
+pub(crate) mod r#fill_vault_event;
 pub(crate) mod r#lockup_period;
+pub(crate) mod r#lockup_tier;
+pub(crate) mod r#merged_weighted_stake_diff;
+pub(crate) mod r#pending_rewards;
+pub(crate) mod r#propose_authority_event;
+pub(crate) mod r#reward_token;
+pub(crate) mod r#rewards_event;
+pub(crate) mod r#set_paused_operations_event;
+pub(crate) mod r#slash_event;
+pub(crate) mod r#vault_leftovers;
+pub(crate) mod r#vesting_tranche;
+pub(crate) mod r#withdraw_event;
+pub(crate) mod r#withdraw_vested_event;
 
+pub use self::r#accept_authority_event::*;
 pub use self::r#account_type::*;
+pub use self::r#authority_role::*;
+pub use self::r#change_delegate_event::*;
+pub use self::r#claim_event::*;
+pub use self::r#close_pool_event::*;
+pub use self::r#compact_index_history_event::*;
+pub use self::r#crank_bounty::*;
+pub use self::r#decrease_rewards_event::*;
+pub use self::r#defund_vault_event::*;
+pub use self::r#deposit_event::*;
+pub use self::r#distribute_event::*;
+pub use self::r#distribution_mode::*;
+pub use self::r#emission_curve::*;
+pub use self::r#fill_vault_event::*;
 pub use self::r#lockup_period::*;
+pub use self::r#lockup_tier::*;
+pub use self::r#merged_weighted_stake_diff::*;
+pub use self::r#pending_rewards::*;
+pub use self::r#propose_authority_event::*;
+pub use self::r#reward_token::*;
+pub use self::r#rewards_event::*;
+pub use self::r#set_paused_operations_event::*;
+pub use self::r#slash_event::*;
+pub use self::r#vault_leftovers::*;
+pub use self::r#vesting_tranche::*;
+pub use self::r#withdraw_event::*;
+pub use self::r#withdraw_vested_event::*;
@@ -0,0 +1,44 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/trezoaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::AcceptAuthorityEvent;
+use crate::generated::types::ChangeDelegateEvent;
+use crate::generated::types::ClaimEvent;
+use crate::generated::types::ClosePoolEvent;
+use crate::generated::types::CompactIndexHistoryEvent;
+use crate::generated::types::DecreaseRewardsEvent;
+use crate::generated::types::DefundVaultEvent;
+use crate::generated::types::DepositEvent;
+use crate::generated::types::DistributeEvent;
+use crate::generated::types::FillVaultEvent;
+use crate::generated::types::ProposeAuthorityEvent;
+use crate::generated::types::SetPausedOperationsEvent;
+use crate::generated::types::SlashEvent;
+use crate::generated::types::WithdrawEvent;
+use crate::generated::types::WithdrawVestedEvent;
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RewardsEvent {
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    Claim(ClaimEvent),
+    Distribute(DistributeEvent),
+    Slash(SlashEvent),
+    DecreaseRewards(DecreaseRewardsEvent),
+    ChangeDelegate(ChangeDelegateEvent),
+    FillVault(FillVaultEvent),
+    DefundVault(DefundVaultEvent),
+    ClosePool(ClosePoolEvent),
+    WithdrawVested(WithdrawVestedEvent),
+    CompactIndexHistory(CompactIndexHistoryEvent),
+    ProposeAuthority(ProposeAuthorityEvent),
+    AcceptAuthority(AcceptAuthorityEvent),
+    SetPausedOperations(SetPausedOperationsEvent),
+}
@@ -0,0 +1,19 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/trezoaplex-foundation/kinobi]
+//!
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VestingTranche {
+    pub vesting_amounts: [u64; 3],
+    pub locked_amounts: [u64; 3],
+    pub vesting_start: u64,
+    pub cliff_secs: u64,
+    pub duration_secs: u64,
+}
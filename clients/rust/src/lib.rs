@@ -1,4 +1,8 @@
+pub mod client;
+pub mod decoders;
+pub mod events;
 mod generated;
+pub mod pda;
 
 pub use generated::programs::TRZ_REWARDS_ID as ID;
 pub use generated::*;
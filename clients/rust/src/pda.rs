@@ -0,0 +1,37 @@
+//! Addresses of the program derived accounts
+
+use trezoa_program::pubkey::Pubkey;
+use trz_rewards::utils::{
+    find_delegate_program_address, find_delegation_program_address, find_mining_program_address,
+    find_vault_authority_program_address, find_vault_program_address, find_vesting_program_address,
+};
+
+/// Address of the mining of the owner in the reward pool
+pub fn find_mining_address(reward_pool: &Pubkey, mining_owner: &Pubkey) -> (Pubkey, u8) {
+    find_mining_program_address(&crate::ID, mining_owner, reward_pool)
+}
+
+/// Address of the vault storing the reward mint's rewards of the reward pool
+pub fn find_vault_address(reward_pool: &Pubkey, reward_mint: &Pubkey) -> (Pubkey, u8) {
+    find_vault_program_address(&crate::ID, reward_pool, reward_mint)
+}
+
+/// Address of the PDA owning the reward pool's vaults once they are migrated
+pub fn find_vault_authority_address(reward_pool: &Pubkey) -> (Pubkey, u8) {
+    find_vault_authority_program_address(&crate::ID, reward_pool)
+}
+
+/// Address of the vesting of the mining's claimed rewards
+pub fn find_vesting_address(mining: &Pubkey) -> (Pubkey, u8) {
+    find_vesting_program_address(&crate::ID, mining)
+}
+
+/// Address of the account tracking the stake delegated to the delegate mining
+pub fn find_delegate_address(delegate_mining: &Pubkey) -> (Pubkey, u8) {
+    find_delegate_program_address(&crate::ID, delegate_mining)
+}
+
+/// Address of the account tracking the stake the mining has delegated to the delegate mining
+pub fn find_delegation_address(delegate_mining: &Pubkey, mining: &Pubkey) -> (Pubkey, u8) {
+    find_delegation_program_address(&crate::ID, delegate_mining, mining)
+}
@@ -0,0 +1,246 @@
+#![cfg(feature = "test-sbf")]
+
+use rewards::{
+    client::{PoolConfig, RewardsClient, RewardsRpc},
+    pda::find_vault_address,
+};
+use tpl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use trezoa_program::{instruction::Instruction, program_pack::Pack, pubkey::Pubkey};
+use trezoa_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use trezoa_sdk::{
+    account::Account,
+    clock::SECONDS_PER_DAY,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use trz_rewards_core::{state::DistributionMode, utils::LockupPeriod};
+
+/// Banks client of the test validator standing in for the cluster's RPC
+struct BanksRpc {
+    context: ProgramTestContext,
+}
+
+itpl RewardsRpc for BanksRpc {
+    type Error = BanksClientError;
+
+    fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    async fn get_account(&mut self, address: &Pubkey) -> Result<Option<Account>, Self::Error> {
+        self.context.banks_client.get_account(*address).await
+    }
+
+    async fn minimum_balance_for_rent_exemption(
+        &mut self,
+        data_len: usize,
+    ) -> Result<u64, Self::Error> {
+        let rent = self.context.banks_client.get_rent().await?;
+        Ok(rent.minimum_balance(data_len))
+    }
+
+    async fn send_transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), Self::Error> {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        self.context.banks_client.process_transaction(tx).await
+    }
+
+    async fn simulate_return_data(
+        &mut self,
+        instructions: &[Instruction],
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        let blockhash = self.context.banks_client.get_latest_blockhash().await?;
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &[&self.context.payer],
+            blockhash,
+        );
+
+        let simulation = self.context.banks_client.simulate_transaction(tx).await?;
+        Ok(simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .map(|return_data| return_data.data))
+    }
+}
+
+async fn create_mint(rpc: &mut BanksRpc, mint_authority: &Pubkey) -> Pubkey {
+    let mint = Keypair::new();
+    let lamports = rpc
+        .minimum_balance_for_rent_exemption(tpl_token::state::Mint::LEN)
+        .await
+        .unwrap();
+    rpc.send_transaction(
+        &[
+            system_instruction::create_account(
+                &rpc.payer(),
+                &mint.pubkey(),
+                lamports,
+                tpl_token::state::Mint::LEN as u64,
+                &tpl_token::id(),
+            ),
+            tpl_token::instruction::initialize_mint(
+                &tpl_token::id(),
+                &mint.pubkey(),
+                mint_authority,
+                None,
+                0,
+            )
+            .unwrap(),
+        ],
+        &[&mint],
+    )
+    .await
+    .unwrap();
+
+    mint.pubkey()
+}
+
+#[tokio::test]
+async fn stake_and_claim_all() {
+    let context = ProgramTest::new("trz_rewards", rewards::ID, None)
+        .start_with_context()
+        .await;
+    let mut client = RewardsClient::new(BanksRpc { context });
+
+    let payer = client.rpc().payer();
+    let reward_mint = create_mint(client.rpc(), &payer).await;
+    let reward_pool = Keypair::new();
+    let deposit_authority = Keypair::new();
+    let fill_authority = Keypair::new();
+    let distribute_authority = Keypair::new();
+    client
+        .create_pool(
+            &reward_pool,
+            &reward_mint,
+            &deposit_authority,
+            PoolConfig {
+                fill_authority: fill_authority.pubkey(),
+                distribute_authority: distribute_authority.pubkey(),
+                admin: payer,
+                lockup_tiers: vec![],
+                epoch_length_secs: 0,
+                epoch_offset_secs: 0,
+                distribution_mode: DistributionMode::Epochs,
+            },
+        )
+        .await
+        .unwrap();
+
+    let user = Keypair::new();
+    let mining = client
+        .open_mining_and_deposit(
+            &reward_pool.pubkey(),
+            &user.pubkey(),
+            &deposit_authority,
+            100,
+            LockupPeriod::ThreeMonths,
+        )
+        .await
+        .unwrap();
+    assert_eq!(client.mining(&mining).await.unwrap().share, 200);
+
+    // fill the vault with rewards distributed over 100 days and distribute the first day
+    let rewarder = get_associated_token_address(&fill_authority.pubkey(), &reward_mint);
+    let user_rewards = get_associated_token_address(&user.pubkey(), &reward_mint);
+    let curr_ts = client
+        .rpc()
+        .context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    client
+        .rpc()
+        .send_transaction(
+            &[
+                create_associated_token_account(
+                    &payer,
+                    &fill_authority.pubkey(),
+                    &reward_mint,
+                    &tpl_token::id(),
+                ),
+                create_associated_token_account(
+                    &payer,
+                    &user.pubkey(),
+                    &reward_mint,
+                    &tpl_token::id(),
+                ),
+                tpl_token::instruction::mint_to(
+                    &tpl_token::id(),
+                    &reward_mint,
+                    &rewarder,
+                    &payer,
+                    &[],
+                    100,
+                )
+                .unwrap(),
+                trz_rewards::instruction::fill_vault(
+                    &rewards::ID,
+                    &reward_pool.pubkey(),
+                    &reward_mint,
+                    &find_vault_address(&reward_pool.pubkey(), &reward_mint).0,
+                    &fill_authority.pubkey(),
+                    &rewarder,
+                    100,
+                    curr_ts + 100 * SECONDS_PER_DAY,
+                ),
+                trz_rewards::instruction::distribute_rewards(
+                    &rewards::ID,
+                    &reward_pool.pubkey(),
+                    &distribute_authority.pubkey(),
+                ),
+            ],
+            &[&fill_authority, &distribute_authority],
+        )
+        .await
+        .unwrap();
+
+    let stats = client.pool_stats(&reward_pool.pubkey()).await.unwrap();
+    assert_eq!(stats.total_share, 200);
+    assert_eq!(stats.open_minings, 1);
+    assert_eq!(stats.index_history_len, 1);
+    // the lockup expires at the beginning of the day
+    assert_eq!(
+        stats.next_modifier_at,
+        Some(curr_ts - curr_ts % SECONDS_PER_DAY + 90 * SECONDS_PER_DAY)
+    );
+    assert_eq!(stats.reward_tokens[0].tokens_available_for_distribution, 99);
+
+    let pending_rewards = client
+        .pending_rewards(&reward_pool.pubkey(), &mining)
+        .await
+        .unwrap();
+    assert_eq!(pending_rewards.unclaimed_rewards[0], 1);
+
+    client
+        .claim_all(&reward_pool.pubkey(), &user, Some(&deposit_authority))
+        .await
+        .unwrap();
+    let user_rewards_account = client
+        .rpc()
+        .get_account(&user_rewards)
+        .await
+        .unwrap()
+        .unwrap();
+    let user_rewards_account =
+        tpl_token::state::Account::unpack(&user_rewards_account.data).unwrap();
+    assert_eq!(user_rewards_account.amount, 1);
+}
@@ -0,0 +1,138 @@
+use rewards::{
+    decoders::{DecodedMining, DecodedRewardPool, IndexRecord, WeightedStakeDiff},
+    types,
+};
+use sokoban::NodeAllocatorMap;
+use trezoa_program::{program_error::ProgramError, pubkey::Pubkey};
+use trz_rewards_core::state::{DistributionMode, Mining, RewardPool, WrappedMining, WrappedRewardPool};
+
+/// Writes the account bytes after a padding byte, so they are misaligned as RPC bytes might be
+fn unaligned(len: usize, write: itpl FnOnce(&mut [u8])) -> Vec<u8> {
+    let mut words = vec![0u128; len.div_ceil(std::mem::size_of::<u128>())];
+    let bytes = &mut bytemuck::cast_slice_mut::<u128, u8>(&mut words)[..len];
+    write(bytes);
+
+    let mut data = vec![0];
+    data.extend_from_slice(bytes);
+    data
+}
+
+#[test]
+fn decodes_mining_with_modifiers() {
+    let reward_pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let data = unaligned(WrappedMining::LEN, |bytes| {
+        let wrapped_mining =
+            WrappedMining::initialize(bytes, Mining::initialize(reward_pool, owner, 0)).unwrap();
+        wrapped_mining.mining.share = 300;
+        wrapped_mining.weighted_stake_diffs.insert(200, 50).unwrap();
+        wrapped_mining
+            .weighted_stake_diffs
+            .insert(100, 100)
+            .unwrap();
+    });
+
+    let mining = DecodedMining::from_account_data(&data[1..]).unwrap();
+
+    assert_eq!(mining.reward_pool, reward_pool);
+    assert_eq!(mining.owner, owner);
+    assert_eq!(mining.share, 300);
+    assert_eq!(
+        mining.weighted_stake_diffs,
+        vec![
+            WeightedStakeDiff {
+                date: 100,
+                diff: 100
+            },
+            WeightedStakeDiff {
+                date: 200,
+                diff: 50
+            },
+        ]
+    );
+    assert_eq!(
+        mining.upcoming_weighted_stake_diffs(100),
+        &[WeightedStakeDiff {
+            date: 200,
+            diff: 50
+        }]
+    );
+}
+
+#[test]
+fn decodes_reward_pool_with_index_history() {
+    let reward_mint = Pubkey::new_unique();
+    let data = unaligned(WrappedRewardPool::LEN, |bytes| {
+        let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(bytes).unwrap();
+        *wrapped_reward_pool.pool = RewardPool::initialize(
+            0,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            reward_mint,
+            Pubkey::new_unique(),
+            &[],
+            0,
+            0,
+            DistributionMode::Epochs,
+        )
+        .unwrap();
+        wrapped_reward_pool.pool.vesting_rewards[0] = 5;
+        wrapped_reward_pool.pool.open_minings = 2;
+        wrapped_reward_pool.pool.minings_checkpoint = 86_400;
+        wrapped_reward_pool.pool.start_minings_checkpoint(172_800);
+        wrapped_reward_pool.weighted_stake_diffs.initialize();
+        wrapped_reward_pool
+            .weighted_stake_diffs
+            .insert(86_400, 10)
+            .unwrap();
+        wrapped_reward_pool
+            .weighted_stake_diffs
+            .insert(259_200, 20)
+            .unwrap();
+        wrapped_reward_pool.cumulative_index.initialize();
+        wrapped_reward_pool
+            .cumulative_index
+            .insert(86_400, [10, 0, 0])
+            .unwrap();
+    });
+
+    let reward_pool = DecodedRewardPool::from_account_data(&data[1..]).unwrap();
+
+    assert_eq!(reward_pool.reward_tokens.len(), 1);
+    assert_eq!(reward_pool.reward_tokens[0].mint, reward_mint);
+    assert_eq!(reward_pool.reward_tokens[0].vesting_rewards, 5);
+    assert_eq!(
+        reward_pool.distribution_mode,
+        types::DistributionMode::Epochs
+    );
+    assert_eq!(reward_pool.crank_bounty, types::CrankBounty::None);
+    assert!(reward_pool.lockup_tiers[0].enabled);
+    assert_eq!(reward_pool.minings_checkpoint, 86_400);
+    assert_eq!(reward_pool.pending_minings_checkpoint, 172_800);
+    assert_eq!(reward_pool.minings_behind_checkpoint, 2);
+    assert_eq!(reward_pool.checkpoint_generation, 1);
+    assert_eq!(reward_pool.weighted_stake_diffs.len(), 2);
+    assert_eq!(
+        reward_pool.upcoming_weighted_stake_diffs(172_800),
+        &[WeightedStakeDiff {
+            date: 259_200,
+            diff: 20
+        }]
+    );
+    assert_eq!(
+        reward_pool.cumulative_index,
+        vec![IndexRecord {
+            date: 86_400,
+            index_with_precision: [10, 0, 0],
+        }]
+    );
+}
+
+#[test]
+fn rejects_truncated_account() {
+    assert_eq!(
+        DecodedMining::from_account_data(&[0; 8]),
+        Err(ProgramError::InvalidAccountData)
+    );
+}
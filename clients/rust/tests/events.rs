@@ -0,0 +1,58 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rewards::events::{self, FillVaultEvent, RewardsEvent};
+use trezoa_program::pubkey::Pubkey;
+
+fn fill_vault_event(amount: u64) -> RewardsEvent {
+    RewardsEvent::FillVault(FillVaultEvent {
+        reward_pool: Pubkey::new_unique(),
+        reward_mint: Pubkey::new_unique(),
+        amount,
+        distribution_ends_at: 86_400,
+        tokens_available_for_distribution: amount,
+        timestamp: 3_600,
+    })
+}
+
+fn data_log(event: &RewardsEvent) -> String {
+    format!("Program data: {}", STANDARD.encode(event.encode().unwrap()))
+}
+
+#[test]
+fn decodes_events_of_the_program_only() {
+    let program_id = rewards::ID.to_string();
+    let token_program = Pubkey::new_unique().to_string();
+    let first_event = fill_vault_event(100);
+    let second_event = fill_vault_event(200);
+
+    let logs = vec![
+        format!("Program {program_id} invoke [1]"),
+        "Program log: RewardsInstruction: FillVault".to_string(),
+        format!("Program {token_program} invoke [2]"),
+        data_log(&fill_vault_event(300)),
+        format!("Program {token_program} success"),
+        data_log(&first_event),
+        format!("Program {program_id} consumed 10000 of 200000 compute units"),
+        format!("Program {program_id} success"),
+        format!("Program {token_program} invoke [1]"),
+        data_log(&fill_vault_event(400)),
+        format!("Program {token_program} success"),
+        format!("Program {program_id} invoke [1]"),
+        data_log(&second_event),
+        format!("Program {program_id} success"),
+    ];
+
+    assert_eq!(
+        events::from_logs(&logs).unwrap(),
+        vec![first_event, second_event]
+    );
+}
+
+#[test]
+fn fails_on_malformed_data() {
+    let logs = vec![
+        format!("Program {} invoke [1]", rewards::ID),
+        "Program data: AAAA".to_string(),
+    ];
+
+    assert!(events::from_logs(&logs).is_err());
+}
@@ -0,0 +1,24 @@
+use rewards::pda::{find_mining_address, find_vault_address};
+use trezoa_program::pubkey::Pubkey;
+
+#[test]
+fn derives_addresses_from_the_program_seeds() {
+    let reward_pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let reward_mint = Pubkey::new_unique();
+
+    assert_eq!(
+        find_mining_address(&reward_pool, &owner),
+        Pubkey::find_program_address(
+            &[b"mining", owner.as_ref(), reward_pool.as_ref()],
+            &rewards::ID
+        )
+    );
+    assert_eq!(
+        find_vault_address(&reward_pool, &reward_mint),
+        Pubkey::find_program_address(
+            &[b"vault", reward_pool.as_ref(), reward_mint.as_ref()],
+            &rewards::ID
+        )
+    );
+}
@@ -0,0 +1,9 @@
+//! Off-chain simulator of the rewards program. It runs the very code of the program
+//! over the in-memory reward pool and minings, so the simulated rewards are the ones
+//! the program would have calculated. The time is virtual and set by the simulator.
+
+mod scenario;
+mod simulator;
+
+pub use scenario::*;
+pub use simulator::*;
@@ -0,0 +1,242 @@
+use crate::simulator::{MiningId, Simulator};
+use trezoa_program::{clock::SECONDS_PER_DAY, program_error::ProgramError};
+use trz_rewards_core::{state::MAX_REWARD_TOKENS, utils::LockupPeriod};
+
+/// Action of the scenario, mirroring the instruction of the same name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    FillVault {
+        reward_token_index: usize,
+        rewards: u64,
+        distribution_ends_at: u64,
+    },
+    /// The crank bounty goes to the keeper's mining if it's given
+    DistributeRewards { keeper: Option<MiningId> },
+    DepositMining {
+        mining: MiningId,
+        amount: u64,
+        lockup_period: LockupPeriod,
+        delegate: Option<MiningId>,
+    },
+    WithdrawMining {
+        mining: MiningId,
+        amount: u64,
+        delegate: Option<MiningId>,
+    },
+    ExtendStake {
+        mining: MiningId,
+        old_lockup_period: LockupPeriod,
+        new_lockup_period: LockupPeriod,
+        deposit_start_ts: u64,
+        base_amount: u64,
+        additional_amount: u64,
+        delegate: Option<MiningId>,
+    },
+    Slash {
+        mining: MiningId,
+        slash_amount_in_native: u64,
+        slash_amount_multiplied_by_period: u64,
+        stake_expiration_date: Option<u64>,
+    },
+    Claim {
+        mining: MiningId,
+        reward_token_index: usize,
+    },
+    InitializeDelegate {
+        mining: MiningId,
+        commission_bps: u16,
+    },
+    SetDelegateCommission {
+        mining: MiningId,
+        commission_bps: u16,
+    },
+    InitializeDelegation {
+        delegate: MiningId,
+        mining: MiningId,
+        delegated_amount: u64,
+    },
+    SettleDelegationRewards {
+        mining: MiningId,
+        delegate: MiningId,
+    },
+}
+
+/// Action performed at the given time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedAction {
+    pub at: u64,
+    pub action: Action,
+}
+
+/// Actions performed over the simulated days, actions of the same time are performed
+/// in the order they are added
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scenario {
+    pub actions: Vec<TimedAction>,
+}
+
+itpl Scenario {
+    /// Adds the action performed at the given time
+    pub fn at(mut self, at: u64, action: Action) -> Self {
+        self.actions.push(TimedAction { at, action });
+        self
+    }
+}
+
+/// Rewards of the mining at the end of the day
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MiningRewards {
+    /// Rewards of every reward token left to claim
+    pub unclaimed_rewards: [u64; MAX_REWARD_TOKENS],
+    /// Rewards of every reward token claimed so far
+    pub claimed_rewards: [u64; MAX_REWARD_TOKENS],
+    /// Weighted stake the mining earns rewards with, the stake delegated to it included
+    pub share: u64,
+}
+
+/// Rewards of every mining at the end of the day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    /// Number of the day since the beginning of the simulation, starting from zero
+    pub day: u64,
+    /// The last second of the day the rewards are calculated at
+    pub timestamp: u64,
+    /// Rewards of every mining, ordered as the minings are
+    pub minings: Vec<MiningRewards>,
+}
+
+/// Failure of the simulation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationError {
+    /// Index of the failed action in the scenario, none if the report failed
+    pub action_index: Option<usize>,
+    pub error: ProgramError,
+}
+
+itpl Simulator {
+    /// Performs the scenario over the given number of days since the current time,
+    /// reporting the rewards of every mining at the end of every day.
+    /// Actions scheduled after the last simulated day aren't performed.
+    pub fn run(
+        &mut self,
+        scenario: &Scenario,
+        days: u64,
+    ) -> Result<Vec<DayReport>, SimulationError> {
+        let mut actions = scenario.actions.iter().enumerate().collect::<Vec<_>>();
+        actions.sort_by_key(|(_, timed_action)| timed_action.at);
+        let mut actions = actions.into_iter().peekable();
+
+        let start_ts = self.curr_ts();
+        let mut reports = Vec::with_capacity(days as usize);
+        for day in 0..days {
+            let day_ends_at = start_ts + (day + 1) * SECONDS_PER_DAY - 1;
+
+            while let Some((action_index, timed_action)) =
+                actions.next_if(|(_, timed_action)| timed_action.at <= day_ends_at)
+            {
+                self.advance_to(timed_action.at);
+                self.perform(&timed_action.action)
+                    .map_err(|error| SimulationError {
+                        action_index: Some(action_index),
+                        error,
+                    })?;
+            }
+
+            self.advance_to(day_ends_at);
+            reports.push(DayReport {
+                day,
+                timestamp: day_ends_at,
+                minings: self.minings_rewards().map_err(|error| SimulationError {
+                    action_index: None,
+                    error,
+                })?,
+            });
+        }
+
+        Ok(reports)
+    }
+
+    /// Performs the action at the current time
+    pub fn perform(&mut self, action: &Action) -> Result<(), ProgramError> {
+        match *action {
+            Action::FillVault {
+                reward_token_index,
+                rewards,
+                distribution_ends_at,
+            } => self.fill(reward_token_index, rewards, distribution_ends_at),
+            Action::DistributeRewards { keeper } => self.distribute(keeper),
+            Action::DepositMining {
+                mining,
+                amount,
+                lockup_period,
+                delegate,
+            } => self.deposit(mining, amount, lockup_period, delegate),
+            Action::WithdrawMining {
+                mining,
+                amount,
+                delegate,
+            } => self.withdraw(mining, amount, delegate),
+            Action::ExtendStake {
+                mining,
+                old_lockup_period,
+                new_lockup_period,
+                deposit_start_ts,
+                base_amount,
+                additional_amount,
+                delegate,
+            } => self.extend(
+                mining,
+                old_lockup_period,
+                new_lockup_period,
+                deposit_start_ts,
+                base_amount,
+                additional_amount,
+                delegate,
+            ),
+            Action::Slash {
+                mining,
+                slash_amount_in_native,
+                slash_amount_multiplied_by_period,
+                stake_expiration_date,
+            } => self.slash(
+                mining,
+                slash_amount_in_native,
+                slash_amount_multiplied_by_period,
+                stake_expiration_date,
+            ),
+            Action::Claim {
+                mining,
+                reward_token_index,
+            } => self.claim(mining, reward_token_index).map(|_| ()),
+            Action::InitializeDelegate {
+                mining,
+                commission_bps,
+            } => self.initialize_delegate(mining, commission_bps),
+            Action::SetDelegateCommission {
+                mining,
+                commission_bps,
+            } => self.set_delegate_commission(mining, commission_bps),
+            Action::InitializeDelegation {
+                delegate,
+                mining,
+                delegated_amount,
+            } => self.initialize_delegation(delegate, mining, delegated_amount),
+            Action::SettleDelegationRewards { mining, delegate } => {
+                self.settle_delegation_rewards(mining, delegate)
+            }
+        }
+    }
+
+    fn minings_rewards(&self) -> Result<Vec<MiningRewards>, ProgramError> {
+        (0..self.minings_count())
+            .map(|mining| {
+                let pending_rewards = self.pending_rewards(mining)?;
+                Ok(MiningRewards {
+                    unclaimed_rewards: pending_rewards.unclaimed_rewards,
+                    claimed_rewards: self.claimed_rewards(mining)?,
+                    share: pending_rewards.share,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,612 @@
+use std::collections::BTreeMap;
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use trz_rewards::{
+    operations,
+    state::{Delegate, Delegation, DelegationAccounts},
+};
+use trz_rewards_core::{
+    state::{
+        CrankBounty, DistributionMode, EmissionCurve, LockupTier, Mining, PendingRewards,
+        RewardPool, WrappedImmutableMining, WrappedImmutableRewardPool, WrappedMining,
+        WrappedRewardPool, MAX_REWARD_TOKENS,
+    },
+    utils::{LockupPeriod, SafeArithmeticOperations},
+    AlignedAccountData, TrzRewardsError,
+};
+
+/// Index of the mining in the simulator, in the order the minings are added
+pub type MiningId = usize;
+
+/// Configuration of the simulated reward pool, the way `InitializePool` takes it
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    /// Lockup schedule, the default one if empty
+    pub lockup_tiers: Vec<LockupTier>,
+    /// Length of the epoch, a day if zero
+    pub epoch_length_secs: u64,
+    /// Offset of the epoch boundaries
+    pub epoch_offset_secs: u64,
+    pub distribution_mode: DistributionMode,
+    pub emission_curve: EmissionCurve,
+    /// Bounty paid to the keepers distributing rewards with their minings, see `ConfigureDistributionCrank`
+    pub crank_bounty: CrankBounty,
+    /// Number of the reward tokens the pool distributes
+    pub reward_tokens: usize,
+}
+
+itpl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            lockup_tiers: vec![],
+            epoch_length_secs: 0,
+            epoch_offset_secs: 0,
+            distribution_mode: DistributionMode::Epochs,
+            emission_curve: EmissionCurve::Even,
+            crank_bounty: CrankBounty::None,
+            reward_tokens: 1,
+        }
+    }
+}
+
+/// Runs the on-chain reward pool and mining logic over in-memory accounts.
+/// Every operation is performed at the simulator's current time.
+pub struct Simulator {
+    curr_ts: u64,
+    reward_pool: AlignedAccountData,
+    minings: Vec<SimulatedMining>,
+    /// Delegations of the minings to the delegates charging a commission,
+    /// by the delegate mining and the delegating one
+    delegations: BTreeMap<(MiningId, MiningId), SimulatedAccount>,
+}
+
+itpl Simulator {
+    /// Creates the simulator with the initialized pool at the given time
+    pub fn new(config: PoolConfig, curr_ts: u64) -> Result<Self, ProgramError> {
+        let mut pool = RewardPool::initialize(
+            0,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            &config.lockup_tiers,
+            config.epoch_length_secs,
+            config.epoch_offset_secs,
+            config.distribution_mode,
+        )?;
+        for _ in 1..config.reward_tokens {
+            pool.add_reward_token(Pubkey::new_unique(), 0)?;
+        }
+        pool.configure_emission_curve(config.emission_curve)?;
+        pool.configure_crank(false, config.crank_bounty)?;
+
+        let mut reward_pool = AlignedAccountData::zeroed(WrappedRewardPool::LEN);
+        let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool.bytes_mut())?;
+        *wrapped_reward_pool.pool = pool;
+        wrapped_reward_pool.weighted_stake_diffs.initialize();
+        wrapped_reward_pool.cumulative_index.initialize();
+
+        Ok(Self {
+            curr_ts,
+            reward_pool,
+            minings: vec![],
+            delegations: BTreeMap::new(),
+        })
+    }
+
+    /// Current time of the simulation
+    pub fn curr_ts(&self) -> u64 {
+        self.curr_ts
+    }
+
+    /// Moves the time of the simulation forward
+    pub fn advance_to(&mut self, ts: u64) {
+        self.curr_ts = self.curr_ts.max(ts);
+    }
+
+    /// Adds a new mining with the default modifiers tree capacity
+    pub fn add_mining(&mut self) -> Result<MiningId, ProgramError> {
+        let reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let mut mining = Mining::initialize(Pubkey::default(), Pubkey::new_unique(), 0);
+        reward_pool.pool.open_mining(&mut mining)?;
+
+        let mut account = SimulatedAccount::new(WrappedMining::LEN);
+        WrappedMining::initialize(account.data.bytes_mut(), mining)?;
+        self.minings.push(SimulatedMining {
+            account,
+            delegate: None,
+            claimed_rewards: [0; MAX_REWARD_TOKENS],
+        });
+
+        Ok(self.minings.len() - 1)
+    }
+
+    /// Number of the added minings
+    pub fn minings_count(&self) -> usize {
+        self.minings.len()
+    }
+
+    /// Adds the rewards of the reward token to distribute till the given date, see `FillVault`
+    pub fn fill(
+        &mut self,
+        reward_token_index: usize,
+        rewards: u64,
+        distribution_ends_at: u64,
+    ) -> ProgramResult {
+        if rewards == 0 {
+            return Err(TrzRewardsError::RewardsMustBeGreaterThanZero.into());
+        }
+
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        operations::fill_vault(
+            &mut reward_pool,
+            reward_token_index,
+            rewards,
+            distribution_ends_at,
+            self.curr_ts,
+        )
+    }
+
+    /// Distributes the rewards of the epochs passed, see `DistributeRewards`.
+    /// The crank bounty is credited to the keeper's mining if it's given.
+    pub fn distribute(&mut self, keeper: Option<MiningId>) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let keeper_mining = match keeper {
+            Some(keeper) => {
+                let keeper = get_mining_mut(&mut self.minings, keeper)?;
+                Some(WrappedMining::from_bytes_mut(keeper.account.data.bytes_mut())?.mining)
+            }
+            None => None,
+        };
+
+        operations::distribute_rewards(&mut reward_pool, keeper_mining, self.curr_ts)?;
+
+        Ok(())
+    }
+
+    /// Deposits the stake, see `DepositMining`
+    pub fn deposit(
+        &mut self,
+        mining: MiningId,
+        amount: u64,
+        lockup_period: LockupPeriod,
+        delegate: Option<MiningId>,
+    ) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let (mining, delegate) =
+            mining_with_delegate(&mut self.minings, &mut self.delegations, mining, delegate)?;
+        let mut wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        operations::deposit_mining(
+            &mut reward_pool,
+            &mut wrapped_mining,
+            amount,
+            lockup_period,
+            delegate.as_ref().map(|delegate| &delegate.mining),
+            delegate
+                .as_ref()
+                .and_then(DelegateAccountInfos::delegation_accounts)
+                .as_ref(),
+            self.curr_ts,
+        )
+    }
+
+    /// Withdraws the stake, see `WithdrawMining`
+    pub fn withdraw(
+        &mut self,
+        mining: MiningId,
+        amount: u64,
+        delegate: Option<MiningId>,
+    ) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let (mining, delegate) =
+            mining_with_delegate(&mut self.minings, &mut self.delegations, mining, delegate)?;
+        let mut wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        operations::withdraw_mining(
+            &mut reward_pool,
+            &mut wrapped_mining,
+            amount,
+            delegate.as_ref().map(|delegate| &delegate.mining),
+            delegate
+                .as_ref()
+                .and_then(DelegateAccountInfos::delegation_accounts)
+                .as_ref(),
+            self.curr_ts,
+        )
+    }
+
+    /// Restakes the deposit for the new lockup period, see `ExtendStake`
+    #[allow(clippy::too_many_arguments)]
+    pub fn extend(
+        &mut self,
+        mining: MiningId,
+        old_lockup_period: LockupPeriod,
+        new_lockup_period: LockupPeriod,
+        deposit_start_ts: u64,
+        base_amount: u64,
+        additional_amount: u64,
+        delegate: Option<MiningId>,
+    ) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let (mining, delegate) =
+            mining_with_delegate(&mut self.minings, &mut self.delegations, mining, delegate)?;
+        let mut wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        operations::extend_stake(
+            &mut reward_pool,
+            &mut wrapped_mining,
+            old_lockup_period,
+            new_lockup_period,
+            deposit_start_ts,
+            base_amount,
+            additional_amount,
+            delegate.as_ref().map(|delegate| &delegate.mining),
+            delegate
+                .as_ref()
+                .and_then(DelegateAccountInfos::delegation_accounts)
+                .as_ref(),
+            self.curr_ts,
+        )
+    }
+
+    /// Slashes the stake, see `Slash`
+    pub fn slash(
+        &mut self,
+        mining: MiningId,
+        slash_amount_in_native: u64,
+        slash_amount_multiplied_by_period: u64,
+        stake_expiration_date: Option<u64>,
+    ) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let mining = get_mining_mut(&mut self.minings, mining)?;
+        let mut wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        reward_pool.slash(
+            &mut wrapped_mining,
+            slash_amount_in_native,
+            slash_amount_multiplied_by_period,
+            stake_expiration_date,
+            self.curr_ts,
+        )?;
+
+        Ok(())
+    }
+
+    /// Claims all the rewards of the reward token, see `Claim`. Returns the claimed amount.
+    pub fn claim(
+        &mut self,
+        mining: MiningId,
+        reward_token_index: usize,
+    ) -> Result<u64, ProgramError> {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let mining = get_mining_mut(&mut self.minings, mining)?;
+        let mut wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        // vestings aren't simulated, so the pools vesting the claimed rewards fail to claim
+        let amount = operations::claim(
+            &mut reward_pool,
+            &mut wrapped_mining,
+            reward_token_index,
+            None,
+            None,
+            self.curr_ts,
+        )?;
+        mining.claimed_rewards[reward_token_index] =
+            mining.claimed_rewards[reward_token_index].safe_add(amount)?;
+
+        Ok(amount)
+    }
+
+    /// Makes the mining charge its delegators the commission, see `InitializeDelegate`
+    pub fn initialize_delegate(&mut self, mining: MiningId, commission_bps: u16) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let mining = get_mining_mut(&mut self.minings, mining)?;
+        if mining.delegate.is_some() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        let wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        let mut delegate = Delegate::initialize(Pubkey::default(), mining.account.key, 0);
+        operations::initialize_delegate(
+            &mut reward_pool,
+            wrapped_mining.mining,
+            &mut delegate,
+            commission_bps,
+            self.curr_ts,
+        )?;
+
+        let mut account = SimulatedAccount::new(Delegate::LEN);
+        Delegate::save(account.data.bytes_mut(), delegate)?;
+        mining.delegate = Some(account);
+
+        Ok(())
+    }
+
+    /// Changes the commission of the delegate, see `SetDelegateCommission`
+    pub fn set_delegate_commission(
+        &mut self,
+        mining: MiningId,
+        commission_bps: u16,
+    ) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let mining = get_mining_mut(&mut self.minings, mining)?;
+        let delegate = mining
+            .delegate
+            .as_mut()
+            .ok_or(ProgramError::UninitializedAccount)?;
+        let wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        operations::set_delegate_commission(
+            &mut reward_pool,
+            wrapped_mining.mining,
+            Delegate::from_bytes_mut(delegate.data.bytes_mut())?,
+            commission_bps,
+            self.curr_ts,
+        )
+    }
+
+    /// Delegates the mining's stake to the delegate under its commission, moving the given
+    /// stake delegated before, see `InitializeDelegation`
+    pub fn initialize_delegation(
+        &mut self,
+        delegate: MiningId,
+        mining: MiningId,
+        delegated_amount: u64,
+    ) -> ProgramResult {
+        if self.delegations.contains_key(&(delegate, mining)) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let mining_key = self
+            .minings
+            .get(mining)
+            .ok_or(ProgramError::InvalidArgument)?
+            .account
+            .key;
+        let delegate_mining = get_mining_mut(&mut self.minings, delegate)?;
+        let delegate_account = delegate_mining
+            .delegate
+            .as_mut()
+            .ok_or(ProgramError::UninitializedAccount)?;
+        let mut wrapped_delegate_mining =
+            WrappedMining::from_bytes_mut(delegate_mining.account.data.bytes_mut())?;
+        let delegate_state = Delegate::from_bytes_mut(delegate_account.data.bytes_mut())?;
+
+        let mut delegation = Delegation::initialize(
+            Pubkey::default(),
+            delegate_mining.account.key,
+            mining_key,
+            delegate_state,
+            0,
+        );
+        operations::initialize_delegation(
+            &mut reward_pool,
+            &mut wrapped_delegate_mining,
+            delegate_state,
+            &mut delegation,
+            delegated_amount,
+            self.curr_ts,
+        )?;
+
+        let mut account = SimulatedAccount::new(Delegation::LEN);
+        Delegation::save(account.data.bytes_mut(), delegation)?;
+        self.delegations.insert((delegate, mining), account);
+
+        Ok(())
+    }
+
+    /// Credits the delegator's part of the delegated stake rewards, see `SettleDelegationRewards`
+    pub fn settle_delegation_rewards(
+        &mut self,
+        mining: MiningId,
+        delegate: MiningId,
+    ) -> ProgramResult {
+        let mut reward_pool = WrappedRewardPool::from_bytes_mut(self.reward_pool.bytes_mut())?;
+        let (mining, delegate) = mining_with_delegate(
+            &mut self.minings,
+            &mut self.delegations,
+            mining,
+            Some(delegate),
+        )?;
+        let mut wrapped_mining = WrappedMining::from_bytes_mut(mining.account.data.bytes_mut())?;
+
+        // the delegate doesn't charge a commission, so the rewards are never split
+        match delegate
+            .as_ref()
+            .and_then(DelegateAccountInfos::delegation_accounts)
+        {
+            Some(delegation_accounts) => operations::settle_delegation_rewards(
+                &mut reward_pool,
+                &mut wrapped_mining,
+                &delegation_accounts,
+                self.curr_ts,
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Rewards of the mining pending at the current time, see `GetPendingRewards`
+    pub fn pending_rewards(&self, mining: MiningId) -> Result<PendingRewards, ProgramError> {
+        let reward_pool = WrappedImmutableRewardPool::from_bytes(self.reward_pool.bytes())?;
+        let mining = self
+            .minings
+            .get(mining)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        let pending_rewards = WrappedImmutableMining::from_bytes(mining.account.data.bytes())?
+            .pending_rewards(&reward_pool, self.curr_ts)?;
+
+        Ok(pending_rewards)
+    }
+
+    /// Rewards of every reward token the mining has claimed so far
+    pub fn claimed_rewards(
+        &self,
+        mining: MiningId,
+    ) -> Result<[u64; MAX_REWARD_TOKENS], ProgramError> {
+        self.minings
+            .get(mining)
+            .map(|mining| mining.claimed_rewards)
+            .ok_or(ProgramError::InvalidArgument)
+    }
+
+    /// Copy of the pool state
+    pub fn pool(&self) -> Result<RewardPool, ProgramError> {
+        Ok(*WrappedImmutableRewardPool::from_bytes(self.reward_pool.bytes())?.pool)
+    }
+
+    /// Copy of the mining state
+    pub fn mining(&self, mining: MiningId) -> Result<Mining, ProgramError> {
+        let mining = self
+            .minings
+            .get(mining)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        Ok(*WrappedImmutableMining::from_bytes(mining.account.data.bytes())?.mining)
+    }
+
+    /// Copy of the delegate state, if the mining charges a commission
+    pub fn delegate(&self, mining: MiningId) -> Result<Option<Delegate>, ProgramError> {
+        let mining = self
+            .minings
+            .get(mining)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        mining
+            .delegate
+            .as_ref()
+            .map(|delegate| load::<Delegate>(&delegate.data))
+            .transpose()
+    }
+
+    /// Copy of the delegation state, if the mining has delegated under the commission
+    pub fn delegation(
+        &self,
+        delegate: MiningId,
+        mining: MiningId,
+    ) -> Result<Option<Delegation>, ProgramError> {
+        self.delegations
+            .get(&(delegate, mining))
+            .map(|delegation| load::<Delegation>(&delegation.data))
+            .transpose()
+    }
+}
+
+struct SimulatedAccount {
+    key: Pubkey,
+    lamports: u64,
+    data: AlignedAccountData,
+}
+
+itpl SimulatedAccount {
+    fn new(len: usize) -> Self {
+        Self {
+            key: Pubkey::new_unique(),
+            lamports: 0,
+            data: AlignedAccountData::zeroed(len),
+        }
+    }
+
+    fn account_info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            false,
+            true,
+            &mut self.lamports,
+            self.data.bytes_mut(),
+            &trz_rewards::ID,
+            false,
+            0,
+        )
+    }
+}
+
+struct SimulatedMining {
+    account: SimulatedAccount,
+    /// Delegate account of the mining charging its delegators a commission
+    delegate: Option<SimulatedAccount>,
+    claimed_rewards: [u64; MAX_REWARD_TOKENS],
+}
+
+/// Accounts of the delegate mining the way the processors get them
+struct DelegateAccountInfos<'a> {
+    mining: AccountInfo<'a>,
+    /// Delegate and delegation accounts, if the delegate charges a commission
+    delegation: Option<(AccountInfo<'a>, AccountInfo<'a>)>,
+}
+
+itpl<'a> DelegateAccountInfos<'a> {
+    fn delegation_accounts(&self) -> Option<DelegationAccounts<'_, 'a>> {
+        self.delegation
+            .as_ref()
+            .map(|(delegate, delegation)| DelegationAccounts {
+                delegate_mining: &self.mining,
+                delegate,
+                delegation,
+            })
+    }
+}
+
+/// Copy of the zero-copy account state
+fn load<T: bytemuck::Pod>(data: &AlignedAccountData) -> Result<T, ProgramError> {
+    bytemuck::try_from_bytes(data.bytes())
+        .copied()
+        .map_err(|_| TrzRewardsError::RetreivingZeroCopyAccountFailire.into())
+}
+
+fn get_mining_mut(
+    minings: &mut [SimulatedMining],
+    mining: MiningId,
+) -> Result<&mut SimulatedMining, ProgramError> {
+    minings.get_mut(mining).ok_or(ProgramError::InvalidArgument)
+}
+
+/// Borrows the mining along with the accounts of its delegate mining. Stake delegated
+/// to the mining itself isn't delegated at all, like on-chain. The delegation has to be
+/// initialized if the delegate charges a commission.
+fn mining_with_delegate<'a>(
+    minings: &'a mut [SimulatedMining],
+    delegations: &'a mut BTreeMap<(MiningId, MiningId), SimulatedAccount>,
+    mining: MiningId,
+    delegate: Option<MiningId>,
+) -> Result<(&'a mut SimulatedMining, Option<DelegateAccountInfos<'a>>), ProgramError> {
+    let delegate = match delegate {
+        Some(delegate) if delegate != mining => delegate,
+        _ => return Ok((get_mining_mut(minings, mining)?, None)),
+    };
+    if mining.max(delegate) >= minings.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (mining_account, delegate_mining) = if mining < delegate {
+        let (left, right) = minings.split_at_mut(delegate);
+        (&mut left[mining], &mut right[0])
+    } else {
+        let (left, right) = minings.split_at_mut(mining);
+        (&mut right[0], &mut left[delegate])
+    };
+
+    let delegation = match (
+        delegate_mining.delegate.as_mut(),
+        delegations.get_mut(&(delegate, mining)),
+    ) {
+        (Some(delegate), Some(delegation)) => {
+            Some((delegate.account_info(), delegation.account_info()))
+        }
+        (Some(_), None) => return Err(TrzRewardsError::DelegationAccountsMissing.into()),
+        (None, _) => None,
+    };
+
+    Ok((
+        mining_account,
+        Some(DelegateAccountInfos {
+            mining: delegate_mining.account.account_info(),
+            delegation,
+        }),
+    ))
+}
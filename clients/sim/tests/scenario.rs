@@ -0,0 +1,99 @@
+use trezoa_program::clock::SECONDS_PER_DAY;
+use trz_rewards_core::utils::LockupPeriod;
+use trz_rewards_sim::{Action, MiningRewards, PoolConfig, Scenario, Simulator};
+
+const START_TS: u64 = 19_700 * SECONDS_PER_DAY;
+
+fn deposit(mining: usize, amount: u64, lockup_period: LockupPeriod) -> Action {
+    Action::DepositMining {
+        mining,
+        amount,
+        lockup_period,
+        delegate: None,
+    }
+}
+
+fn fill(rewards: u64, days: u64) -> Action {
+    Action::FillVault {
+        reward_token_index: 0,
+        rewards,
+        distribution_ends_at: START_TS + days * SECONDS_PER_DAY,
+    }
+}
+
+fn rewards(unclaimed: u64, claimed: u64, share: u64) -> MiningRewards {
+    MiningRewards {
+        unclaimed_rewards: [unclaimed, 0, 0],
+        claimed_rewards: [claimed, 0, 0],
+        share,
+    }
+}
+
+#[test]
+fn matches_on_chain_distribution() {
+    let mut simulator = Simulator::new(PoolConfig::default(), START_TS).unwrap();
+    let user = simulator.add_mining().unwrap();
+
+    let scenario = Scenario::default()
+        .at(START_TS, deposit(user, 100, LockupPeriod::ThreeMonths))
+        .at(START_TS, fill(100, 100))
+        .at(START_TS, Action::DistributeRewards { keeper: None });
+    let reports = simulator.run(&scenario, 1).unwrap();
+
+    assert_eq!(reports[0].minings[0].unclaimed_rewards[0], 1);
+}
+
+#[test]
+fn reports_rewards_by_day() {
+    let mut simulator = Simulator::new(PoolConfig::default(), START_TS).unwrap();
+    let alice = simulator.add_mining().unwrap();
+    let bob = simulator.add_mining().unwrap();
+
+    let mut scenario = Scenario::default()
+        .at(START_TS, deposit(alice, 100, LockupPeriod::Flex))
+        .at(START_TS, deposit(bob, 300, LockupPeriod::Flex))
+        .at(START_TS, fill(400, 4))
+        .at(
+            START_TS + 2 * SECONDS_PER_DAY + 1,
+            Action::Claim {
+                mining: alice,
+                reward_token_index: 0,
+            },
+        );
+    for day in 0..4 {
+        scenario = scenario.at(
+            START_TS + day * SECONDS_PER_DAY,
+            Action::DistributeRewards { keeper: None },
+        );
+    }
+    let reports = simulator.run(&scenario, 4).unwrap();
+
+    let expected = [
+        [rewards(25, 0, 100), rewards(75, 0, 300)],
+        [rewards(50, 0, 100), rewards(150, 0, 300)],
+        [rewards(0, 75, 100), rewards(225, 0, 300)],
+        [rewards(25, 75, 100), rewards(300, 0, 300)],
+    ];
+    for (report, expected) in reports.iter().zip(expected) {
+        assert_eq!(
+            report.timestamp,
+            START_TS + (report.day + 1) * SECONDS_PER_DAY - 1
+        );
+        assert_eq!(report.minings, expected);
+    }
+}
+
+#[test]
+fn reports_the_failed_action() {
+    let mut simulator = Simulator::new(PoolConfig::default(), START_TS).unwrap();
+    simulator.add_mining().unwrap();
+
+    // nothing is staked, so there is nobody to distribute to
+    let scenario = Scenario::default().at(START_TS, fill(100, 10)).at(
+        START_TS + SECONDS_PER_DAY,
+        Action::DistributeRewards { keeper: None },
+    );
+    let error = simulator.run(&scenario, 2).unwrap_err();
+
+    assert_eq!(error.action_index, Some(1));
+}
@@ -29,6 +29,20 @@ kinobi.update(
 //   })
 // );
 
+// Omit the optional accounts which aren't provided, as the program expects.
+const omitted = { optionalAccountStrategy: "omitted" };
+kinobi.update(
+  new k.updateInstructionsVisitor({
+    depositMining: omitted,
+    withdrawMining: omitted,
+    claim: omitted,
+    extendStake: omitted,
+    distributeRewards: omitted,
+    changeDelegate: omitted,
+    claimAmount: omitted,
+  })
+);
+
 // Update instructions.
 // kinobi.update(
 //   new k.updateInstructionsVisitor({
@@ -0,0 +1,52 @@
+use alloc::{vec, vec::Vec};
+use core::mem;
+
+/// Account data kept in a buffer aligned the way the runtime aligns it, the zero-copy
+/// structures rely on that while RPC bytes and plain vectors are aligned arbitrarily
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AlignedAccountData {
+    words: Vec<u128>,
+    len: usize,
+}
+
+itpl AlignedAccountData {
+    /// Zeroed data of the given length, e.g. of an account about to be initialized
+    pub fn zeroed(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(mem::size_of::<u128>())],
+            len,
+        }
+    }
+
+    /// Copies the data, e.g. the one returned by RPC
+    pub fn new(data: &[u8]) -> Self {
+        let mut aligned_data = Self::zeroed(data.len());
+        aligned_data.bytes_mut().copy_from_slice(data);
+        aligned_data
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &bytemuck::cast_slice::<u128, u8>(&self.words)[..self.len]
+    }
+
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut bytemuck::cast_slice_mut::<u128, u8>(&mut self.words)[..self.len]
+    }
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keeps_the_data_aligned() {
+        let data = [1, 2, 3, 4, 5];
+        let aligned_data = AlignedAccountData::new(&data);
+
+        assert_eq!(aligned_data.bytes(), &data);
+        assert_eq!(
+            aligned_data.bytes().as_ptr() as usize % mem::align_of::<u128>(),
+            0
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Weights of the epochs the emission curves distribute rewards by. The weights are
+//! counted from the last epoch, which weighs the least, so that rewards added later
+//! follow the same curve. Every function returns the weight of the current epoch
+//! along with the sum of the weights of all the epochs left, the current one included.
+
+use crate::{math::SafeArithmeticOperations, MathError};
+
+/// Fixed point the halving curve weights are scaled by,
+/// the periods halved more times than its bits weigh nothing
+pub const HALVING_SCALE_BITS: u64 = 32;
+
+/// Every epoch weighs the same
+pub fn even_weights(epochs_left: u64) -> (u128, u128) {
+    (1, u128::from(epochs_left))
+}
+
+/// The steps of the given number of epochs weigh 1, 2, .. counting from the last one
+pub fn stepped_weights(epochs_left: u64, step_epochs: u64) -> Result<(u128, u128), MathError> {
+    let epochs_left = u128::from(epochs_left);
+    let step_epochs = u128::from(step_epochs);
+
+    let full_steps = epochs_left.safe_div(step_epochs)?;
+    let epochs_of_partial_step = epochs_left % step_epochs;
+
+    let epoch_weight = epochs_left.saturating_sub(1) / step_epochs + 1;
+    let weights_left = step_epochs
+        .safe_mul(full_steps.safe_mul(full_steps + 1)? / 2)?
+        .safe_add(epochs_of_partial_step.safe_mul(full_steps + 1)?)?;
+
+    Ok((epoch_weight, weights_left))
+}
+
+/// The weight halves every period of the given number of epochs
+pub fn halving_weights(epochs_left: u64, period_epochs: u64) -> Result<(u128, u128), MathError> {
+    let epochs_left = u128::from(epochs_left);
+    let period_epochs = u128::from(period_epochs);
+
+    // weights are relative to the current epoch's one, which is the largest
+    let halvings = epochs_left.saturating_sub(1).safe_div(period_epochs)?;
+    let current_period_epochs = epochs_left - halvings * period_epochs;
+    let scaled_halvings = halvings.min(HALVING_SCALE_BITS.into());
+
+    let epoch_weight = 1u128 << HALVING_SCALE_BITS;
+    let weights_left = current_period_epochs
+        .safe_mul(epoch_weight)?
+        .safe_add(period_epochs.safe_mul(epoch_weight - (epoch_weight >> scaled_halvings))?)?;
+
+    Ok((epoch_weight, weights_left))
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn weights_decrease_towards_the_last_epoch() {
+        assert_eq!(even_weights(4), (1, 4));
+        // 4 + 3 + 2 + 1
+        assert_eq!(stepped_weights(4, 1), Ok((4, 10)));
+        // 2 + 2 + 1 + 1
+        assert_eq!(stepped_weights(4, 2), Ok((2, 6)));
+        // 1 + 1 + 0.5 + 0.5
+        assert_eq!(
+            halving_weights(4, 2),
+            Ok((1 << HALVING_SCALE_BITS, 3 << HALVING_SCALE_BITS))
+        );
+        assert_eq!(halving_weights(4, 0), Err(MathError::Overflow));
+    }
+}
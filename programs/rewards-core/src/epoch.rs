@@ -0,0 +1,36 @@
+/// Length of the default epoch, the one of the legacy pools
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Beginning of the epoch the timestamp belongs to, epochs of the given length
+/// start at the given offset from the unix epoch. The math can't overflow,
+/// a zero length means there are no epochs, so the timestamp itself is returned.
+pub fn epoch_start(timestamp: u64, epoch_length: u64, epoch_offset_secs: u64) -> u64 {
+    if epoch_length == 0 {
+        return timestamp;
+    }
+
+    let time_since_period_start = timestamp % epoch_length;
+    let epoch_offset_secs = epoch_offset_secs % epoch_length;
+    let time_since_epoch_start = if time_since_period_start >= epoch_offset_secs {
+        time_since_period_start - epoch_offset_secs
+    } else {
+        time_since_period_start + (epoch_length - epoch_offset_secs)
+    };
+
+    timestamp.saturating_sub(time_since_epoch_start)
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn epoch_start_does_not_overflow() {
+        assert_eq!(epoch_start(100, 30, 10), 100);
+        assert_eq!(epoch_start(109, 30, 10), 100);
+        assert_eq!(epoch_start(95, 30, 10), 70);
+        assert_eq!(epoch_start(u64::MAX, u64::MAX, u64::MAX - 1), u64::MAX - 1);
+        assert_eq!(epoch_start(u64::MAX - 2, u64::MAX, u64::MAX - 1), 0);
+        assert_eq!(epoch_start(42, 0, 0), 42);
+    }
+}
@@ -0,0 +1,273 @@
+#[cfg(feature = "program")]
+use alloc::format;
+use core::fmt;
+use num_derive::FromPrimitive;
+#[cfg(feature = "program")]
+use trezoa_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+
+/// Errors of the accounting math
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MathError {
+    /// Math operation overflow, division by zero included
+    Overflow,
+    /// The result doesn't fit the type it's converted to
+    Conversion,
+}
+
+itpl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow => f.write_str("Math operation overflow"),
+            MathError::Conversion => f.write_str("Invalid math conversion between types"),
+        }
+    }
+}
+
+/// Errors that may be returned by the program.
+#[derive(Clone, Debug, Eq, FromPrimitive, PartialEq)]
+pub enum TrzRewardsError {
+    /// 0
+    /// Input account owner
+    InvalidAccountOwner,
+
+    /// 1
+    /// Math operation overflow
+    MathOverflow,
+
+    /// 2
+    /// No deposits
+    RewardsNoDeposits,
+
+    /// 3
+    /// Invalid lockup period
+    InvalidLockupPeriod,
+
+    /// 4
+    /// Invalid distribution_ends_at data
+    DistributionInThePast,
+
+    /// 5
+    /// Invalid math conversion between types
+    InvalidPrimitiveTypesConversion,
+
+    /// 6
+    /// Impossible to close accounts while it has unclaimed rewards
+    RewardsMustBeClaimed,
+
+    /// 7
+    /// No need to transfer zero amount of rewards.
+    RewardsMustBeGreaterThanZero,
+
+    /// 8
+    /// Stake from others must be zero
+    StakeFromOthersMustBeZero,
+
+    /// 9
+    /// No need to transfer zero amount of rewards.
+    NoWeightedStakeModifiersAtADate,
+
+    /// 10
+    /// To change a delegate, the new delegate must differ from the current one
+    DelegatesAreTheSame,
+
+    /// 11
+    /// Getting pointer to the data of the zero-copy account has failed
+    RetreivingZeroCopyAccountFailire,
+
+    /// 12
+    /// Account is already initialized
+    AlreadyInitialized,
+
+    /// 13
+    /// Incorrect mining address.
+    InvalidMining,
+
+    /// 14
+    /// Failed to derive PDA.
+    DerivationError,
+
+    /// 15
+    DecreaseRewardsTooBig,
+
+    /// 16
+    /// Deposits into the lockup tier are disabled
+    LockupTierIsDisabled,
+
+    /// 17
+    /// Lockup tier has wrong duration or multiplier
+    InvalidLockupTier,
+
+    /// 18
+    /// Configured lockup tiers might be enabled or disabled but never changed
+    LockupTierIsImmutable,
+
+    /// 19
+    /// Reward mint isn't distributed by the pool
+    UnknownRewardMint,
+
+    /// 20
+    /// Reward mint is distributed by the pool already
+    RewardMintAlreadyAdded,
+
+    /// 21
+    /// No empty slots for reward tokens left
+    RewardTokensLimitReached,
+
+    /// 22
+    /// Index history has no room for one more distribution
+    IndexHistoryIsFull,
+
+    /// 23
+    /// Pool's weighted stake modifiers tree has no room for a new date
+    PoolModifiersTreeIsFull,
+
+    /// 24
+    /// Mining's weighted stake modifiers tree has no room for a new date
+    /// and there is no later date the modifier might be merged into, or no room to record the merge
+    MiningModifiersTreeIsFull,
+
+    /// 25
+    /// Mining's weighted stake modifiers tree capacity isn't supported
+    InvalidModifiersTreeCapacity,
+
+    /// 26
+    /// Epoch offset must be less than the epoch length
+    InvalidEpoch,
+
+    /// 27
+    /// Crank bounty can't exceed the distributed rewards or be set for a missing reward token
+    InvalidCrankBounty,
+
+    /// 28
+    /// Authority might only be accepted by the proposed one
+    NoPendingAuthority,
+
+    /// 29
+    /// The operation is paused by the guardian or the admin
+    PoolPaused,
+
+    /// 30
+    /// Only undistributed rewards might be taken back from the vault
+    NotEnoughUndistributedRewards,
+
+    /// 31
+    /// The pool still has stakes, undistributed rewards or unclaimed minings
+    PoolIsInUse,
+
+    /// 32
+    /// Direct claims and the deposit authority rotation need the vaults
+    /// to be owned by the vault authority PDA
+    VaultAuthorityNotMigrated,
+
+    /// 33
+    /// The amount to claim exceeds the unclaimed rewards
+    ClaimAmountExceedsRewards,
+
+    /// 34
+    /// The cliff must not be longer than the whole vesting
+    InvalidVestingSchedule,
+
+    /// 35
+    /// The commission must not exceed the whole of the rewards
+    InvalidCommission,
+
+    /// 36
+    /// The delegate charges a commission, so the delegation has to be tracked
+    DelegationAccountsMissing,
+
+    /// 37
+    /// The curve's period must not be empty, rewards are streamed evenly in the streaming mode
+    InvalidEmissionCurve,
+
+    /// 38
+    NotLegacyAccount,
+
+    /// 39
+    MiningsCheckpointNotReached,
+
+    /// 40
+    /// Every tranche of the vesting locks the rewards of an earlier claim
+    VestingTranchesAreFull,
+}
+
+itpl fmt::Display for TrzRewardsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrzRewardsError::InvalidAccountOwner => f.write_str("Input account owner"),
+            TrzRewardsError::MathOverflow => f.write_str("Math operation overflow"),
+            TrzRewardsError::RewardsNoDeposits => f.write_str("Rewards: No deposits"),
+            TrzRewardsError::InvalidLockupPeriod => f.write_str("Rewards: lockup period invalid"),
+            TrzRewardsError::DistributionInThePast => f.write_str("Rewards: distribution_ends_at date is lower than current date"),
+            TrzRewardsError::InvalidPrimitiveTypesConversion => f.write_str("Rewards: distribution_ends_at date is lower than current date"),
+            TrzRewardsError::RewardsMustBeClaimed => f.write_str("Rewards: unclaimed rewards must be claimed"),
+            TrzRewardsError::RewardsMustBeGreaterThanZero => f.write_str("Rewards: rewards amount must be positive"),
+            TrzRewardsError::StakeFromOthersMustBeZero => f.write_str("Rewards: Stake from others must be zero"),
+            TrzRewardsError::NoWeightedStakeModifiersAtADate => f.write_str("No changes at the date in weighted stake modifiers while they're expected"),
+            TrzRewardsError::DelegatesAreTheSame => f.write_str("Passed delegates are the same"),
+            TrzRewardsError::RetreivingZeroCopyAccountFailire => f.write_str("Getting pointer to the data of the zero-copy account has failed"),
+            TrzRewardsError::AlreadyInitialized => f.write_str("Account is already initialized"),
+            TrzRewardsError::InvalidMining => f.write_str("Invalid mining"),
+            TrzRewardsError::DerivationError => f.write_str("Failed to derive PDA"),
+            TrzRewardsError::DecreaseRewardsTooBig => f.write_str("Rewards: Penalty is not apliable becase it's bigger than the mining's weighted stake"),
+            TrzRewardsError::LockupTierIsDisabled => f.write_str("Rewards: lockup tier is disabled"),
+            TrzRewardsError::InvalidLockupTier => f.write_str("Rewards: lockup tier is invalid"),
+            TrzRewardsError::LockupTierIsImmutable => f.write_str("Rewards: lockup tier duration and multiplier can't be changed once configured"),
+            TrzRewardsError::UnknownRewardMint => f.write_str("Rewards: the mint isn't one of the pool's reward tokens"),
+            TrzRewardsError::RewardMintAlreadyAdded => f.write_str("Rewards: the mint has already been added to the pool"),
+            TrzRewardsError::RewardTokensLimitReached => f.write_str("Rewards: the pool distributes max number of reward tokens already"),
+            TrzRewardsError::IndexHistoryIsFull => f.write_str("Rewards: index history is full, it has to be compacted"),
+            TrzRewardsError::PoolModifiersTreeIsFull => f.write_str("Rewards: pool's weighted stake modifiers tree is full"),
+            TrzRewardsError::MiningModifiersTreeIsFull => f.write_str("Rewards: mining's weighted stake modifiers tree is full"),
+            TrzRewardsError::InvalidModifiersTreeCapacity => f.write_str("Rewards: unsupported mining's weighted stake modifiers tree capacity"),
+            TrzRewardsError::InvalidEpoch => f.write_str("Rewards: invalid distribution epoch configuration"),
+            TrzRewardsError::InvalidCrankBounty => f.write_str("Rewards: invalid crank bounty"),
+            TrzRewardsError::NoPendingAuthority => f.write_str("Rewards: no such pending authority"),
+            TrzRewardsError::PoolPaused => f.write_str("Rewards: the operation is paused for the pool"),
+            TrzRewardsError::NotEnoughUndistributedRewards => f.write_str("Rewards: not enough undistributed rewards in the vault"),
+            TrzRewardsError::PoolIsInUse => f.write_str("Rewards: the pool is still in use and cannot be closed"),
+            TrzRewardsError::VaultAuthorityNotMigrated => f.write_str("Rewards: vault authority is not migrated to the PDA"),
+            TrzRewardsError::ClaimAmountExceedsRewards => f.write_str("Rewards: claim amount exceeds unclaimed rewards"),
+            TrzRewardsError::InvalidVestingSchedule => f.write_str("Rewards: invalid vesting schedule"),
+            TrzRewardsError::InvalidCommission => f.write_str("Rewards: invalid delegate commission"),
+            TrzRewardsError::DelegationAccountsMissing => f.write_str("Rewards: delegate and delegation accounts are missing"),
+            TrzRewardsError::InvalidEmissionCurve => f.write_str("Rewards: invalid emission curve"),
+            TrzRewardsError::NotLegacyAccount => f.write_str("Rewards: the account isn't stored in the legacy layout"),
+            TrzRewardsError::MiningsCheckpointNotReached => f.write_str("Rewards: not every mining has been refreshed since the date"),
+            TrzRewardsError::VestingTranchesAreFull => f.write_str("Rewards: no room in the vesting for one more claim"),
+        }
+    }
+}
+
+#[cfg(feature = "program")]
+itpl PrintProgramError for TrzRewardsError {
+    fn print<E>(&self) {
+        msg!("Error: {}", self);
+    }
+}
+
+itpl From<MathError> for TrzRewardsError {
+    fn from(e: MathError) -> Self {
+        match e {
+            MathError::Overflow => TrzRewardsError::MathOverflow,
+            MathError::Conversion => TrzRewardsError::InvalidPrimitiveTypesConversion,
+        }
+    }
+}
+
+#[cfg(feature = "program")]
+itpl From<TrzRewardsError> for ProgramError {
+    fn from(e: TrzRewardsError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+#[cfg(feature = "program")]
+itpl<T> DecodeError<T> for TrzRewardsError {
+    fn type_of() -> &'static str {
+        "TrzRewardsError"
+    }
+}
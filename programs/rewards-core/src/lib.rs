@@ -0,0 +1,29 @@
+//! Accounting core of the rewards program: the math, the pool and mining state
+//! and their accounting. The time is always passed in, so it runs the same
+//! on-chain and off-chain. The `program` feature makes the addresses and the errors
+//! the program's ones, the program and its clients build the core with it.
+
+#![no_std]
+
+extern crate alloc;
+
+mod account_data;
+mod emission;
+mod epoch;
+mod error;
+mod math;
+mod pubkey;
+pub mod state;
+pub mod utils;
+
+pub use account_data::*;
+pub use emission::*;
+pub use epoch::*;
+pub use error::*;
+pub use math::*;
+pub use pubkey::*;
+
+/// Basis points making up the whole
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+/// Precision for index calculation
+pub const PRECISION: u128 = 10_000_000_000_000_000;
@@ -0,0 +1,72 @@
+use crate::{MathError, PRECISION};
+
+/// Growth of the index the rewards distributed among the total share make
+pub fn index_growth(rewards: u64, total_share: u64) -> Result<u128, MathError> {
+    PRECISION
+        .safe_mul(u128::from(rewards))?
+        .safe_div(u128::from(total_share))
+}
+
+/// Rewards the stake has earned while the index has grown by the given amount
+pub fn stake_rewards(index_growth: u128, stake: u64) -> Result<u64, MathError> {
+    u64::try_from(
+        index_growth
+            .safe_mul(u128::from(stake))?
+            .safe_div(PRECISION)?,
+    )
+    .map_err(|_| MathError::Conversion)
+}
+
+/// Part of the amount, amount * numerator / denominator, rounded down
+pub fn mul_div(amount: u64, numerator: u128, denominator: u128) -> Result<u64, MathError> {
+    u64::try_from(
+        u128::from(amount)
+            .safe_mul(numerator)?
+            .safe_div(denominator)?,
+    )
+    .map_err(|_| MathError::Conversion)
+}
+
+pub(crate) trait SafeArithmeticOperations
+where
+    Self: core::marker::Sized,
+{
+    fn safe_add(&self, amount: Self) -> Result<Self, MathError>;
+    fn safe_mul(&self, amount: Self) -> Result<Self, MathError>;
+    fn safe_div(&self, amount: Self) -> Result<Self, MathError>;
+}
+
+itpl SafeArithmeticOperations for u128 {
+    fn safe_add(&self, amount: u128) -> Result<u128, MathError> {
+        self.checked_add(amount).ok_or(MathError::Overflow)
+    }
+
+    fn safe_mul(&self, amount: u128) -> Result<u128, MathError> {
+        self.checked_mul(amount).ok_or(MathError::Overflow)
+    }
+
+    fn safe_div(&self, amount: u128) -> Result<u128, MathError> {
+        self.checked_div(amount).ok_or(MathError::Overflow)
+    }
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stake_earns_its_part_of_the_rewards() {
+        let index_growth = index_growth(100, 400).unwrap();
+
+        assert_eq!(stake_rewards(index_growth, 100), Ok(25));
+        assert_eq!(stake_rewards(index_growth, 300), Ok(75));
+        assert_eq!(super::index_growth(100, 0), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn parts_are_rounded_down() {
+        assert_eq!(mul_div(100, 1, 3), Ok(33));
+        assert_eq!(mul_div(u64::MAX, 2, 1), Err(MathError::Conversion));
+        assert_eq!(mul_div(100, 1, 0), Err(MathError::Overflow));
+    }
+}
@@ -0,0 +1,60 @@
+//! Addresses the state refers to. They're the program's `Pubkey` with the `program` feature,
+//! otherwise the same 32 bytes, so the accounts are decoded the same way either way.
+
+#[cfg(feature = "program")]
+pub use trezoa_program::pubkey::Pubkey;
+
+#[cfg(not(feature = "program"))]
+pub use address::Pubkey;
+
+#[cfg(not(feature = "program"))]
+mod address {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use bytemuck::{Pod, Zeroable};
+
+    /// Address of an account, laid out as the program's `Pubkey` is
+    #[repr(transparent)]
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        Pod,
+        Zeroable,
+        BorshDeserialize,
+        BorshSerialize,
+    )]
+    pub struct Pubkey([u8; 32]);
+
+    itpl Pubkey {
+        pub const fn new_from_array(bytes: [u8; 32]) -> Self {
+            Self(bytes)
+        }
+
+        pub const fn to_bytes(self) -> [u8; 32] {
+            self.0
+        }
+
+        /// Unique address for the tests
+        #[cfg(test)]
+        pub fn new_unique() -> Self {
+            use core::sync::atomic::{AtomicU64, Ordering};
+
+            static UNIQUE: AtomicU64 = AtomicU64::new(1);
+            let mut bytes = [0; 32];
+            bytes[..8].copy_from_slice(&UNIQUE.fetch_add(1, Ordering::Relaxed).to_le_bytes());
+            Self(bytes)
+        }
+    }
+
+    itpl AsRef<[u8]> for Pubkey {
+        fn as_ref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+}
@@ -0,0 +1,1009 @@
+use crate::{stake_rewards, utils::SafeArithmeticOperations, Pubkey, TrzRewardsError};
+use alloc::{vec, vec::Vec};
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "program")]
+use shank::ShankAccount;
+use sokoban::{NodeAllocatorMap, RedBlackTree, ZeroCopy};
+#[cfg(feature = "program")]
+use trezoa_program::program_pack::IsInitialized;
+
+use super::{
+    find_max_value_limited_by_key, AccountType, CumulativeIndex, MiningWeightedStakeDiffs,
+    DistributionMode, RewardIndexes, RewardPool, WrappedImmutableRewardPool,
+    MAX_MERGED_WEIGHTED_STAKE_DIFFS, MAX_REWARD_TOKENS, MINING_MODIFIERS_TREE_CAPACITY,
+};
+
+pub struct WrappedMining<'a> {
+    pub mining: &'a mut Mining,
+    /// This structures stores the weighted stake modifiers on the date,
+    /// where staking ends. This modifier will be applied on the specified date to the global stake,
+    /// so that rewards distribution will change. BTreeMap<unix_timestamp, modifier diff>
+    pub weighted_stake_diffs: &'a mut MiningWeightedStakeDiffs,
+}
+pub struct WrappedImmutableMining<'a> {
+    pub mining: &'a Mining,
+    /// This structures stores the weighted stake modifiers on the date,
+    /// where staking ends. This modifier will be applied on the specified date to the global stake,
+    /// so that rewards distribution will change. BTreeMap<unix_timestamp, modifier diff>
+    pub weighted_stake_diffs: &'a MiningWeightedStakeDiffs,
+}
+
+pub const ACCOUNT_TYPE_BYTE: usize = 0;
+pub const DELEGATE_COMMISSION_BYTE: usize = 1;
+const LAYOUT_VERSION_BYTE: usize = 2;
+const CHECKPOINT_GENERATION_BYTE: usize = 3;
+
+/// Version of the mining layout the minings are created with
+pub const MINING_LAYOUT_VERSION: u8 = 1;
+
+itpl<'a> WrappedMining<'a> {
+    /// Bytes required to store the mining with the default modifiers tree capacity
+    pub const LEN: usize = core::mem::size_of::<Mining>()
+        + core::mem::size_of::<RedBlackTree<u64, u64, MINING_MODIFIERS_TREE_CAPACITY>>();
+
+    /// Bytes required to store the mining with the given modifiers tree capacity
+    pub fn len_for_capacity(modifiers_tree_capacity: u16) -> Result<usize, TrzRewardsError> {
+        let weighted_stake_diffs_len = match modifiers_tree_capacity {
+            50 => core::mem::size_of::<RedBlackTree<u64, u64, 50>>(),
+            100 => core::mem::size_of::<RedBlackTree<u64, u64, 100>>(),
+            200 => core::mem::size_of::<RedBlackTree<u64, u64, 200>>(),
+            300 => core::mem::size_of::<RedBlackTree<u64, u64, 300>>(),
+            _ => return Err(TrzRewardsError::InvalidModifiersTreeCapacity),
+        };
+
+        Ok(Mining::LEN + weighted_stake_diffs_len)
+    }
+
+    pub fn from_bytes_mut(bytes: &'a mut [u8]) -> Result<Self, TrzRewardsError> {
+        let (mining, weighted_stake_diffs) = bytes.split_at_mut(Mining::LEN);
+        let mining = Mining::load_mut_bytes(mining)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        let weighted_stake_diffs =
+            load_weighted_stake_diffs_mut(weighted_stake_diffs, mining.modifiers_tree_capacity)?;
+
+        Ok(Self {
+            mining,
+            weighted_stake_diffs,
+        })
+    }
+
+    /// Initializes the mining along with the empty modifiers tree of the capacity it's set to
+    pub fn initialize(bytes: &'a mut [u8], mining: Mining) -> Result<Self, TrzRewardsError> {
+        let (mining_bytes, weighted_stake_diffs) = bytes.split_at_mut(Mining::LEN);
+        let mining_bytes = Mining::load_mut_bytes(mining_bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        *mining_bytes = mining;
+
+        weighted_stake_diffs.fill(0);
+        initialize_weighted_stake_diffs(weighted_stake_diffs, mining.modifiers_tree_capacity)?;
+
+        Self::from_bytes_mut(bytes)
+    }
+
+    /// Converts the mining stored in the legacy layout into the current one. The bytes must
+    /// have been resized to fit the current layout with the default modifiers tree capacity
+    /// already, the legacy mining staying at their beginning. The legacy modifiers tree has
+    /// the default capacity, so it's only moved past the grown header.
+    pub fn migrate_legacy(bytes: &'a mut [u8]) -> Result<Self, TrzRewardsError> {
+        if bytes.len() != Self::LEN {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+        let legacy_mining = *LegacyMining::load_bytes(&bytes[..LegacyMining::LEN])
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        if !legacy_mining.is_initialized() {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+
+        bytes.copy_within(LegacyMining::LEN..LegacyMining::ACCOUNT_LEN, Mining::LEN);
+        *Mining::load_mut_bytes(&mut bytes[..Mining::LEN])
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)? = legacy_mining.migrate();
+
+        Self::from_bytes_mut(bytes)
+    }
+
+    /// Rebuilds the modifiers tree with the given capacity out of the modifiers
+    /// stored before. The bytes must have been resized to fit the capacity already.
+    pub fn resize(
+        bytes: &'a mut [u8],
+        modifiers_tree_capacity: u16,
+        weighted_stake_diffs: &[(u64, u64)],
+    ) -> Result<Self, TrzRewardsError> {
+        let mut mining = *Mining::load_bytes(&bytes[..Mining::LEN])
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        mining.modifiers_tree_capacity = modifiers_tree_capacity;
+
+        let wrapped_mining = Self::initialize(bytes, mining)?;
+        for (date, modifier) in weighted_stake_diffs {
+            wrapped_mining
+                .weighted_stake_diffs
+                .insert(*date, *modifier)
+                .ok_or(TrzRewardsError::MiningModifiersTreeIsFull)?;
+        }
+
+        Ok(wrapped_mining)
+    }
+
+    /// Refresh rewards. The mining gets up to date with the pool's minings checkpoint.
+    pub fn refresh_rewards(
+        &mut self,
+        pool: &mut RewardPool,
+        cumulative_index: &CumulativeIndex,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        let processed_dates = self.mining.refresh_rewards(
+            pool,
+            cumulative_index,
+            self.weighted_stake_diffs,
+            &[],
+            curr_ts,
+        )?;
+
+        for date in processed_dates {
+            self.weighted_stake_diffs.remove(&date);
+            self.mining.forget_merged_weighted_stake_diffs(date);
+        }
+        pool.check_in_mining(self.mining)?;
+
+        Ok(())
+    }
+
+    /// Decrease rewards
+    pub fn decrease_rewards(
+        &mut self,
+        mut decreased_weighted_stake_number: u64,
+    ) -> Result<(), TrzRewardsError> {
+        if decreased_weighted_stake_number == 0 {
+            return Ok(());
+        }
+
+        if decreased_weighted_stake_number > self.mining.share {
+            return Err(TrzRewardsError::DecreaseRewardsTooBig);
+        }
+
+        // apply penalty to the weighted stake
+        self.mining.share = self
+            .mining
+            .share
+            .safe_sub(decreased_weighted_stake_number)?;
+
+        // going through the weighted stake diffs backwards
+        // and decreasing the modifiers accordingly to the decreased share number.
+        // otherwise moddifier might decrease the share more then needed, even to negative value.
+        for (_, stake_diff) in self.weighted_stake_diffs.iter_mut().rev() {
+            if stake_diff >= &mut decreased_weighted_stake_number {
+                *stake_diff = stake_diff.safe_sub(decreased_weighted_stake_number)?;
+                break;
+            } else {
+                decreased_weighted_stake_number =
+                    decreased_weighted_stake_number.safe_sub(*stake_diff)?;
+                *stake_diff = 0;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "program", derive(ShankAccount))]
+pub struct Mining {
+    /// The address of corresponding Reward pool.
+    pub reward_pool: Pubkey,
+    /// Mining owner. This user corresponds to the voter_authority
+    /// on the staking contract, which means those idendities are the same.
+    pub owner: Pubkey,
+    /// That is the index that increases on each distribution, one per reward token
+    /// in the same order the reward pool stores them.
+    /// It points at the moment of time where the last reward was claimed.
+    /// Also, responsible for rewards calculations for each staker.
+    pub index_with_precision: RewardIndexes,
+    /// Weighted stake on the processed day.
+    pub share: u64,
+    /// Amount of unclaimed rewards of each reward token.
+    /// After claim the value is set to zero.
+    pub unclaimed_rewards: [u64; MAX_REWARD_TOKENS],
+    /// This field sums up each time somebody stakes to that account as a delegate.
+    pub stake_from_others: u64,
+    /// Bump of the mining account
+    pub bump: u8,
+    /// Account type - Mining. This discriminator should exist in order to prevent
+    /// shenanigans with customly modified accounts and their fields.
+    /// 0: account type
+    /// 1: whether the mining charges its delegators a commission
+    /// 2: layout version
+    /// 3: generation of the pool's minings checkpoint the mining has been refreshed for
+    /// 4: unused
+    pub data: [u8; 5],
+    /// Capacity of the weighted stake modifiers tree stored after the mining.
+    pub modifiers_tree_capacity: u16,
+    /// Weighted stake diffs merged into later modifiers because the tree was full.
+    /// They're taken off those modifiers when the stakes are extended or slashed.
+    pub merged_weighted_stake_diffs: [MergedWeightedStakeDiff; MAX_MERGED_WEIGHTED_STAKE_DIFFS],
+}
+
+/// Weighted stake diff of the stakes expiring at the date, which has been merged
+/// into the later modifier. Empty if the modifier date is zero.
+#[repr(C)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Pod, Zeroable, BorshDeserialize, BorshSerialize,
+)]
+pub struct MergedWeightedStakeDiff {
+    /// The date the stakes expire at
+    pub expires_at: u64,
+    /// The date of the modifier the diff has been merged into
+    pub merged_into: u64,
+    /// The merged weighted stake diff
+    pub diff: u64,
+}
+
+itpl ZeroCopy for Mining {}
+
+itpl Mining {
+    /// Bytes required to store the `Mining`.
+    pub const LEN: usize = core::mem::size_of::<Mining>();
+
+    /// Initialize a Reward Pool
+    pub fn initialize(reward_pool: Pubkey, owner: Pubkey, bump: u8) -> Mining {
+        let mut data = [0; 5];
+        data[ACCOUNT_TYPE_BYTE] = AccountType::Mining.into();
+        data[LAYOUT_VERSION_BYTE] = MINING_LAYOUT_VERSION;
+
+        Mining {
+            bump,
+            data,
+            reward_pool,
+            owner,
+            modifiers_tree_capacity: MINING_MODIFIERS_TREE_CAPACITY as u16,
+            ..Default::default()
+        }
+    }
+
+    pub fn account_type(&self) -> AccountType {
+        AccountType::from(self.data[ACCOUNT_TYPE_BYTE])
+    }
+
+    /// Shows whether the stake delegated to the mining has to be tracked by the delegations
+    pub fn charges_commission(&self) -> bool {
+        self.data[DELEGATE_COMMISSION_BYTE] != 0
+    }
+
+    /// Marks the mining as the delegate charging its delegators a commission
+    pub fn start_charging_commission(&mut self) {
+        self.data[DELEGATE_COMMISSION_BYTE] = 1;
+    }
+
+    /// Generation of the pool's minings checkpoint the mining has been refreshed for
+    pub fn checkpoint_generation(&self) -> u8 {
+        self.data[CHECKPOINT_GENERATION_BYTE]
+    }
+
+    pub fn set_checkpoint_generation(&mut self, generation: u8) {
+        self.data[CHECKPOINT_GENERATION_BYTE] = generation;
+    }
+
+    /// Records the weighted stake diff of the stakes expiring at the date merged into
+    /// the later modifier
+    pub fn record_merged_weighted_stake_diff(
+        &mut self,
+        expires_at: u64,
+        merged_into: u64,
+        diff: u64,
+    ) -> Result<(), TrzRewardsError> {
+        let position = self
+            .merged_weighted_stake_diffs
+            .iter()
+            .position(|merged| merged.expires_at == expires_at && merged.merged_into == merged_into)
+            .or_else(|| {
+                self.merged_weighted_stake_diffs
+                    .iter()
+                    .position(|merged| merged.merged_into == 0)
+            })
+            .ok_or(TrzRewardsError::MiningModifiersTreeIsFull)?;
+
+        let merged = &mut self.merged_weighted_stake_diffs[position];
+        merged.expires_at = expires_at;
+        merged.merged_into = merged_into;
+        merged.diff = merged.diff.safe_add(diff)?;
+
+        Ok(())
+    }
+
+    /// Takes the weighted stake diff of the stakes expiring at the date off the record
+    /// of the merged diffs. Returns the date of the modifier the diff has been merged into,
+    /// if there is one.
+    pub fn take_merged_weighted_stake_diff(
+        &mut self,
+        expires_at: u64,
+        diff: u64,
+    ) -> Result<Option<u64>, TrzRewardsError> {
+        let merged = self.merged_weighted_stake_diffs.iter_mut().find(|merged| {
+            merged.merged_into != 0 && merged.expires_at == expires_at && merged.diff >= diff
+        });
+
+        match merged {
+            None => Ok(None),
+            Some(merged) => {
+                let merged_into = merged.merged_into;
+                merged.diff = merged.diff.safe_sub(diff)?;
+                if merged.diff == 0 {
+                    *merged = MergedWeightedStakeDiff::default();
+                }
+
+                Ok(Some(merged_into))
+            }
+        }
+    }
+
+    /// Forgets the weighted stake diffs merged into the modifier applied at the date
+    pub fn forget_merged_weighted_stake_diffs(&mut self, applied_at: u64) {
+        for merged in self.merged_weighted_stake_diffs.iter_mut() {
+            if merged.merged_into == applied_at {
+                *merged = MergedWeightedStakeDiff::default();
+            }
+        }
+    }
+
+    /// Claim reward of the reward token with the given index, the given amount or all of it.
+    /// Returns the claimed amount.
+    pub fn claim(
+        &mut self,
+        reward_token_index: usize,
+        amount: Option<u64>,
+    ) -> Result<u64, TrzRewardsError> {
+        let unclaimed_rewards = self.unclaimed_rewards[reward_token_index];
+        let amount = amount.unwrap_or(unclaimed_rewards);
+        if amount > unclaimed_rewards {
+            return Err(TrzRewardsError::ClaimAmountExceedsRewards);
+        }
+
+        self.unclaimed_rewards[reward_token_index] = unclaimed_rewards - amount;
+
+        Ok(amount)
+    }
+
+    /// Credits rewards of every reward token, e.g. the crank bounty
+    pub fn add_unclaimed_rewards(
+        &mut self,
+        rewards: [u64; MAX_REWARD_TOKENS],
+    ) -> Result<(), TrzRewardsError> {
+        for (unclaimed_rewards, rewards) in self.unclaimed_rewards.iter_mut().zip(rewards) {
+            *unclaimed_rewards = unclaimed_rewards.safe_add(rewards)?;
+        }
+
+        Ok(())
+    }
+
+    /// Shows whether there are rewards of any reward token left to claim
+    pub fn has_unclaimed_rewards(&self) -> bool {
+        self.unclaimed_rewards.iter().any(|rewards| *rewards != 0)
+    }
+
+    /// Accrues rewards till the given time applying the weighted stake modifiers due by then.
+    /// The modifiers tree isn't touched, the dates of the applied modifiers are returned
+    /// instead. `streamed_indexes` are the indexes at the dates of the modifiers streamed
+    /// by a copy of the pool, which aren't stored in the index history.
+    pub fn refresh_rewards(
+        &mut self,
+        pool: &RewardPool,
+        cumulative_index: &CumulativeIndex,
+        weighted_stake_diffs: &MiningWeightedStakeDiffs,
+        streamed_indexes: &[(u64, RewardIndexes)],
+        curr_ts: u64,
+    ) -> Result<Vec<u64>, TrzRewardsError> {
+        let share = self.share.safe_add(self.stake_from_others)?;
+
+        let (share, processed_dates) = match pool.distribution_mode() {
+            DistributionMode::Epochs => {
+                let (share, processed_dates) = self.consume_old_modifiers(
+                    pool.epoch_start(curr_ts),
+                    share,
+                    |date| find_max_value_limited_by_key(cumulative_index, date),
+                    weighted_stake_diffs,
+                )?;
+                Mining::update_index(
+                    cumulative_index,
+                    curr_ts,
+                    share,
+                    &mut self.unclaimed_rewards,
+                    &mut self.index_with_precision,
+                )?;
+
+                (share, processed_dates)
+            }
+            DistributionMode::Streaming => {
+                // the pool is expected to be accrued till now, and to have the indexes
+                // stored at the exact dates of the modifiers
+                let (share, processed_dates) = self.consume_old_modifiers(
+                    curr_ts,
+                    share,
+                    |date| {
+                        cumulative_index.get(&date).copied().or_else(|| {
+                            streamed_indexes
+                                .iter()
+                                .find(|(streamed_at, _)| *streamed_at == date)
+                                .map(|(_, indexes)| *indexes)
+                        })
+                    },
+                    weighted_stake_diffs,
+                )?;
+                Mining::accrue_rewards(
+                    pool.reward_indexes(),
+                    share,
+                    &mut self.unclaimed_rewards,
+                    &mut self.index_with_precision,
+                )?;
+
+                (share, processed_dates)
+            }
+        };
+        self.share = share.safe_sub(self.stake_from_others)?;
+
+        Ok(processed_dates)
+    }
+
+    /// Consume old modifiers. Returns the share along with the dates of the consumed modifiers,
+    /// which are to be removed from the tree.
+    pub fn consume_old_modifiers(
+        &mut self,
+        beginning_of_the_epoch: u64,
+        mut total_share: u64,
+        vault_indexes_for_date: itpl Fn(u64) -> Option<RewardIndexes>,
+        weighted_stake_diffs: &MiningWeightedStakeDiffs,
+    ) -> Result<(u64, Vec<u64>), TrzRewardsError> {
+        let mut processed_dates = vec![];
+        for (date, modifier_diff) in weighted_stake_diffs.iter() {
+            if date > &beginning_of_the_epoch {
+                break;
+            }
+
+            // the history might have been compacted, so there is no index for the date,
+            // in that case no rewards are accrued for the period
+            Self::accrue_rewards(
+                vault_indexes_for_date(*date).unwrap_or(self.index_with_precision),
+                total_share,
+                &mut self.unclaimed_rewards,
+                &mut self.index_with_precision,
+            )?;
+
+            total_share = total_share.safe_sub(*modifier_diff)?;
+            processed_dates.push(*date);
+        }
+
+        Ok((total_share, processed_dates))
+    }
+
+    /// Updates index and distributes rewards
+    pub fn update_index(
+        cumulative_index: &CumulativeIndex,
+        date: u64,
+        total_share: u64,
+        unclaimed_rewards: &mut [u64; MAX_REWARD_TOKENS],
+        index_with_precision: &mut RewardIndexes,
+    ) -> Result<(), TrzRewardsError> {
+        // the history might have been compacted, so there is no index for the date,
+        // in that case no rewards are accrued for the period
+        let vault_indexes_for_date =
+            find_max_value_limited_by_key(cumulative_index, date).unwrap_or(*index_with_precision);
+
+        Self::accrue_rewards(
+            vault_indexes_for_date,
+            total_share,
+            unclaimed_rewards,
+            index_with_precision,
+        )
+    }
+
+    /// Accrues rewards of the share for the growth of the indexes
+    pub fn accrue_rewards(
+        vault_indexes_for_date: RewardIndexes,
+        total_share: u64,
+        unclaimed_rewards: &mut [u64; MAX_REWARD_TOKENS],
+        index_with_precision: &mut RewardIndexes,
+    ) -> Result<(), TrzRewardsError> {
+        for ((unclaimed_rewards, index_with_precision), vault_index_for_date) in unclaimed_rewards
+            .iter_mut()
+            .zip(index_with_precision.iter_mut())
+            .zip(vault_indexes_for_date)
+        {
+            let rewards = stake_rewards(
+                vault_index_for_date.safe_sub(*index_with_precision)?,
+                total_share,
+            )
+            .map_err(TrzRewardsError::from)?;
+
+            if rewards > 0 {
+                *unclaimed_rewards = (*unclaimed_rewards).safe_add(rewards)?;
+            }
+
+            *index_with_precision = vault_index_for_date;
+        }
+
+        Ok(())
+    }
+}
+
+itpl Mining {
+    /// Shows whether the mining has been initialized
+    pub fn is_initialized(&self) -> bool {
+        self.data[ACCOUNT_TYPE_BYTE] == <u8>::from(AccountType::Mining)
+    }
+}
+
+#[cfg(feature = "program")]
+itpl IsInitialized for Mining {
+    fn is_initialized(&self) -> bool {
+        Mining::is_initialized(self)
+    }
+}
+
+/// Mining in the layout the minings were created with before the pools got several reward
+/// tokens and the modifiers tree became resizable. Such minings have to be migrated to be used.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Pod, Zeroable)]
+pub struct LegacyMining {
+    pub reward_pool: Pubkey,
+    pub owner: Pubkey,
+    pub reward_mint: Pubkey,
+    pub index_with_precision: u128,
+    pub share: u64,
+    pub unclaimed_rewards: u64,
+    pub stake_from_others: u64,
+    pub bump: u8,
+    /// 0: account type
+    /// 1-7: unused
+    pub data: [u8; 7],
+}
+
+itpl ZeroCopy for LegacyMining {}
+
+itpl LegacyMining {
+    pub const LEN: usize = core::mem::size_of::<LegacyMining>();
+    /// Bytes the legacy mining takes along with its modifiers tree of the default capacity
+    pub const ACCOUNT_LEN: usize = LegacyMining::LEN
+        + core::mem::size_of::<RedBlackTree<u64, u64, MINING_MODIFIERS_TREE_CAPACITY>>();
+
+    pub fn from_account_data(bytes: &[u8]) -> Result<&LegacyMining, TrzRewardsError> {
+        if bytes.len() != Self::ACCOUNT_LEN {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+
+        let mining = LegacyMining::load_bytes(&bytes[..Self::LEN])
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        if !mining.is_initialized() {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+
+        Ok(mining)
+    }
+
+    /// The mining of the current layout in the same state. The legacy reward mint is
+    /// the first reward token of the migrated pool, so the index and the rewards become
+    /// the first ones.
+    fn migrate(&self) -> Mining {
+        let mut mining = Mining::initialize(self.reward_pool, self.owner, self.bump);
+        mining.index_with_precision[0] = self.index_with_precision;
+        mining.share = self.share;
+        mining.unclaimed_rewards[0] = self.unclaimed_rewards;
+        mining.stake_from_others = self.stake_from_others;
+
+        mining
+    }
+}
+
+itpl LegacyMining {
+    /// Shows whether the account holds a legacy mining
+    pub fn is_initialized(&self) -> bool {
+        self.data[ACCOUNT_TYPE_BYTE] == <u8>::from(AccountType::Mining)
+    }
+}
+
+#[cfg(feature = "program")]
+itpl IsInitialized for LegacyMining {
+    fn is_initialized(&self) -> bool {
+        LegacyMining::is_initialized(self)
+    }
+}
+
+itpl<'a> WrappedImmutableMining<'a> {
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, TrzRewardsError> {
+        let (mining, weighted_stake_diffs) = bytes.split_at(Mining::LEN);
+        let mining =
+            Mining::load_bytes(mining).ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        let weighted_stake_diffs =
+            load_weighted_stake_diffs(weighted_stake_diffs, mining.modifiers_tree_capacity)?;
+
+        Ok(Self {
+            mining,
+            weighted_stake_diffs,
+        })
+    }
+
+    /// Calculates the rewards pending at the given time the way they are refreshed on claim,
+    /// on copies of the mining and the pool, so that nothing is changed. Rewards of the stake
+    /// delegated to a delegate charging a commission are pending till they are settled.
+    pub fn pending_rewards(
+        &self,
+        reward_pool: &WrappedImmutableRewardPool,
+        curr_ts: u64,
+    ) -> Result<PendingRewards, TrzRewardsError> {
+        let mut pool = *reward_pool.pool;
+        let streamed_indexes = match pool.distribution_mode() {
+            DistributionMode::Epochs => vec![],
+            DistributionMode::Streaming => {
+                pool.stream_rewards(reward_pool.weighted_stake_diffs, curr_ts)?
+            }
+        };
+
+        let mut mining = *self.mining;
+        let processed_dates = mining.refresh_rewards(
+            &pool,
+            reward_pool.cumulative_index,
+            self.weighted_stake_diffs,
+            &streamed_indexes,
+            curr_ts,
+        )?;
+        // the processed modifiers are the earliest ones
+        let next_modifier_at = self
+            .weighted_stake_diffs
+            .iter()
+            .nth(processed_dates.len())
+            .map(|(date, _)| *date);
+
+        Ok(PendingRewards {
+            unclaimed_rewards: mining.unclaimed_rewards,
+            share: mining.share.safe_add(mining.stake_from_others)?,
+            next_modifier_at,
+        })
+    }
+}
+
+/// Rewards of the mining pending to be claimed, returned by `GetPendingRewards`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PendingRewards {
+    /// Unclaimed rewards of every reward token, ordered as the pool's reward tokens are
+    pub unclaimed_rewards: [u64; MAX_REWARD_TOKENS],
+    /// Weighted stake the mining currently earns rewards with, the stake delegated to it included
+    pub share: u64,
+    /// Date the next weighted stake modifier, e.g. of an expiring lockup, is applied at
+    pub next_modifier_at: Option<u64>,
+}
+
+fn load_weighted_stake_diffs(
+    bytes: &[u8],
+    modifiers_tree_capacity: u16,
+) -> Result<&MiningWeightedStakeDiffs, TrzRewardsError> {
+    let weighted_stake_diffs = match modifiers_tree_capacity {
+        50 => RedBlackTree::<u64, u64, 50>::load_bytes(bytes)
+            .map(|tree| tree as &MiningWeightedStakeDiffs),
+        100 => RedBlackTree::<u64, u64, 100>::load_bytes(bytes)
+            .map(|tree| tree as &MiningWeightedStakeDiffs),
+        200 => RedBlackTree::<u64, u64, 200>::load_bytes(bytes)
+            .map(|tree| tree as &MiningWeightedStakeDiffs),
+        300 => RedBlackTree::<u64, u64, 300>::load_bytes(bytes)
+            .map(|tree| tree as &MiningWeightedStakeDiffs),
+        _ => return Err(TrzRewardsError::InvalidModifiersTreeCapacity),
+    };
+
+    weighted_stake_diffs.ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)
+}
+
+fn load_weighted_stake_diffs_mut(
+    bytes: &mut [u8],
+    modifiers_tree_capacity: u16,
+) -> Result<&mut MiningWeightedStakeDiffs, TrzRewardsError> {
+    let weighted_stake_diffs = match modifiers_tree_capacity {
+        50 => RedBlackTree::<u64, u64, 50>::load_mut_bytes(bytes)
+            .map(|tree| tree as &mut MiningWeightedStakeDiffs),
+        100 => RedBlackTree::<u64, u64, 100>::load_mut_bytes(bytes)
+            .map(|tree| tree as &mut MiningWeightedStakeDiffs),
+        200 => RedBlackTree::<u64, u64, 200>::load_mut_bytes(bytes)
+            .map(|tree| tree as &mut MiningWeightedStakeDiffs),
+        300 => RedBlackTree::<u64, u64, 300>::load_mut_bytes(bytes)
+            .map(|tree| tree as &mut MiningWeightedStakeDiffs),
+        _ => return Err(TrzRewardsError::InvalidModifiersTreeCapacity),
+    };
+
+    weighted_stake_diffs.ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)
+}
+
+fn initialize_weighted_stake_diffs(
+    bytes: &mut [u8],
+    modifiers_tree_capacity: u16,
+) -> Result<(), TrzRewardsError> {
+    match modifiers_tree_capacity {
+        50 => RedBlackTree::<u64, u64, 50>::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?
+            .initialize(),
+        100 => RedBlackTree::<u64, u64, 100>::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?
+            .initialize(),
+        200 => RedBlackTree::<u64, u64, 200>::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?
+            .initialize(),
+        300 => RedBlackTree::<u64, u64, 300>::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?
+            .initialize(),
+        _ => return Err(TrzRewardsError::InvalidModifiersTreeCapacity),
+    }
+
+    Ok(())
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wrapped_immutable_mining_is_same_size_as_wrapped_mining() {
+        assert_eq!(
+            core::mem::size_of::<super::WrappedImmutableMining>(),
+            core::mem::size_of::<super::WrappedMining>()
+        );
+    }
+
+    #[test]
+    fn test_can_deserialize_wrapped_immutable_mining_from_bytes_initialized_with_wrapped_mining() {
+        let mut bytes = vec![0; super::WrappedMining::LEN];
+        let wrapped_mining = super::WrappedMining::from_bytes_mut(&mut bytes).unwrap();
+        let reward_pool = Pubkey::new_unique();
+        let mining_owner = Pubkey::new_unique();
+        let index_with_precision = [1234, 0, 4321];
+        let share = 23456;
+        let unclaimed_rewards = [34567, 0, 76543];
+        let stake_from_others = 45678;
+        let bump = 1;
+        wrapped_mining.mining.reward_pool = reward_pool;
+        wrapped_mining.mining.owner = mining_owner;
+        wrapped_mining.mining.index_with_precision = index_with_precision;
+        wrapped_mining.mining.share = share;
+        wrapped_mining.mining.unclaimed_rewards = unclaimed_rewards;
+        wrapped_mining.mining.stake_from_others = stake_from_others;
+        wrapped_mining.mining.bump = bump;
+        let wrapped_immutable_mining = super::WrappedImmutableMining::from_bytes(&bytes).unwrap();
+        assert_eq!(wrapped_immutable_mining.mining.reward_pool, reward_pool);
+        assert_eq!(wrapped_immutable_mining.mining.owner, mining_owner);
+        assert_eq!(
+            wrapped_immutable_mining.mining.index_with_precision,
+            index_with_precision
+        );
+        assert_eq!(wrapped_immutable_mining.mining.share, share);
+        assert_eq!(
+            wrapped_immutable_mining.mining.unclaimed_rewards,
+            unclaimed_rewards
+        );
+        assert_eq!(
+            wrapped_immutable_mining.mining.stake_from_others,
+            stake_from_others
+        );
+        assert_eq!(wrapped_immutable_mining.mining.bump, bump);
+    }
+
+    #[test]
+    fn claim_leaves_the_rest_of_rewards_accrued() {
+        let mut mining = super::Mining {
+            unclaimed_rewards: [100, 0, 50],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            mining.claim(0, Some(101)),
+            Err(TrzRewardsError::ClaimAmountExceedsRewards)
+        );
+        assert_eq!(mining.claim(0, Some(30)), Ok(30));
+        assert_eq!(mining.unclaimed_rewards, [70, 0, 50]);
+        assert_eq!(mining.claim(0, None), Ok(70));
+        assert_eq!(mining.unclaimed_rewards, [0, 0, 50]);
+    }
+
+    #[test]
+    fn legacy_mining_takes_as_many_bytes_as_the_live_minings() {
+        assert_eq!(LegacyMining::ACCOUNT_LEN, 1776);
+    }
+
+    #[test]
+    fn migrated_mining_keeps_weighted_stake_diffs() {
+        let mut bytes = vec![0; LegacyMining::ACCOUNT_LEN];
+        let (legacy_mining, weighted_stake_diffs) = bytes.split_at_mut(LegacyMining::LEN);
+        let legacy_mining = LegacyMining::load_mut_bytes(legacy_mining).unwrap();
+        legacy_mining.owner = Pubkey::new_unique();
+        legacy_mining.index_with_precision = 7;
+        legacy_mining.share = 300;
+        legacy_mining.unclaimed_rewards = 40;
+        legacy_mining.stake_from_others = 20;
+        legacy_mining.data[ACCOUNT_TYPE_BYTE] = AccountType::Mining.into();
+        let owner = legacy_mining.owner;
+
+        let legacy_weighted_stake_diffs =
+            RedBlackTree::<u64, u64, MINING_MODIFIERS_TREE_CAPACITY>::load_mut_bytes(
+                weighted_stake_diffs,
+            )
+            .unwrap();
+        legacy_weighted_stake_diffs.insert(180, 700);
+        legacy_weighted_stake_diffs.insert(365, 1500);
+
+        bytes.resize(super::WrappedMining::LEN, 0);
+        let wrapped_mining = super::WrappedMining::migrate_legacy(&mut bytes).unwrap();
+        assert!(wrapped_mining.mining.is_initialized());
+        assert_eq!(wrapped_mining.mining.owner, owner);
+        assert_eq!(wrapped_mining.mining.index_with_precision, [7, 0, 0]);
+        assert_eq!(wrapped_mining.mining.share, 300);
+        assert_eq!(wrapped_mining.mining.unclaimed_rewards, [40, 0, 0]);
+        assert_eq!(wrapped_mining.mining.stake_from_others, 20);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.len(), 2);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&180), Some(&700));
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&365), Some(&1500));
+
+        assert!(LegacyMining::from_account_data(&bytes).is_err());
+    }
+
+    #[test]
+    fn resized_mining_keeps_weighted_stake_diffs() {
+        let mut bytes = vec![0; super::WrappedMining::LEN];
+        let mining = super::Mining::initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        let wrapped_mining = super::WrappedMining::initialize(&mut bytes, mining).unwrap();
+        wrapped_mining.weighted_stake_diffs.insert(180, 700);
+        wrapped_mining.weighted_stake_diffs.insert(365, 1500);
+        let weighted_stake_diffs: Vec<(u64, u64)> = wrapped_mining
+            .weighted_stake_diffs
+            .iter()
+            .map(|(date, modifier)| (*date, *modifier))
+            .collect();
+
+        bytes.resize(super::WrappedMining::len_for_capacity(200).unwrap(), 0);
+        let wrapped_mining =
+            super::WrappedMining::resize(&mut bytes, 200, &weighted_stake_diffs).unwrap();
+        assert_eq!(wrapped_mining.mining.modifiers_tree_capacity, 200);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.capacity(), 200);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&180), Some(&700));
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&365), Some(&1500));
+
+        let wrapped_immutable_mining = super::WrappedImmutableMining::from_bytes(&bytes).unwrap();
+        assert_eq!(wrapped_immutable_mining.weighted_stake_diffs.len(), 2);
+        assert_eq!(
+            super::WrappedMining::len_for_capacity(150),
+            Err(TrzRewardsError::InvalidModifiersTreeCapacity)
+        );
+    }
+
+    #[test]
+    fn no_rewards_accrued_for_compacted_index_history() {
+        let mut mining = super::Mining {
+            index_with_precision: [30, 0, 0],
+            ..Default::default()
+        };
+        let mut cumulative_index = super::CumulativeIndex::new();
+        cumulative_index.insert(10, [50, 0, 0]);
+
+        super::Mining::update_index(
+            &cumulative_index,
+            5,
+            100,
+            &mut mining.unclaimed_rewards,
+            &mut mining.index_with_precision,
+        )
+        .unwrap();
+
+        assert_eq!(mining.index_with_precision, [30, 0, 0]);
+        assert_eq!(mining.unclaimed_rewards, [0, 0, 0]);
+    }
+
+    #[test]
+    fn slighly_decrease_rewards() {
+        let mut wrapped_mining = super::WrappedMining {
+            mining: &mut super::Mining {
+                share: 3600,
+                ..Default::default()
+            },
+            weighted_stake_diffs:
+                &mut RedBlackTree::<u64, u64, MINING_MODIFIERS_TREE_CAPACITY>::new(),
+        };
+        // three stakes:
+        // - 500 x4 (six months)
+        // - 700 x2 (three months)
+        // - 200 x1 (flex)
+        wrapped_mining.weighted_stake_diffs.insert(365, 1500);
+        wrapped_mining.weighted_stake_diffs.insert(180, 700);
+
+        wrapped_mining.decrease_rewards(300).unwrap();
+
+        assert_eq!(wrapped_mining.mining.share, 3300);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&365), Some(&1200));
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&180), Some(&700));
+    }
+
+    #[test]
+    fn moderate_decrease_rewards() {
+        let mut wrapped_mining = super::WrappedMining {
+            mining: &mut super::Mining {
+                share: 3600,
+                ..Default::default()
+            },
+            weighted_stake_diffs:
+                &mut RedBlackTree::<u64, u64, MINING_MODIFIERS_TREE_CAPACITY>::new(),
+        };
+        // three stakes:
+        // - 500 x4 (six months)
+        // - 700 x2 (three months)
+        // - 200 x1 (flex)
+        wrapped_mining.weighted_stake_diffs.insert(365, 1500);
+        wrapped_mining.weighted_stake_diffs.insert(180, 700);
+
+        wrapped_mining.decrease_rewards(2200).unwrap();
+
+        assert_eq!(wrapped_mining.mining.share, 1400);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&365), Some(&0));
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&180), Some(&0));
+    }
+
+    #[test]
+    fn severe_decrease_rewards() {
+        let mut wrapped_mining = super::WrappedMining {
+            mining: &mut super::Mining {
+                share: 3600,
+                ..Default::default()
+            },
+            weighted_stake_diffs:
+                &mut RedBlackTree::<u64, u64, MINING_MODIFIERS_TREE_CAPACITY>::new(),
+        };
+        // three stakes:
+        // - 500 x4 (six months)
+        // - 700 x2 (three months)
+        // - 200 x1 (flex)
+        wrapped_mining.weighted_stake_diffs.insert(365, 1500);
+        wrapped_mining.weighted_stake_diffs.insert(180, 700);
+
+        wrapped_mining.decrease_rewards(3500).unwrap();
+
+        assert_eq!(wrapped_mining.mining.share, 100);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&365), Some(&0));
+        assert_eq!(wrapped_mining.weighted_stake_diffs.get(&180), Some(&0));
+    }
+
+    #[test]
+    fn pending_rewards_leave_mining_untouched() {
+        use crate::{
+            state::{WrappedImmutableRewardPool, WrappedRewardPool, PRECISION},
+            SECONDS_PER_DAY,
+        };
+
+        let modifier_at = 10 * SECONDS_PER_DAY;
+        let next_modifier_at = 15 * SECONDS_PER_DAY;
+
+        let mut pool_bytes = vec![0; WrappedRewardPool::LEN];
+        let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(&mut pool_bytes).unwrap();
+        wrapped_reward_pool
+            .cumulative_index
+            .insert(modifier_at, [2 * PRECISION, 0, 0]);
+
+        let mut mining_bytes = vec![0; super::WrappedMining::LEN];
+        let mining = super::Mining::initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        let wrapped_mining = super::WrappedMining::initialize(&mut mining_bytes, mining).unwrap();
+        wrapped_mining.mining.share = 100;
+        wrapped_mining.weighted_stake_diffs.insert(modifier_at, 40);
+        wrapped_mining
+            .weighted_stake_diffs
+            .insert(next_modifier_at, 60);
+
+        let reward_pool = WrappedImmutableRewardPool::from_bytes(&pool_bytes).unwrap();
+        let wrapped_mining = super::WrappedImmutableMining::from_bytes(&mining_bytes).unwrap();
+        let pending_rewards = wrapped_mining
+            .pending_rewards(&reward_pool, modifier_at + 3600)
+            .unwrap();
+
+        assert_eq!(
+            pending_rewards,
+            PendingRewards {
+                unclaimed_rewards: [200, 0, 0],
+                share: 60,
+                next_modifier_at: Some(next_modifier_at),
+            }
+        );
+        assert_eq!(wrapped_mining.mining.share, 100);
+        assert_eq!(wrapped_mining.mining.unclaimed_rewards, [0, 0, 0]);
+        assert_eq!(wrapped_mining.weighted_stake_diffs.len(), 2);
+    }
+}
@@ -0,0 +1,138 @@
+//! State of the reward pools and the minings, along with their accounting
+
+mod mining;
+mod reward_pool;
+
+pub use crate::{MAX_BASIS_POINTS, PRECISION};
+use crate::SECONDS_PER_DAY;
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use bytemuck::Pod;
+use core::fmt::Debug;
+pub use mining::*;
+pub use reward_pool::*;
+use sokoban::{NodeAllocatorMap, RedBlackTree, SENTINEL};
+
+pub const MINING_MODIFIERS_TREE_CAPACITY: usize = 50;
+/// Capacities mining's weighted stake modifiers tree might have, the first one is the default
+pub const MINING_MODIFIERS_TREE_CAPACITIES: [u16; 4] = [50, 100, 200, 300];
+pub const POOL_MODIFIERS_TREE_CAPACITY: usize = 365;
+pub const INDEX_HISTORY_MAX_SIZE: usize = 1095;
+/// Max number of index history records a single compaction might remove
+pub const MAX_INDEX_RECORDS_TO_COMPACT: usize = 200;
+/// Max number of epochs a single distribution might process catching up the missed ones
+pub const MAX_EPOCHS_TO_DISTRIBUTE: u64 = 30;
+/// Period minings have to claim their rewards after the pool stops distributing them
+pub const CLAIM_PERIOD_SECS: u64 = 365 * SECONDS_PER_DAY;
+/// Max number of lockup tiers a reward pool might be configured with
+pub const MAX_LOCKUP_TIERS: usize = 8;
+/// Number of the pool's authorities which might be rotated
+pub const AUTHORITY_ROLES: usize = 5;
+/// Max number of reward tokens a reward pool might distribute
+pub const MAX_REWARD_TOKENS: usize = 3;
+/// Max number of the weighted stake diffs a mining might merge into later modifiers
+pub const MAX_MERGED_WEIGHTED_STAKE_DIFFS: usize = 4;
+/// Max number of the claims a vesting might keep locked on their own schedules at once
+pub const MAX_VESTING_TRANCHES: usize = 8;
+
+/// Indexes with precision of every reward token, ordered as the pool's reward tokens are
+pub type RewardIndexes = [u128; MAX_REWARD_TOKENS];
+pub type CumulativeIndex = RedBlackTree<u64, RewardIndexes, INDEX_HISTORY_MAX_SIZE>;
+/// Index history of the pools stored in the legacy layout, with the index of the only reward token
+pub type LegacyCumulativeIndex = RedBlackTree<u64, u128, INDEX_HISTORY_MAX_SIZE>;
+pub type PoolWeightedStakeDiffs = RedBlackTree<u64, u64, POOL_MODIFIERS_TREE_CAPACITY>;
+/// Mining's tree capacity is stored in the account, so the tree is only known by its interface
+pub type MiningWeightedStakeDiffs = dyn NodeAllocatorMap<u64, u64>;
+
+/// Enum representing the account type managed by the program
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, BorshSchema, Default)]
+pub enum AccountType {
+    /// If the account has not been initialized, the enum will be 0
+    #[default]
+    Uninitialized,
+    /// Reward pool
+    RewardPool,
+    /// Mining Account
+    Mining,
+    /// Vesting of the rewards claimed by a mining
+    Vesting,
+    /// Stake delegated to a mining charging a commission
+    Delegate,
+    /// Stake a mining has delegated to a delegate charging a commission
+    Delegation,
+}
+
+itpl From<u8> for AccountType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => AccountType::Uninitialized,
+            1 => AccountType::RewardPool,
+            2 => AccountType::Mining,
+            3 => AccountType::Vesting,
+            4 => AccountType::Delegate,
+            5 => AccountType::Delegation,
+            _ => panic!("invalid AccountType value: {value}"),
+        }
+    }
+}
+
+itpl From<AccountType> for u8 {
+    fn from(value: AccountType) -> Self {
+        match value {
+            AccountType::Uninitialized => 0,
+            AccountType::RewardPool => 1,
+            AccountType::Mining => 2,
+            AccountType::Vesting => 3,
+            AccountType::Delegate => 4,
+            AccountType::Delegation => 5,
+        }
+    }
+}
+
+/// Value of the latest key preceding the given one, if there is any
+pub fn find_max_value_limited_by_key<
+    K: Ord + Default + Pod + Debug,
+    V: Default + Pod,
+    const CAP: usize,
+>(
+    tree: &RedBlackTree<K, V, CAP>,
+    key: K,
+) -> Option<V> {
+    let mut current_id = tree.root; // Start at the root node
+    let mut result = None;
+
+    while current_id != SENTINEL {
+        let node = tree.get_node(current_id); // Get the current node
+        if node.key < key {
+            // Update result to the current key if it's a valid candidate
+            result = Some(node.value);
+            // Move to the right subtree to potentially find a larger valid key
+            current_id = tree.get_right(current_id);
+        } else {
+            // Move to the left subtree to find a smaller key
+            current_id = tree.get_left(current_id);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use sokoban::NodeAllocatorMap;
+
+    use super::*;
+
+    #[test]
+    fn test_find_max_value_limited_by_key() {
+        let mut tree = RedBlackTree::<u64, u64, 300>::new();
+        tree.insert(1, 10);
+        tree.insert(2, 20);
+        tree.insert(3, 30);
+        tree.insert(4, 40);
+        tree.insert(5, 50);
+
+        assert_eq!(find_max_value_limited_by_key(&tree, 3).unwrap(), 20);
+        assert_eq!(find_max_value_limited_by_key(&tree, 6).unwrap(), 50);
+        assert_eq!(find_max_value_limited_by_key(&tree, 0), None);
+    }
+}
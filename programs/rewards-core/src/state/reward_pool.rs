@@ -0,0 +1,2711 @@
+use crate::{
+    epoch_start, even_weights, halving_weights, index_growth, mul_div,
+    state::{AccountType, ACCOUNT_TYPE_BYTE},
+    stepped_weights,
+    utils::{LockupPeriod, SafeArithmeticOperations},
+    Pubkey, TrzRewardsError, SECONDS_PER_DAY,
+};
+use alloc::{vec, vec::Vec};
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "program")]
+use shank::ShankAccount;
+use sokoban::{NodeAllocatorMap, ZeroCopy};
+#[cfg(feature = "program")]
+use trezoa_program::{program_error::ProgramError, program_pack::IsInitialized};
+
+use super::{
+    CumulativeIndex, LegacyCumulativeIndex, Mining, PoolWeightedStakeDiffs, RewardIndexes,
+    WrappedMining, AUTHORITY_ROLES, CLAIM_PERIOD_SECS, INDEX_HISTORY_MAX_SIZE, MAX_BASIS_POINTS,
+    MAX_EPOCHS_TO_DISTRIBUTE, MAX_INDEX_RECORDS_TO_COMPACT, MAX_LOCKUP_TIERS, MAX_REWARD_TOKENS,
+};
+
+/// Version of the reward pool layout the pools are created with
+pub const REWARD_POOL_LAYOUT_VERSION: u8 = 1;
+const LAYOUT_VERSION_BYTE: usize = 1;
+const MIGRATED_FROM_LEGACY_BYTE: usize = 2;
+const CHECKPOINT_GENERATION_BYTE: usize = 3;
+
+/// Bytes of the sokoban tree preceding its nodes: the sequence number, the root,
+/// and the allocator's size, bump index and free list head
+const TREE_HEADER_LEN: usize = 32;
+/// Bytes of the index history node preceding its value: the node's registers
+/// (left, right, parent and color) and the date padded to the alignment of the value
+const INDEX_NODE_VALUE_OFFSET: usize = 32;
+const LEGACY_INDEX_NODE_LEN: usize = INDEX_NODE_VALUE_OFFSET + core::mem::size_of::<u128>();
+const INDEX_NODE_LEN: usize = INDEX_NODE_VALUE_OFFSET + core::mem::size_of::<RewardIndexes>();
+
+// the legacy index history is converted node by node, so the layout sokoban stores
+// the trees in must be the one the migration expects
+const _: () = assert!(
+    core::mem::size_of::<LegacyCumulativeIndex>()
+        == TREE_HEADER_LEN + INDEX_HISTORY_MAX_SIZE * LEGACY_INDEX_NODE_LEN
+);
+const _: () = assert!(
+    core::mem::size_of::<CumulativeIndex>()
+        == TREE_HEADER_LEN + INDEX_HISTORY_MAX_SIZE * INDEX_NODE_LEN
+);
+
+pub struct WrappedRewardPool<'a> {
+    pub pool: &'a mut RewardPool,
+    /// Weighted stake diffs data structure is used to represent in time
+    /// when total_share (which represents sum of all stakers' weighted stake) must change
+    /// accordingly to the changes in the staking contract.
+    pub weighted_stake_diffs: &'a mut PoolWeightedStakeDiffs,
+    /// This cumulative "index" increases on each distribution. It represents both the last time when
+    /// the distribution happened and the number which is used in distribution calculations. <Date, index>
+    pub cumulative_index: &'a mut CumulativeIndex,
+}
+
+pub struct WrappedImmutableRewardPool<'a> {
+    pub pool: &'a RewardPool,
+    /// Weighted stake diffs data structure is used to represent in time
+    /// when total_share (which represents sum of all stakers' weighted stake) must change
+    /// accordingly to the changes in the staking contract.
+    pub weighted_stake_diffs: &'a PoolWeightedStakeDiffs,
+    /// This cumulative "index" increases on each distribution. It represents both the last time when
+    /// the distribution happened and the number which is used in distribution calculations. <Date, index>
+    pub cumulative_index: &'a CumulativeIndex,
+}
+
+itpl<'a> WrappedImmutableRewardPool<'a> {
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, TrzRewardsError> {
+        let (pool, trees) = bytes.split_at(RewardPool::LEN);
+        let (weighted_stake_diffs, cumulative_index) =
+            trees.split_at(core::mem::size_of::<PoolWeightedStakeDiffs>());
+
+        let pool = RewardPool::load_bytes(pool)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        let weighted_stake_diffs = PoolWeightedStakeDiffs::load_bytes(weighted_stake_diffs)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        let cumulative_index = CumulativeIndex::load_bytes(cumulative_index)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        Ok(Self {
+            pool,
+            weighted_stake_diffs,
+            cumulative_index,
+        })
+    }
+}
+
+itpl<'a> WrappedRewardPool<'a> {
+    pub const LEN: usize = RewardPool::LEN
+        + core::mem::size_of::<PoolWeightedStakeDiffs>()
+        + core::mem::size_of::<CumulativeIndex>();
+
+    pub fn from_bytes_mut(bytes: &'a mut [u8]) -> Result<Self, TrzRewardsError> {
+        let (pool, trees) = bytes.split_at_mut(RewardPool::LEN);
+        let (weighted_stake_diffs, cumulative_index) =
+            trees.split_at_mut(core::mem::size_of::<PoolWeightedStakeDiffs>());
+
+        let pool = RewardPool::load_mut_bytes(pool)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        let weighted_stake_diffs = PoolWeightedStakeDiffs::load_mut_bytes(weighted_stake_diffs)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        let cumulative_index = CumulativeIndex::load_mut_bytes(cumulative_index)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+
+        Ok(Self {
+            pool,
+            weighted_stake_diffs,
+            cumulative_index,
+        })
+    }
+
+    /// Converts the reward pool stored in the legacy layout into the current one. The bytes
+    /// must have been resized to fit the current layout already, the legacy pool staying
+    /// at their beginning. The trees only move towards the end, so they are moved in place
+    /// starting from the later one. The legacy minings weren't counted, so their number
+    /// has to be given, they're counted as open till they're closed.
+    pub fn migrate_legacy(
+        bytes: &'a mut [u8],
+        admin: Pubkey,
+        legacy_minings: u64,
+    ) -> Result<Self, TrzRewardsError> {
+        if bytes.len() != Self::LEN {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+        let legacy_pool = *LegacyRewardPool::load_bytes(&bytes[..LegacyRewardPool::LEN])
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        if !legacy_pool.is_initialized() {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+
+        let weighted_stake_diffs_len = core::mem::size_of::<PoolWeightedStakeDiffs>();
+        let legacy_weighted_stake_diffs_start = LegacyRewardPool::LEN;
+        let legacy_cumulative_index_start =
+            legacy_weighted_stake_diffs_start + weighted_stake_diffs_len;
+        let weighted_stake_diffs_start = RewardPool::LEN;
+        let cumulative_index_start = weighted_stake_diffs_start + weighted_stake_diffs_len;
+
+        migrate_legacy_index_history(bytes, legacy_cumulative_index_start, cumulative_index_start);
+        bytes.copy_within(
+            legacy_weighted_stake_diffs_start..legacy_cumulative_index_start,
+            weighted_stake_diffs_start,
+        );
+
+        let wrapped_reward_pool = Self::from_bytes_mut(bytes)?;
+        let last_distribution_at = wrapped_reward_pool
+            .cumulative_index
+            .iter()
+            .next_back()
+            .map(|(date, _)| *date)
+            .unwrap_or_default();
+        *wrapped_reward_pool.pool =
+            legacy_pool.migrate(admin, last_distribution_at, legacy_minings);
+
+        Ok(wrapped_reward_pool)
+    }
+
+    /// Consuming old total share modifiers in order to change the total share for the current date
+    pub fn consume_old_modifiers(
+        &mut self,
+        beginning_of_the_epoch: u64,
+        mut total_share: u64,
+    ) -> Result<u64, TrzRewardsError> {
+        let mut processed_dates = vec![];
+        for (date_to_process, modifier) in self.weighted_stake_diffs.iter() {
+            if date_to_process > &beginning_of_the_epoch {
+                break;
+            }
+
+            total_share = total_share.safe_sub(*modifier)?;
+            processed_dates.push(*date_to_process);
+        }
+        for date in processed_dates {
+            self.weighted_stake_diffs.remove(&date);
+        }
+        Ok(total_share)
+    }
+
+    /// recalculates the indexes of every reward token for the given rewards and total share
+    pub fn update_index(
+        cumulative_index: &mut CumulativeIndex,
+        reward_tokens: &mut [RewardToken; MAX_REWARD_TOKENS],
+        rewards: [u64; MAX_REWARD_TOKENS],
+        total_share: u64,
+        date_to_process: u64,
+    ) -> Result<(), TrzRewardsError> {
+        let mut latest_indexes = [0; MAX_REWARD_TOKENS];
+        for ((latest_index, reward_token), rewards) in latest_indexes
+            .iter_mut()
+            .zip(reward_tokens.iter_mut())
+            .zip(rewards)
+        {
+            let index = index_growth(rewards, total_share).map_err(TrzRewardsError::from)?;
+
+            reward_token.index_with_precision =
+                reward_token.index_with_precision.safe_add(index)?;
+            *latest_index = reward_token.index_with_precision;
+        }
+
+        cumulative_index
+            .insert(date_to_process, latest_indexes)
+            .ok_or(TrzRewardsError::IndexHistoryIsFull)?;
+
+        Ok(())
+    }
+
+    /// Removes index history records older than the given date. The latest of them stays
+    /// as a checkpoint, so the index is still known for every date after it.
+    /// The date can't be later than the minings checkpoint, every open mining has been
+    /// refreshed since then, so none of them needs the removed records.
+    /// Returns the number of removed records.
+    pub fn compact_index_history(&mut self, compact_before: u64) -> Result<usize, TrzRewardsError> {
+        if compact_before > self.pool.minings_checkpoint {
+            return Err(TrzRewardsError::MiningsCheckpointNotReached);
+        }
+
+        let mut dates_to_remove: Vec<u64> = self
+            .cumulative_index
+            .iter()
+            .map(|(date, _)| *date)
+            .take_while(|date| *date < compact_before)
+            .collect();
+        // the latest date is kept as a checkpoint
+        dates_to_remove.pop();
+        dates_to_remove.truncate(MAX_INDEX_RECORDS_TO_COMPACT);
+
+        for date in &dates_to_remove {
+            self.cumulative_index.remove(date);
+        }
+
+        Ok(dates_to_remove.len())
+    }
+
+    /// Streams rewards till the current moment if the pool is in the streaming mode.
+    /// The indexes at the dates of the applied weighted stake modifiers are stored
+    /// in the index history, so minings can apply theirs at the very same moments.
+    pub fn accrue_rewards(&mut self, curr_ts: u64) -> Result<(), TrzRewardsError> {
+        if self.pool.distribution_mode() != DistributionMode::Streaming {
+            return Ok(());
+        }
+
+        let checkpoints = self
+            .pool
+            .stream_rewards(self.weighted_stake_diffs, curr_ts)?;
+        for (date, indexes) in checkpoints {
+            self.weighted_stake_diffs.remove(&date);
+            if !self.cumulative_index.contains(&date) {
+                self.cumulative_index
+                    .insert(date, indexes)
+                    .ok_or(TrzRewardsError::IndexHistoryIsFull)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Distributes rewards of every reward token for every epoch since the last distributed one
+    /// till the current one, at most MAX_EPOCHS_TO_DISTRIBUTE of them per call.
+    /// The crank bounty is cut from the rewards if requested, the bounty to pay is returned
+    /// along with the distributed epochs.
+    /// Nothing is distributed and no bounty is paid if the current epoch is already distributed.
+    pub fn distribute(
+        &mut self,
+        pay_crank_bounty: bool,
+        curr_ts: u64,
+    ) -> Result<Distribution, TrzRewardsError> {
+        if self.pool.total_share == 0 {
+            return Err(TrzRewardsError::RewardsNoDeposits);
+        }
+
+        let beginning_of_the_curr_epoch = self.pool.epoch_start(curr_ts);
+        let epoch_length = self.pool.epoch_length();
+
+        let last_distribution_at = match self.pool.last_distribution_at {
+            // pools distributed before the catch-up was introduced have it in the index history
+            0 => self
+                .cumulative_index
+                .iter()
+                .next_back()
+                .map(|(date, _)| *date),
+            last_distribution_at => Some(last_distribution_at),
+        };
+        let mut beginning_of_the_epoch = match last_distribution_at {
+            Some(last_distribution_at) => last_distribution_at.safe_add(epoch_length)?,
+            None => beginning_of_the_curr_epoch,
+        };
+
+        let mut distribution = Distribution::default();
+        while beginning_of_the_epoch <= beginning_of_the_curr_epoch
+            && (distribution.epochs.len() as u64) < MAX_EPOCHS_TO_DISTRIBUTE
+        {
+            // the missed epochs are distributed with the shares in effect back then
+            self.pool.total_share =
+                self.consume_old_modifiers(beginning_of_the_epoch, self.pool.total_share)?;
+
+            // the missed epochs get the amount they would have got if distributed in time
+            let distributed_at = curr_ts.min(beginning_of_the_epoch.safe_add(epoch_length - 1)?);
+            let rewards = self.pool.rewards_to_distribute(distributed_at)?;
+            let epoch = self.distribute_epoch(beginning_of_the_epoch, rewards, pay_crank_bounty)?;
+            for (crank_bounty, epoch_crank_bounty) in distribution
+                .crank_bounties
+                .iter_mut()
+                .zip(epoch.crank_bounties)
+            {
+                *crank_bounty = crank_bounty.safe_add(epoch_crank_bounty)?;
+            }
+            distribution.epochs.push(epoch);
+
+            self.pool.last_distribution_at = beginning_of_the_epoch;
+            beginning_of_the_epoch = beginning_of_the_epoch.safe_add(epoch_length)?;
+        }
+
+        Ok(distribution)
+    }
+
+    fn distribute_epoch(
+        &mut self,
+        beginning_of_the_epoch: u64,
+        rewards: [u64; MAX_REWARD_TOKENS],
+        pay_crank_bounty: bool,
+    ) -> Result<EpochDistribution, TrzRewardsError> {
+        let crank_bounties = if pay_crank_bounty {
+            self.pool.crank_bounties(&rewards)?
+        } else {
+            [0; MAX_REWARD_TOKENS]
+        };
+        let mut stakers_rewards = rewards;
+        for (stakers_rewards, crank_bounty) in stakers_rewards.iter_mut().zip(crank_bounties) {
+            *stakers_rewards = stakers_rewards.safe_sub(crank_bounty)?;
+        }
+
+        WrappedRewardPool::update_index(
+            self.cumulative_index,
+            &mut self.pool.reward_tokens,
+            stakers_rewards,
+            self.pool.total_share,
+            beginning_of_the_epoch,
+        )?;
+
+        // the bounty is a part of the distributed rewards as well
+        for (reward_token, rewards) in self.pool.reward_tokens.iter_mut().zip(rewards) {
+            reward_token.tokens_available_for_distribution = reward_token
+                .tokens_available_for_distribution
+                .safe_sub(rewards)?;
+        }
+
+        Ok(EpochDistribution {
+            epoch_start: beginning_of_the_epoch,
+            rewards: stakers_rewards,
+            crank_bounties,
+            total_share: self.pool.total_share,
+            indexes: self.pool.reward_indexes(),
+        })
+    }
+
+    pub fn change_delegate(
+        &mut self,
+        mining: &mut WrappedMining,
+        new_delegate_mining: Option<&mut WrappedMining>,
+        old_delegate_mining: Option<&mut WrappedMining>,
+        staked_amount: u64,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        self.accrue_rewards(curr_ts)?;
+        mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+
+        if let Some(old_delegate_mining) = old_delegate_mining {
+            old_delegate_mining.mining.stake_from_others = old_delegate_mining
+                .mining
+                .stake_from_others
+                .safe_sub(staked_amount)?;
+            self.pool.total_share = self.pool.total_share.safe_sub(staked_amount)?;
+            old_delegate_mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+        }
+
+        if let Some(new_delegate_mining) = new_delegate_mining {
+            new_delegate_mining.mining.stake_from_others = new_delegate_mining
+                .mining
+                .stake_from_others
+                .safe_add(staked_amount)?;
+            self.pool.total_share = self.pool.total_share.safe_add(staked_amount)?;
+            new_delegate_mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+        }
+
+        Ok(())
+    }
+
+    /// Process deposit
+    pub fn deposit(
+        &mut self,
+        mining: &mut WrappedMining,
+        amount: u64,
+        lockup_period: LockupPeriod,
+        delegate_mining: Option<&mut WrappedMining>,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        self.accrue_rewards(curr_ts)?;
+        mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+
+        let lockup_tier = self.pool.lockup_tier(lockup_period)?;
+        if !lockup_tier.is_enabled() {
+            return Err(TrzRewardsError::LockupTierIsDisabled);
+        }
+        let base_lockup_tier = self.pool.lockup_tier(LockupPeriod::Flex)?;
+
+        // regular weighted stake which will be used in rewards distribution
+        let weighted_stake = amount.safe_mul(lockup_tier.multiplier)?;
+
+        // shows how weighted stake will change at the end of the staking period
+        // weighted_stake_diff = weighted_stake - (amount * flex_multiplier)
+        let weighted_stake_diff =
+            weighted_stake.safe_sub(amount.safe_mul(base_lockup_tier.multiplier)?)?;
+
+        self.pool.total_share = self.pool.total_share.safe_add(weighted_stake)?;
+        mining.mining.share = mining.mining.share.safe_add(weighted_stake)?;
+
+        let stake_expiration_date = self.pool.epoch_start(lockup_tier.end_timestamp(curr_ts)?);
+
+        // the mining might apply the modifier later than the stake expires if its tree is full,
+        // so the pool has to apply it at the same date
+        let stake_expiration_date = RewardPool::add_mining_weighted_stake_diff(
+            mining,
+            stake_expiration_date,
+            weighted_stake_diff,
+        )?;
+        RewardPool::add_pool_weighted_stake_diff(
+            self.weighted_stake_diffs,
+            stake_expiration_date,
+            weighted_stake_diff,
+        )?;
+
+        if let Some(delegate_mining) = delegate_mining {
+            delegate_mining.mining.stake_from_others =
+                delegate_mining.mining.stake_from_others.safe_add(amount)?;
+
+            self.pool.total_share = self.pool.total_share.safe_add(amount)?;
+            delegate_mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+        }
+
+        Ok(())
+    }
+
+    /// Process withdraw
+    pub fn withdraw(
+        &mut self,
+        mining: &mut WrappedMining,
+        amount: u64,
+        delegate_mining: Option<&mut WrappedMining>,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        self.accrue_rewards(curr_ts)?;
+        mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+
+        // the pool's modifiers aren't consumed here, the epochs missed since the last distribution
+        // are still to be distributed with the shares in effect back then
+        self.pool.total_share = self.pool.total_share.safe_sub(amount)?;
+        mining.mining.share = mining.mining.share.safe_sub(amount)?;
+
+        if let Some(delegate_mining) = delegate_mining {
+            delegate_mining.mining.stake_from_others =
+                delegate_mining.mining.stake_from_others.safe_sub(amount)?;
+
+            self.pool.total_share = self.pool.total_share.safe_sub(amount)?;
+            delegate_mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+        }
+
+        Ok(())
+    }
+
+    /// Process slash for specified number of tokens
+    pub fn slash(
+        &mut self,
+        mining: &mut WrappedMining,
+        slash_amount_in_native: u64,
+        slash_amount_multiplied_by_period: u64,
+        stake_expiration_date: Option<u64>,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        self.withdraw(mining, slash_amount_multiplied_by_period, None, curr_ts)?;
+
+        if let Some(stake_expiration_date) = stake_expiration_date {
+            let beginning_of_the_stake_expiration_date =
+                self.pool.epoch_start(stake_expiration_date);
+
+            let diff_by_expiration_date =
+                slash_amount_multiplied_by_period.safe_sub(slash_amount_in_native)?;
+
+            let modifier_date = RewardPool::subtract_mining_weighted_stake_diff(
+                mining,
+                beginning_of_the_stake_expiration_date,
+                diff_by_expiration_date,
+            )?;
+            RewardPool::subtract_pool_weighted_stake_diff(
+                self.weighted_stake_diffs,
+                modifier_date,
+                diff_by_expiration_date,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Process extend stake
+    #[allow(clippy::too_many_arguments)]
+    pub fn extend(
+        &mut self,
+        mining: &mut WrappedMining,
+        old_lockup_period: LockupPeriod,
+        new_lockup_period: LockupPeriod,
+        deposit_start_ts: u64,
+        base_amount: u64,
+        additional_amount: u64,
+        delegate_mining: Option<&mut WrappedMining>,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        self.accrue_rewards(curr_ts)?;
+        mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+
+        // the old tier is taken regardless of whether it's still enabled,
+        // since the stake has been deposited while it was
+        let old_lockup_tier = self.pool.lockup_tier(old_lockup_period)?;
+        let base_lockup_tier = self.pool.lockup_tier(LockupPeriod::Flex)?;
+
+        let deposit_old_expiration_ts = if old_lockup_period.tier_index()? == 0 {
+            0 // it's expired, so the date is in the past
+        } else {
+            self.pool
+                .epoch_start(old_lockup_tier.end_timestamp(deposit_start_ts)?)
+        };
+
+        // curr_part_of_weighted_stake_for_flex = old_base_amount * flex_multipler
+        let curr_part_of_weighted_stake_for_flex =
+            base_amount.safe_mul(base_lockup_tier.multiplier)?;
+
+        // if current date is lower than stake expiration date, we need to
+        // remove stake modifier from the date of expiration
+        if curr_ts < deposit_old_expiration_ts {
+            // current_part_of_weighted_stake = base_amount * lockup_period_multiplier
+            let curr_part_of_weighted_stake = base_amount.safe_mul(old_lockup_tier.multiplier)?;
+
+            // weighted_stake_modifier_to_remove = old_base_amount * lockup_period_multiplier - amount_times_flex
+            let weighted_stake_diff =
+                curr_part_of_weighted_stake.safe_sub(curr_part_of_weighted_stake_for_flex)?;
+
+            let modifier_date = RewardPool::subtract_mining_weighted_stake_diff(
+                mining,
+                deposit_old_expiration_ts,
+                weighted_stake_diff,
+            )?;
+            RewardPool::subtract_pool_weighted_stake_diff(
+                self.weighted_stake_diffs,
+                modifier_date,
+                weighted_stake_diff,
+            )?;
+
+            // also, we need to reduce staking power because we want to extend stake from "scratch"
+            mining.mining.share = mining.mining.share.safe_sub(curr_part_of_weighted_stake)?;
+
+            self.pool.total_share = self
+                .pool
+                .total_share
+                .safe_sub(curr_part_of_weighted_stake)?;
+        } else {
+            // otherwise, we want to substract flex multiplier, becase deposit has expired already
+            mining.mining.share = mining
+                .mining
+                .share
+                .safe_sub(curr_part_of_weighted_stake_for_flex)?;
+
+            self.pool.total_share = self
+                .pool
+                .total_share
+                .safe_sub(curr_part_of_weighted_stake_for_flex)?;
+        }
+
+        // do actions like it's a regular deposit
+        let amount_to_restake = base_amount.safe_add(additional_amount)?;
+
+        let delegate_mining = match delegate_mining {
+            Some(delegate_mining) => {
+                delegate_mining.mining.stake_from_others = delegate_mining
+                    .mining
+                    .stake_from_others
+                    .safe_sub(base_amount)?;
+                self.pool.total_share = self.pool.total_share.safe_sub(base_amount)?;
+                delegate_mining.refresh_rewards(self.pool, self.cumulative_index, curr_ts)?;
+
+                Some(delegate_mining)
+            }
+            None => None,
+        };
+
+        self.deposit(
+            mining,
+            amount_to_restake,
+            new_lockup_period,
+            delegate_mining,
+            curr_ts,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Converts the legacy index history stored at the given offset into the one of the current
+/// layout at the later offset. Every node keeps its place in the allocator, so the links
+/// between the nodes stay valid, only the values are widened: the index of the single reward
+/// token the legacy pools had becomes the first one. The nodes are moved starting from
+/// the last one, so none of them is overwritten before it's moved.
+fn migrate_legacy_index_history(bytes: &mut [u8], legacy_start: usize, start: usize) {
+    for node in (0..INDEX_HISTORY_MAX_SIZE).rev() {
+        let legacy_node = legacy_start + TREE_HEADER_LEN + node * LEGACY_INDEX_NODE_LEN;
+        let node = start + TREE_HEADER_LEN + node * INDEX_NODE_LEN;
+
+        bytes.copy_within(legacy_node..legacy_node + LEGACY_INDEX_NODE_LEN, node);
+        bytes[node + LEGACY_INDEX_NODE_LEN..node + INDEX_NODE_LEN].fill(0);
+    }
+    bytes.copy_within(legacy_start..legacy_start + TREE_HEADER_LEN, start);
+}
+
+/// Reward pool in the layout the pools were created with before the lockup schedule
+/// and the reward tokens became configurable. Such pools have to be migrated to be used.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Pod, Zeroable)]
+pub struct LegacyRewardPool {
+    pub deposit_authority: Pubkey,
+    pub distribute_authority: Pubkey,
+    pub fill_authority: Pubkey,
+    pub reward_mint: Pubkey,
+    pub index_with_precision: u128,
+    pub total_share: u64,
+    pub distribution_ends_at: u64,
+    pub tokens_available_for_distribution: u64,
+    pub token_account_bump: u8,
+    /// 1: account type
+    /// 2-7: unused
+    pub data: [u8; 7],
+}
+
+itpl ZeroCopy for LegacyRewardPool {}
+
+itpl LegacyRewardPool {
+    pub const LEN: usize = core::mem::size_of::<LegacyRewardPool>();
+    /// Bytes the legacy pool takes along with its trees
+    pub const ACCOUNT_LEN: usize = LegacyRewardPool::LEN
+        + core::mem::size_of::<PoolWeightedStakeDiffs>()
+        + core::mem::size_of::<LegacyCumulativeIndex>();
+
+    /// Loads the legacy pool from the account data. The data might have been grown
+    /// already, while the pool is being migrated, but not to the size of the current layout.
+    pub fn from_account_data(bytes: &[u8]) -> Result<&LegacyRewardPool, TrzRewardsError> {
+        if bytes.len() < Self::ACCOUNT_LEN || bytes.len() >= WrappedRewardPool::LEN {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+
+        let pool = LegacyRewardPool::load_bytes(&bytes[..Self::LEN])
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        if !pool.is_initialized() {
+            return Err(TrzRewardsError::NotLegacyAccount);
+        }
+
+        Ok(pool)
+    }
+
+    /// The pool of the current layout in the same state. The pool keeps the lockup schedule
+    /// and the daily epochs the legacy pools had, its reward mint becomes the first reward token.
+    fn migrate(&self, admin: Pubkey, last_distribution_at: u64, legacy_minings: u64) -> RewardPool {
+        let mut data = [0; 8];
+        data[ACCOUNT_TYPE_BYTE] = AccountType::RewardPool.into();
+        data[LAYOUT_VERSION_BYTE] = REWARD_POOL_LAYOUT_VERSION;
+        data[MIGRATED_FROM_LEGACY_BYTE] = 1;
+
+        let mut reward_tokens = [RewardToken::default(); MAX_REWARD_TOKENS];
+        reward_tokens[0] = RewardToken {
+            index_with_precision: self.index_with_precision,
+            distribution_ends_at: self.distribution_ends_at,
+            tokens_available_for_distribution: self.tokens_available_for_distribution,
+            ..RewardToken::new(self.reward_mint, self.token_account_bump)
+        };
+
+        RewardPool {
+            deposit_authority: self.deposit_authority,
+            distribute_authority: self.distribute_authority,
+            fill_authority: self.fill_authority,
+            reward_tokens,
+            total_share: self.total_share,
+            data,
+            admin,
+            lockup_tiers: LockupTier::default_schedule(),
+            epoch_length_secs: SECONDS_PER_DAY,
+            last_distribution_at,
+            open_minings: legacy_minings,
+            ..Default::default()
+        }
+    }
+}
+
+itpl LegacyRewardPool {
+    /// Shows whether the account holds a legacy reward pool
+    pub fn is_initialized(&self) -> bool {
+        self.data[ACCOUNT_TYPE_BYTE] == <u8>::from(AccountType::RewardPool)
+    }
+}
+
+#[cfg(feature = "program")]
+itpl IsInitialized for LegacyRewardPool {
+    fn is_initialized(&self) -> bool {
+        LegacyRewardPool::is_initialized(self)
+    }
+}
+
+/// Reward pool
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Pod, Zeroable)]
+#[cfg_attr(feature = "program", derive(ShankAccount))]
+pub struct RewardPool {
+    /// This address is the authority from the staking contract.
+    /// We want to be sure that some changes might only be done through the
+    /// staking contract. It's PDA from staking that will sign transactions.
+    pub deposit_authority: Pubkey,
+    /// This address is responsible for distributing rewards
+    pub distribute_authority: Pubkey,
+    /// The address is responsible for filling vaults with money.
+    pub fill_authority: Pubkey,
+    /// Reward tokens the pool distributes, each one with its own vault.
+    /// The first one is the token the pool has been initialized with,
+    /// the rest are empty until added by the admin.
+    pub reward_tokens: [RewardToken; MAX_REWARD_TOKENS],
+    /// The total share of the pool for the moment of the last distribution.
+    /// It's so-called "weighted_stake" which is the sum of all stakers' weighted staked.
+    /// When somebody deposits or withdraws, or thier stake is expired this value changes.
+    pub total_share: u64,
+    /// Account type - Mining. This discriminator should exist in order to prevent
+    /// shenanigans with customly modified accounts and their fields.
+    /// 1: account type
+    /// 2: layout version
+    /// 3: whether the pool has been migrated from the legacy layout
+    /// 4: generation of the pending minings checkpoint
+    /// 5-8: unused
+    pub data: [u8; 8],
+    /// The address is responsible for the pool configuration, e.g. lockup tiers.
+    pub admin: Pubkey,
+    /// Lockup tiers deposits might be made for. The first one is the base (flex) tier
+    /// every stake falls back to once its lockup expires. Pools migrated from the legacy
+    /// layout get the default schedule.
+    pub lockup_tiers: [LockupTier; MAX_LOCKUP_TIERS],
+    /// Length of the distribution epoch. Zero means a day.
+    pub epoch_length_secs: u64,
+    /// Shift of the epoch boundaries relative to the unix epoch, e.g. non-UTC midnight
+    pub epoch_offset_secs: u64,
+    /// The time rewards have been streamed till. Used in the streaming mode only.
+    pub last_accrued_at: u64,
+    /// The way rewards are distributed, see `DistributionMode`
+    pub distribution_mode: u8,
+    /// Whether anybody is allowed to distribute rewards, not only the distribute authority
+    pub permissionless_distribution: u8,
+    /// The kind of the bounty paid to the one who distributes rewards, see `CrankBounty`
+    pub crank_bounty_kind: u8,
+    /// Flags of the paused operations, see `PoolOperation`
+    pub paused_operations: u8,
+    /// Whether the vaults are owned by the vault authority PDA instead of the deposit authority
+    pub vault_authority_migrated: u8,
+    /// Bump of the vault authority PDA, set on migration
+    pub vault_authority_bump: u8,
+    /// Whether mining owners might claim without the deposit authority's signature
+    pub direct_claims: u8,
+    /// The kind of the curve rewards are emitted by, see `EmissionCurve`
+    pub emission_curve_kind: u8,
+    /// Basis points of the bounty, the fixed amounts are kept by every reward token
+    pub crank_bounty_value: u64,
+    /// Beginning of the last distributed epoch. Zero if the pool has been distributed
+    /// before it was tracked, the latest index history record is used instead then.
+    pub last_distribution_at: u64,
+    /// Authorities proposed by the admin, indexed by `AuthorityRole`.
+    /// The default pubkey means there is no pending authority for the role.
+    pub pending_authorities: [Pubkey; AUTHORITY_ROLES],
+    /// The address might pause the pool along with the admin. Default means there is none.
+    pub guardian: Pubkey,
+    /// Number of the minings that are initialized and not closed yet. Pools migrated
+    /// from the legacy layout count their legacy minings as well, migrated or not.
+    pub open_minings: u64,
+    /// Claimed rewards are locked till the cliff passes
+    pub vesting_cliff_secs: u64,
+    /// Claimed rewards vest linearly over the duration. Zero means the vesting is disabled.
+    pub vesting_duration_secs: u64,
+    /// Number of epochs of the emission curve's period, depending on its kind
+    pub emission_curve_epochs: u64,
+    /// Every open mining has been refreshed since the date, so the index history
+    /// before it isn't needed anymore and might be compacted.
+    /// Legacy minings are refreshed when they're migrated.
+    pub minings_checkpoint: u64,
+    /// The date the minings are being refreshed since to move the checkpoint to
+    pub pending_minings_checkpoint: u64,
+    /// Number of the open minings that haven't been refreshed since the pending checkpoint.
+    /// Zero means there is no pending checkpoint.
+    pub minings_behind_checkpoint: u64,
+    /// Claimed rewards of every reward token that are kept in the vaults
+    /// till they're withdrawn from the vestings
+    pub vesting_rewards: [u64; MAX_REWARD_TOKENS],
+}
+
+itpl ZeroCopy for RewardPool {}
+
+itpl RewardPool {
+    pub const LEN: usize = core::mem::size_of::<RewardPool>();
+
+    /// Init reward pool
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        token_account_bump: u8,
+        deposit_authority: Pubkey,
+        distribute_authority: Pubkey,
+        fill_authority: Pubkey,
+        reward_mint: Pubkey,
+        admin: Pubkey,
+        lockup_tiers: &[LockupTier],
+        epoch_length_secs: u64,
+        epoch_offset_secs: u64,
+        distribution_mode: DistributionMode,
+    ) -> Result<RewardPool, TrzRewardsError> {
+        let mut data = [0; 8];
+        data[ACCOUNT_TYPE_BYTE] = AccountType::RewardPool.into();
+        data[LAYOUT_VERSION_BYTE] = REWARD_POOL_LAYOUT_VERSION;
+        let mut reward_tokens = [RewardToken::default(); MAX_REWARD_TOKENS];
+        reward_tokens[0] = RewardToken::new(reward_mint, token_account_bump);
+        let mut pool = RewardPool {
+            data,
+            deposit_authority,
+            distribute_authority,
+            fill_authority,
+            reward_tokens,
+            admin,
+            lockup_tiers: LockupTier::default_schedule(),
+            epoch_length_secs,
+            epoch_offset_secs,
+            distribution_mode: distribution_mode.into(),
+            ..Default::default()
+        };
+
+        if pool.epoch_offset_secs >= pool.epoch_length() {
+            return Err(TrzRewardsError::InvalidEpoch);
+        }
+
+        if let Some((base_lockup_tier, lockup_tiers)) = lockup_tiers.split_first() {
+            if lockup_tiers.len() >= MAX_LOCKUP_TIERS || !base_lockup_tier.is_configured() {
+                return Err(TrzRewardsError::InvalidLockupTier);
+            }
+
+            pool.lockup_tiers = [LockupTier::default(); MAX_LOCKUP_TIERS];
+            pool.lockup_tiers[0] = *base_lockup_tier;
+            for (tier_index, lockup_tier) in lockup_tiers.iter().enumerate() {
+                pool.set_lockup_tier(tier_index + 1, *lockup_tier)?;
+            }
+        } else {
+            for lockup_tier in &pool.lockup_tiers[1..] {
+                pool.assert_lockup_tier_lasts_an_epoch(lockup_tier)?;
+            }
+        }
+
+        Ok(pool)
+    }
+
+    /// Returns the lockup tier the lockup period points at
+    pub fn lockup_tier(&self, lockup_period: LockupPeriod) -> Result<LockupTier, TrzRewardsError> {
+        self.lockup_tiers
+            .get(lockup_period.tier_index()?)
+            .filter(|lockup_tier| lockup_tier.is_configured())
+            .copied()
+            .ok_or(TrzRewardsError::InvalidLockupPeriod)
+    }
+
+    /// Configures a lockup tier. Once the tier is configured, its duration and multiplier
+    /// are fixed, because the stake that has been deposited for it relies on them,
+    /// so it might only be enabled or disabled for the future deposits.
+    pub fn set_lockup_tier(
+        &mut self,
+        tier_index: usize,
+        lockup_tier: LockupTier,
+    ) -> Result<(), TrzRewardsError> {
+        if tier_index >= MAX_LOCKUP_TIERS || !lockup_tier.is_configured() {
+            return Err(TrzRewardsError::InvalidLockupTier);
+        }
+
+        let current_lockup_tier = &mut self.lockup_tiers[tier_index];
+        if current_lockup_tier.is_configured() {
+            if current_lockup_tier.days != lockup_tier.days
+                || current_lockup_tier.multiplier != lockup_tier.multiplier
+            {
+                return Err(TrzRewardsError::LockupTierIsImmutable);
+            }
+
+            current_lockup_tier.enabled = lockup_tier.enabled;
+            return Ok(());
+        }
+
+        // every weighted stake falls back to the base tier once the lockup expires,
+        // so no tier might be weighted less than the base one
+        if tier_index != 0 {
+            let base_lockup_tier = self.lockup_tiers[0];
+            if !base_lockup_tier.is_configured()
+                || lockup_tier.multiplier < base_lockup_tier.multiplier
+            {
+                return Err(TrzRewardsError::InvalidLockupTier);
+            }
+            self.assert_lockup_tier_lasts_an_epoch(&lockup_tier)?;
+        }
+
+        self.lockup_tiers[tier_index] = lockup_tier;
+
+        Ok(())
+    }
+
+    /// The stake expires at the beginning of the epoch, so the lockup shorter than an epoch
+    /// might expire in the very epoch it's deposited in. The base tier is never expired into
+    /// another one, so its lockup might be shorter.
+    fn assert_lockup_tier_lasts_an_epoch(
+        &self,
+        lockup_tier: &LockupTier,
+    ) -> Result<(), TrzRewardsError> {
+        if lockup_tier.is_configured()
+            && SECONDS_PER_DAY.safe_mul(lockup_tier.days)? < self.epoch_length()
+        {
+            return Err(TrzRewardsError::InvalidLockupTier);
+        }
+
+        Ok(())
+    }
+
+    /// Length of the distribution epoch in seconds
+    pub fn epoch_length(&self) -> u64 {
+        if self.epoch_length_secs == 0 {
+            SECONDS_PER_DAY
+        } else {
+            self.epoch_length_secs
+        }
+    }
+
+    /// The way the pool distributes rewards
+    pub fn distribution_mode(&self) -> DistributionMode {
+        DistributionMode::from(self.distribution_mode)
+    }
+
+    /// The current authority of the role
+    pub fn authority(&self, role: AuthorityRole) -> Pubkey {
+        match role {
+            AuthorityRole::Deposit => self.deposit_authority,
+            AuthorityRole::Distribute => self.distribute_authority,
+            AuthorityRole::Fill => self.fill_authority,
+            AuthorityRole::Admin => self.admin,
+            AuthorityRole::Guardian => self.guardian,
+        }
+    }
+
+    /// Stores the authority which might take the role over. The default pubkey cancels the proposal.
+    pub fn propose_authority(&mut self, role: AuthorityRole, new_authority: Pubkey) {
+        self.pending_authorities[role.index()] = new_authority;
+    }
+
+    /// Hands the role over to the pending authority, returns the previous one.
+    /// The deposit authority can't be rotated while it still owns the vaults,
+    /// they have to be migrated to the vault authority PDA first.
+    pub fn accept_authority(
+        &mut self,
+        role: AuthorityRole,
+        new_authority: &Pubkey,
+    ) -> Result<Pubkey, TrzRewardsError> {
+        let vault_authority_migrated = self.is_vault_authority_migrated();
+        let pending_authority = &mut self.pending_authorities[role.index()];
+        if *pending_authority == Pubkey::default() || pending_authority != new_authority {
+            return Err(TrzRewardsError::NoPendingAuthority);
+        }
+        if role == AuthorityRole::Deposit && !vault_authority_migrated {
+            return Err(TrzRewardsError::VaultAuthorityNotMigrated);
+        }
+        *pending_authority = Pubkey::default();
+
+        let authority = match role {
+            AuthorityRole::Deposit => &mut self.deposit_authority,
+            AuthorityRole::Distribute => &mut self.distribute_authority,
+            AuthorityRole::Fill => &mut self.fill_authority,
+            AuthorityRole::Admin => &mut self.admin,
+            AuthorityRole::Guardian => &mut self.guardian,
+        };
+
+        Ok(core::mem::replace(authority, *new_authority))
+    }
+
+    /// The time minings are expected to have claimed their rewards by,
+    /// that is the claim period after the last rewards have been distributed
+    pub fn claim_deadline(&self) -> Result<u64, TrzRewardsError> {
+        let distributed_till = self
+            .reward_tokens
+            .iter()
+            .map(|reward_token| reward_token.distribution_ends_at)
+            .chain([self.last_distribution_at, self.last_accrued_at])
+            .max()
+            .unwrap_or_default();
+
+        distributed_till.safe_add(CLAIM_PERIOD_SECS)
+    }
+
+    /// Fails unless the pool is no longer in use: nobody stakes, nothing is left
+    /// to distribute or withdraw from the vestings and either all minings are closed
+    /// or the claim deadline has passed
+    pub fn assert_closable(&self, curr_ts: u64) -> Result<(), TrzRewardsError> {
+        let undistributed_rewards = self
+            .reward_tokens
+            .iter()
+            .any(|reward_token| reward_token.tokens_available_for_distribution > 0)
+            || self.vesting_rewards.iter().any(|rewards| *rewards > 0);
+
+        if self.total_share > 0
+            || undistributed_rewards
+            || (self.open_minings > 0 && curr_ts < self.claim_deadline()?)
+        {
+            return Err(TrzRewardsError::PoolIsInUse);
+        }
+
+        Ok(())
+    }
+
+    /// Generation of the pending minings checkpoint. A mining refreshed since the
+    /// pending checkpoint has the same one.
+    pub fn checkpoint_generation(&self) -> u8 {
+        self.data[CHECKPOINT_GENERATION_BYTE]
+    }
+
+    /// Counts the mining as open. New minings are up to date with the pending checkpoint.
+    pub fn open_mining(&mut self, mining: &mut Mining) -> Result<(), TrzRewardsError> {
+        self.open_minings = self.open_minings.safe_add(1)?;
+        mining.set_checkpoint_generation(self.checkpoint_generation());
+
+        Ok(())
+    }
+
+    /// Marks the refreshed mining as up to date with the pending checkpoint.
+    /// The checkpoint is confirmed once the last mining behind it is refreshed.
+    pub fn check_in_mining(&mut self, mining: &mut Mining) -> Result<(), TrzRewardsError> {
+        if mining.checkpoint_generation() == self.checkpoint_generation() {
+            return Ok(());
+        }
+
+        mining.set_checkpoint_generation(self.checkpoint_generation());
+        self.minings_behind_checkpoint = self.minings_behind_checkpoint.safe_sub(1)?;
+        if self.minings_behind_checkpoint == 0 {
+            self.minings_checkpoint = self.pending_minings_checkpoint;
+        }
+
+        Ok(())
+    }
+
+    /// Starts moving the minings checkpoint to the given date unless it's being moved
+    /// already. Every open mining is behind the new checkpoint till it's refreshed.
+    pub fn start_minings_checkpoint(&mut self, date: u64) {
+        if self.minings_behind_checkpoint > 0 {
+            return;
+        }
+
+        self.data[CHECKPOINT_GENERATION_BYTE] = self.checkpoint_generation().wrapping_add(1);
+        self.pending_minings_checkpoint = date;
+        self.minings_behind_checkpoint = self.open_minings;
+        if self.minings_behind_checkpoint == 0 {
+            self.minings_checkpoint = date;
+        }
+    }
+
+    /// Shows whether the pool has been migrated from the legacy layout
+    pub fn is_migrated_from_legacy(&self) -> bool {
+        self.data[MIGRATED_FROM_LEGACY_BYTE] != 0
+    }
+
+    /// Shows whether the vaults are owned by the vault authority PDA
+    pub fn is_vault_authority_migrated(&self) -> bool {
+        self.vault_authority_migrated != 0
+    }
+
+    /// Bump of the vault authority PDA if the vaults are owned by it
+    pub fn migrated_vault_authority_bump(&self) -> Option<u8> {
+        self.is_vault_authority_migrated()
+            .then_some(self.vault_authority_bump)
+    }
+
+    /// Shows whether mining owners might claim without the deposit authority
+    pub fn allows_direct_claims(&self) -> bool {
+        self.direct_claims != 0
+    }
+
+    /// The owner of the pool's vaults, that is the deposit authority
+    /// or the vault authority PDA of the program once the vaults are migrated
+    #[cfg(feature = "program")]
+    pub fn vault_owner(
+        &self,
+        program_id: &Pubkey,
+        reward_pool: &Pubkey,
+    ) -> Result<Pubkey, ProgramError> {
+        match self.migrated_vault_authority_bump() {
+            Some(bump) => Ok(Pubkey::create_program_address(
+                &[b"vault_authority".as_ref(), reward_pool.as_ref(), &[bump]],
+                program_id,
+            )?),
+            None => Ok(self.deposit_authority),
+        }
+    }
+
+    /// Enables or disables claims signed by mining owners only
+    pub fn configure_direct_claims(&mut self, direct_claims: bool) -> Result<(), TrzRewardsError> {
+        if direct_claims && !self.is_vault_authority_migrated() {
+            return Err(TrzRewardsError::VaultAuthorityNotMigrated);
+        }
+
+        self.direct_claims = direct_claims.into();
+
+        Ok(())
+    }
+
+    /// Shows whether claimed rewards are vested instead of being transferred
+    pub fn is_vesting_enabled(&self) -> bool {
+        self.vesting_duration_secs > 0
+    }
+
+    /// Sets the vesting schedule up, zero duration disables the vesting
+    pub fn configure_vesting(
+        &mut self,
+        cliff_secs: u64,
+        duration_secs: u64,
+    ) -> Result<(), TrzRewardsError> {
+        if cliff_secs > duration_secs {
+            return Err(TrzRewardsError::InvalidVestingSchedule);
+        }
+
+        self.vesting_cliff_secs = cliff_secs;
+        self.vesting_duration_secs = duration_secs;
+
+        Ok(())
+    }
+
+    /// Shows whether the address is allowed to pause the pool
+    pub fn is_pauser(&self, address: &Pubkey) -> bool {
+        *address == self.admin || (*address == self.guardian && self.guardian != Pubkey::default())
+    }
+
+    /// Fails if the operation is paused
+    pub fn assert_not_paused(&self, operation: PoolOperation) -> Result<(), TrzRewardsError> {
+        if self.paused_operations & operation.flag() != 0 {
+            return Err(TrzRewardsError::PoolPaused);
+        }
+
+        Ok(())
+    }
+
+    /// Pauses the operations of the given flags and resumes the rest.
+    /// Unknown flags are dropped, the applied ones are returned.
+    pub fn set_paused_operations(&mut self, paused_operations: u8) -> u8 {
+        self.paused_operations = paused_operations & PoolOperation::all_flags();
+        self.paused_operations
+    }
+
+    /// Shows whether anybody might distribute rewards
+    pub fn is_distribution_permissionless(&self) -> bool {
+        self.permissionless_distribution != 0
+    }
+
+    /// The bounty paid to the one who distributes rewards
+    pub fn crank_bounty(&self) -> CrankBounty {
+        match self.crank_bounty_kind {
+            1 => {
+                let mut amounts = [0; MAX_REWARD_TOKENS];
+                for (amount, reward_token) in amounts.iter_mut().zip(self.reward_tokens.iter()) {
+                    *amount = reward_token.crank_bounty_amount;
+                }
+                CrankBounty::FixedAmount(amounts)
+            }
+            2 => CrankBounty::BasisPoints(self.crank_bounty_value),
+            _ => CrankBounty::None,
+        }
+    }
+
+    /// Configures who might distribute rewards and the bounty they get for it.
+    /// Fixed amounts might only be set for the reward tokens the pool already has.
+    pub fn configure_crank(
+        &mut self,
+        permissionless_distribution: bool,
+        crank_bounty: CrankBounty,
+    ) -> Result<(), TrzRewardsError> {
+        let (crank_bounty_kind, crank_bounty_value, crank_bounty_amounts) =
+            match crank_bounty {
+                CrankBounty::None => (0, 0, [0; MAX_REWARD_TOKENS]),
+                CrankBounty::FixedAmount(amounts) => {
+                    if amounts.iter().zip(self.reward_tokens.iter()).any(
+                        |(amount, reward_token)| *amount != 0 && !reward_token.is_initialized(),
+                    ) {
+                        return Err(TrzRewardsError::InvalidCrankBounty);
+                    }
+                    (1, 0, amounts)
+                }
+                CrankBounty::BasisPoints(basis_points) => {
+                    if basis_points > MAX_BASIS_POINTS {
+                        return Err(TrzRewardsError::InvalidCrankBounty);
+                    }
+                    (2, basis_points, [0; MAX_REWARD_TOKENS])
+                }
+            };
+
+        self.permissionless_distribution = permissionless_distribution.into();
+        self.crank_bounty_kind = crank_bounty_kind;
+        self.crank_bounty_value = crank_bounty_value;
+        for (reward_token, amount) in self.reward_tokens.iter_mut().zip(crank_bounty_amounts) {
+            reward_token.crank_bounty_amount = amount;
+        }
+
+        Ok(())
+    }
+
+    /// The curve rewards are emitted by in the epochs mode
+    pub fn emission_curve(&self) -> EmissionCurve {
+        match self.emission_curve_kind {
+            1 => EmissionCurve::LinearDecay,
+            2 => EmissionCurve::Halving(self.emission_curve_epochs),
+            3 => EmissionCurve::Stepped(self.emission_curve_epochs),
+            _ => EmissionCurve::Even,
+        }
+    }
+
+    /// Sets the curve rewards are emitted by. Rewards are streamed evenly in the streaming mode,
+    /// so the curve might only be configured for the pools distributing them by epochs.
+    pub fn configure_emission_curve(
+        &mut self,
+        emission_curve: EmissionCurve,
+    ) -> Result<(), TrzRewardsError> {
+        let (emission_curve_kind, emission_curve_epochs) = match emission_curve {
+            EmissionCurve::Even => (0, 0),
+            EmissionCurve::LinearDecay => (1, 0),
+            EmissionCurve::Halving(period_epochs) => (2, period_epochs),
+            EmissionCurve::Stepped(step_epochs) => (3, step_epochs),
+        };
+
+        if (emission_curve_kind > 1 && emission_curve_epochs == 0)
+            || (emission_curve != EmissionCurve::Even
+                && self.distribution_mode() == DistributionMode::Streaming)
+        {
+            return Err(TrzRewardsError::InvalidEmissionCurve);
+        }
+
+        self.emission_curve_kind = emission_curve_kind;
+        self.emission_curve_epochs = emission_curve_epochs;
+
+        Ok(())
+    }
+
+    /// Calculates the bounty of every reward token for the given rewards of an epoch
+    pub fn crank_bounties(
+        &self,
+        rewards: &[u64; MAX_REWARD_TOKENS],
+    ) -> Result<[u64; MAX_REWARD_TOKENS], TrzRewardsError> {
+        let mut crank_bounties = [0; MAX_REWARD_TOKENS];
+        let configured_bounty = self.crank_bounty();
+        for (reward_token_index, (crank_bounty, rewards)) in
+            crank_bounties.iter_mut().zip(rewards).enumerate()
+        {
+            *crank_bounty = match configured_bounty {
+                CrankBounty::None => 0,
+                CrankBounty::FixedAmount(amounts) => amounts[reward_token_index].min(*rewards),
+                CrankBounty::BasisPoints(basis_points) => {
+                    rewards.safe_mul(basis_points)?.safe_div(MAX_BASIS_POINTS)?
+                }
+            };
+        }
+
+        Ok(crank_bounties)
+    }
+
+    /// Current indexes of every reward token
+    pub fn reward_indexes(&self) -> RewardIndexes {
+        let mut indexes = [0; MAX_REWARD_TOKENS];
+        for (index, reward_token) in indexes.iter_mut().zip(self.reward_tokens.iter()) {
+            *index = reward_token.index_with_precision;
+        }
+        indexes
+    }
+
+    /// Streams rewards of every reward token till the given time applying the weighted stake
+    /// modifiers at their dates. It doesn't touch the trees, so it might be run on a copy
+    /// of the pool to look into the future. Returns the dates of the applied modifiers
+    /// along with the indexes at those dates, before the modifiers are applied.
+    pub fn stream_rewards(
+        &mut self,
+        weighted_stake_diffs: &PoolWeightedStakeDiffs,
+        till: u64,
+    ) -> Result<Vec<(u64, RewardIndexes)>, TrzRewardsError> {
+        if self.last_accrued_at == 0 {
+            // nothing could have been staked before the first accrual
+            self.last_accrued_at = till;
+        }
+
+        let mut checkpoints = vec![];
+        for (date, modifier) in weighted_stake_diffs.iter() {
+            if *date > till {
+                break;
+            }
+
+            self.stream_rewards_till(*date)?;
+            checkpoints.push((*date, self.reward_indexes()));
+            self.total_share = self.total_share.safe_sub(*modifier)?;
+        }
+        self.stream_rewards_till(till)?;
+
+        Ok(checkpoints)
+    }
+
+    fn stream_rewards_till(&mut self, till: u64) -> Result<(), TrzRewardsError> {
+        if till <= self.last_accrued_at {
+            return Ok(());
+        }
+
+        for reward_token in self.reward_tokens.iter_mut() {
+            reward_token.stream(self.last_accrued_at, till, self.total_share)?;
+        }
+        self.last_accrued_at = till;
+
+        Ok(())
+    }
+
+    /// Beginning of the epoch the timestamp belongs to. Index history and
+    /// weighted stake modifiers are all keyed by those.
+    pub fn epoch_start(&self, timestamp: u64) -> u64 {
+        epoch_start(timestamp, self.epoch_length(), self.epoch_offset_secs)
+    }
+
+    /// Returns the index of the reward token slot the mint is distributed through
+    pub fn reward_token_index(&self, reward_mint: &Pubkey) -> Result<usize, TrzRewardsError> {
+        self.reward_tokens
+            .iter()
+            .position(|reward_token| {
+                reward_token.is_initialized() && reward_token.mint == *reward_mint
+            })
+            .ok_or(TrzRewardsError::UnknownRewardMint)
+    }
+
+    /// Adds one more reward token to the pool, taking the first empty slot
+    pub fn add_reward_token(
+        &mut self,
+        reward_mint: Pubkey,
+        token_account_bump: u8,
+    ) -> Result<usize, TrzRewardsError> {
+        if self.reward_token_index(&reward_mint).is_ok() {
+            return Err(TrzRewardsError::RewardMintAlreadyAdded);
+        }
+
+        let reward_token_index = self
+            .reward_tokens
+            .iter()
+            .position(|reward_token| !reward_token.is_initialized())
+            .ok_or(TrzRewardsError::RewardTokensLimitReached)?;
+        self.reward_tokens[reward_token_index] = RewardToken::new(reward_mint, token_account_bump);
+
+        Ok(reward_token_index)
+    }
+
+    /// Moves the end of the reward token distribution to the epoch the given date belongs to.
+    /// The distribution can't be scheduled to end before the current epoch.
+    pub fn schedule_distribution(
+        &mut self,
+        reward_token_index: usize,
+        distribution_ends_at: u64,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        // beginning of the epoch where distribution_ends_at
+        let distribution_ends_at_epoch_start = self.epoch_start(distribution_ends_at);
+        let beginning_of_the_curr_epoch = self.epoch_start(curr_ts);
+        if distribution_ends_at_epoch_start < beginning_of_the_curr_epoch {
+            return Err(TrzRewardsError::DistributionInThePast);
+        }
+
+        let reward_token = &mut self.reward_tokens[reward_token_index];
+        let epochs_diff =
+            distribution_ends_at_epoch_start.safe_sub(reward_token.distribution_ends_at)?;
+        reward_token.distribution_ends_at =
+            reward_token.distribution_ends_at.safe_add(epochs_diff)?;
+
+        Ok(())
+    }
+
+    /// Defines the amount of money of every reward token that will be distributed
+    /// by the distribution made at the given time
+    pub fn rewards_to_distribute(
+        &self,
+        distributed_at: u64,
+    ) -> Result<[u64; MAX_REWARD_TOKENS], TrzRewardsError> {
+        let mut rewards = [0; MAX_REWARD_TOKENS];
+        for (rewards, reward_token) in rewards.iter_mut().zip(self.reward_tokens.iter()) {
+            *rewards = reward_token.rewards_to_distribute(
+                self.epoch_length(),
+                self.emission_curve(),
+                distributed_at,
+            )?;
+        }
+
+        Ok(rewards)
+    }
+
+    /// Adds the weighted stake diff to the mining's modifier at the date.
+    /// If there is no modifier at the date and the tree is full, the diff is merged
+    /// into the nearest later one, so the stake is weighted a bit longer than locked.
+    /// The merge is recorded, so the diff is taken off the same modifier later on.
+    /// Returns the date the diff has been added at.
+    fn add_mining_weighted_stake_diff(
+        mining: &mut WrappedMining,
+        timestamp: u64,
+        weighted_stake_diff: u64,
+    ) -> Result<u64, TrzRewardsError> {
+        let diffs = &mut *mining.weighted_stake_diffs;
+        if let Some(modifier) = diffs.get_mut(&timestamp) {
+            *modifier = modifier.safe_add(weighted_stake_diff)?;
+            return Ok(timestamp);
+        }
+
+        if diffs.insert(timestamp, weighted_stake_diff).is_some() {
+            return Ok(timestamp);
+        }
+
+        let (modifier_date, modifier) = diffs
+            .iter_mut()
+            .find(|(date, _)| **date > timestamp)
+            .ok_or(TrzRewardsError::MiningModifiersTreeIsFull)?;
+        *modifier = modifier.safe_add(weighted_stake_diff)?;
+        let modifier_date = *modifier_date;
+
+        if weighted_stake_diff > 0 {
+            mining.mining.record_merged_weighted_stake_diff(
+                timestamp,
+                modifier_date,
+                weighted_stake_diff,
+            )?;
+        }
+
+        Ok(modifier_date)
+    }
+
+    /// Takes the weighted stake diff of the stakes expiring at the date off the mining's
+    /// modifier at the date, or off the later one it has been merged into.
+    /// Returns the date of the modifier the diff has been taken off.
+    fn subtract_mining_weighted_stake_diff(
+        mining: &mut WrappedMining,
+        timestamp: u64,
+        weighted_stake_diff: u64,
+    ) -> Result<u64, TrzRewardsError> {
+        if weighted_stake_diff == 0 {
+            return Ok(timestamp);
+        }
+
+        if let Some(modifier) = mining.weighted_stake_diffs.get_mut(&timestamp) {
+            if *modifier >= weighted_stake_diff {
+                *modifier = modifier.safe_sub(weighted_stake_diff)?;
+                return Ok(timestamp);
+            }
+        }
+
+        let modifier_date = mining
+            .mining
+            .take_merged_weighted_stake_diff(timestamp, weighted_stake_diff)?
+            .ok_or(TrzRewardsError::NoWeightedStakeModifiersAtADate)?;
+        let modifier = mining
+            .weighted_stake_diffs
+            .get_mut(&modifier_date)
+            .ok_or(TrzRewardsError::NoWeightedStakeModifiersAtADate)?;
+        *modifier = modifier.safe_sub(weighted_stake_diff)?;
+
+        Ok(modifier_date)
+    }
+
+    /// Adds the weighted stake diff to the pool's modifier at the date
+    fn add_pool_weighted_stake_diff(
+        diffs: &mut PoolWeightedStakeDiffs,
+        timestamp: u64,
+        weighted_stake_diff: u64,
+    ) -> Result<(), TrzRewardsError> {
+        let modifier = diffs.get(&timestamp).copied().unwrap_or_default();
+
+        diffs
+            .insert(timestamp, modifier.safe_add(weighted_stake_diff)?)
+            .ok_or(TrzRewardsError::PoolModifiersTreeIsFull)?;
+
+        Ok(())
+    }
+
+    /// Takes the weighted stake diff off the pool's modifier at the date
+    fn subtract_pool_weighted_stake_diff(
+        diffs: &mut PoolWeightedStakeDiffs,
+        timestamp: u64,
+        weighted_stake_diff: u64,
+    ) -> Result<(), TrzRewardsError> {
+        if weighted_stake_diff == 0 {
+            return Ok(());
+        }
+
+        let modifier = diffs
+            .get_mut(&timestamp)
+            .ok_or(TrzRewardsError::NoWeightedStakeModifiersAtADate)?;
+        *modifier = modifier.safe_sub(weighted_stake_diff)?;
+
+        Ok(())
+    }
+}
+
+itpl RewardPool {
+    /// Shows whether the reward pool has been initialized
+    pub fn is_initialized(&self) -> bool {
+        self.data[ACCOUNT_TYPE_BYTE] == <u8>::from(AccountType::RewardPool)
+    }
+}
+
+#[cfg(feature = "program")]
+itpl IsInitialized for RewardPool {
+    fn is_initialized(&self) -> bool {
+        RewardPool::is_initialized(self)
+    }
+}
+
+/// Outcome of the distribution
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Distribution {
+    /// Crank bounties of every reward token to pay for all the distributed epochs
+    pub crank_bounties: [u64; MAX_REWARD_TOKENS],
+    /// Distributed epochs, the earliest first
+    pub epochs: Vec<EpochDistribution>,
+}
+
+/// Rewards distributed for a single epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EpochDistribution {
+    /// Beginning of the distributed epoch
+    pub epoch_start: u64,
+    /// Rewards of every reward token distributed to the stakers, the crank bounty excluded
+    pub rewards: [u64; MAX_REWARD_TOKENS],
+    /// Crank bounties of every reward token cut from the epoch rewards
+    pub crank_bounties: [u64; MAX_REWARD_TOKENS],
+    /// Total share the rewards are distributed with
+    pub total_share: u64,
+    /// Indexes of every reward token after the distribution
+    pub indexes: RewardIndexes,
+}
+
+/// Reward token the pool distributes
+#[repr(C)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Pod, Zeroable, BorshDeserialize, BorshSerialize,
+)]
+pub struct RewardToken {
+    /// The address of the Reward Token mint account.
+    pub mint: Pubkey,
+    /// That is the index that increases on each vault filling.
+    /// It points at the moment of time where the filling has been proceeded.
+    /// Also, it's responsible for rewards distribution calculations.
+    pub index_with_precision: u128,
+    /// The time where the last distribution made by distribution_authority is allowed. When the date expires,
+    /// the only one distribution may be made, distribution all available tokens at once.
+    pub distribution_ends_at: u64,
+    /// Shows the amount of tokens are ready to be distributed
+    pub tokens_available_for_distribution: u64, // default: 0, increased on each fill, decreased on each user claim
+    /// Bump of the vault the token is stored on
+    pub token_account_bump: u8,
+    /// unused
+    pub padding: [u8; 7],
+    /// The fixed crank bounty of the token, used by `CrankBounty::FixedAmount` only
+    pub crank_bounty_amount: u64,
+}
+
+itpl RewardToken {
+    pub fn new(mint: Pubkey, token_account_bump: u8) -> Self {
+        RewardToken {
+            mint,
+            token_account_bump,
+            ..Default::default()
+        }
+    }
+
+    /// Defines the amount of money that will be distributed in the epoch
+    /// The formula is vault_tokens_are_available_for_distribution * epoch_weight / weights_of_epochs_left_till(distrtribution_period_ends_at)
+    /// The last epoch takes whatever is left, so the whole funded amount is distributed.
+    pub fn rewards_to_distribute(
+        &self,
+        epoch_length: u64,
+        emission_curve: EmissionCurve,
+        distributed_at: u64,
+    ) -> Result<u64, TrzRewardsError> {
+        let distribution_epochs_left =
+            self.distribution_ends_at.saturating_sub(distributed_at) / epoch_length;
+
+        if distribution_epochs_left == 0 {
+            return Ok(self.tokens_available_for_distribution);
+        }
+
+        let (epoch_weight, weights_left) = emission_curve.weights(distribution_epochs_left)?;
+        mul_div(
+            self.tokens_available_for_distribution,
+            epoch_weight,
+            weights_left,
+        )
+        .map_err(TrzRewardsError::from)
+    }
+
+    /// Adds the rewards to the ones waiting for the distribution
+    pub fn fill(&mut self, amount: u64) -> Result<(), TrzRewardsError> {
+        self.tokens_available_for_distribution =
+            self.tokens_available_for_distribution.safe_add(amount)?;
+
+        Ok(())
+    }
+
+    /// Takes back the rewards that are not distributed yet. Distributed rewards are
+    /// already allocated to minings through the index, so they are never touched.
+    pub fn defund(&mut self, amount: u64) -> Result<(), TrzRewardsError> {
+        if amount > self.tokens_available_for_distribution {
+            return Err(TrzRewardsError::NotEnoughUndistributedRewards);
+        }
+
+        self.tokens_available_for_distribution -= amount;
+
+        Ok(())
+    }
+
+    /// Streams rewards for the given period with the rate of
+    /// tokens_available_for_distribution / (distribution_ends_at - from) per second.
+    /// Nothing is streamed while there are no stakers, so the rate grows afterwards.
+    fn stream(&mut self, from: u64, till: u64, total_share: u64) -> Result<(), TrzRewardsError> {
+        if total_share == 0 || self.tokens_available_for_distribution == 0 {
+            return Ok(());
+        }
+
+        let rewards = if from >= self.distribution_ends_at {
+            self.tokens_available_for_distribution
+        } else {
+            let streaming_period = till.min(self.distribution_ends_at).safe_sub(from)?;
+            let time_left = self.distribution_ends_at.safe_sub(from)?;
+
+            mul_div(
+                self.tokens_available_for_distribution,
+                u128::from(streaming_period),
+                u128::from(time_left),
+            )
+            .map_err(TrzRewardsError::from)?
+        };
+
+        self.index_with_precision = self
+            .index_with_precision
+            .safe_add(index_growth(rewards, total_share).map_err(TrzRewardsError::from)?)?;
+        self.tokens_available_for_distribution =
+            self.tokens_available_for_distribution.safe_sub(rewards)?;
+
+        Ok(())
+    }
+}
+
+itpl RewardToken {
+    /// Shows whether the reward token has been added
+    pub fn is_initialized(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+}
+
+#[cfg(feature = "program")]
+itpl IsInitialized for RewardToken {
+    fn is_initialized(&self) -> bool {
+        RewardToken::is_initialized(self)
+    }
+}
+
+/// The way the pool distributes rewards
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum DistributionMode {
+    /// The distribute authority distributes rewards once per epoch
+    #[default]
+    Epochs,
+    /// Rewards are streamed every second and accrued on each interaction with the pool.
+    /// The stakes still expire at the beginning of the epoch their lockup ends in,
+    /// as they do in the epochs mode, so the modifiers trees keep a date per epoch.
+    Streaming,
+}
+
+itpl From<u8> for DistributionMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => DistributionMode::Epochs,
+            1 => DistributionMode::Streaming,
+            _ => panic!("invalid DistributionMode value: {value}"),
+        }
+    }
+}
+
+itpl From<DistributionMode> for u8 {
+    fn from(value: DistributionMode) -> Self {
+        match value {
+            DistributionMode::Epochs => 0,
+            DistributionMode::Streaming => 1,
+        }
+    }
+}
+
+/// Authorities of the pool, every one might be rotated by the admin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum AuthorityRole {
+    /// Signs deposits, withdrawals and claims, owns the vaults unless they are migrated
+    /// to the vault authority PDA. Otherwise they have to be handed over to the new one separately.
+    Deposit,
+    /// Distributes rewards
+    Distribute,
+    /// Fills the vaults
+    Fill,
+    /// Configures the pool and rotates the authorities
+    Admin,
+    /// Pauses the pool in case of emergency
+    Guardian,
+}
+
+itpl AuthorityRole {
+    fn index(&self) -> usize {
+        match self {
+            AuthorityRole::Deposit => 0,
+            AuthorityRole::Distribute => 1,
+            AuthorityRole::Fill => 2,
+            AuthorityRole::Admin => 3,
+            AuthorityRole::Guardian => 4,
+        }
+    }
+}
+
+/// Operations of the pool which might be paused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolOperation {
+    /// Deposits, stake extensions and delegate changes
+    Deposit,
+    /// Withdrawals, slashes and mining closures
+    Withdraw,
+    /// Claims, vested rewards withdrawals and rewards decreases
+    Claim,
+    /// Rewards distribution
+    Distribute,
+    /// Vault fillings and defundings
+    Fill,
+}
+
+itpl PoolOperation {
+    /// The flag of the operation in the pool's paused operations
+    pub fn flag(&self) -> u8 {
+        let bit = match self {
+            PoolOperation::Deposit => 0,
+            PoolOperation::Withdraw => 1,
+            PoolOperation::Claim => 2,
+            PoolOperation::Distribute => 3,
+            PoolOperation::Fill => 4,
+        };
+
+        1 << bit
+    }
+
+    /// Flags of all the operations which might be paused
+    pub fn all_flags() -> u8 {
+        [
+            PoolOperation::Deposit,
+            PoolOperation::Withdraw,
+            PoolOperation::Claim,
+            PoolOperation::Distribute,
+            PoolOperation::Fill,
+        ]
+        .iter()
+        .fold(0, |flags, operation| flags | operation.flag())
+    }
+}
+
+/// The bounty paid from the rewards of an epoch to the one who distributes them.
+/// It's credited to the distributing wallet's mining of the pool, so the wallet has to
+/// have one and pass it to `DistributeRewards`, otherwise no bounty is cut at all.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum CrankBounty {
+    /// No bounty is paid
+    #[default]
+    None,
+    /// Fixed amount of each reward token, in the order of the pool's reward tokens,
+    /// limited by the rewards of the epoch
+    FixedAmount([u64; MAX_REWARD_TOKENS]),
+    /// Basis points of the rewards of the epoch
+    BasisPoints(u64),
+}
+
+/// The curve the rewards of every reward token are emitted by over the epochs
+/// left till their distribution ends
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum EmissionCurve {
+    /// Every epoch gets the same amount
+    #[default]
+    Even,
+    /// The amount decreases by the same step every epoch till the last one
+    LinearDecay,
+    /// The amount halves every period of the given number of epochs
+    Halving(u64),
+    /// The amount is constant within the steps of the given number of epochs,
+    /// decreasing by the same step every one
+    Stepped(u64),
+}
+
+itpl EmissionCurve {
+    /// Returns the weight of the current epoch along with the sum of the weights of all
+    /// the epochs left, the current one included. The weights are counted from the last epoch,
+    /// which weighs the least, so that rewards added later follow the same curve.
+    pub fn weights(&self, epochs_left: u64) -> Result<(u128, u128), TrzRewardsError> {
+        let weights = match *self {
+            EmissionCurve::Even => even_weights(epochs_left),
+            EmissionCurve::LinearDecay => stepped_weights(epochs_left, 1)?,
+            EmissionCurve::Halving(period_epochs) => halving_weights(epochs_left, period_epochs)?,
+            EmissionCurve::Stepped(step_epochs) => stepped_weights(epochs_left, step_epochs)?,
+        };
+
+        Ok(weights)
+    }
+}
+
+/// Lockup tier of the pool's lockup schedule
+#[repr(C)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Pod, Zeroable, BorshDeserialize, BorshSerialize,
+)]
+pub struct LockupTier {
+    /// Number of days the stake is locked for
+    pub days: u64,
+    /// Multiplier which will be used in rewards calculations
+    pub multiplier: u64,
+    /// 1 if deposits for the tier are allowed, 0 otherwise
+    pub enabled: u8,
+    /// unused
+    pub padding: [u8; 7],
+}
+
+itpl LockupTier {
+    pub fn new(days: u64, multiplier: u64, enabled: bool) -> Self {
+        LockupTier {
+            days,
+            multiplier,
+            enabled: enabled.into(),
+            padding: [0; 7],
+        }
+    }
+
+    /// Lockup schedule every pool had before it became configurable
+    pub fn default_schedule() -> [LockupTier; MAX_LOCKUP_TIERS] {
+        let mut lockup_tiers = [LockupTier::default(); MAX_LOCKUP_TIERS];
+        for (lockup_tier, lockup_period) in lockup_tiers.iter_mut().zip([
+            LockupPeriod::Flex,
+            LockupPeriod::ThreeMonths,
+            LockupPeriod::SixMonths,
+            LockupPeriod::OneYear,
+        ]) {
+            *lockup_tier = LockupTier::new(
+                lockup_period.days().unwrap_or_default(),
+                lockup_period.multiplier(),
+                true,
+            );
+        }
+
+        lockup_tiers
+    }
+
+    pub fn is_configured(&self) -> bool {
+        self.days > 0 && self.multiplier > 0
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled != 0
+    }
+
+    /// Calculates the time when a lockup should expire.
+    /// Stake modifiers are applied at the beginning of the epoch it belongs to.
+    pub fn end_timestamp(&self, start_ts: u64) -> Result<u64, TrzRewardsError> {
+        start_ts.safe_add(SECONDS_PER_DAY.safe_mul(self.days)?)
+    }
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+    use crate::state::{
+        MergedWeightedStakeDiff, MINING_MODIFIERS_TREE_CAPACITY, POOL_MODIFIERS_TREE_CAPACITY,
+        PRECISION,
+    };
+    use sokoban::RedBlackTree;
+
+    #[test]
+    fn test_wrapped_immutable_reward_pool_is_same_size_as_wrapped_reward_pool() {
+        assert_eq!(
+            core::mem::size_of::<super::WrappedImmutableRewardPool>(),
+            core::mem::size_of::<super::WrappedRewardPool>()
+        );
+    }
+
+    #[test]
+    fn test_legacy_reward_pool_takes_as_many_bytes_as_the_live_pools() {
+        assert_eq!(LegacyRewardPool::ACCOUNT_LEN, 64480);
+    }
+
+    #[test]
+    fn test_migrate_legacy_keeps_the_trees() {
+        let mut bytes = vec![0; WrappedRewardPool::LEN];
+        let (pool, trees) =
+            bytes[..LegacyRewardPool::ACCOUNT_LEN].split_at_mut(LegacyRewardPool::LEN);
+        let (weighted_stake_diffs, cumulative_index) =
+            trees.split_at_mut(core::mem::size_of::<PoolWeightedStakeDiffs>());
+
+        let legacy_pool = LegacyRewardPool::load_mut_bytes(pool).unwrap();
+        legacy_pool.reward_mint = Pubkey::new_unique();
+        legacy_pool.total_share = 300;
+        legacy_pool.data[ACCOUNT_TYPE_BYTE] = AccountType::RewardPool.into();
+        let reward_mint = legacy_pool.reward_mint;
+
+        let legacy_weighted_stake_diffs =
+            PoolWeightedStakeDiffs::load_mut_bytes(weighted_stake_diffs).unwrap();
+        legacy_weighted_stake_diffs.insert(10 * SECONDS_PER_DAY, 100);
+        legacy_weighted_stake_diffs.insert(20 * SECONDS_PER_DAY, 200);
+
+        let legacy_cumulative_index =
+            LegacyCumulativeIndex::load_mut_bytes(cumulative_index).unwrap();
+        for day in 1..=5 {
+            legacy_cumulative_index.insert(day * SECONDS_PER_DAY, day as u128 * PRECISION);
+        }
+        // the removed node is kept in the free list, which has to stay valid
+        legacy_cumulative_index.remove(&(2 * SECONDS_PER_DAY));
+
+        let admin = Pubkey::new_unique();
+        let wrapped_reward_pool = WrappedRewardPool::migrate_legacy(&mut bytes, admin, 2).unwrap();
+
+        assert_eq!(wrapped_reward_pool.pool.admin, admin);
+        assert_eq!(wrapped_reward_pool.pool.total_share, 300);
+        assert_eq!(wrapped_reward_pool.pool.open_minings, 2);
+        assert_eq!(wrapped_reward_pool.pool.reward_tokens[0].mint, reward_mint);
+        assert_eq!(
+            wrapped_reward_pool.pool.last_distribution_at,
+            5 * SECONDS_PER_DAY
+        );
+        assert!(wrapped_reward_pool.pool.is_initialized());
+        assert!(wrapped_reward_pool.pool.is_migrated_from_legacy());
+        assert_eq!(
+            wrapped_reward_pool
+                .weighted_stake_diffs
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![
+                (&(10 * SECONDS_PER_DAY), &100),
+                (&(20 * SECONDS_PER_DAY), &200)
+            ]
+        );
+        assert_eq!(
+            wrapped_reward_pool
+                .cumulative_index
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![
+                (&SECONDS_PER_DAY, &[PRECISION, 0, 0]),
+                (&(3 * SECONDS_PER_DAY), &[3 * PRECISION, 0, 0]),
+                (&(4 * SECONDS_PER_DAY), &[4 * PRECISION, 0, 0]),
+                (&(5 * SECONDS_PER_DAY), &[5 * PRECISION, 0, 0]),
+            ]
+        );
+
+        // the tree keeps working, the removed node is reused
+        wrapped_reward_pool
+            .cumulative_index
+            .insert(6 * SECONDS_PER_DAY, [6 * PRECISION, PRECISION, 0]);
+        assert_eq!(wrapped_reward_pool.cumulative_index.len(), 5);
+        assert_eq!(
+            wrapped_reward_pool
+                .cumulative_index
+                .get(&(6 * SECONDS_PER_DAY)),
+            Some(&[6 * PRECISION, PRECISION, 0])
+        );
+    }
+
+    #[test]
+    fn test_can_deserialize_wrapped_immutable_reward_pool_from_bytes_initialized_with_wrapped_reward_pool(
+    ) {
+        let mut bytes = vec![0; super::WrappedRewardPool::LEN];
+        let wrapped_reward_pool = super::WrappedRewardPool::from_bytes_mut(&mut bytes).unwrap();
+        let deposit_authority = Pubkey::new_unique();
+        let distribute_authority = Pubkey::new_unique();
+        let fill_authority = Pubkey::new_unique();
+        let reward_mint = Pubkey::new_unique();
+        wrapped_reward_pool.pool.deposit_authority = deposit_authority;
+        wrapped_reward_pool.pool.distribute_authority = distribute_authority;
+        wrapped_reward_pool.pool.fill_authority = fill_authority;
+        let index_with_precision = 12345;
+        let total_share = 65432;
+        let distribution_ends_at = 54321;
+        let tokens_available_for_distribution = 23456;
+        let token_account_bump = 12;
+        let reward_token = &mut wrapped_reward_pool.pool.reward_tokens[1];
+        reward_token.mint = reward_mint;
+        reward_token.index_with_precision = index_with_precision;
+        reward_token.distribution_ends_at = distribution_ends_at;
+        reward_token.tokens_available_for_distribution = tokens_available_for_distribution;
+        reward_token.token_account_bump = token_account_bump;
+        wrapped_reward_pool.pool.total_share = total_share;
+
+        let wrapped_immutable_reward_pool =
+            super::WrappedImmutableRewardPool::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            wrapped_immutable_reward_pool.pool.deposit_authority,
+            deposit_authority
+        );
+        assert_eq!(
+            wrapped_immutable_reward_pool.pool.distribute_authority,
+            distribute_authority
+        );
+        assert_eq!(
+            wrapped_immutable_reward_pool.pool.fill_authority,
+            fill_authority
+        );
+        assert_eq!(wrapped_immutable_reward_pool.pool.total_share, total_share);
+        let reward_token = wrapped_immutable_reward_pool.pool.reward_tokens[1];
+        assert_eq!(reward_token.mint, reward_mint);
+        assert_eq!(reward_token.index_with_precision, index_with_precision);
+        assert_eq!(reward_token.distribution_ends_at, distribution_ends_at);
+        assert_eq!(
+            reward_token.tokens_available_for_distribution,
+            tokens_available_for_distribution
+        );
+        assert_eq!(reward_token.token_account_bump, token_account_bump);
+    }
+
+    #[test]
+    fn reward_tokens_are_added_to_empty_slots() {
+        let reward_mint = Pubkey::new_unique();
+        let mut pool = RewardPool::initialize(
+            1,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            reward_mint,
+            Pubkey::new_unique(),
+            &[],
+            0,
+            0,
+            DistributionMode::Epochs,
+        )
+        .unwrap();
+
+        let second_reward_mint = Pubkey::new_unique();
+        assert_eq!(pool.add_reward_token(second_reward_mint, 2), Ok(1));
+        assert_eq!(pool.reward_token_index(&reward_mint), Ok(0));
+        assert_eq!(pool.reward_token_index(&second_reward_mint), Ok(1));
+        assert_eq!(
+            pool.reward_token_index(&Pubkey::default()),
+            Err(TrzRewardsError::UnknownRewardMint)
+        );
+
+        assert_eq!(
+            pool.add_reward_token(second_reward_mint, 2),
+            Err(TrzRewardsError::RewardMintAlreadyAdded)
+        );
+        assert_eq!(pool.add_reward_token(Pubkey::new_unique(), 3), Ok(2));
+        assert_eq!(
+            pool.add_reward_token(Pubkey::new_unique(), 4),
+            Err(TrzRewardsError::RewardTokensLimitReached)
+        );
+    }
+
+    #[test]
+    fn compaction_keeps_the_latest_outdated_index_as_a_checkpoint() {
+        let mut bytes = vec![0; super::WrappedRewardPool::LEN];
+        let mut wrapped_reward_pool = super::WrappedRewardPool::from_bytes_mut(&mut bytes).unwrap();
+        for date in 1..=5 {
+            wrapped_reward_pool
+                .cumulative_index
+                .insert(date, [u128::from(date) * 10, 0, 0]);
+        }
+
+        wrapped_reward_pool.pool.minings_checkpoint = 4;
+
+        assert_eq!(wrapped_reward_pool.compact_index_history(4), Ok(2));
+        assert_eq!(wrapped_reward_pool.cumulative_index.len(), 3);
+        assert_eq!(
+            wrapped_reward_pool.cumulative_index.get(&3),
+            Some(&[30, 0, 0])
+        );
+        assert_eq!(wrapped_reward_pool.compact_index_history(4), Ok(0));
+        assert_eq!(
+            wrapped_reward_pool.compact_index_history(5),
+            Err(TrzRewardsError::MiningsCheckpointNotReached)
+        );
+    }
+
+    #[test]
+    fn minings_checkpoint_is_confirmed_once_every_mining_is_refreshed() {
+        let mut pool = RewardPool::default();
+        let mut first_mining = Mining::default();
+        let mut second_mining = Mining::default();
+        pool.open_mining(&mut first_mining).unwrap();
+        pool.open_mining(&mut second_mining).unwrap();
+
+        pool.start_minings_checkpoint(10);
+        assert_eq!(pool.minings_behind_checkpoint, 2);
+        assert_eq!(pool.minings_checkpoint, 0);
+
+        // a mining opened meanwhile is up to date already
+        let mut new_mining = Mining::default();
+        pool.open_mining(&mut new_mining).unwrap();
+        pool.check_in_mining(&mut new_mining).unwrap();
+        pool.check_in_mining(&mut first_mining).unwrap();
+        pool.check_in_mining(&mut first_mining).unwrap();
+        assert_eq!(pool.minings_behind_checkpoint, 1);
+
+        // another checkpoint can't be started till the pending one is confirmed
+        pool.start_minings_checkpoint(20);
+        assert_eq!(pool.pending_minings_checkpoint, 10);
+
+        pool.check_in_mining(&mut second_mining).unwrap();
+        assert_eq!(pool.minings_behind_checkpoint, 0);
+        assert_eq!(pool.minings_checkpoint, 10);
+
+        pool.start_minings_checkpoint(20);
+        assert_eq!(pool.minings_behind_checkpoint, 3);
+        assert_eq!(pool.minings_checkpoint, 10);
+    }
+
+    #[test]
+    fn legacy_minings_hold_the_minings_checkpoint_till_migrated() {
+        let mut bytes = vec![0; super::WrappedRewardPool::LEN];
+        let mut wrapped_reward_pool = super::WrappedRewardPool::from_bytes_mut(&mut bytes).unwrap();
+        *wrapped_reward_pool.pool = LegacyRewardPool::default().migrate(Pubkey::new_unique(), 0, 1);
+        for date in 1..=5 {
+            wrapped_reward_pool
+                .cumulative_index
+                .insert(date, [u128::from(date) * 10, 0, 0]);
+        }
+
+        wrapped_reward_pool.pool.start_minings_checkpoint(4);
+        assert_eq!(
+            wrapped_reward_pool.compact_index_history(4),
+            Err(TrzRewardsError::MiningsCheckpointNotReached)
+        );
+
+        // the migrated mining is refreshed, so the index history before it isn't needed.
+        // Migrated minings are of the generation the migrated pool has started with.
+        let mut legacy_mining = Mining::initialize(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+        wrapped_reward_pool
+            .pool
+            .check_in_mining(&mut legacy_mining)
+            .unwrap();
+        assert_eq!(wrapped_reward_pool.pool.minings_checkpoint, 4);
+        assert_eq!(wrapped_reward_pool.compact_index_history(4), Ok(2));
+    }
+
+    #[test]
+    fn modifier_is_merged_into_the_later_one_when_mining_tree_is_full() {
+        let mut bytes = vec![0; WrappedMining::LEN];
+        let mining = Mining::initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        let mut mining = WrappedMining::initialize(&mut bytes, mining).unwrap();
+        for date in 1..=MINING_MODIFIERS_TREE_CAPACITY as u64 {
+            mining.weighted_stake_diffs.insert(date * 10, 100);
+        }
+
+        assert_eq!(
+            RewardPool::add_mining_weighted_stake_diff(&mut mining, 20, 50),
+            Ok(20)
+        );
+        assert_eq!(mining.weighted_stake_diffs.get(&20), Some(&150));
+
+        assert_eq!(
+            RewardPool::add_mining_weighted_stake_diff(&mut mining, 15, 50),
+            Ok(20)
+        );
+        assert_eq!(mining.weighted_stake_diffs.get(&20), Some(&200));
+        assert_eq!(
+            mining.mining.merged_weighted_stake_diffs[0],
+            MergedWeightedStakeDiff {
+                expires_at: 15,
+                merged_into: 20,
+                diff: 50,
+            }
+        );
+
+        assert_eq!(
+            RewardPool::add_mining_weighted_stake_diff(&mut mining, 1000, 50),
+            Err(TrzRewardsError::MiningModifiersTreeIsFull)
+        );
+    }
+
+    #[test]
+    fn merged_diff_is_taken_off_the_modifier_it_has_been_merged_into() {
+        let mut bytes = vec![0; WrappedMining::LEN];
+        let mining = Mining::initialize(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+        let mut mining = WrappedMining::initialize(&mut bytes, mining).unwrap();
+        for date in 1..=MINING_MODIFIERS_TREE_CAPACITY as u64 {
+            mining.weighted_stake_diffs.insert(date * 10, 100);
+        }
+        RewardPool::add_mining_weighted_stake_diff(&mut mining, 15, 50).unwrap();
+
+        // nothing has been merged for the date, so the later modifier isn't touched
+        assert_eq!(
+            RewardPool::subtract_mining_weighted_stake_diff(&mut mining, 25, 10),
+            Err(TrzRewardsError::NoWeightedStakeModifiersAtADate)
+        );
+        assert_eq!(
+            RewardPool::subtract_mining_weighted_stake_diff(&mut mining, 15, 60),
+            Err(TrzRewardsError::NoWeightedStakeModifiersAtADate)
+        );
+
+        assert_eq!(
+            RewardPool::subtract_mining_weighted_stake_diff(&mut mining, 15, 30),
+            Ok(20)
+        );
+        assert_eq!(mining.weighted_stake_diffs.get(&20), Some(&120));
+        assert_eq!(mining.mining.merged_weighted_stake_diffs[0].diff, 20);
+
+        assert_eq!(
+            RewardPool::subtract_mining_weighted_stake_diff(&mut mining, 15, 20),
+            Ok(20)
+        );
+        assert_eq!(mining.weighted_stake_diffs.get(&20), Some(&100));
+        assert_eq!(
+            mining.mining.merged_weighted_stake_diffs[0],
+            MergedWeightedStakeDiff::default()
+        );
+
+        // the stakes expiring at the date of a modifier are taken off it
+        assert_eq!(
+            RewardPool::subtract_mining_weighted_stake_diff(&mut mining, 30, 100),
+            Ok(30)
+        );
+        assert_eq!(mining.weighted_stake_diffs.get(&30), Some(&0));
+    }
+
+    #[test]
+    fn full_pool_tree_fails() {
+        let mut diffs = PoolWeightedStakeDiffs::default();
+        for date in 1..=POOL_MODIFIERS_TREE_CAPACITY as u64 {
+            diffs.insert(date * 10, 100);
+        }
+
+        assert_eq!(
+            RewardPool::add_pool_weighted_stake_diff(&mut diffs, 20, 50),
+            Ok(())
+        );
+        assert_eq!(diffs.get(&20), Some(&150));
+        assert_eq!(
+            RewardPool::add_pool_weighted_stake_diff(&mut diffs, 15, 50),
+            Err(TrzRewardsError::PoolModifiersTreeIsFull)
+        );
+    }
+
+    #[test]
+    fn rewards_are_streamed_with_modifiers_applied_at_their_dates() {
+        let mut pool = RewardPool {
+            total_share: 100,
+            last_accrued_at: 100,
+            ..Default::default()
+        };
+        pool.reward_tokens[0].distribution_ends_at = 1000;
+        pool.reward_tokens[0].tokens_available_for_distribution = 1000;
+        let mut diffs = PoolWeightedStakeDiffs::default();
+        diffs.insert(400, 50);
+        diffs.insert(700, 10);
+
+        let checkpoints = pool.stream_rewards(&diffs, 600).unwrap();
+        // 1000 * 300 / 900 tokens till the modifier date, 667 * 200 / 600 after it
+        let index_at_modifier = PRECISION * 333 / 100;
+        assert_eq!(checkpoints, vec![(400, [index_at_modifier, 0, 0])]);
+        assert_eq!(pool.total_share, 50);
+        assert_eq!(pool.last_accrued_at, 600);
+        assert_eq!(
+            pool.reward_tokens[0].index_with_precision,
+            index_at_modifier + PRECISION * 222 / 50
+        );
+        assert_eq!(pool.reward_tokens[0].tokens_available_for_distribution, 445);
+
+        // everything left is streamed once the distribution ends
+        diffs.remove(&400);
+        let checkpoints = pool.stream_rewards(&diffs, 2000).unwrap();
+        assert_eq!(checkpoints.len(), 1);
+        assert_eq!(pool.total_share, 40);
+        assert_eq!(pool.reward_tokens[0].tokens_available_for_distribution, 0);
+    }
+
+    #[test]
+    fn crank_bounty_is_limited_by_the_rewards() {
+        let mut pool = RewardPool::default();
+        assert_eq!(pool.crank_bounties(&[100, 5, 0]), Ok([0, 0, 0]));
+
+        pool.add_reward_token(Pubkey::new_unique(), 0).unwrap();
+        pool.add_reward_token(Pubkey::new_unique(), 0).unwrap();
+        assert_eq!(
+            pool.configure_crank(true, CrankBounty::FixedAmount([10, 2, 1])),
+            Err(TrzRewardsError::InvalidCrankBounty)
+        );
+        pool.configure_crank(true, CrankBounty::FixedAmount([10, 2, 0]))
+            .unwrap();
+        assert!(pool.is_distribution_permissionless());
+        assert_eq!(pool.crank_bounty(), CrankBounty::FixedAmount([10, 2, 0]));
+        assert_eq!(pool.crank_bounties(&[100, 5, 0]), Ok([10, 2, 0]));
+        assert_eq!(pool.crank_bounties(&[7, 1, 0]), Ok([7, 1, 0]));
+
+        pool.configure_crank(false, CrankBounty::BasisPoints(250))
+            .unwrap();
+        assert_eq!(pool.crank_bounty(), CrankBounty::BasisPoints(250));
+        assert_eq!(pool.reward_tokens[0].crank_bounty_amount, 0);
+        assert_eq!(pool.crank_bounties(&[1000, 39, 0]), Ok([25, 0, 0]));
+
+        assert_eq!(
+            pool.configure_crank(true, CrankBounty::BasisPoints(MAX_BASIS_POINTS + 1)),
+            Err(TrzRewardsError::InvalidCrankBounty)
+        );
+    }
+
+    #[test]
+    fn authority_is_rotated_by_the_proposed_one_only() {
+        let mut pool = RewardPool::default();
+        let new_fill_authority = Pubkey::new_unique();
+
+        assert_eq!(
+            pool.accept_authority(AuthorityRole::Fill, &Pubkey::default()),
+            Err(TrzRewardsError::NoPendingAuthority)
+        );
+
+        pool.propose_authority(AuthorityRole::Fill, new_fill_authority);
+        assert_eq!(
+            pool.accept_authority(AuthorityRole::Fill, &Pubkey::new_unique()),
+            Err(TrzRewardsError::NoPendingAuthority)
+        );
+        assert_eq!(
+            pool.accept_authority(AuthorityRole::Deposit, &new_fill_authority),
+            Err(TrzRewardsError::NoPendingAuthority)
+        );
+
+        assert_eq!(
+            pool.accept_authority(AuthorityRole::Fill, &new_fill_authority),
+            Ok(Pubkey::default())
+        );
+        assert_eq!(pool.authority(AuthorityRole::Fill), new_fill_authority);
+        assert_eq!(
+            pool.accept_authority(AuthorityRole::Fill, &new_fill_authority),
+            Err(TrzRewardsError::NoPendingAuthority)
+        );
+    }
+
+    #[test]
+    fn deposit_authority_is_rotated_once_vault_authority_is_migrated() {
+        let mut pool = RewardPool::default();
+        let new_deposit_authority = Pubkey::new_unique();
+
+        pool.propose_authority(AuthorityRole::Deposit, new_deposit_authority);
+        assert_eq!(
+            pool.accept_authority(AuthorityRole::Deposit, &new_deposit_authority),
+            Err(TrzRewardsError::VaultAuthorityNotMigrated)
+        );
+
+        pool.vault_authority_migrated = 1;
+        assert_eq!(
+            pool.accept_authority(AuthorityRole::Deposit, &new_deposit_authority),
+            Ok(Pubkey::default())
+        );
+        assert_eq!(
+            pool.authority(AuthorityRole::Deposit),
+            new_deposit_authority
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "program")]
+    fn direct_claims_need_migrated_vault_authority() {
+        let program_id = Pubkey::new_unique();
+        let reward_pool = Pubkey::new_unique();
+        let mut pool = RewardPool {
+            deposit_authority: Pubkey::new_unique(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            pool.vault_owner(&program_id, &reward_pool),
+            Ok(pool.deposit_authority)
+        );
+        assert_eq!(
+            pool.configure_direct_claims(true),
+            Err(TrzRewardsError::VaultAuthorityNotMigrated)
+        );
+
+        let (vault_authority, bump) =
+            Pubkey::find_program_address(&[b"vault_authority", reward_pool.as_ref()], &program_id);
+        pool.vault_authority_migrated = 1;
+        pool.vault_authority_bump = bump;
+
+        assert_eq!(
+            pool.vault_owner(&program_id, &reward_pool),
+            Ok(vault_authority)
+        );
+        assert_eq!(pool.configure_direct_claims(true), Ok(()));
+        assert!(pool.allows_direct_claims());
+    }
+
+    #[test]
+    fn pool_is_closable_after_claim_deadline() {
+        let mut pool = RewardPool {
+            open_minings: 1,
+            ..Default::default()
+        };
+        pool.reward_tokens[0].distribution_ends_at = 1000;
+
+        let claim_deadline = 1000 + CLAIM_PERIOD_SECS;
+        assert_eq!(pool.claim_deadline(), Ok(claim_deadline));
+        assert_eq!(
+            pool.assert_closable(claim_deadline - 1),
+            Err(TrzRewardsError::PoolIsInUse)
+        );
+        assert_eq!(pool.assert_closable(claim_deadline), Ok(()));
+
+        pool.open_minings = 0;
+        assert_eq!(pool.assert_closable(claim_deadline - 1), Ok(()));
+
+        pool.total_share = 1;
+        assert_eq!(
+            pool.assert_closable(claim_deadline),
+            Err(TrzRewardsError::PoolIsInUse)
+        );
+
+        pool.total_share = 0;
+        pool.reward_tokens[0].tokens_available_for_distribution = 1;
+        assert_eq!(
+            pool.assert_closable(claim_deadline),
+            Err(TrzRewardsError::PoolIsInUse)
+        );
+
+        pool.reward_tokens[0].tokens_available_for_distribution = 0;
+        pool.vesting_rewards[0] = 1;
+        assert_eq!(
+            pool.assert_closable(claim_deadline),
+            Err(TrzRewardsError::PoolIsInUse)
+        );
+    }
+
+    #[test]
+    fn only_undistributed_rewards_are_defunded() {
+        let mut reward_token = RewardToken {
+            tokens_available_for_distribution: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            reward_token.defund(101),
+            Err(TrzRewardsError::NotEnoughUndistributedRewards)
+        );
+        assert_eq!(reward_token.defund(40), Ok(()));
+        assert_eq!(reward_token.tokens_available_for_distribution, 60);
+    }
+
+    #[test]
+    fn paused_operations_are_rejected() {
+        let mut pool = RewardPool {
+            admin: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        assert!(pool.is_pauser(&pool.admin));
+        assert!(!pool.is_pauser(&Pubkey::default()));
+
+        pool.paused_operations = PoolOperation::Deposit.flag() | PoolOperation::Fill.flag();
+        assert_eq!(
+            pool.assert_not_paused(PoolOperation::Deposit),
+            Err(TrzRewardsError::PoolPaused)
+        );
+        assert_eq!(
+            pool.assert_not_paused(PoolOperation::Fill),
+            Err(TrzRewardsError::PoolPaused)
+        );
+        assert_eq!(pool.assert_not_paused(PoolOperation::Withdraw), Ok(()));
+        assert_eq!(pool.assert_not_paused(PoolOperation::Claim), Ok(()));
+
+        // unknown flags are dropped
+        assert_eq!(
+            pool.set_paused_operations(u8::MAX),
+            PoolOperation::all_flags()
+        );
+        assert_eq!(pool.paused_operations, 0b11111);
+    }
+
+    #[test]
+    fn legacy_pool_falls_back_to_default_lockup_schedule() {
+        let pool = RewardPool::default();
+
+        let lockup_tier = pool.lockup_tier(LockupPeriod::SixMonths).unwrap();
+        assert_eq!(lockup_tier, LockupTier::new(180, 4, true));
+        assert_eq!(
+            pool.lockup_tier(LockupPeriod::Tier(4)),
+            Err(TrzRewardsError::InvalidLockupPeriod)
+        );
+    }
+
+    #[test]
+    fn configured_lockup_tier_might_only_be_toggled() {
+        let mut pool = RewardPool::default();
+
+        pool.set_lockup_tier(4, LockupTier::new(30, 3, true))
+            .unwrap();
+        pool.set_lockup_tier(4, LockupTier::new(30, 3, false))
+            .unwrap();
+        assert!(!pool
+            .lockup_tier(LockupPeriod::Tier(4))
+            .unwrap()
+            .is_enabled());
+
+        assert_eq!(
+            pool.set_lockup_tier(4, LockupTier::new(60, 3, true)),
+            Err(TrzRewardsError::LockupTierIsImmutable)
+        );
+        assert_eq!(
+            pool.set_lockup_tier(1, LockupTier::new(90, 5, true)),
+            Err(TrzRewardsError::LockupTierIsImmutable)
+        );
+    }
+
+    #[test]
+    fn lockup_tier_cannot_be_weighted_less_than_the_base_one() {
+        let base_lockup_tier = LockupTier::new(7, 2, true);
+        let mut pool = RewardPool::initialize(
+            0,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            &[base_lockup_tier],
+            0,
+            0,
+            DistributionMode::Epochs,
+        )
+        .unwrap();
+
+        assert_eq!(
+            pool.lockup_tier(LockupPeriod::Flex).unwrap(),
+            base_lockup_tier
+        );
+        assert_eq!(
+            pool.lockup_tier(LockupPeriod::ThreeMonths),
+            Err(TrzRewardsError::InvalidLockupPeriod)
+        );
+        assert_eq!(
+            pool.set_lockup_tier(1, LockupTier::new(90, 1, true)),
+            Err(TrzRewardsError::InvalidLockupTier)
+        );
+    }
+
+    #[test]
+    fn epoch_start_respects_length_and_offset() {
+        let mut pool = RewardPool::default();
+        let ts = 10 * SECONDS_PER_DAY + 5 * 3600 + 42;
+        assert_eq!(pool.epoch_start(ts), 10 * SECONDS_PER_DAY);
+
+        pool.epoch_length_secs = 3600;
+        assert_eq!(pool.epoch_start(ts), 10 * SECONDS_PER_DAY + 5 * 3600);
+
+        pool.epoch_length_secs = SECONDS_PER_DAY;
+        pool.epoch_offset_secs = 6 * 3600;
+        assert_eq!(pool.epoch_start(ts), 9 * SECONDS_PER_DAY + 6 * 3600);
+        assert_eq!(
+            pool.epoch_start(10 * SECONDS_PER_DAY + 6 * 3600),
+            10 * SECONDS_PER_DAY + 6 * 3600
+        );
+    }
+
+    #[test]
+    fn epoch_offset_must_be_less_than_epoch_length() {
+        let init = |epoch_length_secs, epoch_offset_secs| {
+            RewardPool::initialize(
+                0,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                &[],
+                epoch_length_secs,
+                epoch_offset_secs,
+                DistributionMode::Epochs,
+            )
+        };
+
+        assert!(init(0, 3600).is_ok());
+        assert_eq!(init(3600, 3600).unwrap_err(), TrzRewardsError::InvalidEpoch);
+        assert_eq!(
+            init(0, SECONDS_PER_DAY).unwrap_err(),
+            TrzRewardsError::InvalidEpoch
+        );
+    }
+
+    #[test]
+    fn lockup_tiers_must_last_an_epoch() {
+        let init = |lockup_tiers: &[LockupTier], epoch_length_secs| {
+            RewardPool::initialize(
+                0,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                lockup_tiers,
+                epoch_length_secs,
+                0,
+                DistributionMode::Epochs,
+            )
+        };
+        let week = 7 * SECONDS_PER_DAY;
+
+        // the base tier might be shorter
+        assert!(init(
+            &[LockupTier::new(1, 1, true), LockupTier::new(7, 2, true)],
+            week
+        )
+        .is_ok());
+        assert_eq!(
+            init(
+                &[LockupTier::new(7, 1, true), LockupTier::new(6, 2, true)],
+                week
+            )
+            .unwrap_err(),
+            TrzRewardsError::InvalidLockupTier
+        );
+        // the default schedule's tiers are checked as well
+        assert_eq!(
+            init(&[], 100 * SECONDS_PER_DAY).unwrap_err(),
+            TrzRewardsError::InvalidLockupTier
+        );
+
+        let mut pool = init(&[], week).unwrap();
+        assert_eq!(
+            pool.set_lockup_tier(4, LockupTier::new(3, 2, true)),
+            Err(TrzRewardsError::InvalidLockupTier)
+        );
+        assert_eq!(pool.set_lockup_tier(4, LockupTier::new(7, 2, true)), Ok(()));
+    }
+
+    #[test]
+    fn emission_curves_distribute_the_whole_funded_amount() {
+        let distribute = |emission_curve, funded, epochs: u64| {
+            let mut reward_token = RewardToken {
+                tokens_available_for_distribution: funded,
+                distribution_ends_at: epochs * SECONDS_PER_DAY,
+                ..Default::default()
+            };
+
+            let mut rewards = vec![];
+            for epoch in 0..epochs {
+                let epoch_rewards = reward_token
+                    .rewards_to_distribute(SECONDS_PER_DAY, emission_curve, epoch * SECONDS_PER_DAY)
+                    .unwrap();
+                reward_token.tokens_available_for_distribution -= epoch_rewards;
+                rewards.push(epoch_rewards);
+            }
+            assert_eq!(reward_token.tokens_available_for_distribution, 0);
+
+            rewards
+        };
+
+        assert_eq!(
+            distribute(EmissionCurve::Even, 400, 4),
+            [100, 100, 100, 100]
+        );
+        assert_eq!(
+            distribute(EmissionCurve::LinearDecay, 1000, 4),
+            [400, 300, 200, 100]
+        );
+        assert_eq!(
+            distribute(EmissionCurve::Halving(2), 600, 4),
+            [200, 200, 100, 100]
+        );
+        assert_eq!(
+            distribute(EmissionCurve::Stepped(2), 1200, 6),
+            [300, 300, 200, 200, 100, 100]
+        );
+
+        for emission_curve in [
+            EmissionCurve::Even,
+            EmissionCurve::LinearDecay,
+            EmissionCurve::Halving(1),
+            EmissionCurve::Stepped(7),
+        ] {
+            let rewards = distribute(emission_curve, 1_000_003, 100);
+            assert_eq!(rewards.iter().sum::<u64>(), 1_000_003);
+        }
+    }
+
+    #[test]
+    fn emission_curve_needs_a_period_and_the_epochs_mode() {
+        let mut pool = RewardPool::default();
+        assert_eq!(pool.emission_curve(), EmissionCurve::Even);
+
+        pool.configure_emission_curve(EmissionCurve::Halving(30))
+            .unwrap();
+        assert_eq!(pool.emission_curve(), EmissionCurve::Halving(30));
+        assert_eq!(
+            pool.configure_emission_curve(EmissionCurve::Stepped(0)),
+            Err(TrzRewardsError::InvalidEmissionCurve)
+        );
+
+        pool.distribution_mode = DistributionMode::Streaming.into();
+        assert_eq!(
+            pool.configure_emission_curve(EmissionCurve::LinearDecay),
+            Err(TrzRewardsError::InvalidEmissionCurve)
+        );
+        assert_eq!(pool.configure_emission_curve(EmissionCurve::Even), Ok(()));
+    }
+}
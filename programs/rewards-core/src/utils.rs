@@ -0,0 +1,128 @@
+//! Lockup periods and the checked arithmetic the state accounting relies on
+
+use crate::{TrzRewardsError, SECONDS_PER_DAY};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// LockupPeriod is used to define the time during which the lockup will recieve full reward
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum LockupPeriod {
+    /// Unreachable option
+    None,
+    /// Unlimited lockup period.
+    Flex,
+    /// Three months
+    ThreeMonths,
+    /// SixMonths
+    SixMonths,
+    /// OneYear
+    OneYear,
+    /// Custom tier of the pool's lockup schedule, addressed by its index
+    Tier(u8),
+}
+
+itpl LockupPeriod {
+    /// Returns the index of the pool's lockup tier the period points at
+    pub fn tier_index(&self) -> Result<usize, TrzRewardsError> {
+        match self {
+            LockupPeriod::None => Err(TrzRewardsError::InvalidLockupPeriod),
+            LockupPeriod::Flex => Ok(0),
+            LockupPeriod::ThreeMonths => Ok(1),
+            LockupPeriod::SixMonths => Ok(2),
+            LockupPeriod::OneYear => Ok(3),
+            LockupPeriod::Tier(index) => Ok(usize::from(*index)),
+        }
+    }
+
+    /// Converts LockupPeriod into the Multiplier of the default lockup schedule
+    /// which will be used in rewards calculations
+    pub fn multiplier(&self) -> u64 {
+        match self {
+            LockupPeriod::None | LockupPeriod::Tier(_) => 0,
+            LockupPeriod::ThreeMonths => 2,
+            LockupPeriod::SixMonths => 4,
+            LockupPeriod::OneYear => 6,
+            LockupPeriod::Flex => 1,
+        }
+    }
+
+    /// Calculates the time when a lockup of the default lockup schedule should expire
+    pub fn end_timestamp(&self, start_ts: u64) -> Result<u64, TrzRewardsError> {
+        // conversion should be unfailable because negative timestamp means the ts is earlier than 1970y
+        let beginning_of_the_day = start_ts - (start_ts % SECONDS_PER_DAY);
+
+        match self {
+            LockupPeriod::None | LockupPeriod::Tier(_) => Err(TrzRewardsError::InvalidLockupPeriod),
+            LockupPeriod::ThreeMonths => Ok(beginning_of_the_day + SECONDS_PER_DAY * 90),
+            LockupPeriod::SixMonths => Ok(beginning_of_the_day + SECONDS_PER_DAY * 180),
+            LockupPeriod::OneYear => Ok(beginning_of_the_day + SECONDS_PER_DAY * 365),
+            LockupPeriod::Flex => Ok(beginning_of_the_day + SECONDS_PER_DAY * 5),
+        }
+    }
+
+    /// Return number of days of the default lockup schedule as plain numbers
+    /// to make them appliable for the self.weighted_stake_diff
+    pub fn days(&self) -> Result<u64, TrzRewardsError> {
+        match self {
+            LockupPeriod::None | LockupPeriod::Tier(_) => Err(TrzRewardsError::InvalidLockupPeriod),
+            LockupPeriod::ThreeMonths => Ok(90),
+            LockupPeriod::SixMonths => Ok(180),
+            LockupPeriod::OneYear => Ok(365),
+            LockupPeriod::Flex => Ok(5),
+        }
+    }
+}
+
+/// Arithmetic failing with the overflow error instead of panicking or wrapping
+pub trait SafeArithmeticOperations
+where
+    Self: core::marker::Sized,
+{
+    fn safe_sub(&self, amount: Self) -> Result<Self, TrzRewardsError>;
+    fn safe_add(&self, amount: Self) -> Result<Self, TrzRewardsError>;
+    fn safe_mul(&self, amount: Self) -> Result<Self, TrzRewardsError>;
+    fn safe_div(&self, amount: Self) -> Result<Self, TrzRewardsError>;
+}
+
+itpl SafeArithmeticOperations for u64 {
+    fn safe_sub(&self, amount: u64) -> Result<u64, TrzRewardsError> {
+        self.checked_sub(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+
+    fn safe_add(&self, amount: u64) -> Result<u64, TrzRewardsError> {
+        self.checked_add(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+
+    fn safe_mul(&self, amount: u64) -> Result<u64, TrzRewardsError> {
+        self.checked_mul(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+
+    fn safe_div(&self, amount: u64) -> Result<u64, TrzRewardsError> {
+        self.checked_div(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+}
+
+itpl SafeArithmeticOperations for u128 {
+    fn safe_sub(&self, amount: u128) -> Result<u128, TrzRewardsError> {
+        self.checked_sub(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+
+    fn safe_add(&self, amount: u128) -> Result<u128, TrzRewardsError> {
+        self.checked_add(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+
+    fn safe_mul(&self, amount: u128) -> Result<u128, TrzRewardsError> {
+        self.checked_mul(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+
+    fn safe_div(&self, amount: u128) -> Result<u128, TrzRewardsError> {
+        self.checked_div(amount)
+            .ok_or(TrzRewardsError::MathOverflow)
+    }
+}
@@ -6,7 +6,7 @@ use trezoa_program::{
 
 use crate::{
     error::TrzRewardsError,
-    state::{WrappedMining, WrappedRewardPool},
+    state::{RewardPool, WrappedMining, WrappedRewardPool},
 };
 
 /// Assert signer.
@@ -138,3 +138,19 @@ pub fn assert_and_get_pool_and_mining<'a>(
 
     Ok((wrapped_reward_pool, wrapped_mining))
 }
+
+/// Assert the account owns the pool's vaults. The deposit authority has to sign the transaction,
+/// while the vault authority PDA is signed for by the program.
+pub fn assert_vault_owner(
+    program_id: &Pubkey,
+    account_info: &AccountInfo,
+    pool: &RewardPool,
+    reward_pool: &Pubkey,
+) -> ProgramResult {
+    assert_account_key(account_info, &pool.vault_owner(program_id, reward_pool)?)?;
+    if !pool.is_vault_authority_migrated() {
+        assert_signer(account_info)?;
+    }
+
+    Ok(())
+}
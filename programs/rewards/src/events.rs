@@ -0,0 +1,245 @@
+//! Events the program logs with `sol_log_data`, so indexers can follow the state changes.
+//! Every event is logged as a single Borsh-encoded `RewardsEvent`.
+
+use crate::{state::AuthorityRole, utils::LockupPeriod};
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::io::Error;
+use trezoa_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+/// Event logged by the program
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub enum RewardsEvent {
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    Claim(ClaimEvent),
+    Distribute(DistributeEvent),
+    Slash(SlashEvent),
+    DecreaseRewards(DecreaseRewardsEvent),
+    ChangeDelegate(ChangeDelegateEvent),
+    FillVault(FillVaultEvent),
+    DefundVault(DefundVaultEvent),
+    ClosePool(ClosePoolEvent),
+    WithdrawVested(WithdrawVestedEvent),
+    CompactIndexHistory(CompactIndexHistoryEvent),
+    ProposeAuthority(ProposeAuthorityEvent),
+    AcceptAuthority(AcceptAuthorityEvent),
+    SetPausedOperations(SetPausedOperationsEvent),
+}
+
+itpl RewardsEvent {
+    /// Logs the event
+    pub fn emit(&self) -> ProgramResult {
+        sol_log_data(&[&self.encode()?]);
+        Ok(())
+    }
+
+    /// Encodes the event the way it's logged
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        borsh::to_vec(self)
+    }
+
+    /// Decodes the event from the data the program logged
+    pub fn decode(data: &[u8]) -> Result<Self, Error> {
+        Self::try_from_slice(data)
+    }
+}
+
+/// Stake deposited to the mining
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct DepositEvent {
+    pub reward_pool: Pubkey,
+    pub mining: Pubkey,
+    pub amount: u64,
+    pub lockup_period: LockupPeriod,
+    /// Mining of the delegate the stake is delegated to, none if it isn't delegated
+    pub delegate_mining: Option<Pubkey>,
+    /// Weighted stake of the mining after the deposit
+    pub share: u64,
+    /// Weighted stake of the pool after the deposit
+    pub total_share: u64,
+    pub timestamp: u64,
+}
+
+/// Stake withdrawn from the mining
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawEvent {
+    pub reward_pool: Pubkey,
+    pub mining: Pubkey,
+    /// Withdrawn weighted stake
+    pub amount: u64,
+    /// Mining of the delegate the stake was delegated to, none if it wasn't delegated
+    pub delegate_mining: Option<Pubkey>,
+    /// Weighted stake of the mining after the withdrawal
+    pub share: u64,
+    /// Weighted stake of the pool after the withdrawal
+    pub total_share: u64,
+    pub timestamp: u64,
+}
+
+/// Rewards claimed by the mining owner
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct ClaimEvent {
+    pub reward_pool: Pubkey,
+    pub mining: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    /// The rewards are locked in the vesting instead of being transferred
+    pub vested: bool,
+    pub timestamp: u64,
+}
+
+/// Rewards of a reward token distributed for an epoch
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct DistributeEvent {
+    pub reward_pool: Pubkey,
+    pub reward_mint: Pubkey,
+    /// Beginning of the distributed epoch
+    pub day: u64,
+    /// Rewards distributed to the stakers, the crank bounty excluded
+    pub amount: u64,
+    /// Crank bounty cut from the epoch rewards
+    pub crank_bounty: u64,
+    /// Total share the rewards are distributed with
+    pub total_share: u64,
+    /// Index of the reward token after the distribution
+    pub index_with_precision: u128,
+    pub timestamp: u64,
+}
+
+/// Stake of the mining slashed
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct SlashEvent {
+    pub reward_pool: Pubkey,
+    pub mining: Pubkey,
+    pub slash_amount_in_native: u64,
+    pub slash_amount_multiplied_by_period: u64,
+    pub stake_expiration_date: Option<u64>,
+    /// Weighted stake of the mining after the slash
+    pub share: u64,
+    /// Weighted stake of the pool after the slash
+    pub total_share: u64,
+    pub timestamp: u64,
+}
+
+/// Weighted stake of the mining decreased as a penalty
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct DecreaseRewardsEvent {
+    pub reward_pool: Pubkey,
+    pub mining: Pubkey,
+    pub decreased_weighted_stake_number: u64,
+    /// Weighted stake of the mining after the decrease
+    pub share: u64,
+    pub timestamp: u64,
+}
+
+/// Stake of the mining moved to another delegate
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChangeDelegateEvent {
+    pub reward_pool: Pubkey,
+    pub mining: Pubkey,
+    /// Mining of the previous delegate, none if the stake wasn't delegated
+    pub old_delegate_mining: Option<Pubkey>,
+    /// Mining of the new delegate, none if the stake isn't delegated anymore
+    pub new_delegate_mining: Option<Pubkey>,
+    pub staked_amount: u64,
+    /// Weighted stake of the pool after the change
+    pub total_share: u64,
+    pub timestamp: u64,
+}
+
+/// Rewards added to the vault
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct FillVaultEvent {
+    pub reward_pool: Pubkey,
+    pub reward_mint: Pubkey,
+    /// Rewards the vault has received, the transfer fee excluded
+    pub amount: u64,
+    pub distribution_ends_at: u64,
+    /// Rewards of the reward token left to distribute after the filling
+    pub tokens_available_for_distribution: u64,
+    pub timestamp: u64,
+}
+
+/// Rewards taken back from the vault
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct DefundVaultEvent {
+    pub reward_pool: Pubkey,
+    pub reward_mint: Pubkey,
+    /// Rewards taken out of the vault
+    pub amount: u64,
+    /// End of the distribution after the defunding
+    pub distribution_ends_at: u64,
+    /// Rewards of the reward token left to distribute after the defunding
+    pub tokens_available_for_distribution: u64,
+    pub timestamp: u64,
+}
+
+/// Reward pool closed, its vaults emptied to the treasuries
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct ClosePoolEvent {
+    pub reward_pool: Pubkey,
+    /// Rewards left in the vaults of the pool, ordered as its reward tokens are
+    pub leftovers: Vec<VaultLeftovers>,
+    /// Account the rent of the pool and its vaults is returned to
+    pub rent_receiver: Pubkey,
+    pub timestamp: u64,
+}
+
+/// Rewards left in the vault of the closed pool
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct VaultLeftovers {
+    pub reward_mint: Pubkey,
+    /// Token account the leftovers are transferred to
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+/// Vested rewards withdrawn by the vesting owner
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawVestedEvent {
+    pub reward_pool: Pubkey,
+    pub vesting: Pubkey,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: u64,
+}
+
+/// Index history records preceding the date removed
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct CompactIndexHistoryEvent {
+    pub reward_pool: Pubkey,
+    pub compact_before: u64,
+    /// Number of the removed index history records
+    pub removed_records: u64,
+    /// Date the minings are to be refreshed since before the next compaction
+    pub pending_minings_checkpoint: u64,
+    pub timestamp: u64,
+}
+
+/// New authority proposed for the role, it takes over once it accepts it
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct ProposeAuthorityEvent {
+    pub reward_pool: Pubkey,
+    pub role: AuthorityRole,
+    pub new_authority: Pubkey,
+    pub timestamp: u64,
+}
+
+/// Role handed over to the proposed authority
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct AcceptAuthorityEvent {
+    pub reward_pool: Pubkey,
+    pub role: AuthorityRole,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: u64,
+}
+
+/// Operations of the pool paused or resumed
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct SetPausedOperationsEvent {
+    pub reward_pool: Pubkey,
+    /// Bit flags of the paused operations, the unknown ones dropped
+    pub paused_operations: u8,
+    pub timestamp: u64,
+}
@@ -8,7 +8,13 @@ use trezoa_program::{
     system_program, sysvar,
 };
 
-use crate::utils::LockupPeriod;
+use crate::{
+    state::{AuthorityRole, CrankBounty, DistributionMode, EmissionCurve, LockupTier},
+    utils::{
+        find_delegate_program_address, find_delegation_program_address,
+        find_vault_authority_program_address, find_vesting_program_address, LockupPeriod,
+    },
+};
 
 /// Instructions supported by the program
 #[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, Eq, ShankInstruction, ShankContext)]
@@ -28,6 +34,17 @@ pub enum RewardsInstruction {
         fill_authority: Pubkey,
         /// Account can distribute rewards for stakers
         distribute_authority: Pubkey,
+        /// Account can change the pool configuration
+        admin: Pubkey,
+        /// Lockup tiers deposits might be made for, the first one is the base (flex) tier.
+        /// The default lockup schedule is used if empty.
+        lockup_tiers: Vec<LockupTier>,
+        /// Length of the distribution epoch in seconds, zero means a day
+        epoch_length_secs: u64,
+        /// Shift of the epoch boundaries, must be less than the epoch length
+        epoch_offset_secs: u64,
+        /// Whether rewards are distributed once per epoch or streamed every second
+        distribution_mode: DistributionMode,
     },
 
     #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
@@ -58,6 +75,8 @@ pub enum RewardsInstruction {
     #[account(1, writable, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
     #[account(2, signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs")]
     #[account(3, name = "delegate_mining", desc = "The address of Mining Account that might be used as a delegate in delegated staking model")]
+    #[account(4, optional, writable, name = "delegate", desc = "The delegate account of the delegate mining, required if the delegate charges a commission")]
+    #[account(5, optional, writable, name = "delegation", desc = "The delegation of the mining to the delegate, required if the delegate charges a commission")]
     DepositMining {
         /// Amount to deposit
         amount: u64,
@@ -73,6 +92,8 @@ pub enum RewardsInstruction {
     #[account(1, writable, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
     #[account(2, signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs")]
     #[account(3, name = "delegate_mining", desc = "The address of Mining Account that might be used as a delegate in delegated staking model")]
+    #[account(4, optional, writable, name = "delegate", desc = "The delegate account of the delegate mining, required if the delegate charges a commission")]
+    #[account(5, optional, writable, name = "delegation", desc = "The delegation of the mining to the delegate, required if the delegate charges a commission")]
     WithdrawMining {
         /// Amount to withdraw
         amount: u64,
@@ -81,15 +102,19 @@ pub enum RewardsInstruction {
         delegate: Pubkey,
     },
 
-    /// Claims amount of rewards
-    #[account(0, name = "reward_pool", desc = "The address of the reward pool")]
+    /// Claims amount of rewards.
+    /// The reward pool is writable, since the streamed rewards are accrued and the minings
+    /// checkpoint is tracked on claim. That breaks the callers passing it as read-only.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
     #[account(1, name = "reward_mint", desc = "The address of the reward mint")]
     #[account(2, writable, name = "vault", desc = "The address of the reward vault")]
     #[account(3, writable, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
     #[account(4, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
-    #[account(5, signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs")]
+    #[account(5, optional_signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed")]
     #[account(6, writable, name = "mining_owner_reward_token_account", desc = "ATA where tokens will be claimed to")]
     #[account(7, name = "token_program", desc = "The address of the Token program where rewards are minted")]
+    #[account(8, optional, name = "vault_authority", desc = "The vault authority PDA, required once the vaults are migrated to it")]
+    #[account(9, optional, writable, name = "vesting", desc = "The vesting account of the mining, required if the pool vests claimed rewards")]
     Claim,
 
     /// Extends stake
@@ -98,6 +123,8 @@ pub enum RewardsInstruction {
     #[account(2, name = "reward_mint", desc = "The address of the reward mint")]
     #[account(3, signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs")]
     #[account(4, name = "delegate_mining", desc = "The address of Mining Account that might be used as a delegate in delegated staking model")]
+    #[account(5, optional, writable, name = "delegate", desc = "The delegate account of the delegate mining, required if the delegate charges a commission")]
+    #[account(6, optional, writable, name = "delegation", desc = "The delegation of the mining to the delegate, required if the delegate charges a commission")]
     ExtendStake {
         /// Lockup period before restaking. Actually it's only needed
         /// for Flex to AnyPeriod edge case
@@ -119,17 +146,23 @@ pub enum RewardsInstruction {
         delegate: Pubkey,
     },
 
-    /// Distributes tokens among mining owners
+    /// Distributes tokens among mining owners. The crank bounty is only cut if the distributing
+    /// wallet passes its mining of the pool to be credited with it.
     #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
-    #[account(1, signer, name = "distribute_authority", desc = "The address of Authority who is eligble for distributiong rewards for users")]
+    #[account(1, signer, name = "distribute_authority", desc = "The address of Authority who is eligble for distributiong rewards for users, anybody if the distribution is permissionless")]
+    #[account(2, optional, writable, name = "keeper_mining", desc = "The mining of the distributing wallet the crank bounty is credited to")]
     DistributeRewards,
 
-    /// Closes mining account and transfers all lamports to the target account
+    /// Closes mining account and transfers all lamports to the target account.
+    /// The reward pool is writable, since the open minings are counted. That breaks the callers
+    /// passing it as read-only. The delegate account is checked for the stake delegated
+    /// under the commission, so it's passed even if the mining doesn't charge one.
     #[account(0, name = "mining", desc = "The address of the user's mining account")]
     #[account(1, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
     #[account(2, writable, name = "target_account", desc = "The address where lamports from account closing will be transferred")]
     #[account(3, signer, name = "deposit_authority")]
     #[account(4, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(5, name = "delegate", desc = "The address of the delegate account of the mining, it doesn't exist unless the mining charges a commission")]
     CloseMining,
 
     /// Changes delegate mining account
@@ -139,6 +172,10 @@ pub enum RewardsInstruction {
     #[account(3, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
     #[account(4, writable, name = "old_delegate_mining", desc = "The address of the old delegate mining account")]
     #[account(5, writable, name = "new_delegate_mining", desc = "The address of the new delegate mining account")]
+    #[account(6, optional, writable, name = "old_delegate", desc = "The delegate account of the old delegate mining, required if the old delegate charges a commission")]
+    #[account(7, optional, writable, name = "old_delegation", desc = "The delegation of the mining to the old delegate, required if the old delegate charges a commission")]
+    #[account(8, optional, writable, name = "new_delegate", desc = "The delegate account of the new delegate mining, required if the new delegate charges a commission")]
+    #[account(9, optional, writable, name = "new_delegation", desc = "The delegation of the mining to the new delegate, required if the new delegate charges a commission")]
     ChangeDelegate {
         /// Amount of staked tokens
         staked_amount: u64,
@@ -166,6 +203,263 @@ pub enum RewardsInstruction {
         // The number by which weighted stake should be decreased
         decreased_weighted_stake_number: u64,
     },
+
+    /// Configures a lockup tier for the future deposits
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    ConfigureLockupTier {
+        /// Index of the tier in the pool's lockup schedule
+        tier_index: u8,
+        /// Tier parameters. Only the enabled flag of already configured tier might be changed
+        lockup_tier: LockupTier,
+    },
+
+    /// Adds one more reward token the pool distributes and creates its vault
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, name = "reward_mint", desc = "The address of the reward mint")]
+    #[account(2, writable, name = "vault", desc = "The address of the reward vault")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "deposit_authority", desc = "Owner of the new vault: the deposit authority, or the vault authority PDA once the vaults are migrated")]
+    #[account(5, signer, name = "admin", desc = "The address of the pool's admin")]
+    #[account(6, name = "rent", desc = "The address of the Rent program")]
+    #[account(7, name = "token_program", desc = "The address of the Token program where rewards are minted")]
+    #[account(8, name = "system_program", desc = "The system program")]
+    AddRewardMint,
+
+    /// Removes index history records older than the given date, keeping the latest one of them.
+    /// The date can't be later than the minings checkpoint. A new checkpoint is started
+    /// once every mining has been refreshed since the previous one. Legacy minings aren't
+    /// tracked, so they have to be migrated before the history is compacted.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "distribute_authority", desc = "The address of Authority who is eligble for distributiong rewards for users")]
+    CompactIndexHistory {
+        /// Records dated earlier are removed
+        compact_before: u64,
+    },
+
+    /// Reallocates mining account so its weighted stake modifiers tree has greater capacity
+    #[account(0, writable, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
+    #[account(1, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
+    #[account(2, writable, signer, name = "payer", desc = "The address paying for the extra rent")]
+    #[account(3, name = "system_program", desc = "The system program")]
+    ResizeMining {
+        /// One of the supported modifiers tree capacities, greater than the current one
+        modifiers_tree_capacity: u16,
+    },
+
+    /// Configures whether anybody might distribute rewards and the bounty paid for it
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    ConfigureDistributionCrank {
+        /// Allows anybody to distribute rewards, not only the distribute authority
+        permissionless_distribution: bool,
+        /// The bounty cut from the rewards of an epoch for the one who distributes them
+        crank_bounty: CrankBounty,
+    },
+
+    /// Proposes the new authority for the role, it takes the role over once accepts it
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    ProposeAuthority {
+        /// The role to be handed over
+        role: AuthorityRole,
+        /// The authority taking the role over, the default pubkey cancels the proposal
+        new_authority: Pubkey,
+    },
+
+    /// Accepts the role proposed by the admin. The deposit role can only be accepted
+    /// once the vaults are migrated to the vault authority PDA, since it owns them before.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "new_authority", desc = "The proposed authority")]
+    AcceptAuthority {
+        /// The role to be taken over
+        role: AuthorityRole,
+    },
+
+    /// Pauses or resumes operations of the pool
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "pauser", desc = "The pool's guardian or admin")]
+    SetPausedOperations {
+        /// Flags of the operations to be paused, the rest are resumed. See `PoolOperation`,
+        /// unknown flags are ignored.
+        /// Withdrawals and claims might be left open, so users are never trapped.
+        paused_operations: u8,
+    },
+
+    /// Transfers undistributed rewards from the vault back
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, name = "reward_mint", desc = "The address of the reward mint")]
+    #[account(2, writable, name = "vault", desc = "The address of the reward vault")]
+    #[account(3, signer, name = "fill_authority", desc = "The address of the wallet who is responsible for filling pool's vault with rewards")]
+    #[account(4, optional_signer, name = "vault_owner", desc = "The deposit authority, or the vault authority PDA once the vaults are migrated to it")]
+    #[account(5, writable, name = "destination_token_account", desc = "The address of the TA the tokens will be transferred to")]
+    #[account(6, name = "token_program", desc = "The address of the Token program where rewards are minted")]
+    DefundVault {
+        /// Amount to take back, must not exceed the undistributed rewards
+        amount: u64,
+        /// New date the rewards distribution ends at, if it has to be changed
+        distribution_ends_at: Option<u64>,
+    },
+
+    /// Closes the reward pool along with its vaults, sweeping the tokens left in the vaults to the treasury.
+    /// The pool can't be closed till the claimed rewards are withdrawn from the vestings.
+    /// The mint, the vault and the treasury token account of every reward token, in the pool's order,
+    /// are expected after the fixed accounts. The vaults of Token-2022 mints can't hold withheld
+    /// transfer fees, those have to be harvested to the mint first.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    #[account(2, optional_signer, name = "vault_owner", desc = "The deposit authority, or the vault authority PDA once the vaults are migrated to it")]
+    #[account(3, writable, name = "target_account", desc = "The address where lamports of the pool and the vaults will be transferred to")]
+    #[account(4, name = "token_program", desc = "The address of the Token program where rewards are minted")]
+    ClosePool,
+
+    /// Transfers ownership of the pool's vaults from the deposit authority to the vault authority PDA.
+    /// The vault of every reward token, in the pool's order, is expected after the fixed accounts.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    #[account(2, signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs")]
+    #[account(3, name = "vault_authority", desc = "The vault authority PDA the vaults are transferred to")]
+    #[account(4, name = "token_program", desc = "The address of the Token program where rewards are minted")]
+    MigrateVaultAuthority,
+
+    /// Allows or disallows mining owners to claim without the deposit authority's signature
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    ConfigureDirectClaims {
+        /// Whether claims signed by mining owners only are allowed, needs the vaults to be migrated
+        direct_claims: bool,
+    },
+
+    /// Claims the given amount of rewards, leaving the rest accrued.
+    /// The reward pool is writable for the same reasons `Claim` has it writable.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, name = "reward_mint", desc = "The address of the reward mint")]
+    #[account(2, writable, name = "vault", desc = "The address of the reward vault")]
+    #[account(3, writable, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
+    #[account(4, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
+    #[account(5, optional_signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, which is PDA and is responsible for signing CPIs. Doesn't have to sign if direct claims are allowed")]
+    #[account(6, writable, name = "mining_owner_reward_token_account", desc = "ATA where tokens will be claimed to")]
+    #[account(7, name = "token_program", desc = "The address of the Token program where rewards are minted")]
+    #[account(8, optional, name = "vault_authority", desc = "The vault authority PDA, required once the vaults are migrated to it")]
+    #[account(9, optional, writable, name = "vesting", desc = "The vesting account of the mining, required if the pool vests claimed rewards")]
+    ClaimAmount {
+        /// Amount to claim, must not exceed the unclaimed rewards
+        amount: u64,
+    },
+
+    /// Sets the vesting schedule claimed rewards are released with up.
+    /// The rewards claimed before keep the schedule they have been locked with.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    ConfigureVesting {
+        /// Claimed rewards are locked till the cliff passes
+        cliff_secs: u64,
+        /// Claimed rewards vest linearly over the duration, zero disables the vesting
+        duration_secs: u64,
+    },
+
+    /// Initializes vesting account for the specified mining
+    #[account(0, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
+    #[account(2, writable, name = "vesting", desc = "The address of the vesting account of the mining")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, name = "system_program", desc = "The system program")]
+    InitializeVesting,
+
+    /// Transfers the vested rewards to the mining owner
+    #[account(0, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, name = "reward_mint", desc = "The address of the reward mint")]
+    #[account(2, writable, name = "vault", desc = "The address of the reward vault")]
+    #[account(3, writable, name = "vesting", desc = "The address of the vesting account of the mining")]
+    #[account(4, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
+    #[account(5, optional_signer, name = "vault_owner", desc = "The deposit authority, or the vault authority PDA once the vaults are migrated to it")]
+    #[account(6, writable, name = "mining_owner_reward_token_account", desc = "ATA where tokens will be withdrawn to")]
+    #[account(7, name = "token_program", desc = "The address of the Token program where rewards are minted")]
+    WithdrawVested,
+
+    /// Starts charging the delegators of the mining a commission on the rewards of their stake
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, writable, name = "mining", desc = "The address of the delegate mining account")]
+    #[account(2, writable, name = "delegate", desc = "The address of the delegate account of the mining")]
+    #[account(3, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program", desc = "The system program")]
+    InitializeDelegate {
+        /// Part of the delegated stake rewards the delegate takes, in basis points
+        commission_bps: u16,
+    },
+
+    /// Changes the commission the delegate charges its delegators
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, writable, name = "mining", desc = "The address of the delegate mining account")]
+    #[account(2, writable, name = "delegate", desc = "The address of the delegate account of the mining")]
+    #[account(3, signer, name = "mining_owner", desc = "The end user the mining accounts belongs to")]
+    SetDelegateCommission {
+        /// Part of the delegated stake rewards the delegate takes, in basis points
+        commission_bps: u16,
+    },
+
+    /// Initializes delegation account tracking the stake the mining delegates to the delegate.
+    /// The stake the mining has delegated before the delegate started to charge the commission
+    /// is moved under the commission as well, the deposit authority vouches for its amount.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, writable, name = "delegate_mining", desc = "The address of the delegate mining account")]
+    #[account(2, writable, name = "delegate", desc = "The address of the delegate account of the delegate mining")]
+    #[account(3, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
+    #[account(4, writable, name = "delegation", desc = "The address of the delegation of the mining to the delegate")]
+    #[account(5, writable, signer, name = "payer")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    #[account(7, optional, signer, name = "deposit_authority", desc = "The address of the Staking program's Registrar, required if any stake is delegated already")]
+    InitializeDelegation {
+        /// The stake the mining has delegated to the delegate before it started to charge the commission
+        delegated_amount: u64,
+    },
+
+    /// Splits the delegated stake rewards, crediting the commission to the delegate mining
+    /// and the delegator's part to its mining
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, writable, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
+    #[account(2, writable, name = "delegate_mining", desc = "The address of the delegate mining account")]
+    #[account(3, writable, name = "delegate", desc = "The address of the delegate account of the delegate mining")]
+    #[account(4, writable, name = "delegation", desc = "The address of the delegation of the mining to the delegate")]
+    SettleDelegationRewards,
+
+    /// Configures the curve rewards are emitted by over the distribution period
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "admin", desc = "The address of the pool's admin")]
+    ConfigureEmissionCurve {
+        /// The curve every epoch's rewards are calculated by, the pool must distribute by epochs
+        emission_curve: EmissionCurve,
+    },
+
+    /// Returns the rewards pending for the mining as `PendingRewards` through the return data,
+    /// nothing is changed, so the instruction is meant to be simulated
+    #[account(0, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
+    GetPendingRewards,
+
+    /// Migrates the reward pool stored in the legacy layout to the current one. The account
+    /// grows by the permitted increase per instruction, so the instruction is repeated
+    /// in the same transaction until the pool is converted.
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, signer, name = "distribute_authority", desc = "The address of the pool's distribute authority")]
+    #[account(2, writable, signer, name = "payer", desc = "The address paying for the extra rent")]
+    #[account(3, name = "system_program", desc = "The system program")]
+    MigrateRewardPool {
+        /// The admin responsible for the pool configuration from now on
+        admin: Pubkey,
+        /// Number of the pool's minings stored in the legacy layout. They weren't counted,
+        /// so they're counted as open from now on and hold the index history till migrated.
+        legacy_minings: u64,
+    },
+
+    /// Migrates the mining stored in the legacy layout to the current one,
+    /// the reward pool must have been migrated already
+    #[account(0, writable, name = "reward_pool", desc = "The address of the reward pool")]
+    #[account(1, writable, name = "mining", desc = "The address of the mining account which belongs to the user and stores info about user's rewards")]
+    #[account(2, writable, signer, name = "payer", desc = "The address paying for the extra rent")]
+    #[account(3, name = "system_program", desc = "The system program")]
+    MigrateMining,
 }
 
 /// Creates 'InitializePool' instruction.
@@ -179,6 +473,11 @@ pub fn initialize_pool(
     deposit_authority: &Pubkey,
     fill_authority: &Pubkey,
     distribute_authority: &Pubkey,
+    admin: &Pubkey,
+    lockup_tiers: Vec<LockupTier>,
+    epoch_length_secs: u64,
+    epoch_offset_secs: u64,
+    distribution_mode: DistributionMode,
 ) -> Instruction {
     let accounts = vec![
         AccountMeta::new(*reward_pool, false),
@@ -196,11 +495,42 @@ pub fn initialize_pool(
         &RewardsInstruction::InitializePool {
             fill_authority: *fill_authority,
             distribute_authority: *distribute_authority,
+            admin: *admin,
+            lockup_tiers,
+            epoch_length_secs,
+            epoch_offset_secs,
+            distribution_mode,
         },
         accounts,
     )
 }
 
+/// Creates 'AddRewardMint' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn add_reward_mint(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    reward_mint: &Pubkey,
+    vault: &Pubkey,
+    payer: &Pubkey,
+    deposit_authority: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*reward_mint, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*deposit_authority, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(tpl_token::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(*program_id, &RewardsInstruction::AddRewardMint, accounts)
+}
+
 /// Creates 'FillVault' instruction.
 #[allow(clippy::too_many_arguments)]
 pub fn fill_vault(
@@ -331,7 +661,7 @@ pub fn claim(
     mining_owner_reward_token: &Pubkey,
 ) -> Instruction {
     let accounts = vec![
-        AccountMeta::new_readonly(*reward_pool, false),
+        AccountMeta::new(*reward_pool, false),
         AccountMeta::new_readonly(*reward_mint, false),
         AccountMeta::new(*vault, false),
         AccountMeta::new(*mining, false),
@@ -339,6 +669,36 @@ pub fn claim(
         AccountMeta::new_readonly(*deposit_authority, true),
         AccountMeta::new(*mining_owner_reward_token, false),
         AccountMeta::new_readonly(tpl_token::id(), false),
+        AccountMeta::new_readonly(
+            find_vault_authority_program_address(program_id, reward_pool).0,
+            false,
+        ),
+    ];
+
+    Instruction::new_with_borsh(*program_id, &RewardsInstruction::Claim, accounts)
+}
+
+/// Creates 'Claim' instruction signed by the mining owner only.
+pub fn claim_direct(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    reward_mint: &Pubkey,
+    vault: &Pubkey,
+    mining: &Pubkey,
+    mining_owner: &Pubkey,
+    mining_owner_reward_token: &Pubkey,
+) -> Instruction {
+    let (vault_authority, _) = find_vault_authority_program_address(program_id, reward_pool);
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*reward_mint, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*mining, false),
+        AccountMeta::new_readonly(*mining_owner, true),
+        AccountMeta::new_readonly(vault_authority, false),
+        AccountMeta::new(*mining_owner_reward_token, false),
+        AccountMeta::new_readonly(tpl_token::id(), false),
+        AccountMeta::new_readonly(vault_authority, false),
     ];
 
     Instruction::new_with_borsh(*program_id, &RewardsInstruction::Claim, accounts)
@@ -401,6 +761,69 @@ pub fn distribute_rewards(
     )
 }
 
+/// Creates 'Distribute Rewards" instruction crediting the crank bounty to the keeper's mining.
+pub fn distribute_rewards_with_bounty(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    keeper: &Pubkey,
+    keeper_mining: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*keeper, true),
+        AccountMeta::new(*keeper_mining, false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::DistributeRewards,
+        accounts,
+    )
+}
+
+/// Creates 'CompactIndexHistory' instruction.
+pub fn compact_index_history(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    distribute_authority: &Pubkey,
+    compact_before: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*distribute_authority, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::CompactIndexHistory { compact_before },
+        accounts,
+    )
+}
+
+/// Creates 'ResizeMining' instruction.
+pub fn resize_mining(
+    program_id: &Pubkey,
+    mining: &Pubkey,
+    mining_owner: &Pubkey,
+    payer: &Pubkey,
+    modifiers_tree_capacity: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*mining, false),
+        AccountMeta::new_readonly(*mining_owner, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ResizeMining {
+            modifiers_tree_capacity,
+        },
+        accounts,
+    )
+}
+
 /// Creates 'Distribute Rewards" instruction.
 #[allow(clippy::too_many_arguments)]
 pub fn close_mining(
@@ -411,12 +834,14 @@ pub fn close_mining(
     deposit_authority: &Pubkey,
     reward_pool: &Pubkey,
 ) -> Instruction {
+    let (delegate, _) = find_delegate_program_address(program_id, mining);
     let accounts = vec![
         AccountMeta::new(*mining, false),
         AccountMeta::new_readonly(*mining_owner, true),
         AccountMeta::new(*target_account, false),
         AccountMeta::new_readonly(*deposit_authority, true),
-        AccountMeta::new_readonly(*reward_pool, false),
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(delegate, false),
     ];
 
     Instruction::new_with_borsh(*program_id, &RewardsInstruction::CloseMining, accounts)
@@ -506,3 +931,588 @@ pub fn decrease_rewards(
         accounts,
     )
 }
+
+/// Creates 'ConfigureLockupTier' instruction.
+pub fn configure_lockup_tier(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    tier_index: u8,
+    lockup_tier: LockupTier,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ConfigureLockupTier {
+            tier_index,
+            lockup_tier,
+        },
+        accounts,
+    )
+}
+
+/// Creates 'ConfigureDistributionCrank' instruction.
+pub fn configure_distribution_crank(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    permissionless_distribution: bool,
+    crank_bounty: CrankBounty,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ConfigureDistributionCrank {
+            permissionless_distribution,
+            crank_bounty,
+        },
+        accounts,
+    )
+}
+
+/// Creates 'ProposeAuthority' instruction.
+pub fn propose_authority(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    role: AuthorityRole,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ProposeAuthority {
+            role,
+            new_authority: *new_authority,
+        },
+        accounts,
+    )
+}
+
+/// Creates 'AcceptAuthority' instruction.
+pub fn accept_authority(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    new_authority: &Pubkey,
+    role: AuthorityRole,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*new_authority, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::AcceptAuthority { role },
+        accounts,
+    )
+}
+
+/// Creates 'SetPausedOperations' instruction.
+pub fn set_paused_operations(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    pauser: &Pubkey,
+    paused_operations: u8,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*pauser, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::SetPausedOperations { paused_operations },
+        accounts,
+    )
+}
+
+/// Creates 'DefundVault' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn defund_vault(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    reward_mint: &Pubkey,
+    vault: &Pubkey,
+    fill_authority: &Pubkey,
+    vault_owner: &Pubkey,
+    destination_token_account: &Pubkey,
+    amount: u64,
+    distribution_ends_at: Option<u64>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*reward_mint, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(*fill_authority, true),
+        vault_owner_meta(program_id, reward_pool, vault_owner),
+        AccountMeta::new(*destination_token_account, false),
+        AccountMeta::new_readonly(tpl_token::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::DefundVault {
+            amount,
+            distribution_ends_at,
+        },
+        accounts,
+    )
+}
+
+/// Creates 'ClosePool' instruction.
+/// `vaults` are the mint, the vault and the treasury token account of every reward token.
+pub fn close_pool(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    vault_owner: &Pubkey,
+    target_account: &Pubkey,
+    vaults: &[(Pubkey, Pubkey, Pubkey)],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+        vault_owner_meta(program_id, reward_pool, vault_owner),
+        AccountMeta::new(*target_account, false),
+        AccountMeta::new_readonly(tpl_token::id(), false),
+    ];
+    for (mint, vault, treasury) in vaults {
+        accounts.push(AccountMeta::new_readonly(*mint, false));
+        accounts.push(AccountMeta::new(*vault, false));
+        accounts.push(AccountMeta::new(*treasury, false));
+    }
+
+    Instruction::new_with_borsh(*program_id, &RewardsInstruction::ClosePool, accounts)
+}
+
+/// Creates 'MigrateVaultAuthority' instruction.
+pub fn migrate_vault_authority(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    deposit_authority: &Pubkey,
+    vaults: &[Pubkey],
+) -> Instruction {
+    let (vault_authority, _) = find_vault_authority_program_address(program_id, reward_pool);
+    let mut accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*deposit_authority, true),
+        AccountMeta::new_readonly(vault_authority, false),
+        AccountMeta::new_readonly(tpl_token::id(), false),
+    ];
+    accounts.extend(vaults.iter().map(|vault| AccountMeta::new(*vault, false)));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::MigrateVaultAuthority,
+        accounts,
+    )
+}
+
+/// Creates 'ConfigureDirectClaims' instruction.
+pub fn configure_direct_claims(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    direct_claims: bool,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ConfigureDirectClaims { direct_claims },
+        accounts,
+    )
+}
+
+/// The vault owner has to sign unless it's the vault authority PDA
+fn vault_owner_meta(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    vault_owner: &Pubkey,
+) -> AccountMeta {
+    let (vault_authority, _) = find_vault_authority_program_address(program_id, reward_pool);
+    AccountMeta::new_readonly(*vault_owner, *vault_owner != vault_authority)
+}
+
+/// Points the instruction built for the Token program to the given token program,
+/// e.g. to Token-2022 for the pools rewarding in its mints.
+pub fn with_token_program(mut instruction: Instruction, token_program: &Pubkey) -> Instruction {
+    for account in instruction.accounts.iter_mut() {
+        if account.pubkey == tpl_token::id() {
+            account.pubkey = *token_program;
+        }
+    }
+
+    instruction
+}
+
+/// Creates 'ClaimAmount' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_amount(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    reward_mint: &Pubkey,
+    vault: &Pubkey,
+    mining: &Pubkey,
+    mining_owner: &Pubkey,
+    deposit_authority: &Pubkey,
+    mining_owner_reward_token: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*reward_mint, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(*mining, false),
+        AccountMeta::new_readonly(*mining_owner, true),
+        AccountMeta::new_readonly(*deposit_authority, true),
+        AccountMeta::new(*mining_owner_reward_token, false),
+        AccountMeta::new_readonly(tpl_token::id(), false),
+        AccountMeta::new_readonly(
+            find_vault_authority_program_address(program_id, reward_pool).0,
+            false,
+        ),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ClaimAmount { amount },
+        accounts,
+    )
+}
+
+/// Creates 'Claim' instruction for the pool vesting claimed rewards.
+#[allow(clippy::too_many_arguments)]
+pub fn claim_with_vesting(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    reward_mint: &Pubkey,
+    vault: &Pubkey,
+    mining: &Pubkey,
+    mining_owner: &Pubkey,
+    deposit_authority: &Pubkey,
+    mining_owner_reward_token: &Pubkey,
+) -> Instruction {
+    let mut instruction = claim(
+        program_id,
+        reward_pool,
+        reward_mint,
+        vault,
+        mining,
+        mining_owner,
+        deposit_authority,
+        mining_owner_reward_token,
+    );
+    let (vesting, _) = find_vesting_program_address(program_id, mining);
+    instruction.accounts.push(AccountMeta::new(vesting, false));
+
+    instruction
+}
+
+/// Creates 'ConfigureVesting' instruction.
+pub fn configure_vesting(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    cliff_secs: u64,
+    duration_secs: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ConfigureVesting {
+            cliff_secs,
+            duration_secs,
+        },
+        accounts,
+    )
+}
+
+/// Creates 'InitializeVesting' instruction.
+pub fn initialize_vesting(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    mining: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let (vesting, _) = find_vesting_program_address(program_id, mining);
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_pool, false),
+        AccountMeta::new_readonly(*mining, false),
+        AccountMeta::new(vesting, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::InitializeVesting,
+        accounts,
+    )
+}
+
+/// Creates 'WithdrawVested' instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_vested(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    reward_mint: &Pubkey,
+    vault: &Pubkey,
+    mining: &Pubkey,
+    mining_owner: &Pubkey,
+    vault_owner: &Pubkey,
+    mining_owner_reward_token: &Pubkey,
+) -> Instruction {
+    let (vesting, _) = find_vesting_program_address(program_id, mining);
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_pool, false),
+        AccountMeta::new_readonly(*reward_mint, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new(vesting, false),
+        AccountMeta::new_readonly(*mining_owner, true),
+        vault_owner_meta(program_id, reward_pool, vault_owner),
+        AccountMeta::new(*mining_owner_reward_token, false),
+        AccountMeta::new_readonly(tpl_token::id(), false),
+    ];
+
+    Instruction::new_with_borsh(*program_id, &RewardsInstruction::WithdrawVested, accounts)
+}
+
+/// Creates 'InitializeDelegate' instruction.
+pub fn initialize_delegate(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    mining: &Pubkey,
+    mining_owner: &Pubkey,
+    payer: &Pubkey,
+    commission_bps: u16,
+) -> Instruction {
+    let (delegate, _) = find_delegate_program_address(program_id, mining);
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new(*mining, false),
+        AccountMeta::new(delegate, false),
+        AccountMeta::new_readonly(*mining_owner, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::InitializeDelegate { commission_bps },
+        accounts,
+    )
+}
+
+/// Creates 'SetDelegateCommission' instruction.
+pub fn set_delegate_commission(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    mining: &Pubkey,
+    mining_owner: &Pubkey,
+    commission_bps: u16,
+) -> Instruction {
+    let (delegate, _) = find_delegate_program_address(program_id, mining);
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new(*mining, false),
+        AccountMeta::new(delegate, false),
+        AccountMeta::new_readonly(*mining_owner, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::SetDelegateCommission { commission_bps },
+        accounts,
+    )
+}
+
+/// Creates 'InitializeDelegation' instruction.
+pub fn initialize_delegation(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    delegate_mining: &Pubkey,
+    mining: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let (delegate, _) = find_delegate_program_address(program_id, delegate_mining);
+    let (delegation, _) = find_delegation_program_address(program_id, delegate_mining, mining);
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new(*delegate_mining, false),
+        AccountMeta::new(delegate, false),
+        AccountMeta::new_readonly(*mining, false),
+        AccountMeta::new(delegation, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::InitializeDelegation {
+            delegated_amount: 0,
+        },
+        accounts,
+    )
+}
+
+/// Creates 'InitializeDelegation' instruction moving the stake delegated
+/// before the delegate started to charge the commission under it.
+pub fn initialize_delegation_with_stake(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    delegate_mining: &Pubkey,
+    mining: &Pubkey,
+    payer: &Pubkey,
+    deposit_authority: &Pubkey,
+    delegated_amount: u64,
+) -> Instruction {
+    let mut accounts =
+        initialize_delegation(program_id, reward_pool, delegate_mining, mining, payer).accounts;
+    accounts.push(AccountMeta::new_readonly(*deposit_authority, true));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::InitializeDelegation { delegated_amount },
+        accounts,
+    )
+}
+
+/// Creates 'SettleDelegationRewards' instruction.
+pub fn settle_delegation_rewards(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    mining: &Pubkey,
+    delegate_mining: &Pubkey,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new(*mining, false),
+        AccountMeta::new(*delegate_mining, false),
+    ];
+    accounts.extend(delegation_accounts(program_id, delegate_mining, mining));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::SettleDelegationRewards,
+        accounts,
+    )
+}
+
+/// Creates 'ConfigureEmissionCurve' instruction.
+pub fn configure_emission_curve(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    admin: &Pubkey,
+    emission_curve: EmissionCurve,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::ConfigureEmissionCurve { emission_curve },
+        accounts,
+    )
+}
+
+/// Creates 'GetPendingRewards' instruction.
+pub fn get_pending_rewards(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    mining: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*reward_pool, false),
+        AccountMeta::new_readonly(*mining, false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::GetPendingRewards,
+        accounts,
+    )
+}
+
+/// Creates 'MigrateRewardPool' instruction.
+pub fn migrate_reward_pool(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    distribute_authority: &Pubkey,
+    payer: &Pubkey,
+    admin: &Pubkey,
+    legacy_minings: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new_readonly(*distribute_authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &RewardsInstruction::MigrateRewardPool {
+            admin: *admin,
+            legacy_minings,
+        },
+        accounts,
+    )
+}
+
+/// Creates 'MigrateMining' instruction.
+pub fn migrate_mining(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+    mining: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*reward_pool, false),
+        AccountMeta::new(*mining, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    Instruction::new_with_borsh(*program_id, &RewardsInstruction::MigrateMining, accounts)
+}
+
+/// Delegate and delegation accounts to be appended to the instructions changing
+/// the stake delegated to the delegate charging a commission.
+pub fn delegation_accounts(
+    program_id: &Pubkey,
+    delegate_mining: &Pubkey,
+    mining: &Pubkey,
+) -> Vec<AccountMeta> {
+    let (delegate, _) = find_delegate_program_address(program_id, delegate_mining);
+    let (delegation, _) = find_delegation_program_address(program_id, delegate_mining, mining);
+
+    vec![
+        AccountMeta::new(delegate, false),
+        AccountMeta::new(delegation, false),
+    ]
+}
@@ -0,0 +1,40 @@
+use crate::{
+    events::{AcceptAuthorityEvent, RewardsEvent},
+    state::{AuthorityRole, WrappedRewardPool},
+    utils::{get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+pub fn process_accept_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    role: AuthorityRole,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let new_authority = AccountLoader::next_signer(account_info_iter)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+
+    let old_authority = wrapped_reward_pool
+        .pool
+        .accept_authority(role, new_authority.key)?;
+    msg!(
+        "{:?} authority of the pool {} is rotated from {} to {}",
+        role,
+        reward_pool.key,
+        old_authority,
+        new_authority.key
+    );
+
+    RewardsEvent::AcceptAuthority(AcceptAuthorityEvent {
+        reward_pool: *reward_pool.key,
+        role,
+        old_authority,
+        new_authority: *new_authority.key,
+        timestamp: get_curr_unix_ts()?,
+    })
+    .emit()
+}
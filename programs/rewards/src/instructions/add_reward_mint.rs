@@ -0,0 +1,65 @@
+use crate::{
+    asserts::{assert_account_key, assert_account_owner},
+    state::WrappedRewardPool,
+    utils::{create_vault, find_vault_program_address, AccountLoader},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, rent::Rent,
+    system_program, sysvar::SysvarId,
+};
+
+pub fn process_add_reward_mint<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let reward_mint = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let reward_vault = AccountLoader::next_uninitialized(account_info_iter)?;
+    let payer = AccountLoader::next_signer(account_info_iter)?;
+    let vault_owner = AccountLoader::next_unchecked(account_info_iter)?;
+    let admin = AccountLoader::next_signer(account_info_iter)?;
+    let rent = AccountLoader::next_with_key(account_info_iter, &Rent::id())?;
+    let token_program = AccountLoader::next_token_program(account_info_iter)?;
+    let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
+
+    assert_account_owner(reward_mint, token_program.key)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    assert_account_key(admin, &wrapped_reward_pool.pool.admin)?;
+    // the new vault is owned the same way the existing ones are
+    assert_account_key(
+        vault_owner,
+        &wrapped_reward_pool
+            .pool
+            .vault_owner(program_id, reward_pool.key)?,
+    )?;
+
+    let (vault_pubkey, token_account_bump) =
+        find_vault_program_address(program_id, reward_pool.key, reward_mint.key);
+    assert_account_key(reward_vault, &vault_pubkey)?;
+
+    wrapped_reward_pool
+        .pool
+        .add_reward_token(*reward_mint.key, token_account_bump)?;
+
+    let vault_seeds = &[
+        b"vault".as_ref(),
+        reward_pool.key.as_ref(),
+        reward_mint.key.as_ref(),
+        &[token_account_bump],
+    ];
+
+    create_vault(
+        payer.clone(),
+        reward_vault.clone(),
+        reward_mint.clone(),
+        vault_owner.clone(),
+        rent.clone(),
+        &[vault_seeds],
+    )?;
+
+    Ok(())
+}
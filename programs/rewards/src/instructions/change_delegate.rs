@@ -1,7 +1,12 @@
 use crate::{
     asserts::assert_and_get_pool_and_mining,
     error::TrzRewardsError,
-    utils::{get_delegate_mining, verify_delegate_mining_address, AccountLoader},
+    events::{ChangeDelegateEvent, RewardsEvent},
+    state::PoolOperation,
+    utils::{
+        get_curr_unix_ts, get_delegate_mining, get_delegation_accounts,
+        verify_delegate_mining_address, with_delegate_mining, AccountLoader,
+    },
 };
 use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
@@ -36,6 +41,9 @@ pub fn process_change_delegate<'a>(
         reward_pool_data,
         mining_data,
     )?;
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Deposit)?;
 
     let new_delegate_mining = get_delegate_mining(new_delegate_mining, mining)?;
     if let Some(new_delegate_mining) = new_delegate_mining {
@@ -49,12 +57,62 @@ pub fn process_change_delegate<'a>(
 
     let old_delegate_mining = get_delegate_mining(old_delegate_mining, mining)?;
 
-    wrapped_reward_pool.change_delegate(
-        &mut wrapped_mining,
-        new_delegate_mining,
+    let old_delegation_accounts = get_delegation_accounts(
+        account_info_iter,
+        program_id,
         old_delegate_mining,
-        staked_amount,
+        mining.key,
     )?;
+    let new_delegation_accounts = get_delegation_accounts(
+        account_info_iter,
+        program_id,
+        new_delegate_mining,
+        mining.key,
+    )?;
+    let curr_ts = get_curr_unix_ts()?;
+    if old_delegation_accounts.is_some() || new_delegation_accounts.is_some() {
+        wrapped_reward_pool.accrue_rewards(curr_ts)?;
+    }
+    if let Some(old_delegation_accounts) = old_delegation_accounts {
+        old_delegation_accounts.settle_rewards(
+            &wrapped_reward_pool,
+            wrapped_mining.mining,
+            curr_ts,
+        )?;
+        old_delegation_accounts.untrack(staked_amount)?;
+    }
+    if let Some(new_delegation_accounts) = &new_delegation_accounts {
+        new_delegation_accounts.settle_rewards(
+            &wrapped_reward_pool,
+            wrapped_mining.mining,
+            curr_ts,
+        )?;
+    }
+
+    with_delegate_mining(new_delegate_mining, |new_delegate_mining| {
+        with_delegate_mining(old_delegate_mining, |old_delegate_mining| {
+            wrapped_reward_pool.change_delegate(
+                &mut wrapped_mining,
+                new_delegate_mining,
+                old_delegate_mining,
+                staked_amount,
+                curr_ts,
+            )
+        })
+    })?;
 
-    Ok(())
+    if let Some(new_delegation_accounts) = new_delegation_accounts {
+        new_delegation_accounts.track(staked_amount)?;
+    }
+
+    RewardsEvent::ChangeDelegate(ChangeDelegateEvent {
+        reward_pool: *reward_pool.key,
+        mining: *mining.key,
+        old_delegate_mining: old_delegate_mining.map(|delegate_mining| *delegate_mining.key),
+        new_delegate_mining: new_delegate_mining.map(|delegate_mining| *delegate_mining.key),
+        staked_amount,
+        total_share: wrapped_reward_pool.pool.total_share,
+        timestamp: curr_ts,
+    })
+    .emit()
 }
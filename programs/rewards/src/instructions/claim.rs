@@ -1,42 +1,79 @@
 use crate::{
-    asserts::{assert_account_key, assert_account_owner},
-    state::{WrappedMining, WrappedRewardPool},
-    utils::{spl_transfer, AccountLoader},
+    asserts::{assert_account_key, assert_account_owner, assert_signer},
+    events::{ClaimEvent, RewardsEvent},
+    operations,
+    state::{PoolOperation, Vesting, WrappedMining, WrappedRewardPool},
+    utils::{get_curr_unix_ts, unpack_token_account, vault_transfer, AccountLoader},
 };
 use borsh::BorshSerialize;
 use trezoa_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
-    program_pack::Pack, pubkey::Pubkey,
+    program_error::ProgramError, pubkey::Pubkey,
 };
-use tpl_token::state::Account;
 
-pub fn process_claim<'a>(program_id: &Pubkey, accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub fn process_claim<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    amount: Option<u64>,
+) -> ProgramResult {
     let account_info_iter = &mut accounts.iter().enumerate();
 
     let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
-    let reward_mint = AccountLoader::next_with_owner(account_info_iter, &tpl_token::id())?;
-    let vault = AccountLoader::next_with_owner(account_info_iter, &tpl_token::id())?;
+    let reward_mint = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let vault = AccountLoader::next_with_token_program_owner(account_info_iter)?;
     let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
     let mining_owner = AccountLoader::next_signer(account_info_iter)?;
-    let deposit_authority = AccountLoader::next_signer(account_info_iter)?;
+    let deposit_authority = AccountLoader::next_unchecked(account_info_iter)?;
     let mining_owner_reward_token_account =
-        AccountLoader::next_with_owner(account_info_iter, &tpl_token::id())?;
-    let _token_program = AccountLoader::next_with_key(account_info_iter, &tpl_token::id())?;
+        AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let token_program = AccountLoader::next_token_program(account_info_iter)?;
+    let vault_authority = if AccountLoader::has_more(account_info_iter) {
+        Some(AccountLoader::next_unchecked(account_info_iter)?)
+    } else {
+        None
+    };
+    let vesting = if AccountLoader::has_more(account_info_iter) {
+        Some(AccountLoader::next_with_owner(
+            account_info_iter,
+            program_id,
+        )?)
+    } else {
+        None
+    };
 
+    assert_account_owner(reward_mint, token_program.key)?;
+    assert_account_owner(vault, token_program.key)?;
+    assert_account_owner(mining_owner_reward_token_account, token_program.key)?;
     {
-        let mining_user_rewards =
-            Account::unpack(&mining_owner_reward_token_account.data.borrow())?;
+        let mining_user_rewards = unpack_token_account(mining_owner_reward_token_account)?;
         assert_account_key(mining_owner, &mining_user_rewards.owner)?;
     }
 
-    let amount = {
+    let curr_ts = get_curr_unix_ts()?;
+    let (amount, vested, vault_owner, vault_authority_bump) = {
         let reward_pool_data = &mut reward_pool.data.borrow_mut();
-        let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+        let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
 
+        if !wrapped_reward_pool.pool.allows_direct_claims() {
+            assert_signer(deposit_authority)?;
+            assert_account_key(
+                deposit_authority,
+                &wrapped_reward_pool.pool.deposit_authority,
+            )?;
+        }
+        let vault_owner = match wrapped_reward_pool.pool.migrated_vault_authority_bump() {
+            Some(_) => vault_authority.ok_or(ProgramError::NotEnoughAccountKeys)?,
+            None => deposit_authority,
+        };
         assert_account_key(
-            deposit_authority,
-            &wrapped_reward_pool.pool.deposit_authority,
+            vault_owner,
+            &wrapped_reward_pool
+                .pool
+                .vault_owner(program_id, reward_pool.key)?,
         )?;
+        wrapped_reward_pool
+            .pool
+            .assert_not_paused(PoolOperation::Claim)?;
 
         let amount = {
             let mining_data = &mut mining.data.borrow_mut();
@@ -46,36 +83,75 @@ pub fn process_claim<'a>(program_id: &Pubkey, accounts: &'a [AccountInfo<'a>]) -
             assert_account_key(mining_owner, &wrapped_mining.mining.owner)?;
             assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
 
+            let reward_token_index = wrapped_reward_pool
+                .pool
+                .reward_token_index(reward_mint.key)?;
             let vault_seeds = &[
                 b"vault".as_ref(),
                 &reward_pool.key.to_bytes(),
                 &reward_mint.key.to_bytes(),
-                &[wrapped_reward_pool.pool.token_account_bump],
+                &[wrapped_reward_pool.pool.reward_tokens[reward_token_index].token_account_bump],
             ];
             assert_account_key(
                 vault,
                 &Pubkey::create_program_address(vault_seeds, program_id)?,
             )?;
 
-            wrapped_mining.refresh_rewards(&*wrapped_reward_pool.cumulative_index)?;
-            let amount = wrapped_mining.mining.unclaimed_rewards;
-            wrapped_mining.mining.claim();
-            amount
+            // the vesting is only needed if the pool vests the claimed rewards
+            let vesting_data = &mut vesting
+                .filter(|_| wrapped_reward_pool.pool.is_vesting_enabled())
+                .map(|vesting| vesting.data.borrow_mut());
+            let vesting = match vesting_data {
+                Some(vesting_data) => {
+                    let vesting = Vesting::from_bytes_mut(vesting_data)?;
+                    assert_account_key(mining, &vesting.mining)?;
+
+                    Some(vesting)
+                }
+                None => None,
+            };
+
+            operations::claim(
+                &mut wrapped_reward_pool,
+                &mut wrapped_mining,
+                reward_token_index,
+                amount,
+                vesting,
+                curr_ts,
+            )?
         };
 
-        amount
+        (
+            amount,
+            wrapped_reward_pool.pool.is_vesting_enabled(),
+            vault_owner,
+            wrapped_reward_pool.pool.migrated_vault_authority_bump(),
+        )
     };
 
-    if amount > 0 {
-        spl_transfer(
+    // vested rewards stay in the vault till they are withdrawn
+    if amount > 0 && !vested {
+        vault_transfer(
             vault.to_owned(),
+            reward_mint.to_owned(),
             mining_owner_reward_token_account.to_owned(),
-            deposit_authority.to_owned(),
+            vault_owner.to_owned(),
             amount,
-            &[],
+            reward_pool.key,
+            vault_authority_bump,
         )?;
     }
 
+    RewardsEvent::Claim(ClaimEvent {
+        reward_pool: *reward_pool.key,
+        mining: *mining.key,
+        reward_mint: *reward_mint.key,
+        amount,
+        vested,
+        timestamp: curr_ts,
+    })
+    .emit()?;
+
     let mut amount_writer = vec![];
     amount.serialize(&mut amount_writer)?;
     set_return_data(&amount_writer);
@@ -1,8 +1,10 @@
 use crate::{
-    asserts::assert_account_key,
+    asserts::{assert_account_key, assert_account_owner},
     error::TrzRewardsError,
-    state::{WrappedMining, WrappedRewardPool},
-    utils::{AccountLoader, SafeArithmeticOperations},
+    state::{Delegate, PoolOperation, WrappedMining, WrappedRewardPool},
+    utils::{
+        find_delegate_program_address, get_curr_unix_ts, AccountLoader, SafeArithmeticOperations,
+    },
 };
 use trezoa_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, system_program,
@@ -19,27 +21,53 @@ pub fn process_close_mining<'a>(
     let target_account = AccountLoader::next_with_owner(account_info_iter, &system_program::id())?;
     let deposit_authority = AccountLoader::next_signer(account_info_iter)?;
     let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let delegate = AccountLoader::next_unchecked(account_info_iter)?;
+    assert_account_key(
+        delegate,
+        &find_delegate_program_address(program_id, mining.key).0,
+    )?;
 
     {
         let reward_pool_data = &mut reward_pool.data.borrow_mut();
-        let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+        let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
         assert_account_key(
             deposit_authority,
             &wrapped_reward_pool.pool.deposit_authority,
         )?;
+        wrapped_reward_pool
+            .pool
+            .assert_not_paused(PoolOperation::Withdraw)?;
 
         let mining_data = &mut (*mining.data).borrow_mut();
         let mut wrapped_mining = WrappedMining::from_bytes_mut(mining_data)?;
         assert_account_key(mining_owner, &wrapped_mining.mining.owner)?;
+        assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
 
-        wrapped_mining.refresh_rewards(wrapped_reward_pool.cumulative_index)?;
+        let curr_ts = get_curr_unix_ts()?;
+        wrapped_reward_pool.accrue_rewards(curr_ts)?;
+        wrapped_mining.refresh_rewards(
+            wrapped_reward_pool.pool,
+            wrapped_reward_pool.cumulative_index,
+            curr_ts,
+        )?;
 
         if wrapped_mining.mining.stake_from_others > 0 {
             return Err(TrzRewardsError::StakeFromOthersMustBeZero.into());
         }
-        if wrapped_mining.mining.unclaimed_rewards != 0 {
+        // the stake delegated under the commission isn't in the mining's stake from others
+        if wrapped_mining.mining.charges_commission() {
+            assert_account_owner(delegate, program_id)?;
+            let delegate_data = &mut delegate.data.borrow_mut();
+            if Delegate::from_bytes_mut(delegate_data)?.delegated_stake > 0 {
+                return Err(TrzRewardsError::StakeFromOthersMustBeZero.into());
+            }
+        }
+        if wrapped_mining.mining.has_unclaimed_rewards() {
             return Err(TrzRewardsError::RewardsMustBeClaimed.into());
         }
+
+        wrapped_reward_pool.pool.open_minings =
+            wrapped_reward_pool.pool.open_minings.safe_sub(1)?;
     }
 
     // Snippet from trezoa cookbook
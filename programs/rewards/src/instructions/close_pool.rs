@@ -0,0 +1,117 @@
+use crate::{
+    asserts::{assert_account_key, assert_vault_owner},
+    events::{ClosePoolEvent, RewardsEvent, VaultLeftovers},
+    state::WrappedRewardPool,
+    utils::{
+        get_curr_unix_ts, invoke_as_vault_owner, unpack_token_account, vault_transfer,
+        AccountLoader, SafeArithmeticOperations,
+    },
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, system_program,
+};
+
+pub fn process_close_pool<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let admin = AccountLoader::next_signer(account_info_iter)?;
+    let vault_owner = AccountLoader::next_unchecked(account_info_iter)?;
+    let target_account = AccountLoader::next_with_owner(account_info_iter, &system_program::id())?;
+    let _token_program = AccountLoader::next_token_program(account_info_iter)?;
+
+    let curr_ts = get_curr_unix_ts()?;
+    let (reward_tokens, vault_authority_bump) = {
+        let reward_pool_data = &mut reward_pool.data.borrow_mut();
+        let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+
+        assert_account_key(admin, &wrapped_reward_pool.pool.admin)?;
+        assert_vault_owner(
+            program_id,
+            vault_owner,
+            wrapped_reward_pool.pool,
+            reward_pool.key,
+        )?;
+
+        wrapped_reward_pool.accrue_rewards(curr_ts)?;
+        wrapped_reward_pool.pool.assert_closable(curr_ts)?;
+
+        (
+            wrapped_reward_pool.pool.reward_tokens,
+            wrapped_reward_pool.pool.migrated_vault_authority_bump(),
+        )
+    };
+
+    let mut leftovers = Vec::new();
+    for reward_token in reward_tokens.iter().filter(|token| token.is_initialized()) {
+        let mint = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+        let vault = AccountLoader::next_with_owner(account_info_iter, mint.owner)?;
+        let treasury = AccountLoader::next_with_owner(account_info_iter, mint.owner)?;
+        assert_account_key(mint, &reward_token.mint)?;
+
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            reward_pool.key.as_ref(),
+            reward_token.mint.as_ref(),
+            &[reward_token.token_account_bump],
+        ];
+        assert_account_key(
+            vault,
+            &Pubkey::create_program_address(vault_seeds, program_id)?,
+        )?;
+
+        // whatever is left in the vault is either dust or unclaimed after the deadline
+        let amount = unpack_token_account(vault)?.amount;
+        if amount > 0 {
+            vault_transfer(
+                vault.clone(),
+                mint.clone(),
+                treasury.clone(),
+                vault_owner.clone(),
+                amount,
+                reward_pool.key,
+                vault_authority_bump,
+            )?;
+        }
+        leftovers.push(VaultLeftovers {
+            reward_mint: *mint.key,
+            treasury: *treasury.key,
+            amount,
+        });
+
+        let ix = tpl_token_2022::instruction::close_account(
+            mint.owner,
+            vault.key,
+            target_account.key,
+            vault_owner.key,
+            &[],
+        )?;
+        invoke_as_vault_owner(
+            &ix,
+            &[vault.clone(), target_account.clone(), vault_owner.clone()],
+            reward_pool.key,
+            vault_authority_bump,
+        )?;
+    }
+
+    // Snippet from trezoa cookbook
+    // https://trezoacookbook.com/references/accounts.html#how-to-close-accounts
+    let dest_starting_lamports = target_account.lamports();
+
+    **target_account.lamports.borrow_mut() =
+        dest_starting_lamports.safe_add(reward_pool.lamports())?;
+    **reward_pool.lamports.borrow_mut() = 0;
+    let mut source_data = reward_pool.data.borrow_mut();
+    source_data.fill(0);
+
+    RewardsEvent::ClosePool(ClosePoolEvent {
+        reward_pool: *reward_pool.key,
+        leftovers,
+        rent_receiver: *target_account.key,
+        timestamp: curr_ts,
+    })
+    .emit()
+}
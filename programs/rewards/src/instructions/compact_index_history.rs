@@ -0,0 +1,43 @@
+use crate::{
+    asserts::assert_account_key,
+    events::{CompactIndexHistoryEvent, RewardsEvent},
+    state::WrappedRewardPool,
+    utils::{get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+pub fn process_compact_index_history<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    compact_before: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let distribute_authority = AccountLoader::next_signer(account_info_iter)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    assert_account_key(
+        distribute_authority,
+        &wrapped_reward_pool.pool.distribute_authority,
+    )?;
+
+    let removed_records = wrapped_reward_pool.compact_index_history(compact_before)?;
+    msg!("Removed {} index history records", removed_records);
+
+    let curr_ts = get_curr_unix_ts()?;
+    let checkpoint = wrapped_reward_pool.pool.epoch_start(curr_ts);
+    wrapped_reward_pool
+        .pool
+        .start_minings_checkpoint(checkpoint);
+
+    RewardsEvent::CompactIndexHistory(CompactIndexHistoryEvent {
+        reward_pool: *reward_pool.key,
+        compact_before,
+        removed_records: removed_records as u64,
+        pending_minings_checkpoint: wrapped_reward_pool.pool.pending_minings_checkpoint,
+        timestamp: curr_ts,
+    })
+    .emit()
+}
@@ -0,0 +1,24 @@
+use crate::{asserts::assert_account_key, state::WrappedRewardPool, utils::AccountLoader};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub fn process_configure_vesting<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    cliff_secs: u64,
+    duration_secs: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let admin = AccountLoader::next_signer(account_info_iter)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    assert_account_key(admin, &wrapped_reward_pool.pool.admin)?;
+
+    wrapped_reward_pool
+        .pool
+        .configure_vesting(cliff_secs, duration_secs)?;
+
+    Ok(())
+}
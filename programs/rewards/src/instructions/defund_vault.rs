@@ -0,0 +1,108 @@
+use crate::{
+    asserts::{assert_account_key, assert_account_owner, assert_vault_owner},
+    error::TrzRewardsError,
+    events::{DefundVaultEvent, RewardsEvent},
+    state::{PoolOperation, WrappedRewardPool},
+    utils::{get_curr_unix_ts, vault_transfer, AccountLoader},
+};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub fn process_defund_vault<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    amount: u64,
+    distribution_ends_at: Option<u64>,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let reward_mint = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let vault = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let fill_authority = AccountLoader::next_signer(account_info_iter)?;
+    let vault_owner = AccountLoader::next_unchecked(account_info_iter)?;
+    let destination_token_account =
+        AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let token_program = AccountLoader::next_token_program(account_info_iter)?;
+
+    assert_account_owner(reward_mint, token_program.key)?;
+    assert_account_owner(vault, token_program.key)?;
+    assert_account_owner(destination_token_account, token_program.key)?;
+
+    if amount == 0 {
+        return Err(TrzRewardsError::RewardsMustBeGreaterThanZero.into());
+    }
+
+    let (vault_authority_bump, event) = {
+        let reward_pool_data = &mut reward_pool.data.borrow_mut();
+        let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+
+        assert_account_key(fill_authority, &wrapped_reward_pool.pool.fill_authority)?;
+        assert_vault_owner(
+            program_id,
+            vault_owner,
+            wrapped_reward_pool.pool,
+            reward_pool.key,
+        )?;
+        wrapped_reward_pool
+            .pool
+            .assert_not_paused(PoolOperation::Fill)?;
+        let curr_ts = get_curr_unix_ts()?;
+        // rewards streamed till now belong to the minings already
+        wrapped_reward_pool.accrue_rewards(curr_ts)?;
+
+        let reward_token_index = wrapped_reward_pool
+            .pool
+            .reward_token_index(reward_mint.key)?;
+        let distribution_ends_at_epoch_start =
+            distribution_ends_at.map(|ts| wrapped_reward_pool.pool.epoch_start(ts));
+        let beginning_of_the_curr_epoch = wrapped_reward_pool.pool.epoch_start(curr_ts);
+        let reward_token = &mut wrapped_reward_pool.pool.reward_tokens[reward_token_index];
+
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            reward_pool.key.as_ref(),
+            reward_mint.key.as_ref(),
+            &[reward_token.token_account_bump],
+        ];
+        assert_account_key(
+            vault,
+            &Pubkey::create_program_address(vault_seeds, program_id)?,
+        )?;
+
+        reward_token.defund(amount)?;
+
+        if let Some(distribution_ends_at_epoch_start) = distribution_ends_at_epoch_start {
+            if distribution_ends_at_epoch_start < beginning_of_the_curr_epoch {
+                return Err(TrzRewardsError::DistributionInThePast.into());
+            }
+
+            reward_token.distribution_ends_at = distribution_ends_at_epoch_start;
+        }
+
+        let event = DefundVaultEvent {
+            reward_pool: *reward_pool.key,
+            reward_mint: *reward_mint.key,
+            amount,
+            distribution_ends_at: reward_token.distribution_ends_at,
+            tokens_available_for_distribution: reward_token.tokens_available_for_distribution,
+            timestamp: curr_ts,
+        };
+
+        (
+            wrapped_reward_pool.pool.migrated_vault_authority_bump(),
+            event,
+        )
+    };
+
+    vault_transfer(
+        vault.clone(),
+        reward_mint.clone(),
+        destination_token_account.clone(),
+        vault_owner.clone(),
+        amount,
+        reward_pool.key,
+        vault_authority_bump,
+    )?;
+
+    RewardsEvent::DefundVault(event).emit()
+}
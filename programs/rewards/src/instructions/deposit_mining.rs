@@ -1,6 +1,12 @@
 use crate::{
     asserts::assert_and_get_pool_and_mining,
-    utils::{get_delegate_mining, verify_delegate_mining_address, AccountLoader, LockupPeriod},
+    events::{DepositEvent, RewardsEvent},
+    operations,
+    state::PoolOperation,
+    utils::{
+        get_curr_unix_ts, get_delegate_mining, get_delegation_accounts,
+        verify_delegate_mining_address, AccountLoader, LockupPeriod,
+    },
 };
 use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
@@ -32,12 +38,37 @@ pub fn process_deposit_mining<'a>(
         mining_data,
     )?;
 
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Deposit)?;
+
     let delegate_mining = get_delegate_mining(delegate_mining, mining)?;
     if let Some(delegate_mining) = delegate_mining {
         verify_delegate_mining_address(program_id, delegate_mining, delegate, reward_pool.key)?
     }
 
-    wrapped_reward_pool.deposit(&mut wrapped_mining, amount, lockup_period, delegate_mining)?;
+    let curr_ts = get_curr_unix_ts()?;
+    let delegation_accounts =
+        get_delegation_accounts(account_info_iter, program_id, delegate_mining, mining.key)?;
+    operations::deposit_mining(
+        &mut wrapped_reward_pool,
+        &mut wrapped_mining,
+        amount,
+        lockup_period,
+        delegate_mining,
+        delegation_accounts.as_ref(),
+        curr_ts,
+    )?;
 
-    Ok(())
+    RewardsEvent::Deposit(DepositEvent {
+        reward_pool: *reward_pool.key,
+        mining: *mining.key,
+        amount,
+        lockup_period,
+        delegate_mining: delegate_mining.map(|delegate_mining| *delegate_mining.key),
+        share: wrapped_mining.mining.share,
+        total_share: wrapped_reward_pool.pool.total_share,
+        timestamp: curr_ts,
+    })
+    .emit()
 }
@@ -1,4 +1,10 @@
-use crate::{asserts::assert_account_key, state::WrappedRewardPool, utils::AccountLoader};
+use crate::{
+    asserts::assert_account_key,
+    events::{DistributeEvent, RewardsEvent},
+    operations,
+    state::{PoolOperation, WrappedMining, WrappedRewardPool},
+    utils::{get_curr_unix_ts, AccountLoader},
+};
 
 use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
@@ -10,16 +16,68 @@ pub fn process_distribute_rewards<'a>(
 
     let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
     let distribute_authority = AccountLoader::next_signer(account_info_iter)?;
+    let keeper_mining = if AccountLoader::has_more(account_info_iter) {
+        Some(AccountLoader::next_with_owner(
+            account_info_iter,
+            program_id,
+        )?)
+    } else {
+        None
+    };
 
     let reward_pool_data = &mut reward_pool.data.borrow_mut();
     let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
-    let rewards_to_distribute = wrapped_reward_pool.pool.rewards_to_distribute()?;
-    assert_account_key(
-        distribute_authority,
-        &wrapped_reward_pool.pool.distribute_authority,
-    )?;
+    if !wrapped_reward_pool.pool.is_distribution_permissionless() {
+        assert_account_key(
+            distribute_authority,
+            &wrapped_reward_pool.pool.distribute_authority,
+        )?;
+    }
 
-    wrapped_reward_pool.distribute(rewards_to_distribute)?;
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Distribute)?;
+
+    let keeper_mining_data =
+        &mut keeper_mining.map(|keeper_mining| keeper_mining.data.borrow_mut());
+    let keeper_mining = match keeper_mining_data {
+        Some(keeper_mining_data) => {
+            let wrapped_mining = WrappedMining::from_bytes_mut(keeper_mining_data)?;
+            assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
+            assert_account_key(distribute_authority, &wrapped_mining.mining.owner)?;
+
+            Some(wrapped_mining.mining)
+        }
+        None => None,
+    };
+
+    let curr_ts = get_curr_unix_ts()?;
+    let distribution =
+        match operations::distribute_rewards(&mut wrapped_reward_pool, keeper_mining, curr_ts)? {
+            Some(distribution) => distribution,
+            None => return Ok(()),
+        };
+    for epoch in &distribution.epochs {
+        for (reward_token_index, reward_token) in wrapped_reward_pool
+            .pool
+            .reward_tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, reward_token)| reward_token.is_initialized())
+        {
+            RewardsEvent::Distribute(DistributeEvent {
+                reward_pool: *reward_pool.key,
+                reward_mint: reward_token.mint,
+                day: epoch.epoch_start,
+                amount: epoch.rewards[reward_token_index],
+                crank_bounty: epoch.crank_bounties[reward_token_index],
+                total_share: epoch.total_share,
+                index_with_precision: epoch.indexes[reward_token_index],
+                timestamp: curr_ts,
+            })
+            .emit()?;
+        }
+    }
 
     Ok(())
 }
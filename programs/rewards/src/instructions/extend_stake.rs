@@ -1,6 +1,11 @@
 use crate::{
     asserts::assert_and_get_pool_and_mining,
-    utils::{get_delegate_mining, verify_delegate_mining_address, AccountLoader, LockupPeriod},
+    operations,
+    state::PoolOperation,
+    utils::{
+        get_curr_unix_ts, get_delegate_mining, get_delegation_accounts,
+        verify_delegate_mining_address, AccountLoader, LockupPeriod,
+    },
 };
 use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
@@ -36,13 +41,21 @@ pub fn process_extend_stake<'a>(
         mining_data,
     )?;
 
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Deposit)?;
+
     let delegate_mining = get_delegate_mining(delegate_mining, mining)?;
 
     if let Some(delegate_mining) = delegate_mining {
         verify_delegate_mining_address(program_id, delegate_mining, delegate, reward_pool.key)?
     }
 
-    wrapped_reward_pool.extend(
+    let curr_ts = get_curr_unix_ts()?;
+    let delegation_accounts =
+        get_delegation_accounts(account_info_iter, program_id, delegate_mining, mining.key)?;
+    operations::extend_stake(
+        &mut wrapped_reward_pool,
         &mut wrapped_mining,
         old_lockup_period,
         new_lockup_period,
@@ -50,7 +63,7 @@ pub fn process_extend_stake<'a>(
         base_amount,
         additional_amount,
         delegate_mining,
-    )?;
-
-    Ok(())
+        delegation_accounts.as_ref(),
+        curr_ts,
+    )
 }
@@ -1,12 +1,15 @@
 use crate::{
-    asserts::assert_account_key,
+    asserts::{assert_account_key, assert_account_owner},
     error::TrzRewardsError,
-    state::WrappedRewardPool,
-    utils::{get_curr_unix_ts, spl_transfer, AccountLoader, SafeArithmeticOperations},
-};
-use trezoa_program::{
-    account_info::AccountInfo, clock::SECONDS_PER_DAY, entrypoint::ProgramResult, pubkey::Pubkey,
+    events::{FillVaultEvent, RewardsEvent},
+    operations,
+    state::{PoolOperation, WrappedRewardPool},
+    utils::{
+        get_curr_unix_ts, spl_transfer, unpack_token_account, AccountLoader,
+        SafeArithmeticOperations,
+    },
 };
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 pub fn process_fill_vault<'a>(
     program_id: &Pubkey,
@@ -17,27 +20,37 @@ pub fn process_fill_vault<'a>(
     let account_info_iter = &mut accounts.iter().enumerate();
 
     let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
-    let reward_mint = AccountLoader::next_with_owner(account_info_iter, &tpl_token::id())?;
-    let vault = AccountLoader::next_with_owner(account_info_iter, &tpl_token::id())?;
+    let reward_mint = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let vault = AccountLoader::next_with_token_program_owner(account_info_iter)?;
     let fill_authority = AccountLoader::next_signer(account_info_iter)?;
-    let source_token_account = AccountLoader::next_with_owner(account_info_iter, &tpl_token::id())?;
-    let _token_program = AccountLoader::next_with_key(account_info_iter, &tpl_token::id())?;
+    let source_token_account = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let token_program = AccountLoader::next_token_program(account_info_iter)?;
+
+    assert_account_owner(reward_mint, token_program.key)?;
+    assert_account_owner(vault, token_program.key)?;
+    assert_account_owner(source_token_account, token_program.key)?;
 
     if rewards == 0 {
         return Err(TrzRewardsError::RewardsMustBeGreaterThanZero.into());
     }
 
     let reward_pool_data = &mut reward_pool.data.borrow_mut();
-    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
 
     assert_account_key(fill_authority, &wrapped_reward_pool.pool.fill_authority)?;
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Fill)?;
 
+    let reward_token_index = wrapped_reward_pool
+        .pool
+        .reward_token_index(reward_mint.key)?;
     {
         let vault_seeds = &[
             b"vault".as_ref(),
             reward_pool.key.as_ref(),
             reward_mint.key.as_ref(),
-            &[wrapped_reward_pool.pool.token_account_bump],
+            &[wrapped_reward_pool.pool.reward_tokens[reward_token_index].token_account_bump],
         ];
         assert_account_key(
             vault,
@@ -45,37 +58,37 @@ pub fn process_fill_vault<'a>(
         )?;
     }
 
-    {
-        // beginning of the day where distribution_ends_at
-        let distribution_ends_at_day_start =
-            distribution_ends_at - (distribution_ends_at % SECONDS_PER_DAY);
-        let curr_ts = get_curr_unix_ts();
-        let beginning_of_the_curr_day = curr_ts - (curr_ts % SECONDS_PER_DAY);
-        if distribution_ends_at_day_start < beginning_of_the_curr_day {
-            return Err(TrzRewardsError::DistributionInThePast.into());
-        }
-
-        let days_diff = distribution_ends_at_day_start
-            .safe_sub(wrapped_reward_pool.pool.distribution_ends_at)?;
-
-        wrapped_reward_pool.pool.distribution_ends_at = wrapped_reward_pool
-            .pool
-            .distribution_ends_at
-            .safe_add(days_diff)?;
-
-        wrapped_reward_pool.pool.tokens_available_for_distribution = wrapped_reward_pool
-            .pool
-            .tokens_available_for_distribution
-            .safe_add(rewards)?;
-    }
-
+    let vault_amount_before = unpack_token_account(vault)?.amount;
     spl_transfer(
         source_token_account.clone(),
+        reward_mint.clone(),
         vault.clone(),
         fill_authority.clone(),
         rewards,
         &[],
     )?;
+    // under the transfer fee extension the vault receives less than it's sent
+    let received_rewards = unpack_token_account(vault)?
+        .amount
+        .safe_sub(vault_amount_before)?;
+
+    let curr_ts = get_curr_unix_ts()?;
+    operations::fill_vault(
+        &mut wrapped_reward_pool,
+        reward_token_index,
+        received_rewards,
+        distribution_ends_at,
+        curr_ts,
+    )?;
+    let reward_token = &wrapped_reward_pool.pool.reward_tokens[reward_token_index];
 
-    Ok(())
+    RewardsEvent::FillVault(FillVaultEvent {
+        reward_pool: *reward_pool.key,
+        reward_mint: *reward_mint.key,
+        amount: received_rewards,
+        distribution_ends_at: reward_token.distribution_ends_at,
+        tokens_available_for_distribution: reward_token.tokens_available_for_distribution,
+        timestamp: curr_ts,
+    })
+    .emit()
 }
@@ -0,0 +1,34 @@
+use crate::{
+    asserts::assert_account_key,
+    state::{WrappedImmutableMining, WrappedImmutableRewardPool},
+    utils::{get_curr_unix_ts, AccountLoader},
+};
+use borsh::BorshSerialize;
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data, pubkey::Pubkey,
+};
+
+pub fn process_get_pending_rewards<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+
+    let reward_pool_data = &reward_pool.data.borrow();
+    let wrapped_reward_pool = WrappedImmutableRewardPool::from_bytes(reward_pool_data)?;
+    let mining_data = &mining.data.borrow();
+    let wrapped_mining = WrappedImmutableMining::from_bytes(mining_data)?;
+    assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
+
+    let pending_rewards =
+        wrapped_mining.pending_rewards(&wrapped_reward_pool, get_curr_unix_ts()?)?;
+
+    let mut pending_rewards_writer = vec![];
+    pending_rewards.serialize(&mut pending_rewards_writer)?;
+    set_return_data(&pending_rewards_writer);
+
+    Ok(())
+}
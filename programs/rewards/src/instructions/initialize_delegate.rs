@@ -0,0 +1,61 @@
+use crate::{
+    asserts::assert_account_key,
+    operations,
+    state::{Delegate, WrappedMining, WrappedRewardPool},
+    utils::{find_delegate_program_address, get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
+    rent::Rent, system_instruction, system_program, sysvar::Sysvar,
+};
+
+pub fn process_initialize_delegate<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    commission_bps: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let delegate = AccountLoader::next_uninitialized(account_info_iter)?;
+    let mining_owner = AccountLoader::next_signer(account_info_iter)?;
+    let payer = AccountLoader::next_signer(account_info_iter)?;
+    let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
+
+    let (pubkey, bump) = find_delegate_program_address(program_id, mining.key);
+    assert_account_key(delegate, &pubkey)?;
+
+    let signers_seeds = &["delegate".as_bytes(), &mining.key.to_bytes(), &[bump]];
+
+    let rent = Rent::get()?;
+    let ix = system_instruction::create_account(
+        payer.key,
+        delegate.key,
+        rent.minimum_balance(Delegate::LEN),
+        Delegate::LEN as u64,
+        program_id,
+    );
+    invoke_signed(&ix, &[payer.clone(), delegate.clone()], &[signers_seeds])?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    let mining_data = &mut mining.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data)?;
+    assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
+    assert_account_key(mining_owner, &wrapped_mining.mining.owner)?;
+
+    let mut new_delegate = Delegate::initialize(*reward_pool.key, *mining.key, bump);
+    operations::initialize_delegate(
+        &mut wrapped_reward_pool,
+        wrapped_mining.mining,
+        &mut new_delegate,
+        commission_bps,
+        get_curr_unix_ts()?,
+    )?;
+
+    let delegate_data = &mut delegate.data.borrow_mut();
+    Delegate::save(delegate_data, new_delegate)?;
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::{
+    asserts::assert_account_key,
+    operations,
+    state::{Delegate, Delegation, WrappedMining, WrappedRewardPool},
+    utils::{find_delegation_program_address, get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+pub fn process_initialize_delegation<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    delegated_amount: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let delegate_mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let delegate = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let delegation = AccountLoader::next_uninitialized(account_info_iter)?;
+    let payer = AccountLoader::next_signer(account_info_iter)?;
+    let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
+    let deposit_authority = if AccountLoader::has_more(account_info_iter) {
+        Some(AccountLoader::next_signer(account_info_iter)?)
+    } else {
+        None
+    };
+
+    {
+        let delegate_data = &mut delegate.data.borrow_mut();
+        let delegate = Delegate::from_bytes_mut(delegate_data)?;
+        assert_account_key(reward_pool, &delegate.reward_pool)?;
+        assert_account_key(delegate_mining, &delegate.mining)?;
+    }
+    {
+        let mining_data = &mut mining.data.borrow_mut();
+        let wrapped_mining = WrappedMining::from_bytes_mut(mining_data)?;
+        assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
+    }
+
+    let (pubkey, bump) =
+        find_delegation_program_address(program_id, delegate_mining.key, mining.key);
+    assert_account_key(delegation, &pubkey)?;
+
+    let signers_seeds = &[
+        "delegation".as_bytes(),
+        &delegate_mining.key.to_bytes(),
+        &mining.key.to_bytes(),
+        &[bump],
+    ];
+
+    let rent = Rent::get()?;
+    let ix = system_instruction::create_account(
+        payer.key,
+        delegation.key,
+        rent.minimum_balance(Delegation::LEN),
+        Delegation::LEN as u64,
+        program_id,
+    );
+    invoke_signed(&ix, &[payer.clone(), delegation.clone()], &[signers_seeds])?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    // only the deposit authority knows how much has been delegated before the commission
+    if delegated_amount > 0 {
+        let deposit_authority = deposit_authority.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        assert_account_key(
+            deposit_authority,
+            &wrapped_reward_pool.pool.deposit_authority,
+        )?;
+    }
+
+    let delegate_mining_data = &mut delegate_mining.data.borrow_mut();
+    let mut wrapped_delegate_mining = WrappedMining::from_bytes_mut(delegate_mining_data)?;
+    let delegate_data = &mut delegate.data.borrow_mut();
+    let delegate = Delegate::from_bytes_mut(delegate_data)?;
+    let mut new_delegation = Delegation::initialize(
+        *reward_pool.key,
+        *delegate_mining.key,
+        *mining.key,
+        delegate,
+        bump,
+    );
+    operations::initialize_delegation(
+        &mut wrapped_reward_pool,
+        &mut wrapped_delegate_mining,
+        delegate,
+        &mut new_delegation,
+        delegated_amount,
+        get_curr_unix_ts()?,
+    )?;
+
+    let delegation_data = &mut delegation.data.borrow_mut();
+    Delegation::save(delegation_data, new_delegation)?;
+
+    Ok(())
+}
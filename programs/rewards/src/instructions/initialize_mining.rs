@@ -1,6 +1,6 @@
 use crate::{
     asserts::assert_account_key,
-    state::{Mining, WrappedMining},
+    state::{Mining, WrappedMining, WrappedRewardPool},
     utils::{find_mining_program_address, AccountLoader},
 };
 use trezoa_program::{
@@ -40,11 +40,13 @@ pub fn process_initialize_mining<'a>(
     );
     invoke_signed(&ix, &[payer.clone(), mining.clone()], &[signers_seeds])?;
 
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    let mut new_mining = Mining::initialize(*reward_pool.key, *mining_owner, bump);
+    wrapped_reward_pool.pool.open_mining(&mut new_mining)?;
+
     let mining_data = &mut mining.data.borrow_mut();
-    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data)?;
-    let mining = Mining::initialize(*reward_pool.key, *mining_owner, bump);
-    *wrapped_mining.mining = mining;
-    wrapped_mining.weighted_stake_diffs.initialize();
+    WrappedMining::initialize(mining_data, new_mining)?;
 
     Ok(())
 }
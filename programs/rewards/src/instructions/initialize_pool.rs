@@ -1,33 +1,38 @@
 use crate::{
     asserts::{assert_account_key, assert_account_len, assert_account_owner},
     error::TrzRewardsError,
-    state::{RewardPool, WrappedRewardPool},
-    utils::{create_account, find_vault_program_address, initialize_account, AccountLoader},
+    state::{DistributionMode, LockupTier, RewardPool, WrappedRewardPool},
+    utils::{create_vault, find_vault_program_address, AccountLoader},
 };
 use trezoa_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_pack::IsInitialized,
-    pubkey::Pubkey, rent::Rent, system_program, sysvar::SysvarId,
+    account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey, rent::Rent,
+    system_program, sysvar::SysvarId,
 };
-use tpl_token::state::Account as SplTokenAccount;
 
 pub fn process_initialize_pool<'a>(
     program_id: &Pubkey,
     accounts: &'a [AccountInfo<'a>],
     fill_authority: Pubkey,
     distribute_authority: Pubkey,
+    admin: Pubkey,
+    lockup_tiers: &[LockupTier],
+    epoch_length_secs: u64,
+    epoch_offset_secs: u64,
+    distribution_mode: DistributionMode,
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter().enumerate();
 
     let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
-    let reward_mint = AccountLoader::next_with_owner(account_info_iter, &tpl_token::id())?;
+    let reward_mint = AccountLoader::next_with_token_program_owner(account_info_iter)?;
     let reward_vault = AccountLoader::next_uninitialized(account_info_iter)?;
     let payer = AccountLoader::next_signer(account_info_iter)?;
     let deposit_authority = AccountLoader::next_signer(account_info_iter)?;
     let rent = AccountLoader::next_with_key(account_info_iter, &Rent::id())?;
-    let _token_program = AccountLoader::next_with_key(account_info_iter, &tpl_token::id())?;
+    let token_program = AccountLoader::next_token_program(account_info_iter)?;
     let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
 
     assert_account_owner(reward_pool, program_id)?;
+    assert_account_owner(reward_mint, token_program.key)?;
     assert_account_len(reward_pool, WrappedRewardPool::LEN)?;
 
     let reward_pool_data = &mut reward_pool.data.borrow_mut();
@@ -46,17 +51,13 @@ pub fn process_initialize_pool<'a>(
         &[token_account_bump],
     ];
 
-    create_account::<SplTokenAccount>(
-        &tpl_token::id(),
+    create_vault(
         payer.clone(),
-        reward_vault.clone(),
-        &[vault_seeds],
-    )?;
-    initialize_account(
         reward_vault.clone(),
         reward_mint.clone(),
         deposit_authority.clone(),
         rent.clone(),
+        &[vault_seeds],
     )?;
 
     let pool = RewardPool::initialize(
@@ -65,7 +66,12 @@ pub fn process_initialize_pool<'a>(
         distribute_authority,
         fill_authority,
         *reward_mint.key,
-    );
+        admin,
+        lockup_tiers,
+        epoch_length_secs,
+        epoch_offset_secs,
+        distribution_mode,
+    )?;
 
     *wrapped_reward_pool.pool = pool;
     wrapped_reward_pool.weighted_stake_diffs.initialize();
@@ -0,0 +1,51 @@
+use crate::{
+    asserts::assert_account_key,
+    state::{Vesting, WrappedMining},
+    utils::{find_vesting_program_address, AccountLoader},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
+    rent::Rent, system_instruction, system_program, sysvar::Sysvar,
+};
+
+pub fn process_initialize_vesting<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let vesting = AccountLoader::next_uninitialized(account_info_iter)?;
+    let payer = AccountLoader::next_signer(account_info_iter)?;
+    let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
+
+    let mining_owner = {
+        let mining_data = &mut mining.data.borrow_mut();
+        let wrapped_mining = WrappedMining::from_bytes_mut(mining_data)?;
+        assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
+
+        wrapped_mining.mining.owner
+    };
+
+    let (pubkey, bump) = find_vesting_program_address(program_id, mining.key);
+    assert_account_key(vesting, &pubkey)?;
+
+    let signers_seeds = &["vesting".as_bytes(), &mining.key.to_bytes(), &[bump]];
+
+    let rent = Rent::get()?;
+    let ix = system_instruction::create_account(
+        payer.key,
+        vesting.key,
+        rent.minimum_balance(Vesting::LEN),
+        Vesting::LEN as u64,
+        program_id,
+    );
+    invoke_signed(&ix, &[payer.clone(), vesting.clone()], &[signers_seeds])?;
+
+    let vesting_data = &mut vesting.data.borrow_mut();
+    let vesting = Vesting::initialize(*reward_pool.key, *mining.key, mining_owner, bump);
+    Vesting::save(vesting_data, vesting)?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::{
+    asserts::assert_account_key,
+    state::{LegacyMining, WrappedMining, WrappedRewardPool},
+    utils::{get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke, pubkey::Pubkey,
+    rent::Rent, system_instruction, system_program, sysvar::Sysvar,
+};
+
+pub fn process_migrate_mining<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let payer = AccountLoader::next_signer(account_info_iter)?;
+    let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
+
+    {
+        let mining_data = &mining.data.borrow();
+        let legacy_mining = LegacyMining::from_account_data(mining_data)?;
+        assert_account_key(reward_pool, &legacy_mining.reward_pool)?;
+    }
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    // the pool has to be migrated first
+    let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+
+    let rent = Rent::get()?;
+    let extra_rent = rent
+        .minimum_balance(WrappedMining::LEN)
+        .saturating_sub(mining.lamports());
+    if extra_rent > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, mining.key, extra_rent),
+            &[payer.clone(), mining.clone()],
+        )?;
+    }
+    mining.realloc(WrappedMining::LEN, false)?;
+
+    let mining_data = &mut mining.data.borrow_mut();
+    let mut wrapped_mining = WrappedMining::migrate_legacy(mining_data)?;
+
+    // the legacy minings are counted since the pool's migration, the mining is refreshed
+    // to be up to date with the minings checkpoint
+    let curr_ts = get_curr_unix_ts()?;
+    wrapped_reward_pool.accrue_rewards(curr_ts)?;
+    wrapped_mining.refresh_rewards(
+        wrapped_reward_pool.pool,
+        wrapped_reward_pool.cumulative_index,
+        curr_ts,
+    )?;
+
+    Ok(())
+}
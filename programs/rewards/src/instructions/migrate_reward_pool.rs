@@ -0,0 +1,53 @@
+use crate::{
+    asserts::assert_account_key,
+    state::{LegacyRewardPool, WrappedRewardPool},
+    utils::AccountLoader,
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    program::invoke, pubkey::Pubkey, rent::Rent, system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+pub fn process_migrate_reward_pool<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    admin: Pubkey,
+    legacy_minings: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let distribute_authority = AccountLoader::next_signer(account_info_iter)?;
+    let payer = AccountLoader::next_signer(account_info_iter)?;
+    let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
+
+    {
+        let reward_pool_data = &reward_pool.data.borrow();
+        let legacy_pool = LegacyRewardPool::from_account_data(reward_pool_data)?;
+        assert_account_key(distribute_authority, &legacy_pool.distribute_authority)?;
+    }
+
+    // the account can't grow by more than the permitted increase in a single instruction,
+    // so the pool is converted once the instructions have grown it to the current layout
+    let reward_pool_len =
+        WrappedRewardPool::LEN.min(reward_pool.data_len() + MAX_PERMITTED_DATA_INCREASE);
+    let rent = Rent::get()?;
+    let extra_rent = rent
+        .minimum_balance(reward_pool_len)
+        .saturating_sub(reward_pool.lamports());
+    if extra_rent > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, reward_pool.key, extra_rent),
+            &[payer.clone(), reward_pool.clone()],
+        )?;
+    }
+    reward_pool.realloc(reward_pool_len, false)?;
+
+    if reward_pool_len == WrappedRewardPool::LEN {
+        let reward_pool_data = &mut reward_pool.data.borrow_mut();
+        WrappedRewardPool::migrate_legacy(reward_pool_data, admin, legacy_minings)?;
+    }
+
+    Ok(())
+}
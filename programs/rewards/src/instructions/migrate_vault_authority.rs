@@ -0,0 +1,74 @@
+use crate::{
+    asserts::assert_account_key,
+    state::WrappedRewardPool,
+    utils::{find_vault_authority_program_address, AccountLoader},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke, pubkey::Pubkey,
+};
+use tpl_token_2022::instruction::AuthorityType;
+
+pub fn process_migrate_vault_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let admin = AccountLoader::next_signer(account_info_iter)?;
+    let deposit_authority = AccountLoader::next_signer(account_info_iter)?;
+    let vault_authority = AccountLoader::next_unchecked(account_info_iter)?;
+    let _token_program = AccountLoader::next_token_program(account_info_iter)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    assert_account_key(admin, &wrapped_reward_pool.pool.admin)?;
+    assert_account_key(
+        deposit_authority,
+        &wrapped_reward_pool.pool.deposit_authority,
+    )?;
+
+    let (vault_authority_pubkey, vault_authority_bump) =
+        find_vault_authority_program_address(program_id, reward_pool.key);
+    assert_account_key(vault_authority, &vault_authority_pubkey)?;
+
+    for reward_token in wrapped_reward_pool
+        .pool
+        .reward_tokens
+        .iter()
+        .filter(|reward_token| reward_token.is_initialized())
+    {
+        let vault = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            reward_pool.key.as_ref(),
+            reward_token.mint.as_ref(),
+            &[reward_token.token_account_bump],
+        ];
+        assert_account_key(
+            vault,
+            &Pubkey::create_program_address(vault_seeds, program_id)?,
+        )?;
+
+        let ix = tpl_token_2022::instruction::set_authority(
+            vault.owner,
+            vault.key,
+            Some(vault_authority.key),
+            AuthorityType::AccountOwner,
+            deposit_authority.key,
+            &[],
+        )?;
+        invoke(&ix, &[vault.clone(), deposit_authority.clone()])?;
+    }
+
+    wrapped_reward_pool.pool.vault_authority_migrated = 1;
+    wrapped_reward_pool.pool.vault_authority_bump = vault_authority_bump;
+    msg!(
+        "Vaults of the pool {} are migrated to {}",
+        reward_pool.key,
+        vault_authority.key
+    );
+
+    Ok(())
+}
@@ -3,29 +3,75 @@ use crate::instruction::RewardsInstruction;
 use borsh::BorshDeserialize;
 use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
+mod accept_authority;
+mod add_reward_mint;
 mod change_delegate;
 mod claim;
 mod close_mining;
+mod close_pool;
+mod compact_index_history;
+mod configure_direct_claims;
+mod configure_distribution_crank;
+mod configure_emission_curve;
+mod configure_lockup_tier;
+mod configure_vesting;
+mod defund_vault;
 mod deposit_mining;
 mod distribute_rewards;
 mod extend_stake;
 mod fill_vault;
+mod get_pending_rewards;
+mod initialize_delegate;
+mod initialize_delegation;
 mod initialize_mining;
 mod initialize_pool;
+mod initialize_vesting;
+mod migrate_mining;
+mod migrate_reward_pool;
+mod migrate_vault_authority;
 mod penalties;
+mod propose_authority;
+mod resize_mining;
+mod set_delegate_commission;
+mod set_paused_operations;
+mod settle_delegation_rewards;
 mod withdraw_mining;
+mod withdraw_vested;
 
+pub(crate) use accept_authority::*;
+pub(crate) use add_reward_mint::*;
 pub(crate) use change_delegate::*;
 pub(crate) use claim::*;
 pub(crate) use close_mining::*;
+pub(crate) use close_pool::*;
+pub(crate) use compact_index_history::*;
+pub(crate) use configure_direct_claims::*;
+pub(crate) use configure_distribution_crank::*;
+pub(crate) use configure_emission_curve::*;
+pub(crate) use configure_lockup_tier::*;
+pub(crate) use configure_vesting::*;
+pub(crate) use defund_vault::*;
 pub(crate) use deposit_mining::*;
 pub(crate) use distribute_rewards::*;
 pub(crate) use extend_stake::*;
 pub(crate) use fill_vault::*;
+pub(crate) use get_pending_rewards::*;
+pub(crate) use initialize_delegate::*;
+pub(crate) use initialize_delegation::*;
 pub(crate) use initialize_mining::*;
 pub(crate) use initialize_pool::*;
+pub(crate) use initialize_vesting::*;
+pub(crate) use migrate_mining::*;
+pub(crate) use migrate_reward_pool::*;
+pub(crate) use migrate_vault_authority::*;
 pub(crate) use penalties::*;
+pub(crate) use propose_authority::*;
+pub(crate) use resize_mining::*;
+pub(crate) use set_delegate_commission::*;
+pub(crate) use set_paused_operations::*;
+pub(crate) use settle_delegation_rewards::*;
 pub(crate) use withdraw_mining::*;
+pub(crate) use withdraw_vested::*;
 
 pub fn process_instruction<'a>(
     program_id: &Pubkey,
@@ -38,9 +84,24 @@ pub fn process_instruction<'a>(
         RewardsInstruction::InitializePool {
             fill_authority,
             distribute_authority,
+            admin,
+            lockup_tiers,
+            epoch_length_secs,
+            epoch_offset_secs,
+            distribution_mode,
         } => {
             msg!("RewardsInstruction: InitializePool");
-            process_initialize_pool(program_id, accounts, fill_authority, distribute_authority)
+            process_initialize_pool(
+                program_id,
+                accounts,
+                fill_authority,
+                distribute_authority,
+                admin,
+                &lockup_tiers,
+                epoch_length_secs,
+                epoch_offset_secs,
+                distribution_mode,
+            )
         }
         RewardsInstruction::FillVault {
             rewards,
@@ -79,7 +140,7 @@ pub fn process_instruction<'a>(
         }
         RewardsInstruction::Claim => {
             msg!("RewardsInstruction: Claim");
-            process_claim(program_id, accounts)
+            process_claim(program_id, accounts, None)
         }
         RewardsInstruction::ExtendStake {
             old_lockup_period,
@@ -104,11 +165,11 @@ pub fn process_instruction<'a>(
             )
         }
         RewardsInstruction::DistributeRewards => {
-            msg!("RewardsInstruction: FillVault");
+            msg!("RewardsInstruction: DistributeRewards");
             process_distribute_rewards(program_id, accounts)
         }
         RewardsInstruction::CloseMining => {
-            msg!("RewardsInstruction: CloseAccount");
+            msg!("RewardsInstruction: CloseMining");
             process_close_mining(program_id, accounts)
         }
         RewardsInstruction::ChangeDelegate {
@@ -146,5 +207,126 @@ pub fn process_instruction<'a>(
                 decreased_weighted_stake_number,
             )
         }
+        RewardsInstruction::ConfigureLockupTier {
+            tier_index,
+            lockup_tier,
+        } => {
+            msg!("RewardsInstruction: ConfigureLockupTier");
+            process_configure_lockup_tier(program_id, accounts, tier_index, lockup_tier)
+        }
+        RewardsInstruction::AddRewardMint => {
+            msg!("RewardsInstruction: AddRewardMint");
+            process_add_reward_mint(program_id, accounts)
+        }
+        RewardsInstruction::CompactIndexHistory { compact_before } => {
+            msg!("RewardsInstruction: CompactIndexHistory");
+            process_compact_index_history(program_id, accounts, compact_before)
+        }
+        RewardsInstruction::ResizeMining {
+            modifiers_tree_capacity,
+        } => {
+            msg!("RewardsInstruction: ResizeMining");
+            process_resize_mining(program_id, accounts, modifiers_tree_capacity)
+        }
+        RewardsInstruction::ConfigureDistributionCrank {
+            permissionless_distribution,
+            crank_bounty,
+        } => {
+            msg!("RewardsInstruction: ConfigureDistributionCrank");
+            process_configure_distribution_crank(
+                program_id,
+                accounts,
+                permissionless_distribution,
+                crank_bounty,
+            )
+        }
+        RewardsInstruction::ProposeAuthority {
+            role,
+            new_authority,
+        } => {
+            msg!("RewardsInstruction: ProposeAuthority");
+            process_propose_authority(program_id, accounts, role, new_authority)
+        }
+        RewardsInstruction::AcceptAuthority { role } => {
+            msg!("RewardsInstruction: AcceptAuthority");
+            process_accept_authority(program_id, accounts, role)
+        }
+        RewardsInstruction::SetPausedOperations { paused_operations } => {
+            msg!("RewardsInstruction: SetPausedOperations");
+            process_set_paused_operations(program_id, accounts, paused_operations)
+        }
+        RewardsInstruction::DefundVault {
+            amount,
+            distribution_ends_at,
+        } => {
+            msg!("RewardsInstruction: DefundVault");
+            process_defund_vault(program_id, accounts, amount, distribution_ends_at)
+        }
+        RewardsInstruction::ClosePool => {
+            msg!("RewardsInstruction: ClosePool");
+            process_close_pool(program_id, accounts)
+        }
+        RewardsInstruction::MigrateVaultAuthority => {
+            msg!("RewardsInstruction: MigrateVaultAuthority");
+            process_migrate_vault_authority(program_id, accounts)
+        }
+        RewardsInstruction::ConfigureDirectClaims { direct_claims } => {
+            msg!("RewardsInstruction: ConfigureDirectClaims");
+            process_configure_direct_claims(program_id, accounts, direct_claims)
+        }
+        RewardsInstruction::ClaimAmount { amount } => {
+            msg!("RewardsInstruction: ClaimAmount");
+            process_claim(program_id, accounts, Some(amount))
+        }
+        RewardsInstruction::ConfigureVesting {
+            cliff_secs,
+            duration_secs,
+        } => {
+            msg!("RewardsInstruction: ConfigureVesting");
+            process_configure_vesting(program_id, accounts, cliff_secs, duration_secs)
+        }
+        RewardsInstruction::InitializeVesting => {
+            msg!("RewardsInstruction: InitializeVesting");
+            process_initialize_vesting(program_id, accounts)
+        }
+        RewardsInstruction::WithdrawVested => {
+            msg!("RewardsInstruction: WithdrawVested");
+            process_withdraw_vested(program_id, accounts)
+        }
+        RewardsInstruction::InitializeDelegate { commission_bps } => {
+            msg!("RewardsInstruction: InitializeDelegate");
+            process_initialize_delegate(program_id, accounts, commission_bps)
+        }
+        RewardsInstruction::SetDelegateCommission { commission_bps } => {
+            msg!("RewardsInstruction: SetDelegateCommission");
+            process_set_delegate_commission(program_id, accounts, commission_bps)
+        }
+        RewardsInstruction::InitializeDelegation { delegated_amount } => {
+            msg!("RewardsInstruction: InitializeDelegation");
+            process_initialize_delegation(program_id, accounts, delegated_amount)
+        }
+        RewardsInstruction::SettleDelegationRewards => {
+            msg!("RewardsInstruction: SettleDelegationRewards");
+            process_settle_delegation_rewards(program_id, accounts)
+        }
+        RewardsInstruction::ConfigureEmissionCurve { emission_curve } => {
+            msg!("RewardsInstruction: ConfigureEmissionCurve");
+            process_configure_emission_curve(program_id, accounts, emission_curve)
+        }
+        RewardsInstruction::GetPendingRewards => {
+            msg!("RewardsInstruction: GetPendingRewards");
+            process_get_pending_rewards(program_id, accounts)
+        }
+        RewardsInstruction::MigrateRewardPool {
+            admin,
+            legacy_minings,
+        } => {
+            msg!("RewardsInstruction: MigrateRewardPool");
+            process_migrate_reward_pool(program_id, accounts, admin, legacy_minings)
+        }
+        RewardsInstruction::MigrateMining => {
+            msg!("RewardsInstruction: MigrateMining");
+            process_migrate_mining(program_id, accounts)
+        }
     }
 }
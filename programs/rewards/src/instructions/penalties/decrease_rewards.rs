@@ -1,4 +1,9 @@
-use crate::{asserts::assert_and_get_pool_and_mining, utils::AccountLoader};
+use crate::{
+    asserts::assert_and_get_pool_and_mining,
+    events::{DecreaseRewardsEvent, RewardsEvent},
+    state::PoolOperation,
+    utils::{get_curr_unix_ts, AccountLoader},
+};
 use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 pub fn process_decrease_rewards<'a>(
@@ -16,7 +21,7 @@ pub fn process_decrease_rewards<'a>(
     let reward_pool_data = &mut reward_pool.data.borrow_mut();
     let mining_data = &mut mining.data.borrow_mut();
 
-    let (_, mut wrapped_mining) = assert_and_get_pool_and_mining(
+    let (wrapped_reward_pool, mut wrapped_mining) = assert_and_get_pool_and_mining(
         program_id,
         mining_owner,
         mining,
@@ -25,8 +30,18 @@ pub fn process_decrease_rewards<'a>(
         reward_pool_data,
         mining_data,
     )?;
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Claim)?;
 
     wrapped_mining.decrease_rewards(decreased_weighted_stake_number)?;
 
-    Ok(())
+    RewardsEvent::DecreaseRewards(DecreaseRewardsEvent {
+        reward_pool: *reward_pool.key,
+        mining: *mining.key,
+        decreased_weighted_stake_number,
+        share: wrapped_mining.mining.share,
+        timestamp: get_curr_unix_ts()?,
+    })
+    .emit()
 }
@@ -1,4 +1,9 @@
-use crate::{asserts::assert_and_get_pool_and_mining, utils::AccountLoader};
+use crate::{
+    asserts::assert_and_get_pool_and_mining,
+    events::{RewardsEvent, SlashEvent},
+    state::PoolOperation,
+    utils::{get_curr_unix_ts, AccountLoader},
+};
 use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 pub fn process_slash<'a>(
@@ -27,13 +32,28 @@ pub fn process_slash<'a>(
         reward_pool_data,
         mining_data,
     )?;
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Withdraw)?;
 
+    let curr_ts = get_curr_unix_ts()?;
     wrapped_reward_pool.slash(
         &mut wrapped_mining,
         slash_amount_in_native,
         slash_amount_multiplied_by_period,
         stake_expiration_date,
+        curr_ts,
     )?;
 
-    Ok(())
+    RewardsEvent::Slash(SlashEvent {
+        reward_pool: *reward_pool.key,
+        mining: *mining.key,
+        slash_amount_in_native,
+        slash_amount_multiplied_by_period,
+        stake_expiration_date,
+        share: wrapped_mining.mining.share,
+        total_share: wrapped_reward_pool.pool.total_share,
+        timestamp: curr_ts,
+    })
+    .emit()
 }
@@ -0,0 +1,35 @@
+use crate::{
+    asserts::assert_account_key,
+    events::{ProposeAuthorityEvent, RewardsEvent},
+    state::{AuthorityRole, WrappedRewardPool},
+    utils::{get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub fn process_propose_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    role: AuthorityRole,
+    new_authority: Pubkey,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let admin = AccountLoader::next_signer(account_info_iter)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    assert_account_key(admin, &wrapped_reward_pool.pool.admin)?;
+
+    wrapped_reward_pool
+        .pool
+        .propose_authority(role, new_authority);
+
+    RewardsEvent::ProposeAuthority(ProposeAuthorityEvent {
+        reward_pool: *reward_pool.key,
+        role,
+        new_authority,
+        timestamp: get_curr_unix_ts()?,
+    })
+    .emit()
+}
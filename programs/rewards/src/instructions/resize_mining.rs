@@ -0,0 +1,57 @@
+use crate::{
+    asserts::assert_account_key,
+    error::TrzRewardsError,
+    state::{WrappedImmutableMining, WrappedMining},
+    utils::AccountLoader,
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke, pubkey::Pubkey,
+    rent::Rent, system_instruction, system_program, sysvar::Sysvar,
+};
+
+pub fn process_resize_mining<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    modifiers_tree_capacity: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining_owner = AccountLoader::next_signer(account_info_iter)?;
+    let payer = AccountLoader::next_signer(account_info_iter)?;
+    let _system_program = AccountLoader::next_with_key(account_info_iter, &system_program::id())?;
+
+    let weighted_stake_diffs: Vec<(u64, u64)> = {
+        let mining_data = &mining.data.borrow();
+        let wrapped_mining = WrappedImmutableMining::from_bytes(mining_data)?;
+        assert_account_key(mining_owner, &wrapped_mining.mining.owner)?;
+
+        if modifiers_tree_capacity <= wrapped_mining.mining.modifiers_tree_capacity {
+            return Err(TrzRewardsError::InvalidModifiersTreeCapacity.into());
+        }
+
+        wrapped_mining
+            .weighted_stake_diffs
+            .iter()
+            .map(|(date, modifier)| (*date, *modifier))
+            .collect()
+    };
+
+    let mining_len = WrappedMining::len_for_capacity(modifiers_tree_capacity)?;
+    let rent = Rent::get()?;
+    let extra_rent = rent
+        .minimum_balance(mining_len)
+        .saturating_sub(mining.lamports());
+    if extra_rent > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, mining.key, extra_rent),
+            &[payer.clone(), mining.clone()],
+        )?;
+    }
+    mining.realloc(mining_len, false)?;
+
+    let mining_data = &mut mining.data.borrow_mut();
+    WrappedMining::resize(mining_data, modifiers_tree_capacity, &weighted_stake_diffs)?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::{
+    asserts::assert_account_key,
+    operations,
+    state::{Delegate, WrappedMining, WrappedRewardPool},
+    utils::{get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub fn process_set_delegate_commission<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    commission_bps: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let delegate = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining_owner = AccountLoader::next_signer(account_info_iter)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    let mining_data = &mut mining.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data)?;
+    let delegate_data = &mut delegate.data.borrow_mut();
+    let delegate = Delegate::from_bytes_mut(delegate_data)?;
+
+    assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
+    assert_account_key(mining, &delegate.mining)?;
+    assert_account_key(mining_owner, &wrapped_mining.mining.owner)?;
+
+    operations::set_delegate_commission(
+        &mut wrapped_reward_pool,
+        wrapped_mining.mining,
+        delegate,
+        commission_bps,
+        get_curr_unix_ts()?,
+    )
+}
@@ -0,0 +1,46 @@
+use crate::{
+    events::{RewardsEvent, SetPausedOperationsEvent},
+    state::WrappedRewardPool,
+    utils::{get_curr_unix_ts, AccountLoader},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+pub fn process_set_paused_operations<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    paused_operations: u8,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let pauser = AccountLoader::next_signer(account_info_iter)?;
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    if !wrapped_reward_pool.pool.is_pauser(pauser.key) {
+        msg!(
+            "{} is neither the guardian nor the admin of the pool",
+            pauser.key
+        );
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let paused_operations = wrapped_reward_pool
+        .pool
+        .set_paused_operations(paused_operations);
+    msg!(
+        "Paused operations of the pool {} are set to {:#07b}",
+        reward_pool.key,
+        paused_operations
+    );
+
+    RewardsEvent::SetPausedOperations(SetPausedOperationsEvent {
+        reward_pool: *reward_pool.key,
+        paused_operations,
+        timestamp: get_curr_unix_ts()?,
+    })
+    .emit()
+}
@@ -0,0 +1,42 @@
+use crate::{
+    asserts::assert_account_key,
+    operations,
+    state::{WrappedMining, WrappedRewardPool},
+    utils::{get_curr_unix_ts, get_delegation_accounts, AccountLoader},
+};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub fn process_settle_delegation_rewards<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let delegate_mining = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+
+    let delegation_accounts = match get_delegation_accounts(
+        account_info_iter,
+        program_id,
+        Some(delegate_mining),
+        mining.key,
+    )? {
+        Some(delegation_accounts) => delegation_accounts,
+        // the delegate doesn't charge a commission, so the rewards are never split
+        None => return Ok(()),
+    };
+
+    let reward_pool_data = &mut reward_pool.data.borrow_mut();
+    let mut wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+    let mining_data = &mut mining.data.borrow_mut();
+    let mut wrapped_mining = WrappedMining::from_bytes_mut(mining_data)?;
+    assert_account_key(reward_pool, &wrapped_mining.mining.reward_pool)?;
+
+    operations::settle_delegation_rewards(
+        &mut wrapped_reward_pool,
+        &mut wrapped_mining,
+        &delegation_accounts,
+        get_curr_unix_ts()?,
+    )
+}
@@ -1,6 +1,9 @@
 use crate::{
     asserts::assert_and_get_pool_and_mining,
-    utils::{get_delegate_mining, AccountLoader},
+    events::{RewardsEvent, WithdrawEvent},
+    operations,
+    state::PoolOperation,
+    utils::{get_curr_unix_ts, get_delegate_mining, get_delegation_accounts, AccountLoader},
 };
 
 use crate::utils::verify_delegate_mining_address;
@@ -33,12 +36,35 @@ pub fn process_withdraw_mining<'a>(
         mining_data,
     )?;
 
+    wrapped_reward_pool
+        .pool
+        .assert_not_paused(PoolOperation::Withdraw)?;
+
     let delegate_mining = get_delegate_mining(delegate_mining, mining)?;
     if let Some(delegate_mining) = delegate_mining {
         verify_delegate_mining_address(program_id, delegate_mining, delegate, reward_pool.key)?
     }
 
-    wrapped_reward_pool.withdraw(&mut wrapped_mining, amount, delegate_mining)?;
+    let curr_ts = get_curr_unix_ts()?;
+    let delegation_accounts =
+        get_delegation_accounts(account_info_iter, program_id, delegate_mining, mining.key)?;
+    operations::withdraw_mining(
+        &mut wrapped_reward_pool,
+        &mut wrapped_mining,
+        amount,
+        delegate_mining,
+        delegation_accounts.as_ref(),
+        curr_ts,
+    )?;
 
-    Ok(())
+    RewardsEvent::Withdraw(WithdrawEvent {
+        reward_pool: *reward_pool.key,
+        mining: *mining.key,
+        amount,
+        delegate_mining: delegate_mining.map(|delegate_mining| *delegate_mining.key),
+        share: wrapped_mining.mining.share,
+        total_share: wrapped_reward_pool.pool.total_share,
+        timestamp: curr_ts,
+    })
+    .emit()
 }
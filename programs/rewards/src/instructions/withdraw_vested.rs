@@ -0,0 +1,89 @@
+use crate::{
+    asserts::{assert_account_key, assert_account_owner, assert_vault_owner},
+    events::{RewardsEvent, WithdrawVestedEvent},
+    state::{PoolOperation, Vesting, WrappedRewardPool},
+    utils::{
+        get_curr_unix_ts, unpack_token_account, vault_transfer, AccountLoader,
+        SafeArithmeticOperations,
+    },
+};
+use trezoa_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub fn process_withdraw_vested<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter().enumerate();
+
+    let reward_pool = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let reward_mint = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let vault = AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let vesting = AccountLoader::next_with_owner(account_info_iter, program_id)?;
+    let mining_owner = AccountLoader::next_signer(account_info_iter)?;
+    let vault_owner = AccountLoader::next_unchecked(account_info_iter)?;
+    let mining_owner_reward_token_account =
+        AccountLoader::next_with_token_program_owner(account_info_iter)?;
+    let token_program = AccountLoader::next_token_program(account_info_iter)?;
+
+    assert_account_owner(reward_mint, token_program.key)?;
+    assert_account_owner(vault, token_program.key)?;
+    assert_account_owner(mining_owner_reward_token_account, token_program.key)?;
+    {
+        let mining_user_rewards = unpack_token_account(mining_owner_reward_token_account)?;
+        assert_account_key(mining_owner, &mining_user_rewards.owner)?;
+    }
+
+    let curr_ts = get_curr_unix_ts()?;
+    let (amount, vault_authority_bump) = {
+        let reward_pool_data = &mut reward_pool.data.borrow_mut();
+        let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data)?;
+        let pool = wrapped_reward_pool.pool;
+
+        assert_vault_owner(program_id, vault_owner, pool, reward_pool.key)?;
+        pool.assert_not_paused(PoolOperation::Claim)?;
+
+        let reward_token_index = pool.reward_token_index(reward_mint.key)?;
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            reward_pool.key.as_ref(),
+            reward_mint.key.as_ref(),
+            &[pool.reward_tokens[reward_token_index].token_account_bump],
+        ];
+        assert_account_key(
+            vault,
+            &Pubkey::create_program_address(vault_seeds, program_id)?,
+        )?;
+
+        let vesting_data = &mut vesting.data.borrow_mut();
+        let vesting = Vesting::from_bytes_mut(vesting_data)?;
+        assert_account_key(reward_pool, &vesting.reward_pool)?;
+        assert_account_key(mining_owner, &vesting.owner)?;
+
+        let amount = vesting.withdraw(reward_token_index, curr_ts)?;
+        pool.vesting_rewards[reward_token_index] =
+            pool.vesting_rewards[reward_token_index].safe_sub(amount)?;
+
+        (amount, pool.migrated_vault_authority_bump())
+    };
+
+    if amount > 0 {
+        vault_transfer(
+            vault.to_owned(),
+            reward_mint.to_owned(),
+            mining_owner_reward_token_account.to_owned(),
+            vault_owner.to_owned(),
+            amount,
+            reward_pool.key,
+            vault_authority_bump,
+        )?;
+    }
+
+    RewardsEvent::WithdrawVested(WithdrawVestedEvent {
+        reward_pool: *reward_pool.key,
+        vesting: *vesting.key,
+        reward_mint: *reward_mint.key,
+        amount,
+        timestamp: curr_ts,
+    })
+    .emit()
+}
@@ -6,8 +6,10 @@ pub mod asserts;
 #[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
 pub mod error;
+pub mod events;
 pub mod instruction;
 pub mod instructions;
+pub mod operations;
 pub mod state;
 pub mod utils;
 
@@ -0,0 +1,257 @@
+//! State changes the instructions make once their accounts are validated. The processors
+//! and the off-chain simulations share them, so both calculate the very same rewards.
+//! The current time is passed in rather than taken from the clock.
+
+use crate::{
+    state::{
+        Delegate, Delegation, DelegationAccounts, DistributionMode, Distribution, Mining, Vesting,
+        WrappedMining, WrappedRewardPool,
+    },
+    utils::{with_delegate_mining, LockupPeriod, SafeArithmeticOperations},
+};
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+/// Adds the rewards the vault has received to the ones distributed till the given date,
+/// see `FillVault`
+pub fn fill_vault(
+    reward_pool: &mut WrappedRewardPool,
+    reward_token_index: usize,
+    received_rewards: u64,
+    distribution_ends_at: u64,
+    curr_ts: u64,
+) -> ProgramResult {
+    // the streaming rate changes, so the rewards have to be streamed with the old one first
+    reward_pool.accrue_rewards(curr_ts)?;
+    reward_pool
+        .pool
+        .schedule_distribution(reward_token_index, distribution_ends_at, curr_ts)?;
+
+    reward_pool.pool.reward_tokens[reward_token_index].fill(received_rewards)?;
+
+    Ok(())
+}
+
+/// Distributes the rewards of the epochs passed, see `DistributeRewards`. The crank bounty
+/// is only cut if the keeper's mining is passed, and it's credited to the mining.
+/// Streamed rewards are accrued instead, so there is no distribution to return.
+pub fn distribute_rewards(
+    reward_pool: &mut WrappedRewardPool,
+    keeper_mining: Option<&mut Mining>,
+    curr_ts: u64,
+) -> Result<Option<Distribution>, ProgramError> {
+    // streamed rewards don't need a distribution, but it's harmless to accrue them
+    if reward_pool.pool.distribution_mode() == DistributionMode::Streaming {
+        reward_pool.accrue_rewards(curr_ts)?;
+        return Ok(None);
+    }
+
+    let distribution = reward_pool.distribute(keeper_mining.is_some(), curr_ts)?;
+    if let Some(keeper_mining) = keeper_mining {
+        keeper_mining.add_unclaimed_rewards(distribution.crank_bounties)?;
+    }
+
+    Ok(Some(distribution))
+}
+
+/// Deposits the stake, see `DepositMining`. The delegation accounts are expected
+/// if the delegate charges a commission.
+pub fn deposit_mining(
+    reward_pool: &mut WrappedRewardPool,
+    mining: &mut WrappedMining,
+    amount: u64,
+    lockup_period: LockupPeriod,
+    delegate_mining: Option<&AccountInfo>,
+    delegation_accounts: Option<&DelegationAccounts>,
+    curr_ts: u64,
+) -> ProgramResult {
+    if let Some(delegation_accounts) = delegation_accounts {
+        reward_pool.accrue_rewards(curr_ts)?;
+        delegation_accounts.settle_rewards(reward_pool, mining.mining, curr_ts)?;
+    }
+
+    with_delegate_mining(delegate_mining, |delegate_mining| {
+        reward_pool.deposit(mining, amount, lockup_period, delegate_mining, curr_ts)
+    })?;
+
+    if let Some(delegation_accounts) = delegation_accounts {
+        delegation_accounts.track(amount)?;
+    }
+
+    Ok(())
+}
+
+/// Withdraws the stake, see `WithdrawMining`. The delegation accounts are expected
+/// if the delegate charges a commission.
+pub fn withdraw_mining(
+    reward_pool: &mut WrappedRewardPool,
+    mining: &mut WrappedMining,
+    amount: u64,
+    delegate_mining: Option<&AccountInfo>,
+    delegation_accounts: Option<&DelegationAccounts>,
+    curr_ts: u64,
+) -> ProgramResult {
+    if let Some(delegation_accounts) = delegation_accounts {
+        reward_pool.accrue_rewards(curr_ts)?;
+        delegation_accounts.settle_rewards(reward_pool, mining.mining, curr_ts)?;
+        delegation_accounts.untrack(amount)?;
+    }
+
+    with_delegate_mining(delegate_mining, |delegate_mining| {
+        reward_pool.withdraw(mining, amount, delegate_mining, curr_ts)
+    })
+}
+
+/// Restakes the deposit for the new lockup period, see `ExtendStake`. The delegation
+/// accounts are expected if the delegate charges a commission.
+#[allow(clippy::too_many_arguments)]
+pub fn extend_stake(
+    reward_pool: &mut WrappedRewardPool,
+    mining: &mut WrappedMining,
+    old_lockup_period: LockupPeriod,
+    new_lockup_period: LockupPeriod,
+    deposit_start_ts: u64,
+    base_amount: u64,
+    additional_amount: u64,
+    delegate_mining: Option<&AccountInfo>,
+    delegation_accounts: Option<&DelegationAccounts>,
+    curr_ts: u64,
+) -> ProgramResult {
+    if let Some(delegation_accounts) = delegation_accounts {
+        reward_pool.accrue_rewards(curr_ts)?;
+        delegation_accounts.settle_rewards(reward_pool, mining.mining, curr_ts)?;
+        delegation_accounts.untrack(base_amount)?;
+    }
+
+    with_delegate_mining(delegate_mining, |delegate_mining| {
+        reward_pool.extend(
+            mining,
+            old_lockup_period,
+            new_lockup_period,
+            deposit_start_ts,
+            base_amount,
+            additional_amount,
+            delegate_mining,
+            curr_ts,
+        )
+    })?;
+
+    // the whole restaked amount is delegated anew
+    if let Some(delegation_accounts) = delegation_accounts {
+        delegation_accounts.track(base_amount.safe_add(additional_amount)?)?;
+    }
+
+    Ok(())
+}
+
+/// Credits the delegator's part of the delegated stake rewards, see `SettleDelegationRewards`
+pub fn settle_delegation_rewards(
+    reward_pool: &mut WrappedRewardPool,
+    mining: &mut WrappedMining,
+    delegation_accounts: &DelegationAccounts,
+    curr_ts: u64,
+) -> ProgramResult {
+    reward_pool.accrue_rewards(curr_ts)?;
+    delegation_accounts.settle_rewards(reward_pool, mining.mining, curr_ts)
+}
+
+/// Claims the rewards of the reward token, all of them if the amount isn't given,
+/// see `Claim`. The claimed rewards are locked in the vesting if the pool vests them,
+/// the vesting is expected then. Returns the claimed amount.
+pub fn claim(
+    reward_pool: &mut WrappedRewardPool,
+    mining: &mut WrappedMining,
+    reward_token_index: usize,
+    amount: Option<u64>,
+    vesting: Option<&mut Vesting>,
+    curr_ts: u64,
+) -> Result<u64, ProgramError> {
+    reward_pool.accrue_rewards(curr_ts)?;
+    mining.refresh_rewards(reward_pool.pool, reward_pool.cumulative_index, curr_ts)?;
+    let amount = mining.mining.claim(reward_token_index, amount)?;
+
+    if reward_pool.pool.is_vesting_enabled() {
+        let vesting = vesting.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        vesting.lock(
+            reward_token_index,
+            amount,
+            reward_pool.pool.vesting_cliff_secs,
+            reward_pool.pool.vesting_duration_secs,
+            curr_ts,
+        )?;
+        reward_pool.pool.vesting_rewards[reward_token_index] =
+            reward_pool.pool.vesting_rewards[reward_token_index].safe_add(amount)?;
+    }
+
+    Ok(amount)
+}
+
+/// Makes the mining charge the commission of the delegated stake rewards, see `InitializeDelegate`
+pub fn initialize_delegate(
+    reward_pool: &mut WrappedRewardPool,
+    mining: &mut Mining,
+    delegate: &mut Delegate,
+    commission_bps: u16,
+    curr_ts: u64,
+) -> ProgramResult {
+    reward_pool.accrue_rewards(curr_ts)?;
+
+    delegate.set_commission(commission_bps)?;
+    // nothing is delegated yet, so it only catches up with the pool's indexes
+    delegate.accrue_rewards(
+        reward_pool.pool,
+        reward_pool.cumulative_index,
+        mining,
+        curr_ts,
+    )?;
+    mining.start_charging_commission();
+
+    Ok(())
+}
+
+/// Changes the commission, see `SetDelegateCommission`
+pub fn set_delegate_commission(
+    reward_pool: &mut WrappedRewardPool,
+    mining: &mut Mining,
+    delegate: &mut Delegate,
+    commission_bps: u16,
+    curr_ts: u64,
+) -> ProgramResult {
+    // the rewards delegated so far are split with the previous commission
+    reward_pool.accrue_rewards(curr_ts)?;
+    delegate.accrue_rewards(
+        reward_pool.pool,
+        reward_pool.cumulative_index,
+        mining,
+        curr_ts,
+    )?;
+    delegate.set_commission(commission_bps)?;
+
+    Ok(())
+}
+
+/// Starts settling the rewards of the delegation from now on, see `InitializeDelegation`.
+/// The given stake, delegated before the delegate has started to charge the commission,
+/// is moved under it.
+pub fn initialize_delegation(
+    reward_pool: &mut WrappedRewardPool,
+    delegate_mining: &mut WrappedMining,
+    delegate: &mut Delegate,
+    delegation: &mut Delegation,
+    delegated_amount: u64,
+    curr_ts: u64,
+) -> ProgramResult {
+    // the rewards earned till now go to the delegate as they did
+    reward_pool.accrue_rewards(curr_ts)?;
+    delegate_mining.refresh_rewards(reward_pool.pool, reward_pool.cumulative_index, curr_ts)?;
+    delegate.accrue_rewards(
+        reward_pool.pool,
+        reward_pool.cumulative_index,
+        delegate_mining.mining,
+        curr_ts,
+    )?;
+
+    delegation.index_with_precision = delegate.delegators_index_with_precision;
+    delegate.track(delegation, delegate_mining.mining, delegated_amount)
+}
@@ -0,0 +1,406 @@
+use crate::{error::TrzRewardsError, utils::SafeArithmeticOperations};
+use bytemuck::{Pod, Zeroable};
+use shank::ShankAccount;
+use sokoban::ZeroCopy;
+use trezoa_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    program_pack::IsInitialized, pubkey::Pubkey,
+};
+
+use super::{
+    find_max_value_limited_by_key, AccountType, CumulativeIndex, DistributionMode, Mining,
+    RewardIndexes, RewardPool, WrappedMining, WrappedRewardPool, ACCOUNT_TYPE_BYTE,
+    MAX_BASIS_POINTS, MAX_REWARD_TOKENS,
+};
+
+/// Stake delegated to the mining that charges its delegators a commission.
+/// The stake delegated through the delegations is accounted here instead of
+/// the `stake_from_others` of the delegate mining, and its rewards are split
+/// between the delegate and the delegators.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Pod, Zeroable, ShankAccount)]
+pub struct Delegate {
+    /// The address of corresponding Reward pool.
+    pub reward_pool: Pubkey,
+    /// The address of the delegate mining.
+    pub mining: Pubkey,
+    /// Indexes of the pool the delegated stake rewards have been accrued till.
+    pub index_with_precision: RewardIndexes,
+    /// Rewards of a single delegated token that go to the delegators,
+    /// summed up since the delegate has started to charge the commission.
+    pub delegators_index_with_precision: RewardIndexes,
+    /// Stake delegated through the delegations.
+    pub delegated_stake: u64,
+    /// Part of the delegated stake rewards the delegate takes, in basis points.
+    pub commission_bps: u16,
+    /// Bump of the delegate account
+    pub bump: u8,
+    /// Account type - Delegate.
+    /// 0: account type
+    /// 1-4: unused
+    pub data: [u8; 5],
+}
+
+itpl ZeroCopy for Delegate {}
+
+itpl Delegate {
+    pub const LEN: usize = std::mem::size_of::<Delegate>();
+
+    /// Initialize a Delegate
+    pub fn initialize(reward_pool: Pubkey, mining: Pubkey, bump: u8) -> Delegate {
+        let mut data = [0; 5];
+        data[ACCOUNT_TYPE_BYTE] = AccountType::Delegate.into();
+
+        Delegate {
+            reward_pool,
+            mining,
+            bump,
+            data,
+            ..Default::default()
+        }
+    }
+
+    /// Writes the delegate into the account's bytes
+    pub fn save(bytes: &mut [u8], delegate: Delegate) -> Result<(), ProgramError> {
+        let delegate_bytes = Delegate::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        *delegate_bytes = delegate;
+
+        Ok(())
+    }
+
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Delegate, ProgramError> {
+        let delegate = Delegate::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        if !delegate.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(delegate)
+    }
+
+    /// Sets the commission, the rewards have to be accrued with the previous one before
+    pub fn set_commission(&mut self, commission_bps: u16) -> Result<(), TrzRewardsError> {
+        if u64::from(commission_bps) > MAX_BASIS_POINTS {
+            return Err(TrzRewardsError::InvalidCommission);
+        }
+        self.commission_bps = commission_bps;
+
+        Ok(())
+    }
+
+    /// Accrues the rewards of the delegated stake the same way the minings do.
+    /// The commission is credited to the delegate mining, while the rest
+    /// is left for the delegators to settle.
+    pub fn accrue_rewards(
+        &mut self,
+        pool: &RewardPool,
+        cumulative_index: &CumulativeIndex,
+        delegate_mining: &mut Mining,
+        curr_ts: u64,
+    ) -> ProgramResult {
+        let vault_indexes = match pool.distribution_mode() {
+            // the history might have been compacted, so there is no index for the date,
+            // in that case no rewards are accrued for the period
+            DistributionMode::Epochs => find_max_value_limited_by_key(cumulative_index, curr_ts)
+                .unwrap_or(self.index_with_precision),
+            // the pool is expected to be accrued till now
+            DistributionMode::Streaming => pool.reward_indexes(),
+        };
+
+        self.accrue_rewards_for_indexes(vault_indexes, &mut delegate_mining.unclaimed_rewards)
+    }
+
+    fn accrue_rewards_for_indexes(
+        &mut self,
+        vault_indexes: RewardIndexes,
+        delegate_unclaimed_rewards: &mut [u64; MAX_REWARD_TOKENS],
+    ) -> ProgramResult {
+        let delegators_part = MAX_BASIS_POINTS.safe_sub(u64::from(self.commission_bps))?;
+
+        for i in 0..MAX_REWARD_TOKENS {
+            let index_growth = vault_indexes[i].safe_sub(self.index_with_precision[i])?;
+            let delegators_index_growth = index_growth
+                .safe_mul(u128::from(delegators_part))?
+                .safe_div(u128::from(MAX_BASIS_POINTS))?;
+
+            let rewards = stake_rewards(index_growth, self.delegated_stake)?;
+            let delegators_rewards = stake_rewards(delegators_index_growth, self.delegated_stake)?;
+            delegate_unclaimed_rewards[i] =
+                delegate_unclaimed_rewards[i].safe_add(rewards.safe_sub(delegators_rewards)?)?;
+
+            self.delegators_index_with_precision[i] =
+                self.delegators_index_with_precision[i].safe_add(delegators_index_growth)?;
+            self.index_with_precision[i] = vault_indexes[i];
+        }
+
+        Ok(())
+    }
+
+    /// Moves the stake out of the delegate mining's `stake_from_others`,
+    /// so that its rewards are split from now on
+    pub fn track(
+        &mut self,
+        delegation: &mut Delegation,
+        delegate_mining: &mut Mining,
+        amount: u64,
+    ) -> ProgramResult {
+        delegate_mining.stake_from_others = delegate_mining.stake_from_others.safe_sub(amount)?;
+        self.delegated_stake = self.delegated_stake.safe_add(amount)?;
+        delegation.delegated_amount = delegation.delegated_amount.safe_add(amount)?;
+
+        Ok(())
+    }
+
+    /// Moves the stake delegated through the delegation back to the delegate mining's
+    /// `stake_from_others`. The stake delegated before the delegate started to charge
+    /// the commission is only moved if the delegation has been initialized with it,
+    /// so only the moved part of the amount is.
+    pub fn untrack(
+        &mut self,
+        delegation: &mut Delegation,
+        delegate_mining: &mut Mining,
+        amount: u64,
+    ) -> ProgramResult {
+        let amount = amount.min(delegation.delegated_amount);
+
+        delegation.delegated_amount = delegation.delegated_amount.safe_sub(amount)?;
+        self.delegated_stake = self.delegated_stake.safe_sub(amount)?;
+        delegate_mining.stake_from_others = delegate_mining.stake_from_others.safe_add(amount)?;
+
+        Ok(())
+    }
+}
+
+itpl IsInitialized for Delegate {
+    fn is_initialized(&self) -> bool {
+        self.data[ACCOUNT_TYPE_BYTE] == <u8>::from(AccountType::Delegate)
+    }
+}
+
+/// Stake the mining has delegated to the delegate charging a commission
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Pod, Zeroable, ShankAccount)]
+pub struct Delegation {
+    /// The address of corresponding Reward pool.
+    pub reward_pool: Pubkey,
+    /// The address of the delegate mining.
+    pub delegate_mining: Pubkey,
+    /// The address of the delegator's mining the rewards are settled to.
+    pub mining: Pubkey,
+    /// Delegators index of the delegate the rewards have been settled till.
+    pub index_with_precision: RewardIndexes,
+    /// Stake delegated under the commission, either since the delegate has started
+    /// to charge it or moved under it when the delegation has been initialized.
+    pub delegated_amount: u64,
+    /// Bump of the delegation account
+    pub bump: u8,
+    /// Account type - Delegation.
+    /// 0: account type
+    /// 1-6: unused
+    pub data: [u8; 7],
+}
+
+itpl ZeroCopy for Delegation {}
+
+itpl Delegation {
+    pub const LEN: usize = std::mem::size_of::<Delegation>();
+
+    /// Initialize a Delegation, the rewards are settled from the current delegators index on
+    pub fn initialize(
+        reward_pool: Pubkey,
+        delegate_mining: Pubkey,
+        mining: Pubkey,
+        delegate: &Delegate,
+        bump: u8,
+    ) -> Delegation {
+        let mut data = [0; 7];
+        data[ACCOUNT_TYPE_BYTE] = AccountType::Delegation.into();
+
+        Delegation {
+            reward_pool,
+            delegate_mining,
+            mining,
+            index_with_precision: delegate.delegators_index_with_precision,
+            bump,
+            data,
+            ..Default::default()
+        }
+    }
+
+    /// Writes the delegation into the account's bytes
+    pub fn save(bytes: &mut [u8], delegation: Delegation) -> Result<(), ProgramError> {
+        let delegation_bytes = Delegation::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        *delegation_bytes = delegation;
+
+        Ok(())
+    }
+
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Delegation, ProgramError> {
+        let delegation = Delegation::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        if !delegation.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(delegation)
+    }
+
+    /// Credits the delegator's part of the delegated stake rewards to its mining
+    pub fn settle_rewards(&mut self, delegate: &Delegate, mining: &mut Mining) -> ProgramResult {
+        for i in 0..MAX_REWARD_TOKENS {
+            let index_growth = delegate.delegators_index_with_precision[i]
+                .safe_sub(self.index_with_precision[i])?;
+            let rewards = stake_rewards(index_growth, self.delegated_amount)?;
+
+            mining.unclaimed_rewards[i] = mining.unclaimed_rewards[i].safe_add(rewards)?;
+            self.index_with_precision[i] = delegate.delegators_index_with_precision[i];
+        }
+
+        Ok(())
+    }
+}
+
+itpl IsInitialized for Delegation {
+    fn is_initialized(&self) -> bool {
+        self.data[ACCOUNT_TYPE_BYTE] == <u8>::from(AccountType::Delegation)
+    }
+}
+
+/// Delegate and delegation accounts passed along with the delegate mining
+/// if the delegate charges a commission
+pub struct DelegationAccounts<'a, 'b> {
+    pub delegate_mining: &'a AccountInfo<'b>,
+    pub delegate: &'a AccountInfo<'b>,
+    pub delegation: &'a AccountInfo<'b>,
+}
+
+itpl DelegationAccounts<'_, '_> {
+    /// Accrues the delegated stake rewards and settles the delegator's part of them.
+    /// The pool is expected to be accrued till now.
+    pub fn settle_rewards(
+        &self,
+        pool: &WrappedRewardPool,
+        mining: &mut Mining,
+        curr_ts: u64,
+    ) -> ProgramResult {
+        let delegate_mining_data = &mut self.delegate_mining.data.borrow_mut();
+        let delegate_mining = WrappedMining::from_bytes_mut(delegate_mining_data)?;
+        let delegate_data = &mut self.delegate.data.borrow_mut();
+        let delegate = Delegate::from_bytes_mut(delegate_data)?;
+        let delegation_data = &mut self.delegation.data.borrow_mut();
+        let delegation = Delegation::from_bytes_mut(delegation_data)?;
+
+        delegate.accrue_rewards(
+            pool.pool,
+            pool.cumulative_index,
+            delegate_mining.mining,
+            curr_ts,
+        )?;
+        delegation.settle_rewards(delegate, mining)
+    }
+
+    /// Tracks the stake delegated through the delegation, the rewards have to be settled before
+    pub fn track(&self, amount: u64) -> ProgramResult {
+        self.with_accounts(|delegate, delegation, delegate_mining| {
+            delegate.track(delegation, delegate_mining, amount)
+        })
+    }
+
+    /// Untracks the stake delegated through the delegation, the rewards have to be settled before
+    pub fn untrack(&self, amount: u64) -> ProgramResult {
+        self.with_accounts(|delegate, delegation, delegate_mining| {
+            delegate.untrack(delegation, delegate_mining, amount)
+        })
+    }
+
+    fn with_accounts(
+        &self,
+        f: itpl FnOnce(&mut Delegate, &mut Delegation, &mut Mining) -> ProgramResult,
+    ) -> ProgramResult {
+        let delegate_mining_data = &mut self.delegate_mining.data.borrow_mut();
+        let delegate_mining = WrappedMining::from_bytes_mut(delegate_mining_data)?;
+        let delegate_data = &mut self.delegate.data.borrow_mut();
+        let delegation_data = &mut self.delegation.data.borrow_mut();
+
+        f(
+            Delegate::from_bytes_mut(delegate_data)?,
+            Delegation::from_bytes_mut(delegation_data)?,
+            delegate_mining.mining,
+        )
+    }
+}
+
+fn stake_rewards(index_growth: u128, stake: u64) -> Result<u64, ProgramError> {
+    Ok(trz_rewards_core::stake_rewards(index_growth, stake).map_err(TrzRewardsError::from)?)
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+    use crate::state::PRECISION;
+
+    #[test]
+    fn delegated_stake_rewards_are_split_by_commission() {
+        let mut delegate = Delegate {
+            commission_bps: 1000,
+            ..Default::default()
+        };
+        let mut delegation = Delegation::default();
+        let mut delegate_mining = Mining {
+            stake_from_others: 1000,
+            ..Default::default()
+        };
+        delegate
+            .track(&mut delegation, &mut delegate_mining, 1000)
+            .unwrap();
+        assert_eq!(delegate_mining.stake_from_others, 0);
+
+        // one reward token per a delegated one
+        delegate
+            .accrue_rewards_for_indexes([PRECISION, 0, 0], &mut delegate_mining.unclaimed_rewards)
+            .unwrap();
+        assert_eq!(delegate_mining.unclaimed_rewards, [100, 0, 0]);
+
+        let mut mining = Mining::default();
+        delegation.settle_rewards(&delegate, &mut mining).unwrap();
+        assert_eq!(mining.unclaimed_rewards, [900, 0, 0]);
+
+        // settled rewards aren't credited twice
+        delegation.settle_rewards(&delegate, &mut mining).unwrap();
+        assert_eq!(mining.unclaimed_rewards, [900, 0, 0]);
+    }
+
+    #[test]
+    fn stake_delegated_before_commission_stays_with_delegate() {
+        let mut delegate = Delegate::default();
+        let mut delegation = Delegation::default();
+        let mut delegate_mining = Mining {
+            stake_from_others: 1500,
+            ..Default::default()
+        };
+        delegate
+            .track(&mut delegation, &mut delegate_mining, 1000)
+            .unwrap();
+
+        delegate
+            .untrack(&mut delegation, &mut delegate_mining, 1200)
+            .unwrap();
+
+        assert_eq!(delegation.delegated_amount, 0);
+        assert_eq!(delegate.delegated_stake, 0);
+        assert_eq!(delegate_mining.stake_from_others, 1500);
+    }
+
+    #[test]
+    fn commission_cannot_exceed_the_whole() {
+        let mut delegate = Delegate::default();
+
+        assert_eq!(
+            delegate.set_commission(10_001),
+            Err(TrzRewardsError::InvalidCommission)
+        );
+        assert_eq!(delegate.set_commission(10_000), Ok(()));
+    }
+}
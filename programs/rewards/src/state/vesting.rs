@@ -0,0 +1,358 @@
+use crate::{error::TrzRewardsError, utils::SafeArithmeticOperations};
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use shank::ShankAccount;
+use sokoban::ZeroCopy;
+use trz_rewards_core::mul_div;
+use trezoa_program::{program_error::ProgramError, program_pack::IsInitialized, pubkey::Pubkey};
+
+use super::{AccountType, ACCOUNT_TYPE_BYTE, MAX_REWARD_TOKENS, MAX_VESTING_TRANCHES};
+
+/// Rewards claimed by the mining that are released gradually. Every claim is locked
+/// in its own tranche while there is room, vesting on the schedule the pool had
+/// when it was made.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Pod, Zeroable, ShankAccount)]
+pub struct Vesting {
+    /// The address of corresponding Reward pool.
+    pub reward_pool: Pubkey,
+    /// The address of the mining the rewards are claimed from.
+    pub mining: Pubkey,
+    /// Mining owner, the only one who might withdraw vested rewards.
+    pub owner: Pubkey,
+    /// Amounts of every reward token that have been unlocked but not withdrawn yet
+    pub unlocked_amounts: [u64; MAX_REWARD_TOKENS],
+    /// Rewards locked by the claims which haven't fully vested yet
+    pub tranches: [VestingTranche; MAX_VESTING_TRANCHES],
+    /// Bump of the vesting account
+    pub bump: u8,
+    /// Account type - Vesting.
+    /// 0: account type
+    /// 1-6: unused
+    pub data: [u8; 7],
+}
+
+/// Rewards locked by the claims made at the same time, or merged into it once the vesting
+/// had no room for one more tranche. Empty if the duration is zero.
+#[repr(C)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Pod, Zeroable, BorshDeserialize, BorshSerialize,
+)]
+pub struct VestingTranche {
+    /// Amounts of every reward token that have been locked by the claims
+    pub vesting_amounts: [u64; MAX_REWARD_TOKENS],
+    /// Amounts of every reward token that are still locked
+    pub locked_amounts: [u64; MAX_REWARD_TOKENS],
+    /// The time the claims have been made at
+    pub vesting_start: u64,
+    /// Nothing is unlocked till the cliff passes since the vesting start
+    pub cliff_secs: u64,
+    /// The rewards vest linearly over the duration, counting from the vesting start
+    pub duration_secs: u64,
+}
+
+itpl ZeroCopy for Vesting {}
+
+itpl Vesting {
+    pub const LEN: usize = std::mem::size_of::<Vesting>();
+
+    /// Initialize a Vesting
+    pub fn initialize(reward_pool: Pubkey, mining: Pubkey, owner: Pubkey, bump: u8) -> Vesting {
+        let mut data = [0; 7];
+        data[ACCOUNT_TYPE_BYTE] = AccountType::Vesting.into();
+
+        Vesting {
+            reward_pool,
+            mining,
+            owner,
+            bump,
+            data,
+            ..Default::default()
+        }
+    }
+
+    /// Writes the vesting into the account's bytes
+    pub fn save(bytes: &mut [u8], vesting: Vesting) -> Result<(), ProgramError> {
+        let vesting_bytes = Vesting::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        *vesting_bytes = vesting;
+
+        Ok(())
+    }
+
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Result<&mut Vesting, ProgramError> {
+        let vesting = Vesting::load_mut_bytes(bytes)
+            .ok_or(TrzRewardsError::RetreivingZeroCopyAccountFailire)?;
+        if !vesting.is_initialized() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(vesting)
+    }
+
+    /// Unlocks the rewards every tranche has vested by the given time.
+    /// The tranches which have fully vested are emptied.
+    pub fn unlock(&mut self, curr_ts: u64) -> Result<(), TrzRewardsError> {
+        for tranche in self
+            .tranches
+            .iter_mut()
+            .filter(|tranche| !tranche.is_empty())
+        {
+            let newly_unlocked = tranche.unlock(curr_ts)?;
+            for (unlocked_amount, newly_unlocked) in
+                self.unlocked_amounts.iter_mut().zip(newly_unlocked)
+            {
+                *unlocked_amount = unlocked_amount.safe_add(newly_unlocked)?;
+            }
+
+            if tranche.locked_amounts.iter().all(|locked| *locked == 0) {
+                *tranche = VestingTranche::default();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Locks the claimed rewards on the given schedule. The rewards locked before keep
+    /// their own schedules, the claims made at the same time share the tranche.
+    /// Once every tranche is taken, the claim is merged into the latest tranche
+    /// of the same schedule or into the oldest one, see `VestingTranche::restart`.
+    /// Zero duration means the vesting is disabled, so everything is unlocked.
+    pub fn lock(
+        &mut self,
+        reward_token_index: usize,
+        amount: u64,
+        cliff_secs: u64,
+        duration_secs: u64,
+        curr_ts: u64,
+    ) -> Result<(), TrzRewardsError> {
+        self.unlock(curr_ts)?;
+
+        if duration_secs == 0 {
+            self.unlocked_amounts[reward_token_index] =
+                self.unlocked_amounts[reward_token_index].safe_add(amount)?;
+            return Ok(());
+        }
+
+        let tranche = match self.tranches.iter().position(|tranche| {
+            tranche.vesting_start == curr_ts && tranche.has_schedule(cliff_secs, duration_secs)
+        }) {
+            Some(tranche_index) => &mut self.tranches[tranche_index],
+            None => {
+                let tranche_index = self
+                    .tranches
+                    .iter()
+                    .position(VestingTranche::is_empty)
+                    .unwrap_or_else(|| self.tranche_to_merge_into(cliff_secs, duration_secs));
+                let tranche = &mut self.tranches[tranche_index];
+                tranche.restart(cliff_secs, duration_secs, curr_ts);
+                tranche
+            }
+        };
+
+        tranche.vesting_amounts[reward_token_index] =
+            tranche.vesting_amounts[reward_token_index].safe_add(amount)?;
+        tranche.locked_amounts[reward_token_index] =
+            tranche.locked_amounts[reward_token_index].safe_add(amount)?;
+
+        Ok(())
+    }
+
+    /// The tranche a claim is merged into when there is no room for its own one:
+    /// the latest tranche of the same schedule, or the oldest one if there is none
+    fn tranche_to_merge_into(&self, cliff_secs: u64, duration_secs: u64) -> usize {
+        let tranches = self.tranches.iter().enumerate();
+        tranches
+            .clone()
+            .filter(|(_, tranche)| tranche.has_schedule(cliff_secs, duration_secs))
+            .max_by_key(|(_, tranche)| tranche.vesting_start)
+            .or_else(|| tranches.min_by_key(|(_, tranche)| tranche.vesting_start))
+            .map(|(tranche_index, _)| tranche_index)
+            .unwrap_or_default()
+    }
+
+    /// Takes the unlocked rewards of the reward token out, returns the amount to be withdrawn
+    pub fn withdraw(
+        &mut self,
+        reward_token_index: usize,
+        curr_ts: u64,
+    ) -> Result<u64, TrzRewardsError> {
+        self.unlock(curr_ts)?;
+
+        let amount = self.unlocked_amounts[reward_token_index];
+        self.unlocked_amounts[reward_token_index] = 0;
+
+        Ok(amount)
+    }
+}
+
+itpl VestingTranche {
+    /// Shows whether the tranche doesn't lock anything
+    pub fn is_empty(&self) -> bool {
+        self.duration_secs == 0
+    }
+
+    /// Shows whether the tranche vests on the given schedule
+    fn has_schedule(&self, cliff_secs: u64, duration_secs: u64) -> bool {
+        self.cliff_secs == cliff_secs && self.duration_secs == duration_secs
+    }
+
+    /// Starts vesting the rewards which are still locked anew, on the given schedule
+    /// from the given time. The rewards locked along with them then vest together.
+    /// An empty tranche just gets the schedule.
+    fn restart(&mut self, cliff_secs: u64, duration_secs: u64, curr_ts: u64) {
+        *self = VestingTranche {
+            vesting_amounts: self.locked_amounts,
+            locked_amounts: self.locked_amounts,
+            vesting_start: curr_ts,
+            cliff_secs,
+            duration_secs,
+        };
+    }
+
+    /// Unlocks the rewards vested by the given time, returns the newly unlocked amounts
+    fn unlock(&mut self, curr_ts: u64) -> Result<[u64; MAX_REWARD_TOKENS], TrzRewardsError> {
+        let mut newly_unlocked = [0; MAX_REWARD_TOKENS];
+
+        let elapsed = curr_ts.saturating_sub(self.vesting_start);
+        if elapsed < self.cliff_secs {
+            return Ok(newly_unlocked);
+        }
+
+        for (i, newly_unlocked) in newly_unlocked.iter_mut().enumerate() {
+            let vested = if elapsed >= self.duration_secs {
+                self.vesting_amounts[i]
+            } else {
+                mul_div(
+                    self.vesting_amounts[i],
+                    u128::from(elapsed),
+                    u128::from(self.duration_secs),
+                )?
+            };
+            let released = self.vesting_amounts[i].safe_sub(self.locked_amounts[i])?;
+            *newly_unlocked = vested.saturating_sub(released);
+
+            self.locked_amounts[i] = self.locked_amounts[i].safe_sub(*newly_unlocked)?;
+        }
+
+        Ok(newly_unlocked)
+    }
+}
+
+itpl IsInitialized for Vesting {
+    fn is_initialized(&self) -> bool {
+        self.data[ACCOUNT_TYPE_BYTE] == <u8>::from(AccountType::Vesting)
+    }
+}
+
+#[allow(unused_imports)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rewards_vest_linearly_after_cliff() {
+        let mut vesting = Vesting::default();
+        vesting.lock(0, 1000, 100, 1000, 0).unwrap();
+
+        assert_eq!(vesting.withdraw(0, 99), Ok(0));
+        assert_eq!(vesting.withdraw(0, 100), Ok(100));
+        assert_eq!(vesting.withdraw(0, 500), Ok(400));
+        assert_eq!(vesting.withdraw(0, 2000), Ok(500));
+        assert!(vesting.tranches.iter().all(VestingTranche::is_empty));
+    }
+
+    #[test]
+    fn claim_keeps_schedule_of_locked_rewards() {
+        let mut vesting = Vesting::default();
+        vesting.lock(0, 1000, 100, 1000, 0).unwrap();
+        vesting.lock(0, 500, 100, 1000, 150).unwrap();
+
+        // the later claim is still before its cliff
+        assert_eq!(vesting.withdraw(0, 200), Ok(200));
+        assert_eq!(vesting.withdraw(0, 650), Ok(450 + 250));
+        assert_eq!(vesting.withdraw(0, 1150), Ok(350 + 250));
+        assert!(vesting.tranches.iter().all(VestingTranche::is_empty));
+    }
+
+    #[test]
+    fn claims_made_at_the_same_time_share_the_tranche() {
+        let mut vesting = Vesting::default();
+        for i in 0..MAX_VESTING_TRANCHES as u64 {
+            vesting.lock(0, 100, 0, 1000, i).unwrap();
+        }
+        vesting
+            .lock(1, 100, 0, 1000, MAX_VESTING_TRANCHES as u64 - 1)
+            .unwrap();
+        assert_eq!(
+            vesting.tranches[MAX_VESTING_TRANCHES - 1].locked_amounts,
+            [100, 100, 0]
+        );
+
+        // the fully vested tranche is emptied, so there is a room for the next claim
+        assert_eq!(vesting.lock(0, 100, 0, 1000, 1000), Ok(()));
+        assert_eq!(vesting.tranches[0].vesting_start, 1000);
+        assert_eq!(vesting.tranches[0].locked_amounts, [100, 0, 0]);
+    }
+
+    #[test]
+    fn claims_are_merged_into_the_latest_tranche_once_there_is_no_room() {
+        let mut vesting = Vesting::default();
+        for i in 0..2 * MAX_VESTING_TRANCHES as u64 {
+            vesting.lock(0, 100, 0, 1000, i * 10).unwrap();
+        }
+
+        // the earlier tranches keep their schedules
+        assert_eq!(vesting.tranches[0].vesting_start, 0);
+        assert_eq!(
+            vesting.tranches[MAX_VESTING_TRANCHES - 1].vesting_start,
+            (2 * MAX_VESTING_TRANCHES as u64 - 1) * 10
+        );
+
+        // nothing is lost or unlocked twice
+        let total_claimed = 2 * MAX_VESTING_TRANCHES as u64 * 100;
+        let locked: u64 = vesting
+            .tranches
+            .iter()
+            .map(|tranche| tranche.locked_amounts[0])
+            .sum();
+        assert_eq!(locked + vesting.unlocked_amounts[0], total_claimed);
+
+        let withdrawn = vesting.withdraw(0, 500).unwrap() + vesting.withdraw(0, 1500).unwrap();
+        assert_eq!(withdrawn, total_claimed);
+        assert!(vesting.tranches.iter().all(VestingTranche::is_empty));
+    }
+
+    #[test]
+    fn claim_of_another_schedule_is_merged_into_the_oldest_tranche_once_there_is_no_room() {
+        let mut vesting = Vesting::default();
+        for i in 0..MAX_VESTING_TRANCHES as u64 {
+            vesting.lock(0, 100, 0, 1000, (i + 1) * 100).unwrap();
+        }
+
+        vesting.lock(1, 100, 50, 500, 1000).unwrap();
+
+        // the oldest tranche had vested 90% by then, the rest vests on the new schedule
+        assert_eq!(
+            vesting.tranches[0],
+            VestingTranche {
+                vesting_amounts: [10, 100, 0],
+                locked_amounts: [10, 100, 0],
+                vesting_start: 1000,
+                cliff_secs: 50,
+                duration_secs: 500,
+            }
+        );
+        assert_eq!(
+            vesting.unlocked_amounts,
+            [90 + 80 + 70 + 60 + 50 + 40 + 30 + 20, 0, 0]
+        );
+    }
+
+    #[test]
+    fn disabled_vesting_unlocks_everything() {
+        let mut vesting = Vesting::default();
+        vesting.lock(1, 1000, 100, 0, 0).unwrap();
+
+        assert_eq!(vesting.withdraw(1, 1), Ok(1000));
+    }
+}
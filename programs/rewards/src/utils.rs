@@ -1,12 +1,16 @@
 //! Arbitrary auxilliary functions
 use std::iter::Enumerate;
 
-use crate::{error::TrzRewardsError, state::WrappedImmutableMining};
-use borsh::{BorshDeserialize, BorshSerialize};
+use crate::{
+    asserts::assert_account_key,
+    error::TrzRewardsError,
+    state::{DelegationAccounts, WrappedImmutableMining, WrappedMining},
+};
 use trezoa_program::{
     account_info::AccountInfo,
-    clock::{Clock, SECONDS_PER_DAY},
+    clock::Clock,
     entrypoint::ProgramResult,
+    instruction::Instruction,
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
@@ -16,6 +20,11 @@ use trezoa_program::{
     system_instruction,
     sysvar::Sysvar,
 };
+pub use trz_rewards_core::utils::{LockupPeriod, SafeArithmeticOperations};
+use tpl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::{Account as TokenAccount, Mint},
+};
 
 /// Generates mining address
 pub fn find_mining_program_address(
@@ -33,6 +42,49 @@ pub fn find_mining_program_address(
     )
 }
 
+/// Generates vesting address
+pub fn find_vesting_program_address(program_id: &Pubkey, mining: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&["vesting".as_bytes(), &mining.to_bytes()], program_id)
+}
+
+/// Generates address of the account tracking the stake delegated to the mining
+pub fn find_delegate_program_address(
+    program_id: &Pubkey,
+    delegate_mining: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &["delegate".as_bytes(), &delegate_mining.to_bytes()],
+        program_id,
+    )
+}
+
+/// Generates address of the account tracking the stake the mining has delegated to the delegate
+pub fn find_delegation_program_address(
+    program_id: &Pubkey,
+    delegate_mining: &Pubkey,
+    mining: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "delegation".as_bytes(),
+            &delegate_mining.to_bytes(),
+            &mining.to_bytes(),
+        ],
+        program_id,
+    )
+}
+
+/// Generates address of the PDA owning the pool's vaults
+pub fn find_vault_authority_program_address(
+    program_id: &Pubkey,
+    reward_pool: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &["vault_authority".as_bytes(), &reward_pool.to_bytes()],
+        program_id,
+    )
+}
+
 /// Generates vault address
 pub fn find_vault_program_address(
     program_id: &Pubkey,
@@ -49,35 +101,15 @@ pub fn find_vault_program_address(
     )
 }
 
-/// Create account
-pub fn create_account<'a, S: Pack>(
-    program_id: &Pubkey,
-    from: AccountInfo<'a>,
-    to: AccountInfo<'a>,
-    signers_seeds: &[&[&[u8]]],
-) -> ProgramResult {
-    let rent = Rent::get()?;
-
-    let ix = system_instruction::create_account(
-        from.key,
-        to.key,
-        rent.minimum_balance(S::LEN),
-        S::LEN as u64,
-        program_id,
-    );
-
-    invoke_signed(&ix, &[from, to], signers_seeds)
-}
-
-/// Initialize TPL account instruction.
+/// Initialize TPL account instruction. The account is initialized by the program owning the mint.
 pub fn initialize_account<'a>(
     account: AccountInfo<'a>,
     mint: AccountInfo<'a>,
     authority: AccountInfo<'a>,
     rent: AccountInfo<'a>,
 ) -> ProgramResult {
-    let ix = tpl_token::instruction::initialize_account(
-        &tpl_token::id(),
+    let ix = tpl_token_2022::instruction::initialize_account(
+        mint.owner,
         account.key,
         mint.key,
         authority.key,
@@ -86,24 +118,130 @@ pub fn initialize_account<'a>(
     invoke(&ix, &[account, mint, authority, rent])
 }
 
-/// TPL transfer instruction.
+/// Creates and initializes the pool's vault of the mint. Vaults of Token-2022 mints
+/// are sized for the extensions the mint requires its token accounts to have.
+pub fn create_vault<'a>(
+    payer: AccountInfo<'a>,
+    vault: AccountInfo<'a>,
+    mint: AccountInfo<'a>,
+    vault_owner: AccountInfo<'a>,
+    rent: AccountInfo<'a>,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let vault_len = token_account_len(&mint)?;
+    let ix = system_instruction::create_account(
+        payer.key,
+        vault.key,
+        Rent::get()?.minimum_balance(vault_len),
+        vault_len as u64,
+        mint.owner,
+    );
+    invoke_signed(&ix, &[payer, vault.clone()], signers_seeds)?;
+
+    initialize_account(vault, mint, vault_owner, rent)
+}
+
+/// Shows whether the program is one of the token programs reward mints might belong to
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == tpl_token::id() || *program_id == tpl_token_2022::id()
+}
+
+/// Length of the mint's token account along with the extensions the mint requires
+pub fn token_account_len(mint: &AccountInfo) -> Result<usize, ProgramError> {
+    if *mint.owner == tpl_token::id() {
+        return Ok(tpl_token::state::Account::LEN);
+    }
+
+    let mint_data = mint.data.borrow();
+    let mint_extensions = StateWithExtensions::<Mint>::unpack(&mint_data)?.get_extension_types()?;
+    let account_extensions = ExtensionType::get_required_init_account_extensions(&mint_extensions);
+
+    ExtensionType::try_calculate_account_len::<TokenAccount>(&account_extensions)
+}
+
+/// Unpacks the token account of any of the token programs, the extensions are skipped
+pub fn unpack_token_account(account: &AccountInfo) -> Result<TokenAccount, ProgramError> {
+    Ok(StateWithExtensions::<TokenAccount>::unpack(&account.data.borrow())?.base)
+}
+
+/// Decimals of the mint of any of the token programs
+pub fn mint_decimals(mint: &AccountInfo) -> Result<u8, ProgramError> {
+    Ok(StateWithExtensions::<Mint>::unpack(&mint.data.borrow())?
+        .base
+        .decimals)
+}
+
+/// TPL transfer instruction. The transfer is checked against the mint,
+/// so that it works for mints of both token programs.
 pub fn spl_transfer<'a>(
     source: AccountInfo<'a>,
+    mint: AccountInfo<'a>,
     destination: AccountInfo<'a>,
     authority: AccountInfo<'a>,
     amount: u64,
     signers_seeds: &[&[&[u8]]],
 ) -> Result<(), ProgramError> {
-    let ix = tpl_token::instruction::transfer(
-        &tpl_token::id(),
+    let ix = tpl_token_2022::instruction::transfer_checked(
+        mint.owner,
         source.key,
+        mint.key,
         destination.key,
         authority.key,
         &[],
         amount,
+        mint_decimals(&mint)?,
+    )?;
+
+    invoke_signed(&ix, &[source, mint, destination, authority], signers_seeds)
+}
+
+/// Invokes the instruction on behalf of the owner of the pool's vaults. The vault authority PDA
+/// is signed for by the program, while the deposit authority signs the transaction itself.
+pub fn invoke_as_vault_owner(
+    ix: &Instruction,
+    account_infos: &[AccountInfo],
+    reward_pool: &Pubkey,
+    vault_authority_bump: Option<u8>,
+) -> ProgramResult {
+    match vault_authority_bump {
+        Some(bump) => invoke_signed(
+            ix,
+            account_infos,
+            &[&[b"vault_authority".as_ref(), reward_pool.as_ref(), &[bump]]],
+        ),
+        None => invoke(ix, account_infos),
+    }
+}
+
+/// Transfers tokens out of the pool's vault. Under the transfer fee extension
+/// the destination receives the amount less the fee.
+#[allow(clippy::too_many_arguments)]
+pub fn vault_transfer<'a>(
+    vault: AccountInfo<'a>,
+    mint: AccountInfo<'a>,
+    destination: AccountInfo<'a>,
+    vault_owner: AccountInfo<'a>,
+    amount: u64,
+    reward_pool: &Pubkey,
+    vault_authority_bump: Option<u8>,
+) -> ProgramResult {
+    let ix = tpl_token_2022::instruction::transfer_checked(
+        mint.owner,
+        vault.key,
+        mint.key,
+        destination.key,
+        vault_owner.key,
+        &[],
+        amount,
+        mint_decimals(&mint)?,
     )?;
 
-    invoke_signed(&ix, &[source, destination, authority], signers_seeds)
+    invoke_as_vault_owner(
+        &ix,
+        &[vault, mint, destination, vault_owner],
+        reward_pool,
+        vault_authority_bump,
+    )
 }
 
 pub fn get_delegate_mining<'a, 'b>(
@@ -118,6 +256,25 @@ pub fn get_delegate_mining<'a, 'b>(
     }
 }
 
+/// Lends the delegate mining, if there is one, to the pool's stake accounting. It's only borrowed
+/// for the call, so it doesn't overlap with the borrows of the delegation accounts.
+pub fn with_delegate_mining<E>(
+    delegate_mining: Option<&AccountInfo>,
+    f: itpl FnOnce(Option<&mut WrappedMining>) -> Result<(), E>,
+) -> ProgramResult
+where
+    ProgramError: From<E>,
+{
+    match delegate_mining {
+        Some(delegate_mining) => {
+            let delegate_mining_data = &mut delegate_mining.data.borrow_mut();
+            let mut wrapped_delegate_mining = WrappedMining::from_bytes_mut(delegate_mining_data)?;
+            f(Some(&mut wrapped_delegate_mining)).map_err(ProgramError::from)
+        }
+        None => f(None).map_err(ProgramError::from),
+    }
+}
+
 pub fn verify_delegate_mining_address(
     program_id: &Pubkey,
     delegate_mining: &AccountInfo<'_>,
@@ -141,6 +298,46 @@ pub fn verify_delegate_mining_address(
     Ok(())
 }
 
+/// Takes the delegate and delegation accounts if the delegate mining charges a commission,
+/// in that case they have to follow the rest of the instruction's accounts
+pub fn get_delegation_accounts<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    iter: &mut Enumerate<I>,
+    program_id: &Pubkey,
+    delegate_mining: Option<&'a AccountInfo<'b>>,
+    mining: &Pubkey,
+) -> Result<Option<DelegationAccounts<'a, 'b>>, ProgramError> {
+    let delegate_mining = match delegate_mining {
+        Some(delegate_mining) => delegate_mining,
+        None => return Ok(None),
+    };
+    if !WrappedImmutableMining::from_bytes(&delegate_mining.data.borrow())?
+        .mining
+        .charges_commission()
+    {
+        return Ok(None);
+    }
+
+    if !AccountLoader::has_more(iter) {
+        return Err(TrzRewardsError::DelegationAccountsMissing.into());
+    }
+    let delegate = AccountLoader::next_with_owner(iter, program_id)?;
+    let delegation = AccountLoader::next_with_owner(iter, program_id)?;
+    assert_account_key(
+        delegate,
+        &find_delegate_program_address(program_id, delegate_mining.key).0,
+    )?;
+    assert_account_key(
+        delegation,
+        &find_delegation_program_address(program_id, delegate_mining.key, mining).0,
+    )?;
+
+    Ok(Some(DelegationAccounts {
+        delegate_mining,
+        delegate,
+        delegation,
+    }))
+}
+
 /// Helper for parsing accounts with arbitrary input conditions
 pub struct AccountLoader {}
 
@@ -182,6 +379,41 @@ itpl AccountLoader {
         Err(TrzRewardsError::InvalidAccountOwner.into())
     }
 
+    /// Checks if the next account is owned by one of the token programs
+    pub fn next_with_token_program_owner<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+        iter: &mut Enumerate<I>,
+    ) -> Result<I::Item, ProgramError> {
+        let (idx, acc) = iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if is_token_program(acc.owner) {
+            return Ok(acc);
+        }
+
+        msg!(
+            "Account #{}:{} owner error. Got {} Expected a token program",
+            idx,
+            acc.key,
+            acc.owner
+        );
+        Err(TrzRewardsError::InvalidAccountOwner.into())
+    }
+
+    /// Checks whether next account is one of the token programs
+    pub fn next_token_program<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+        iter: &mut Enumerate<I>,
+    ) -> Result<I::Item, ProgramError> {
+        let (idx, acc) = iter.next().ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if is_token_program(acc.key) {
+            return Ok(acc);
+        }
+
+        msg!(
+            "Account #{}:{} assert error. Expected a token program",
+            idx,
+            acc.key
+        );
+        Err(ProgramError::InvalidArgument)
+    }
+
     /// Checks whether next account matches a given key
     pub fn next_with_key<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
         iter: &mut Enumerate<I>,
@@ -229,120 +461,12 @@ itpl AccountLoader {
     }
 }
 
-/// LockupPeriod is used to define the time during which the lockup will recieve full reward
-#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
-pub enum LockupPeriod {
-    /// Unreachable option
-    None,
-    /// Unlimited lockup period.
-    Flex,
-    /// Three months
-    ThreeMonths,
-    /// SixMonths
-    SixMonths,
-    /// OneYear
-    OneYear,
-}
-
-itpl LockupPeriod {
-    /// Converts LockupPeriod into the Multiplier
-    /// which will be used in rewards calculations
-    pub fn multiplier(&self) -> u64 {
-        match self {
-            LockupPeriod::None => 0,
-            LockupPeriod::ThreeMonths => 2,
-            LockupPeriod::SixMonths => 4,
-            LockupPeriod::OneYear => 6,
-            LockupPeriod::Flex => 1,
-        }
-    }
-
-    /// Calculates the time when a lockup should expire
-    pub fn end_timestamp(&self, start_ts: u64) -> Result<u64, TrzRewardsError> {
-        // conversion should be unfailable because negative timestamp means the ts is earlier than 1970y
-        let beginning_of_the_day = start_ts - (start_ts % SECONDS_PER_DAY);
-
-        match self {
-            LockupPeriod::None => Err(TrzRewardsError::InvalidLockupPeriod),
-            LockupPeriod::ThreeMonths => Ok(beginning_of_the_day + SECONDS_PER_DAY * 90),
-            LockupPeriod::SixMonths => Ok(beginning_of_the_day + SECONDS_PER_DAY * 180),
-            LockupPeriod::OneYear => Ok(beginning_of_the_day + SECONDS_PER_DAY * 365),
-            LockupPeriod::Flex => Ok(beginning_of_the_day + SECONDS_PER_DAY * 5),
-        }
-    }
-
-    /// Return number of days plain numbers to make them appliable for the self.weighted_stake_diff
-    pub fn days(&self) -> Result<u64, TrzRewardsError> {
-        match self {
-            LockupPeriod::None => Err(TrzRewardsError::InvalidLockupPeriod),
-            LockupPeriod::ThreeMonths => Ok(90),
-            LockupPeriod::SixMonths => Ok(180),
-            LockupPeriod::OneYear => Ok(365),
-            LockupPeriod::Flex => Ok(5),
-        }
-    }
-}
-
 /// Get current unix time
 #[inline]
-pub fn get_curr_unix_ts() -> u64 {
+pub fn get_curr_unix_ts() -> Result<u64, ProgramError> {
     // Conversion must be save because negative values
     // in unix means the date is earlier than 1970y
-    Clock::get().unwrap().unix_timestamp as u64
-}
-
-pub(crate) trait SafeArithmeticOperations
-where
-    Self: std::marker::Sized,
-{
-    fn safe_sub(&self, amount: Self) -> Result<Self, TrzRewardsError>;
-    fn safe_add(&self, amount: Self) -> Result<Self, TrzRewardsError>;
-    fn safe_mul(&self, amount: Self) -> Result<Self, TrzRewardsError>;
-    fn safe_div(&self, amount: Self) -> Result<Self, TrzRewardsError>;
-}
-
-itpl SafeArithmeticOperations for u64 {
-    fn safe_sub(&self, amount: u64) -> Result<u64, TrzRewardsError> {
-        self.checked_sub(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
-
-    fn safe_add(&self, amount: u64) -> Result<u64, TrzRewardsError> {
-        self.checked_add(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
-
-    fn safe_mul(&self, amount: u64) -> Result<u64, TrzRewardsError> {
-        self.checked_mul(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
-
-    fn safe_div(&self, amount: u64) -> Result<u64, TrzRewardsError> {
-        self.checked_div(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
-}
-
-itpl SafeArithmeticOperations for u128 {
-    fn safe_sub(&self, amount: u128) -> Result<u128, TrzRewardsError> {
-        self.checked_sub(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
-
-    fn safe_add(&self, amount: u128) -> Result<u128, TrzRewardsError> {
-        self.checked_add(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
-
-    fn safe_mul(&self, amount: u128) -> Result<u128, TrzRewardsError> {
-        self.checked_mul(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
-
-    fn safe_div(&self, amount: u128) -> Result<u128, TrzRewardsError> {
-        self.checked_div(amount)
-            .ok_or(TrzRewardsError::MathOverflow)
-    }
+    Ok(Clock::get()?.unix_timestamp as u64)
 }
 
 pub fn create_mining_address(
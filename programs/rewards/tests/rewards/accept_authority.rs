@@ -0,0 +1,177 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{AuthorityRole, WrappedRewardPool},
+};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup() -> (ProgramTestContext, TestRewards) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    (context, test_rewards)
+}
+
+async fn create_rewarder(
+    context: &mut ProgramTestContext,
+    test_rewards: &TestRewards,
+    fill_authority: &Pubkey,
+) -> Pubkey {
+    let rewarder = Keypair::new();
+    create_token_account(
+        context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        fill_authority,
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    rewarder.pubkey()
+}
+
+#[tokio::test]
+async fn fill_authority_rotation() {
+    let (mut context, test_rewards) = setup().await;
+
+    let new_fill_authority = Keypair::new();
+    test_rewards
+        .propose_authority(
+            &mut context,
+            &test_rewards.admin,
+            AuthorityRole::Fill,
+            &new_fill_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .accept_authority(&mut context, &new_fill_authority, AuthorityRole::Fill)
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(
+        wrapped_reward_pool.pool.fill_authority,
+        new_fill_authority.pubkey()
+    );
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY;
+    let rewarder = create_rewarder(&mut context, &test_rewards, &new_fill_authority.pubkey()).await;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &new_fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    assert_tokens(&mut context, &test_rewards.vault_pubkey, 100).await;
+}
+
+#[tokio::test]
+async fn only_proposed_authority_might_accept() {
+    let (mut context, test_rewards) = setup().await;
+
+    test_rewards
+        .propose_authority(
+            &mut context,
+            &test_rewards.admin,
+            AuthorityRole::Distribute,
+            &Keypair::new().pubkey(),
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .accept_authority(&mut context, &Keypair::new(), AuthorityRole::Distribute)
+        .await
+        .assert_on_chain_err(TrzRewardsError::NoPendingAuthority);
+}
+
+#[tokio::test]
+async fn deposit_authority_rotation_needs_migrated_vault_authority() {
+    let (mut context, test_rewards) = setup().await;
+
+    let new_deposit_authority = Keypair::new();
+    test_rewards
+        .propose_authority(
+            &mut context,
+            &test_rewards.admin,
+            AuthorityRole::Deposit,
+            &new_deposit_authority.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    // the vault is still owned by the current deposit authority
+    test_rewards
+        .accept_authority(&mut context, &new_deposit_authority, AuthorityRole::Deposit)
+        .await
+        .assert_on_chain_err(TrzRewardsError::VaultAuthorityNotMigrated);
+
+    test_rewards
+        .migrate_vault_authority(&mut context)
+        .await
+        .unwrap();
+    test_rewards
+        .accept_authority(&mut context, &new_deposit_authority, AuthorityRole::Deposit)
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(
+        wrapped_reward_pool.pool.deposit_authority,
+        new_deposit_authority.pubkey()
+    );
+}
+
+#[tokio::test]
+#[should_panic]
+async fn unauthorized_authority_proposal_fail() {
+    let (mut context, test_rewards) = setup().await;
+
+    let not_an_admin = Keypair::new();
+    test_rewards
+        .propose_authority(
+            &mut context,
+            &not_an_admin,
+            AuthorityRole::Admin,
+            &not_an_admin.pubkey(),
+        )
+        .await
+        .unwrap();
+}
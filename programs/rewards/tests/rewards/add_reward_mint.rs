@@ -0,0 +1,186 @@
+use crate::utils::*;
+use trz_rewards::{
+    state::{WrappedMining, WrappedRewardPool},
+    utils::LockupPeriod,
+};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+    let partner_mint = Keypair::new();
+    create_mint(&mut context, &partner_mint, owner)
+        .await
+        .unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    (context, test_rewards, partner_mint.pubkey())
+}
+
+async fn create_rewarder(
+    context: &mut ProgramTestContext,
+    test_rewards: &TestRewards,
+    reward_mint: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let rewarder = Keypair::new();
+    create_token_account(
+        context,
+        &rewarder,
+        reward_mint,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(context, reward_mint, &rewarder.pubkey(), amount)
+        .await
+        .unwrap();
+
+    rewarder.pubkey()
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, test_rewards, partner_mint) = setup().await;
+
+    test_rewards
+        .add_reward_mint(&mut context, &test_rewards.admin, &partner_mint)
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(
+        wrapped_reward_pool.pool.reward_token_index(&partner_mint),
+        Ok(1)
+    );
+    assert_tokens(&mut context, &test_rewards.vault_for_mint(&partner_mint), 0).await;
+}
+
+#[tokio::test]
+async fn distribute_and_claim_both_reward_tokens() {
+    let (mut context, test_rewards, partner_mint) = setup().await;
+    test_rewards
+        .add_reward_mint(&mut context, &test_rewards.admin, &partner_mint)
+        .await
+        .unwrap();
+
+    let (user, user_reward, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    let user_partner_reward = Keypair::new();
+    create_token_account(
+        &mut context,
+        &user_partner_reward,
+        &partner_mint,
+        &user.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY;
+
+    let rewarder = create_rewarder(
+        &mut context,
+        &test_rewards,
+        &test_rewards.token_mint_pubkey,
+        100,
+    )
+    .await;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    let partner_rewarder = create_rewarder(&mut context, &test_rewards, &partner_mint, 300).await;
+    test_rewards
+        .fill_vault_for_mint(
+            &mut context,
+            &partner_mint,
+            &partner_rewarder,
+            &test_rewards.fill_authority,
+            300,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_reward.pubkey(),
+        100,
+    )
+    .await;
+
+    let mut mining_account = get_account(&mut context, &user_mining).await;
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+    assert_eq!(wrapped_mining.mining.unclaimed_rewards, [0, 300, 0]);
+
+    test_rewards
+        .claim_for_mint(
+            &mut context,
+            &partner_mint,
+            &user,
+            &user_mining,
+            &user_partner_reward.pubkey(),
+        )
+        .await
+        .unwrap();
+    assert_tokens(&mut context, &user_partner_reward.pubkey(), 300).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn unauthorized_reward_mint_adding_fail() {
+    let (mut context, test_rewards, partner_mint) = setup().await;
+
+    let not_an_admin = Keypair::new();
+    test_rewards
+        .add_reward_mint(&mut context, &not_an_admin, &partner_mint)
+        .await
+        .unwrap();
+}
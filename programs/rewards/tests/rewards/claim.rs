@@ -1,6 +1,7 @@
-use crate::utils::*;
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
 use trz_rewards::{
-    state::{WrappedMining, WrappedRewardPool},
+    error::TrzRewardsError,
+    state::{DistributionMode, WrappedMining, WrappedRewardPool},
     utils::LockupPeriod,
 };
 use trezoa_program::{program_pack::Pack, pubkey::Pubkey};
@@ -10,6 +11,12 @@ use tpl_token::state::Account;
 use std::borrow::{Borrow, BorrowMut};
 
 async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    setup_with_distribution_mode(DistributionMode::Epochs).await
+}
+
+async fn setup_with_distribution_mode(
+    distribution_mode: DistributionMode,
+) -> (ProgramTestContext, TestRewards, Pubkey) {
     let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
     let mut context = test.start_with_context().await;
 
@@ -19,7 +26,10 @@ async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
     create_mint(&mut context, &mint, owner).await.unwrap();
 
     let test_rewards = TestRewards::new(mint.pubkey());
-    test_rewards.initialize_pool(&mut context).await.unwrap();
+    test_rewards
+        .initialize_pool_with_config(&mut context, vec![], 0, 0, distribution_mode)
+        .await
+        .unwrap();
 
     // mint token for fill_authority aka wallet who will fill the vault with tokens
     let rewarder = Keypair::new();
@@ -1115,3 +1125,144 @@ async fn claim_with_delegate() {
 
     assert_eq!(delegate_rewards.amount, 760_000);
 }
+
+#[tokio::test]
+async fn streamed_rewards_without_distribution() {
+    let (mut context, test_rewards, rewarder) =
+        setup_with_distribution_mode(DistributionMode::Streaming).await;
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let curr_ts = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    let distribution_ends_at = curr_ts + 10 * SECONDS_PER_DAY;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            1000,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+
+    // the distribution ends at the beginning of the day
+    let streaming_period = distribution_ends_at - distribution_ends_at % SECONDS_PER_DAY - curr_ts;
+    advance_clock_by_ts(&mut context, SECONDS_PER_DAY as i64).await;
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        1000 * SECONDS_PER_DAY / streaming_period,
+    )
+    .await;
+
+    advance_clock_by_ts(&mut context, (10 * SECONDS_PER_DAY) as i64).await;
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        1000,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn partial_claim_leaves_the_rest_accrued() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    test_rewards
+        .claim_amount(
+            &mut context,
+            &user,
+            &user_mining,
+            &user_rewards.pubkey(),
+            101,
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::ClaimAmountExceedsRewards);
+
+    test_rewards
+        .claim_amount(
+            &mut context,
+            &user,
+            &user_mining,
+            &user_rewards.pubkey(),
+            30,
+        )
+        .await
+        .unwrap();
+    assert_tokens(&mut context, &user_rewards.pubkey(), 30).await;
+
+    let mut mining_account = get_account(&mut context, &user_mining).await;
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+    assert_eq!(wrapped_mining.mining.unclaimed_rewards[0], 70);
+
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        100,
+    )
+    .await;
+}
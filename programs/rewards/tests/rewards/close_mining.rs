@@ -2,7 +2,11 @@ use std::borrow::BorrowMut;
 
 use crate::utils::*;
 use assert_custom_on_chain_error::AssertCustomOnChainErr;
-use trz_rewards::{error::TrzRewardsError, state::WrappedMining, utils::LockupPeriod};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{WrappedMining, WrappedRewardPool},
+    utils::LockupPeriod,
+};
 use trezoa_program::pubkey::Pubkey;
 use trezoa_program_test::*;
 use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
@@ -193,3 +197,30 @@ async fn close_when_has_unclaimed_rewards() {
         .await
         .assert_on_chain_err(TrzRewardsError::RewardsMustBeClaimed);
 }
+
+#[tokio::test]
+async fn close_against_another_pool() {
+    let (mut context, _, mining_owner, mining) = setup().await;
+
+    let another_pool_mint = Keypair::new();
+    let payer = &context.payer.pubkey();
+    create_mint(&mut context, &another_pool_mint, payer)
+        .await
+        .unwrap();
+    let another_pool = TestRewards::new(another_pool_mint.pubkey());
+    another_pool.initialize_pool(&mut context).await.unwrap();
+    another_pool
+        .initialize_mining(&mut context, &mining_owner)
+        .await;
+
+    let res = another_pool
+        .close_mining(&mut context, &mining, &mining_owner, &mining_owner.pubkey())
+        .await;
+    assert!(res.is_err());
+
+    let mut reward_pool_account =
+        get_account(&mut context, &another_pool.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(wrapped_reward_pool.pool.open_minings, 1);
+}
@@ -0,0 +1,163 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{error::TrzRewardsError, state::CLAIM_PERIOD_SECS};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let treasury = Keypair::new();
+    create_token_account(
+        &mut context,
+        &treasury,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.admin.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+
+    (context, test_rewards, treasury.pubkey())
+}
+
+#[tokio::test]
+async fn leftovers_are_swept_to_treasury() {
+    let (mut context, test_rewards, treasury) = setup().await;
+
+    // dust sent to the vault directly is never distributed
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.vault_pubkey,
+        10,
+    )
+    .await
+    .unwrap();
+
+    let target_account = Keypair::new().pubkey();
+    test_rewards
+        .close_pool(
+            &mut context,
+            &test_rewards.admin,
+            &target_account,
+            &treasury,
+        )
+        .await
+        .unwrap();
+
+    assert_tokens(&mut context, &treasury, 10).await;
+    for closed in [test_rewards.reward_pool.pubkey(), test_rewards.vault_pubkey] {
+        let account = context.banks_client.get_account(closed).await.unwrap();
+        assert!(account.is_none());
+    }
+    assert!(get_account(&mut context, &target_account).await.lamports > 0);
+}
+
+#[tokio::test]
+async fn open_minings_block_closing_till_claim_deadline() {
+    let (mut context, test_rewards, treasury) = setup().await;
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let user = Keypair::new();
+    test_rewards.initialize_mining(&mut context, &user).await;
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY * 10;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder.pubkey(),
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+
+    let target_account = Keypair::new().pubkey();
+    test_rewards
+        .close_pool(
+            &mut context,
+            &test_rewards.admin,
+            &target_account,
+            &treasury,
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::PoolIsInUse);
+
+    test_rewards
+        .defund_vault(
+            &mut context,
+            &test_rewards.fill_authority,
+            &rewarder.pubkey(),
+            100,
+            None,
+        )
+        .await
+        .unwrap();
+
+    advance_clock_by_ts(
+        &mut context,
+        (CLAIM_PERIOD_SECS + SECONDS_PER_DAY * 10)
+            .try_into()
+            .unwrap(),
+    )
+    .await;
+
+    // a new target, so the transaction differs from the failed one
+    let target_account = Keypair::new().pubkey();
+    test_rewards
+        .close_pool(
+            &mut context,
+            &test_rewards.admin,
+            &target_account,
+            &treasury,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+#[should_panic]
+async fn only_admin_might_close_pool() {
+    let (mut context, test_rewards, treasury) = setup().await;
+
+    let stranger = Keypair::new();
+    test_rewards
+        .close_pool(&mut context, &stranger, &stranger.pubkey(), &treasury)
+        .await
+        .unwrap();
+}
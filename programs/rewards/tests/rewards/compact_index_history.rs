@@ -0,0 +1,156 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{error::TrzRewardsError, state::WrappedRewardPool, utils::LockupPeriod};
+use sokoban::NodeAllocatorMap;
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        1_000_000,
+    )
+    .await
+    .unwrap();
+
+    (context, test_rewards, rewarder.pubkey())
+}
+
+#[tokio::test]
+async fn compaction_waits_for_minings_checkpoint() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+
+    let (user, user_reward, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let mut curr_ts = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            100,
+            curr_ts + SECONDS_PER_DAY,
+        )
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        test_rewards
+            .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+            .await
+            .unwrap();
+        curr_ts = advance_clock_by_ts(&mut context, SECONDS_PER_DAY as i64).await as u64;
+    }
+
+    // nothing might be compacted till every mining is refreshed since the checkpoint
+    test_rewards
+        .compact_index_history(&mut context, &test_rewards.distribution_authority, curr_ts)
+        .await
+        .assert_on_chain_err(TrzRewardsError::MiningsCheckpointNotReached);
+    test_rewards
+        .compact_index_history(&mut context, &test_rewards.distribution_authority, 0)
+        .await
+        .unwrap();
+    let checkpoint = curr_ts / SECONDS_PER_DAY * SECONDS_PER_DAY;
+    test_rewards
+        .compact_index_history(
+            &mut context,
+            &test_rewards.distribution_authority,
+            checkpoint,
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::MiningsCheckpointNotReached);
+
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_reward.pubkey(),
+        100,
+    )
+    .await;
+
+    test_rewards
+        .compact_index_history(
+            &mut context,
+            &test_rewards.distribution_authority,
+            checkpoint,
+        )
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(wrapped_reward_pool.pool.minings_checkpoint, checkpoint);
+    assert_eq!(wrapped_reward_pool.cumulative_index.len(), 1);
+
+    // the mining is still usable
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+#[should_panic]
+async fn unauthorized_compaction_fail() {
+    let (mut context, test_rewards, _) = setup().await;
+
+    let not_an_authority = Keypair::new();
+    test_rewards
+        .compact_index_history(&mut context, &not_an_authority, u64::MAX)
+        .await
+        .unwrap();
+}
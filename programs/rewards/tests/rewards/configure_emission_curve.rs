@@ -0,0 +1,128 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{DistributionMode, EmissionCurve},
+    utils::LockupPeriod,
+};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+
+async fn setup(distribution_mode: DistributionMode) -> (ProgramTestContext, TestRewards, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards
+        .initialize_pool_with_config(&mut context, vec![], 0, 0, distribution_mode)
+        .await
+        .unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        1_000,
+    )
+    .await
+    .unwrap();
+
+    (context, test_rewards, rewarder.pubkey())
+}
+
+#[tokio::test]
+async fn linear_decay_front_loads_rewards() {
+    let (mut context, test_rewards, rewarder) = setup(DistributionMode::Epochs).await;
+
+    test_rewards
+        .configure_emission_curve(
+            &mut context,
+            &test_rewards.admin,
+            EmissionCurve::LinearDecay,
+        )
+        .await
+        .unwrap();
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY * 4;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            1_000,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    // the first of the four epochs weighs 4 out of 4 + 3 + 2 + 1
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        400,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn emission_curve_of_streaming_pool_fails() {
+    let (mut context, test_rewards, _) = setup(DistributionMode::Streaming).await;
+
+    test_rewards
+        .configure_emission_curve(&mut context, &test_rewards.admin, EmissionCurve::Halving(7))
+        .await
+        .assert_on_chain_err(TrzRewardsError::InvalidEmissionCurve);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn unauthorized_emission_curve_configuration_fails() {
+    let (mut context, test_rewards, _) = setup(DistributionMode::Epochs).await;
+
+    test_rewards
+        .configure_emission_curve(&mut context, &Keypair::new(), EmissionCurve::Stepped(7))
+        .await
+        .unwrap();
+}
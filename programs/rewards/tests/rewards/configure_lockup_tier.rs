@@ -0,0 +1,165 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{LockupTier, WrappedMining, WrappedRewardPool},
+    utils::LockupPeriod,
+};
+use sokoban::NodeAllocatorMap;
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup(lockup_tiers: Vec<LockupTier>) -> (ProgramTestContext, TestRewards, Pubkey, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards
+        .initialize_pool_with_lockup_tiers(&mut context, lockup_tiers)
+        .await
+        .unwrap();
+
+    let user = Keypair::new();
+    let user_mining = test_rewards.initialize_mining(&mut context, &user).await;
+
+    (context, test_rewards, user.pubkey(), user_mining)
+}
+
+#[tokio::test]
+async fn deposit_for_custom_lockup_schedule() {
+    let (mut context, test_rewards, user, mining) = setup(vec![
+        LockupTier::new(7, 2, true),
+        LockupTier::new(30, 3, true),
+    ])
+    .await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    let curr_ts = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    let expiration_date = curr_ts - curr_ts % SECONDS_PER_DAY + 30 * SECONDS_PER_DAY;
+
+    let mut mining_account = get_account(&mut context, &mining).await;
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+    assert_eq!(wrapped_mining.mining.share, 300);
+    assert_eq!(
+        wrapped_mining.weighted_stake_diffs.get(&expiration_date),
+        Some(&100)
+    );
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            100,
+            LockupPeriod::SixMonths,
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::InvalidLockupPeriod);
+}
+
+#[tokio::test]
+async fn admin_adds_and_disables_lockup_tier() {
+    let (mut context, test_rewards, user, mining) = setup(vec![]).await;
+
+    let two_years = LockupTier::new(730, 10, true);
+    test_rewards
+        .configure_lockup_tier(&mut context, &test_rewards.admin, 4, two_years)
+        .await
+        .unwrap();
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            100,
+            LockupPeriod::Tier(4),
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(wrapped_reward_pool.pool.total_share, 1000);
+    assert_eq!(wrapped_reward_pool.pool.lockup_tiers[4], two_years);
+
+    test_rewards
+        .configure_lockup_tier(
+            &mut context,
+            &test_rewards.admin,
+            4,
+            LockupTier::new(730, 10, false),
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            100,
+            LockupPeriod::Tier(4),
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::LockupTierIsDisabled);
+
+    test_rewards
+        .configure_lockup_tier(
+            &mut context,
+            &test_rewards.admin,
+            4,
+            LockupTier::new(365, 10, true),
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::LockupTierIsImmutable);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn unauthorized_lockup_tier_configuration_fail() {
+    let (mut context, test_rewards, _, _) = setup(vec![]).await;
+
+    let not_an_admin = Keypair::new();
+    test_rewards
+        .configure_lockup_tier(
+            &mut context,
+            &not_an_admin,
+            4,
+            LockupTier::new(730, 10, true),
+        )
+        .await
+        .unwrap();
+}
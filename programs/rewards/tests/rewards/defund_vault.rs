@@ -0,0 +1,183 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{error::TrzRewardsError, state::WrappedRewardPool, utils::LockupPeriod};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    // mint token for fill_authority aka wallet who will fill the vault with tokens
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY * 10;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder.pubkey(),
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+
+    (context, test_rewards, rewarder.pubkey())
+}
+
+async fn tokens_available_for_distribution(
+    context: &mut ProgramTestContext,
+    test_rewards: &TestRewards,
+) -> u64 {
+    let mut reward_pool_account = get_account(context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+
+    wrapped_reward_pool.pool.reward_tokens[0].tokens_available_for_distribution
+}
+
+#[tokio::test]
+async fn distributed_rewards_stay_in_the_vault() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    let undistributed = tokens_available_for_distribution(&mut context, &test_rewards).await;
+    assert!(undistributed > 0 && undistributed < 100);
+
+    test_rewards
+        .defund_vault(
+            &mut context,
+            &test_rewards.fill_authority,
+            &rewarder,
+            undistributed + 1,
+            None,
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::NotEnoughUndistributedRewards);
+
+    test_rewards
+        .defund_vault(
+            &mut context,
+            &test_rewards.fill_authority,
+            &rewarder,
+            undistributed,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_tokens(&mut context, &rewarder, undistributed).await;
+    assert_eq!(
+        tokens_available_for_distribution(&mut context, &test_rewards).await,
+        0
+    );
+
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        100 - undistributed,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn distribution_end_is_moved() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY * 5;
+    test_rewards
+        .defund_vault(
+            &mut context,
+            &test_rewards.fill_authority,
+            &rewarder,
+            50,
+            Some(distribution_ends_at),
+        )
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    let reward_token = wrapped_reward_pool.pool.reward_tokens[0];
+
+    assert_eq!(reward_token.tokens_available_for_distribution, 50);
+    assert_eq!(
+        reward_token.distribution_ends_at,
+        wrapped_reward_pool.pool.epoch_start(distribution_ends_at)
+    );
+    assert_tokens(&mut context, &rewarder, 50).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn only_fill_authority_might_defund() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+
+    let stranger = Keypair::new();
+    test_rewards
+        .defund_vault(&mut context, &stranger, &rewarder, 50, None)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,382 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{Delegate, WrappedMining},
+    utils::{find_delegate_program_address, LockupPeriod},
+};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+async fn setup() -> (
+    ProgramTestContext,
+    TestRewards,
+    Pubkey,
+    (Keypair, Keypair, Pubkey),
+) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        1_000_000,
+    )
+    .await
+    .unwrap();
+
+    let (delegate, delegate_rewards, delegate_mining) =
+        create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &delegate_mining,
+            3_000_000, // 18_000_000 of weighted stake
+            LockupPeriod::OneYear,
+            &delegate.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .initialize_delegate(&mut context, &delegate, &delegate_mining, 1_000)
+        .await
+        .unwrap();
+
+    (
+        context,
+        test_rewards,
+        rewarder.pubkey(),
+        (delegate, delegate_rewards, delegate_mining),
+    )
+}
+
+#[tokio::test]
+async fn delegators_get_their_part_of_delegated_stake_rewards() {
+    let (mut context, test_rewards, rewarder, (delegate, delegate_rewards, delegate_mining)) =
+        setup().await;
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .initialize_delegation(&mut context, &delegate_mining, &user_mining)
+        .await
+        .unwrap();
+    test_rewards
+        .deposit_mining_with_delegation(
+            &mut context,
+            &user_mining,
+            1_000_000, //  6_000_000 of weighted stake
+            LockupPeriod::OneYear,
+            &user.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let mut delegate_mining_account = get_account(&mut context, &delegate_mining).await;
+    let d_mining_data = &mut delegate_mining_account.data.borrow_mut();
+    let d_wrapped_mining = WrappedMining::from_bytes_mut(d_mining_data).unwrap();
+    assert_eq!(d_wrapped_mining.mining.stake_from_others, 0);
+    let (delegate_pubkey, _) = find_delegate_program_address(&trz_rewards::id(), &delegate_mining);
+    let mut delegate_account = get_account(&mut context, &delegate_pubkey).await;
+    let delegate_data = &mut delegate_account.data.borrow_mut();
+    assert_eq!(
+        Delegate::from_bytes_mut(delegate_data)
+            .unwrap()
+            .delegated_stake,
+        1_000_000
+    );
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            1_000_000,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    test_rewards
+        .settle_delegation_rewards(&mut context, &user_mining, &delegate_mining)
+        .await
+        .unwrap();
+
+    // 40_000 of the delegated stake rewards are split, 10% of them is the commission
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        240_000 + 36_000,
+    )
+    .await;
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &delegate,
+        &delegate_mining,
+        &delegate_rewards.pubkey(),
+        720_000 + 4_000,
+    )
+    .await;
+
+    test_rewards
+        .withdraw_mining_with_delegation(
+            &mut context,
+            &user_mining,
+            &delegate_mining,
+            1_000_000,
+            &user.pubkey(),
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    let mut delegate_account = get_account(&mut context, &delegate_pubkey).await;
+    let delegate_data = &mut delegate_account.data.borrow_mut();
+    assert_eq!(
+        Delegate::from_bytes_mut(delegate_data)
+            .unwrap()
+            .delegated_stake,
+        0
+    );
+}
+
+#[tokio::test]
+async fn stake_delegated_before_commission_is_moved_under_it() {
+    let (mut context, test_rewards, _, _) = setup().await;
+
+    let (delegate, _, delegate_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &delegate_mining,
+            3_000_000,
+            LockupPeriod::OneYear,
+            &delegate.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    let (user, _, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            1_000_000,
+            LockupPeriod::OneYear,
+            &user.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .initialize_delegate(&mut context, &delegate, &delegate_mining, 1_000)
+        .await
+        .unwrap();
+
+    test_rewards
+        .initialize_delegation_with_stake(&mut context, &delegate_mining, &user_mining, 1_000_000)
+        .await
+        .unwrap();
+
+    let (delegate_pubkey, _) = find_delegate_program_address(&trz_rewards::id(), &delegate_mining);
+    let mut delegate_mining_account = get_account(&mut context, &delegate_mining).await;
+    let d_wrapped_mining =
+        WrappedMining::from_bytes_mut(&mut delegate_mining_account.data).unwrap();
+    assert_eq!(d_wrapped_mining.mining.stake_from_others, 0);
+    let mut delegate_account = get_account(&mut context, &delegate_pubkey).await;
+    assert_eq!(
+        Delegate::from_bytes_mut(&mut delegate_account.data)
+            .unwrap()
+            .delegated_stake,
+        1_000_000
+    );
+
+    // the moved stake is taken off the commission once it's withdrawn
+    test_rewards
+        .withdraw_mining_with_delegation(
+            &mut context,
+            &user_mining,
+            &delegate_mining,
+            1_000_000,
+            &user.pubkey(),
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let mut delegate_mining_account = get_account(&mut context, &delegate_mining).await;
+    let d_wrapped_mining =
+        WrappedMining::from_bytes_mut(&mut delegate_mining_account.data).unwrap();
+    assert_eq!(d_wrapped_mining.mining.stake_from_others, 0);
+    let mut delegate_account = get_account(&mut context, &delegate_pubkey).await;
+    assert_eq!(
+        Delegate::from_bytes_mut(&mut delegate_account.data)
+            .unwrap()
+            .delegated_stake,
+        0
+    );
+}
+
+#[tokio::test]
+async fn stake_moved_under_commission_needs_deposit_authority() {
+    let (mut context, test_rewards, _, (_, _, delegate_mining)) = setup().await;
+
+    let (_, _, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    let mut ix = trz_rewards::instruction::initialize_delegation_with_stake(
+        &trz_rewards::id(),
+        &test_rewards.reward_pool.pubkey(),
+        &delegate_mining,
+        &user_mining,
+        &context.payer.pubkey(),
+        &context.payer.pubkey(),
+        1_000_000,
+    );
+    ix.accounts.pop();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    assert!(context.banks_client.process_transaction(tx).await.is_err());
+}
+
+#[tokio::test]
+async fn deposit_without_delegation_fails() {
+    let (mut context, test_rewards, _, (delegate, _, delegate_mining)) = setup().await;
+
+    let (user, _, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            1_000_000,
+            LockupPeriod::OneYear,
+            &user.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::DelegationAccountsMissing);
+}
+
+#[tokio::test]
+async fn commission_exceeding_the_whole_fails() {
+    let (mut context, test_rewards, _, (delegate, _, delegate_mining)) = setup().await;
+
+    test_rewards
+        .set_delegate_commission(&mut context, &delegate, &delegate_mining, 10_001)
+        .await
+        .assert_on_chain_err(TrzRewardsError::InvalidCommission);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn commission_set_by_someone_else_fails() {
+    let (mut context, test_rewards, _, (_, _, delegate_mining)) = setup().await;
+
+    test_rewards
+        .set_delegate_commission(&mut context, &Keypair::new(), &delegate_mining, 500)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn delegate_mining_cant_be_closed_while_stake_is_delegated() {
+    let (mut context, test_rewards, _, (delegate, _, delegate_mining)) = setup().await;
+
+    let (user, _, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .initialize_delegation(&mut context, &delegate_mining, &user_mining)
+        .await
+        .unwrap();
+    test_rewards
+        .deposit_mining_with_delegation(
+            &mut context,
+            &user_mining,
+            1_000_000,
+            LockupPeriod::OneYear,
+            &user.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    // the delegated stake isn't in the delegate mining's stake from others
+    test_rewards
+        .close_mining(
+            &mut context,
+            &delegate_mining,
+            &delegate,
+            &delegate.pubkey(),
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::StakeFromOthersMustBeZero);
+
+    test_rewards
+        .withdraw_mining_with_delegation(
+            &mut context,
+            &user_mining,
+            &delegate_mining,
+            1_000_000,
+            &user.pubkey(),
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .close_mining(
+            &mut context,
+            &delegate_mining,
+            &delegate,
+            &Keypair::new().pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let delegate_mining_account = context
+        .banks_client
+        .get_account(delegate_mining)
+        .await
+        .unwrap();
+    assert_eq!(delegate_mining_account, None);
+}
@@ -1,10 +1,24 @@
 use crate::utils::*;
-use trz_rewards::utils::LockupPeriod;
+use trz_rewards::{
+    state::{CrankBounty, DistributionMode, LockupTier, WrappedRewardPool},
+    utils::LockupPeriod,
+};
+use sokoban::NodeAllocatorMap;
 use trezoa_program::pubkey::Pubkey;
 use trezoa_program_test::*;
 use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+const SECONDS_PER_HOUR: u64 = 3600;
 
 async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    setup_with_config(vec![], 0).await
+}
+
+async fn setup_with_config(
+    lockup_tiers: Vec<LockupTier>,
+    epoch_length_secs: u64,
+) -> (ProgramTestContext, TestRewards, Pubkey) {
     let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
     let mut context = test.start_with_context().await;
 
@@ -14,7 +28,16 @@ async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
     create_mint(&mut context, &mint, owner).await.unwrap();
 
     let test_rewards = TestRewards::new(mint.pubkey());
-    test_rewards.initialize_pool(&mut context).await.unwrap();
+    test_rewards
+        .initialize_pool_with_config(
+            &mut context,
+            lockup_tiers,
+            epoch_length_secs,
+            0,
+            DistributionMode::Epochs,
+        )
+        .await
+        .unwrap();
 
     // mint token for fill_authority aka wallet who will fill the vault with tokens
     let rewarder = Keypair::new();
@@ -339,3 +362,329 @@ async fn happy_path_with_flex_continious_distribution_with_two_users() {
     assert_tokens(&mut context, &alice_rewards.pubkey(), 49).await;
     assert_tokens(&mut context, &bob_rewards.pubkey(), 49).await;
 }
+
+#[tokio::test]
+async fn hourly_epochs() {
+    let (mut context, test_rewards, rewarder) = setup_with_config(vec![], SECONDS_PER_HOUR).await;
+
+    let (user, user_rewards, user_mining_addr) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining_addr,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining_addr,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_HOUR * 2;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+
+    for _ in 0..3 {
+        test_rewards
+            .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+            .await
+            .unwrap();
+        advance_clock_by_ts(&mut context, SECONDS_PER_HOUR as i64).await;
+    }
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(wrapped_reward_pool.cumulative_index.len(), 3);
+
+    test_rewards
+        .claim(
+            &mut context,
+            &user,
+            &user_mining_addr,
+            &user_rewards.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    assert_tokens(&mut context, &user_rewards.pubkey(), 100).await;
+}
+
+#[tokio::test]
+async fn permissionless_distribution_pays_crank_bounty() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+    test_rewards
+        .configure_distribution_crank(
+            &mut context,
+            &test_rewards.admin,
+            true,
+            CrankBounty::BasisPoints(1000),
+        )
+        .await
+        .unwrap();
+
+    let (user, user_rewards, user_mining_addr) = create_end_user(&mut context, &test_rewards).await;
+    let (keeper, keeper_rewards, keeper_mining_addr) =
+        create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining_addr,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining_addr,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .distribute_rewards_with_bounty(&mut context, &keeper, &keeper_mining_addr)
+        .await
+        .unwrap();
+
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining_addr,
+        &user_rewards.pubkey(),
+        90,
+    )
+    .await;
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &keeper,
+        &keeper_mining_addr,
+        &keeper_rewards.pubkey(),
+        10,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn missed_epochs_are_caught_up() {
+    let (mut context, test_rewards, rewarder) = setup_with_config(
+        vec![LockupTier::new(1, 1, true), LockupTier::new(2, 3, true)],
+        0,
+    )
+    .await;
+
+    let (user_a, user_rewards_a, user_mining_a) =
+        create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining_a,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user_a.pubkey(),
+            &user_mining_a,
+            &user_a.pubkey(),
+        )
+        .await
+        .unwrap();
+    let (user_b, user_rewards_b, user_mining_b) =
+        create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining_b,
+            100,
+            LockupPeriod::Flex,
+            &user_b.pubkey(),
+            &user_mining_b,
+            &user_b.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY * 4;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            60,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    // two days are missed, user A's lockup expires in the second one
+    advance_clock_by_ts(&mut context, (SECONDS_PER_DAY * 3) as i64).await;
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(wrapped_reward_pool.cumulative_index.len(), 4);
+    assert_eq!(wrapped_reward_pool.pool.total_share, 200);
+
+    // 20 tokens a day: 15 + 15 with the weighted stake of 300, 10 with 100 after the expiration
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user_a,
+        &user_mining_a,
+        &user_rewards_a.pubkey(),
+        40,
+    )
+    .await;
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user_b,
+        &user_mining_b,
+        &user_rewards_b.pubkey(),
+        20,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn withdraw_keeps_missed_epochs_modifiers() {
+    let (mut context, test_rewards, rewarder) = setup_with_config(
+        vec![LockupTier::new(1, 1, true), LockupTier::new(2, 3, true)],
+        0,
+    )
+    .await;
+
+    let (user_a, user_rewards_a, user_mining_a) =
+        create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining_a,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user_a.pubkey(),
+            &user_mining_a,
+            &user_a.pubkey(),
+        )
+        .await
+        .unwrap();
+    let (user_b, user_rewards_b, user_mining_b) =
+        create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining_b,
+            100,
+            LockupPeriod::Flex,
+            &user_b.pubkey(),
+            &user_mining_b,
+            &user_b.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY * 4;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            60,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    // user B withdraws after user A's lockup has expired, but before the missed days are distributed
+    advance_clock_by_ts(&mut context, (SECONDS_PER_DAY * 3) as i64).await;
+    test_rewards
+        .withdraw_mining(
+            &mut context,
+            &user_mining_b,
+            &user_mining_b,
+            100,
+            &user_b.pubkey(),
+            &user_b.pubkey(),
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    // the first missed day is still distributed with user A's weighted stake of 300
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user_a,
+        &user_mining_a,
+        &user_rewards_a.pubkey(),
+        55,
+    )
+    .await;
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user_b,
+        &user_mining_b,
+        &user_rewards_b.pubkey(),
+        5,
+    )
+    .await;
+}
@@ -0,0 +1,206 @@
+use crate::utils::*;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::BorshDeserialize;
+use trz_rewards::{
+    events::{DepositEvent, DistributeEvent, RewardsEvent, SetPausedOperationsEvent},
+    state::PoolOperation,
+    utils::LockupPeriod,
+};
+use trezoa_program::{clock::Clock, instruction::Instruction, pubkey::Pubkey};
+use trezoa_program_test::*;
+use trezoa_sdk::{
+    clock::SECONDS_PER_DAY,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+async fn setup() -> (ProgramTestContext, TestRewards, Keypair, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let mint = Keypair::new();
+    let payer = &context.payer.pubkey();
+    create_mint(&mut context, &mint, payer).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let (user, _user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+
+    (context, test_rewards, user, user_mining)
+}
+
+async fn simulate_events(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signer: &Keypair,
+) -> Vec<RewardsEvent> {
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        context.last_blockhash,
+    );
+
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+    simulation
+        .simulation_details
+        .unwrap()
+        .logs
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .map(|data| RewardsEvent::try_from_slice(&STANDARD.decode(data).unwrap()).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn deposit_emits_event() {
+    let (mut context, test_rewards, user, user_mining) = setup().await;
+
+    let instruction = trz_rewards::instruction::deposit_mining(
+        &trz_rewards::id(),
+        &test_rewards.reward_pool.pubkey(),
+        &user_mining,
+        &test_rewards.deposit_authority.pubkey(),
+        &user_mining,
+        100,
+        LockupPeriod::ThreeMonths,
+        &user.pubkey(),
+        &user.pubkey(),
+    );
+    let events = simulate_events(&mut context, instruction, &test_rewards.deposit_authority).await;
+
+    let curr_ts = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    assert_eq!(
+        events,
+        vec![RewardsEvent::Deposit(DepositEvent {
+            reward_pool: test_rewards.reward_pool.pubkey(),
+            mining: user_mining,
+            amount: 100,
+            lockup_period: LockupPeriod::ThreeMonths,
+            delegate_mining: None,
+            share: 200,
+            total_share: 200,
+            timestamp: curr_ts,
+        })]
+    );
+}
+
+#[tokio::test]
+async fn distribute_emits_event_per_reward_token() {
+    let (mut context, test_rewards, user, user_mining) = setup().await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+    let curr_ts = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder.pubkey(),
+            &test_rewards.fill_authority,
+            100,
+            curr_ts + SECONDS_PER_DAY * 100,
+        )
+        .await
+        .unwrap();
+
+    let instruction = trz_rewards::instruction::distribute_rewards(
+        &trz_rewards::id(),
+        &test_rewards.reward_pool.pubkey(),
+        &test_rewards.distribution_authority.pubkey(),
+    );
+    let events = simulate_events(
+        &mut context,
+        instruction,
+        &test_rewards.distribution_authority,
+    )
+    .await;
+
+    let day = curr_ts - curr_ts % SECONDS_PER_DAY;
+    match events.as_slice() {
+        [RewardsEvent::Distribute(DistributeEvent {
+            reward_mint,
+            day: distributed_day,
+            amount,
+            crank_bounty,
+            total_share,
+            ..
+        })] => {
+            assert_eq!(reward_mint, &test_rewards.token_mint_pubkey);
+            assert_eq!(*distributed_day, day);
+            assert_eq!(*amount, 1);
+            assert_eq!(*crank_bounty, 0);
+            assert_eq!(*total_share, 200);
+        }
+        events => panic!("unexpected events: {events:?}"),
+    }
+}
+
+#[tokio::test]
+async fn set_paused_operations_emits_event_without_unknown_flags() {
+    let (mut context, test_rewards, _, _) = setup().await;
+
+    let instruction = trz_rewards::instruction::set_paused_operations(
+        &trz_rewards::id(),
+        &test_rewards.reward_pool.pubkey(),
+        &test_rewards.admin.pubkey(),
+        PoolOperation::Claim.flag() | 0b1000_0000,
+    );
+    let events = simulate_events(&mut context, instruction, &test_rewards.admin).await;
+
+    let curr_ts = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    assert_eq!(
+        events,
+        vec![RewardsEvent::SetPausedOperations(
+            SetPausedOperationsEvent {
+                reward_pool: test_rewards.reward_pool.pubkey(),
+                paused_operations: PoolOperation::Claim.flag(),
+                timestamp: curr_ts,
+            }
+        )]
+    );
+}
@@ -293,7 +293,7 @@ async fn rewards_top_up_extend() {
     let pool = WrappedRewardPool::from_bytes_mut(&mut binding.data).unwrap();
 
     assert_eq!(
-        pool.pool.distribution_ends_at,
+        pool.pool.reward_tokens[0].distribution_ends_at,
         distribution_ends_at - (distribution_ends_at % SECONDS_PER_DAY)
     )
 }
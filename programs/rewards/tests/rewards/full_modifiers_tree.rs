@@ -0,0 +1,295 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{
+        MergedWeightedStakeDiff, WrappedMining, WrappedRewardPool, MINING_MODIFIERS_TREE_CAPACITY,
+    },
+    utils::LockupPeriod,
+};
+use sokoban::NodeAllocatorMap;
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+/// Modifiers of the other stakes the mining's tree is filled with
+const OTHER_MODIFIER: u64 = 100;
+
+/// Sets up a mining with its modifiers tree filled by the stakes expiring every eight days
+/// starting tomorrow, so the stakes deposited today expire between those dates
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey, Pubkey, u64) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let user = Keypair::new();
+    let user_mining = test_rewards.initialize_mining(&mut context, &user).await;
+
+    let today = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        / SECONDS_PER_DAY
+        * SECONDS_PER_DAY;
+    let dates = (0..MINING_MODIFIERS_TREE_CAPACITY as u64)
+        .map(|day| today + (8 * day + 1) * SECONDS_PER_DAY)
+        .collect::<Vec<_>>();
+
+    let mut mining_account = get_account(&mut context, &user_mining).await;
+    {
+        let wrapped_mining = WrappedMining::from_bytes_mut(&mut mining_account.data).unwrap();
+        for date in &dates {
+            wrapped_mining
+                .weighted_stake_diffs
+                .insert(*date, OTHER_MODIFIER)
+                .unwrap();
+        }
+    }
+    set_program_account(&mut context, &user_mining, &mining_account.data).await;
+
+    let reward_pool = test_rewards.reward_pool.pubkey();
+    let mut reward_pool_account = get_account(&mut context, &reward_pool).await;
+    {
+        let wrapped_reward_pool =
+            WrappedRewardPool::from_bytes_mut(&mut reward_pool_account.data).unwrap();
+        for date in &dates {
+            wrapped_reward_pool
+                .weighted_stake_diffs
+                .insert(*date, OTHER_MODIFIER)
+                .unwrap();
+        }
+    }
+    set_program_account(&mut context, &reward_pool, &reward_pool_account.data).await;
+
+    (context, test_rewards, user.pubkey(), user_mining, today)
+}
+
+async fn assert_modifier(
+    context: &mut ProgramTestContext,
+    test_rewards: &TestRewards,
+    mining: &Pubkey,
+    date: u64,
+    expected_modifier: Option<u64>,
+) {
+    let mut mining_account = get_account(context, mining).await;
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+    assert_eq!(
+        wrapped_mining.weighted_stake_diffs.get(&date).copied(),
+        expected_modifier
+    );
+
+    let mut reward_pool_account = get_account(context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(
+        wrapped_reward_pool.weighted_stake_diffs.get(&date).copied(),
+        expected_modifier
+    );
+}
+
+async fn merged_weighted_stake_diffs(
+    context: &mut ProgramTestContext,
+    mining: &Pubkey,
+) -> Vec<MergedWeightedStakeDiff> {
+    let mut mining_account = get_account(context, mining).await;
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+
+    wrapped_mining
+        .mining
+        .merged_weighted_stake_diffs
+        .iter()
+        .filter(|merged| merged.merged_into != 0)
+        .copied()
+        .collect()
+}
+
+#[tokio::test]
+async fn deposit_is_merged_into_the_later_modifier() {
+    let (mut context, test_rewards, user, user_mining, today) = setup().await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user,
+            &user_mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    let expires_at = today + 90 * SECONDS_PER_DAY;
+    let merged_into = today + 97 * SECONDS_PER_DAY;
+    assert_modifier(&mut context, &test_rewards, &user_mining, expires_at, None).await;
+    assert_modifier(
+        &mut context,
+        &test_rewards,
+        &user_mining,
+        merged_into,
+        Some(OTHER_MODIFIER + 100),
+    )
+    .await;
+    assert_eq!(
+        merged_weighted_stake_diffs(&mut context, &user_mining).await,
+        vec![MergedWeightedStakeDiff {
+            expires_at,
+            merged_into,
+            diff: 100,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn extend_takes_the_merged_diff_off_the_same_modifier() {
+    let (mut context, test_rewards, user, user_mining, today) = setup().await;
+
+    let deposit_start_ts = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user,
+            &user_mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .extend_stake(
+            &mut context,
+            &user_mining,
+            &user_mining,
+            LockupPeriod::ThreeMonths,
+            LockupPeriod::OneYear,
+            deposit_start_ts,
+            100,
+            0,
+            &user,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    // the other stake's modifier is left as it was
+    assert_modifier(
+        &mut context,
+        &test_rewards,
+        &user_mining,
+        today + 97 * SECONDS_PER_DAY,
+        Some(OTHER_MODIFIER),
+    )
+    .await;
+    // and the extended stake is merged into the modifier following its new expiration date
+    let expires_at = today + 365 * SECONDS_PER_DAY;
+    let merged_into = today + 369 * SECONDS_PER_DAY;
+    assert_modifier(
+        &mut context,
+        &test_rewards,
+        &user_mining,
+        merged_into,
+        Some(OTHER_MODIFIER + 500),
+    )
+    .await;
+    assert_eq!(
+        merged_weighted_stake_diffs(&mut context, &user_mining).await,
+        vec![MergedWeightedStakeDiff {
+            expires_at,
+            merged_into,
+            diff: 500,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn slash_takes_the_merged_diff_off_the_same_modifier() {
+    let (mut context, test_rewards, user, user_mining, today) = setup().await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user,
+            &user_mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    let expires_at = today + 90 * SECONDS_PER_DAY;
+    let merged_into = today + 97 * SECONDS_PER_DAY;
+    test_rewards
+        .slash(&mut context, &user_mining, &user, 50, 100, Some(expires_at))
+        .await
+        .unwrap();
+
+    assert_modifier(
+        &mut context,
+        &test_rewards,
+        &user_mining,
+        merged_into,
+        Some(OTHER_MODIFIER + 50),
+    )
+    .await;
+    assert_eq!(
+        merged_weighted_stake_diffs(&mut context, &user_mining).await,
+        vec![MergedWeightedStakeDiff {
+            expires_at,
+            merged_into,
+            diff: 50,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn slash_of_not_merged_stake_fails() {
+    let (mut context, test_rewards, user, user_mining, today) = setup().await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user,
+            &user_mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    // nothing expiring at the date has been merged into the later modifier of the other stake
+    test_rewards
+        .slash(
+            &mut context,
+            &user_mining,
+            &user,
+            50,
+            100,
+            Some(today + 95 * SECONDS_PER_DAY),
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::NoWeightedStakeModifiersAtADate);
+}
@@ -0,0 +1,114 @@
+use crate::utils::*;
+use trz_rewards::{state::PendingRewards, utils::LockupPeriod};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{signature::Keypair, signer::Signer};
+
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        1_000_000,
+    )
+    .await
+    .unwrap();
+
+    (context, test_rewards, rewarder.pubkey())
+}
+
+#[tokio::test]
+async fn pending_rewards_match_the_claimed_ones() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let now = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    assert_eq!(
+        test_rewards
+            .get_pending_rewards(&mut context, &user_mining)
+            .await
+            .unclaimed_rewards,
+        [0, 0, 0]
+    );
+
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            1_000_000,
+            now,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    let pending_rewards = test_rewards
+        .get_pending_rewards(&mut context, &user_mining)
+        .await;
+    assert_eq!(pending_rewards.unclaimed_rewards, [1_000_000, 0, 0]);
+    // the three months lockup doubles the stake till it expires
+    assert_eq!(pending_rewards.share, 200);
+    assert!(pending_rewards.next_modifier_at.unwrap() > now);
+
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        1_000_000,
+    )
+    .await;
+    assert_eq!(
+        test_rewards
+            .get_pending_rewards(&mut context, &user_mining)
+            .await,
+        PendingRewards {
+            unclaimed_rewards: [0, 0, 0],
+            ..pending_rewards
+        }
+    );
+}
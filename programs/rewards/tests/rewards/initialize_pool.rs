@@ -38,5 +38,8 @@ async fn success() {
         reward_pool.fill_authority,
         test_rewards.fill_authority.pubkey()
     );
-    assert_eq!(reward_pool.reward_mint, test_rewards.token_mint_pubkey);
+    assert_eq!(
+        reward_pool.reward_tokens[0].mint,
+        test_rewards.token_mint_pubkey
+    );
 }
@@ -0,0 +1,169 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{
+        AccountType, LegacyMining, WrappedMining, WrappedRewardPool,
+        MINING_MODIFIERS_TREE_CAPACITY, PRECISION,
+    },
+    utils::{find_mining_program_address, LockupPeriod},
+};
+use sokoban::{NodeAllocatorMap, RedBlackTree, ZeroCopy};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup() -> (ProgramTestContext, TestRewards, Keypair, Pubkey, u64) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+
+    let today = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        / SECONDS_PER_DAY
+        * SECONDS_PER_DAY;
+    test_rewards
+        .set_legacy_reward_pool(&mut context, today)
+        .await;
+
+    let user = Keypair::new();
+    let (user_mining, bump) = find_mining_program_address(
+        &trz_rewards::id(),
+        &user.pubkey(),
+        &test_rewards.reward_pool.pubkey(),
+    );
+    let data = legacy_mining_data(&test_rewards, &user.pubkey(), bump, today);
+    set_program_account(&mut context, &user_mining, &data).await;
+
+    (context, test_rewards, user, user_mining, today)
+}
+
+/// The mining as it was stored before the layout changed, with the index of the only
+/// reward token and the modifiers tree of the default capacity
+fn legacy_mining_data(test_rewards: &TestRewards, owner: &Pubkey, bump: u8, today: u64) -> Vec<u8> {
+    let mut data = vec![0; LegacyMining::ACCOUNT_LEN];
+    let (mining, weighted_stake_diffs) = data.split_at_mut(LegacyMining::LEN);
+
+    let mut legacy_data = [0; 7];
+    legacy_data[0] = AccountType::Mining.into();
+    *LegacyMining::load_mut_bytes(mining).unwrap() = LegacyMining {
+        reward_pool: test_rewards.reward_pool.pubkey(),
+        owner: *owner,
+        reward_mint: test_rewards.token_mint_pubkey,
+        index_with_precision: 2 * PRECISION,
+        share: 200,
+        unclaimed_rewards: 50,
+        stake_from_others: 0,
+        bump,
+        data: legacy_data,
+    };
+
+    RedBlackTree::<u64, u64, MINING_MODIFIERS_TREE_CAPACITY>::load_mut_bytes(weighted_stake_diffs)
+        .unwrap()
+        .insert(today + 5 * SECONDS_PER_DAY, 100);
+
+    data
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, test_rewards, user, user_mining, today) = setup().await;
+
+    test_rewards
+        .migrate_reward_pool(&mut context, 1)
+        .await
+        .unwrap();
+    test_rewards
+        .migrate_mining(&mut context, &user_mining)
+        .await
+        .unwrap();
+
+    let mut mining_account = get_account(&mut context, &user_mining).await;
+    assert_eq!(mining_account.data.len(), WrappedMining::LEN);
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+    let mining = &wrapped_mining.mining;
+    assert_eq!(mining.reward_pool, test_rewards.reward_pool.pubkey());
+    assert_eq!(mining.owner, user.pubkey());
+    // the rewards of the days distributed since the last refresh are accrued
+    assert_eq!(mining.index_with_precision, [3 * PRECISION, 0, 0]);
+    assert_eq!(mining.share, 200);
+    assert_eq!(mining.unclaimed_rewards, [250, 0, 0]);
+    assert_eq!(
+        mining.modifiers_tree_capacity,
+        MINING_MODIFIERS_TREE_CAPACITY as u16
+    );
+    assert_eq!(
+        wrapped_mining
+            .weighted_stake_diffs
+            .iter()
+            .collect::<Vec<_>>(),
+        vec![(&(today + 5 * SECONDS_PER_DAY), &100)]
+    );
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    // the legacy mining has been counted since the pool's migration
+    assert_eq!(wrapped_reward_pool.pool.open_minings, 1);
+
+    // the migrated mining is usable as any other
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let mut mining_account = get_account(&mut context, &user_mining).await;
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+    assert_eq!(wrapped_mining.mining.share, 300);
+    assert_eq!(wrapped_mining.mining.unclaimed_rewards, [250, 0, 0]);
+}
+
+#[tokio::test]
+async fn pool_has_to_be_migrated_first() {
+    let (mut context, test_rewards, _, user_mining, _) = setup().await;
+
+    test_rewards
+        .migrate_mining(&mut context, &user_mining)
+        .await
+        .assert_on_chain_err(TrzRewardsError::RetreivingZeroCopyAccountFailire);
+}
+
+#[tokio::test]
+async fn migrated_mining_cant_be_migrated_again() {
+    let (mut context, test_rewards, _, user_mining, _) = setup().await;
+
+    test_rewards
+        .migrate_reward_pool(&mut context, 1)
+        .await
+        .unwrap();
+    test_rewards
+        .migrate_mining(&mut context, &user_mining)
+        .await
+        .unwrap();
+
+    test_rewards
+        .migrate_mining(&mut context, &user_mining)
+        .await
+        .assert_on_chain_err(TrzRewardsError::NotLegacyAccount);
+}
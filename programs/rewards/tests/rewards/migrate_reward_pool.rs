@@ -0,0 +1,174 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{LockupTier, WrappedRewardPool, PRECISION},
+    utils::LockupPeriod,
+};
+use sokoban::NodeAllocatorMap;
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup() -> (ProgramTestContext, TestRewards, u64) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+
+    let today = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        / SECONDS_PER_DAY
+        * SECONDS_PER_DAY;
+
+    test_rewards
+        .set_legacy_reward_pool(&mut context, today)
+        .await;
+
+    (context, test_rewards, today)
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, test_rewards, today) = setup().await;
+
+    test_rewards
+        .migrate_reward_pool(&mut context, 0)
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    assert_eq!(reward_pool_account.data.len(), WrappedRewardPool::LEN);
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    let pool = &wrapped_reward_pool.pool;
+
+    assert_eq!(
+        pool.deposit_authority,
+        test_rewards.deposit_authority.pubkey()
+    );
+    assert_eq!(
+        pool.distribute_authority,
+        test_rewards.distribution_authority.pubkey()
+    );
+    assert_eq!(pool.fill_authority, test_rewards.fill_authority.pubkey());
+    assert_eq!(pool.admin, test_rewards.admin.pubkey());
+    assert_eq!(pool.total_share, LEGACY_POOL_TOTAL_SHARE);
+    assert_eq!(pool.lockup_tiers, LockupTier::default_schedule());
+    assert_eq!(pool.epoch_length_secs, SECONDS_PER_DAY);
+    assert_eq!(pool.last_distribution_at, today - SECONDS_PER_DAY);
+    assert!(pool.is_migrated_from_legacy());
+    assert!(!pool.is_vault_authority_migrated());
+
+    let reward_token = pool.reward_tokens[0];
+    assert_eq!(reward_token.mint, test_rewards.token_mint_pubkey);
+    assert_eq!(reward_token.index_with_precision, 3 * PRECISION);
+    assert_eq!(
+        reward_token.distribution_ends_at,
+        today + 10 * SECONDS_PER_DAY
+    );
+    assert_eq!(reward_token.tokens_available_for_distribution, 500);
+    assert!(pool.reward_tokens[1..]
+        .iter()
+        .all(|reward_token| reward_token.mint == Pubkey::default()));
+
+    assert_eq!(
+        wrapped_reward_pool
+            .weighted_stake_diffs
+            .iter()
+            .collect::<Vec<_>>(),
+        vec![(&(today + 5 * SECONDS_PER_DAY), &LEGACY_POOL_MODIFIER)]
+    );
+    assert_eq!(
+        wrapped_reward_pool
+            .cumulative_index
+            .iter()
+            .collect::<Vec<_>>(),
+        vec![
+            (&(today - 3 * SECONDS_PER_DAY), &[PRECISION, 0, 0]),
+            (&(today - 2 * SECONDS_PER_DAY), &[2 * PRECISION, 0, 0]),
+            (&(today - SECONDS_PER_DAY), &[3 * PRECISION, 0, 0]),
+        ]
+    );
+
+    // the migrated pool is usable as any other
+    let user = Keypair::new();
+    let user_mining = test_rewards.initialize_mining(&mut context, &user).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(
+        wrapped_reward_pool.pool.total_share,
+        LEGACY_POOL_TOTAL_SHARE + 100
+    );
+    assert_eq!(wrapped_reward_pool.pool.open_minings, 1);
+}
+
+#[tokio::test]
+async fn migrated_pool_cant_be_migrated_again() {
+    let (mut context, test_rewards, _) = setup().await;
+
+    test_rewards
+        .migrate_reward_pool(&mut context, 0)
+        .await
+        .unwrap();
+
+    test_rewards
+        .migrate_reward_pool(&mut context, 0)
+        .await
+        .assert_on_chain_err(TrzRewardsError::NotLegacyAccount);
+}
+
+#[tokio::test]
+async fn pool_of_current_layout_cant_be_migrated() {
+    let (mut context, _, _) = setup().await;
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, &context.payer.pubkey())
+        .await
+        .unwrap();
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    test_rewards
+        .migrate_reward_pool(&mut context, 0)
+        .await
+        .assert_on_chain_err(TrzRewardsError::NotLegacyAccount);
+}
+
+#[tokio::test]
+async fn migration_needs_distribute_authority() {
+    let (mut context, test_rewards, _) = setup().await;
+
+    let test_rewards = TestRewards {
+        distribution_authority: Keypair::new(),
+        ..test_rewards
+    };
+
+    let res = test_rewards.migrate_reward_pool(&mut context, 0).await;
+    assert!(res.is_err());
+}
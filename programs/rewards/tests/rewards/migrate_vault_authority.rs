@@ -0,0 +1,154 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    utils::{find_vault_authority_program_address, LockupPeriod},
+};
+use trezoa_program::{program_pack::Pack, pubkey::Pubkey};
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+use tpl_token::state::Account;
+use std::borrow::Borrow;
+
+async fn setup() -> (ProgramTestContext, TestRewards, Keypair, Keypair, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder.pubkey(),
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    (context, test_rewards, user, user_rewards, user_mining)
+}
+
+#[tokio::test]
+async fn direct_claim_after_migration() {
+    let (mut context, test_rewards, user, user_rewards, user_mining) = setup().await;
+
+    test_rewards
+        .migrate_vault_authority(&mut context)
+        .await
+        .unwrap();
+    test_rewards
+        .configure_direct_claims(&mut context, true)
+        .await
+        .unwrap();
+
+    let vault_account = get_account(&mut context, &test_rewards.vault_pubkey).await;
+    let vault = Account::unpack(vault_account.data.borrow()).unwrap();
+    let (vault_authority, _) = find_vault_authority_program_address(
+        &trz_rewards::id(),
+        &test_rewards.reward_pool.pubkey(),
+    );
+    assert_eq!(vault.owner, vault_authority);
+
+    test_rewards
+        .claim_direct(&mut context, &user, &user_mining, &user_rewards.pubkey())
+        .await
+        .unwrap();
+    assert_tokens(&mut context, &user_rewards.pubkey(), 100).await;
+}
+
+#[tokio::test]
+async fn deposit_authority_claims_after_migration() {
+    let (mut context, test_rewards, user, user_rewards, user_mining) = setup().await;
+
+    test_rewards
+        .migrate_vault_authority(&mut context)
+        .await
+        .unwrap();
+
+    claim_and_assert(
+        &test_rewards,
+        &mut context,
+        &user,
+        &user_mining,
+        &user_rewards.pubkey(),
+        100,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn direct_claims_need_migration() {
+    let (mut context, test_rewards, _, _, _) = setup().await;
+
+    test_rewards
+        .configure_direct_claims(&mut context, true)
+        .await
+        .assert_on_chain_err(TrzRewardsError::VaultAuthorityNotMigrated);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn direct_claim_is_disallowed_by_default() {
+    let (mut context, test_rewards, user, user_rewards, user_mining) = setup().await;
+
+    test_rewards
+        .migrate_vault_authority(&mut context)
+        .await
+        .unwrap();
+
+    test_rewards
+        .claim_direct(&mut context, &user, &user_mining, &user_rewards.pubkey())
+        .await
+        .unwrap();
+}
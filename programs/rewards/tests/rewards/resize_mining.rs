@@ -0,0 +1,97 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{WrappedMining, MINING_MODIFIERS_TREE_CAPACITIES},
+    utils::LockupPeriod,
+};
+use sokoban::NodeAllocatorMap;
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{signature::Keypair, signer::Signer};
+use std::borrow::BorrowMut;
+
+async fn setup() -> (ProgramTestContext, TestRewards, Keypair, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let user = Keypair::new();
+    let user_mining = test_rewards.initialize_mining(&mut context, &user).await;
+
+    (context, test_rewards, user, user_mining)
+}
+
+#[tokio::test]
+async fn success() {
+    let (mut context, test_rewards, user, user_mining) = setup().await;
+
+    for lockup_period in [LockupPeriod::ThreeMonths, LockupPeriod::OneYear] {
+        test_rewards
+            .deposit_mining(
+                &mut context,
+                &user_mining,
+                100,
+                lockup_period,
+                &user.pubkey(),
+                &user_mining,
+                &user.pubkey(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let modifiers_tree_capacity = MINING_MODIFIERS_TREE_CAPACITIES[3];
+    test_rewards
+        .resize_mining(&mut context, &user, &user_mining, modifiers_tree_capacity)
+        .await
+        .unwrap();
+
+    let mut mining_account = get_account(&mut context, &user_mining).await;
+    assert_eq!(
+        mining_account.data.len(),
+        WrappedMining::len_for_capacity(modifiers_tree_capacity).unwrap()
+    );
+    let mining_data = &mut mining_account.data.borrow_mut();
+    let wrapped_mining = WrappedMining::from_bytes_mut(mining_data).unwrap();
+    assert_eq!(
+        wrapped_mining.mining.modifiers_tree_capacity,
+        modifiers_tree_capacity
+    );
+    assert_eq!(wrapped_mining.weighted_stake_diffs.len(), 2);
+    assert_eq!(wrapped_mining.mining.share, 800);
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::SixMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn shrinking_fail() {
+    let (mut context, test_rewards, user, user_mining) = setup().await;
+
+    test_rewards
+        .resize_mining(&mut context, &user, &user_mining, 200)
+        .await
+        .unwrap();
+
+    test_rewards
+        .resize_mining(&mut context, &user, &user_mining, 100)
+        .await
+        .assert_on_chain_err(TrzRewardsError::InvalidModifiersTreeCapacity);
+}
@@ -0,0 +1,181 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{
+    error::TrzRewardsError,
+    state::{AuthorityRole, PoolOperation},
+    utils::LockupPeriod,
+};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{signature::Keypair, signer::Signer};
+
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let user = Keypair::new();
+    let user_mining = test_rewards.initialize_mining(&mut context, &user).await;
+
+    (context, test_rewards, user.pubkey(), user_mining)
+}
+
+#[tokio::test]
+async fn paused_deposits_leave_withdrawals_open() {
+    let (mut context, test_rewards, user, mining) = setup().await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            100,
+            LockupPeriod::Flex,
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .set_paused_operations(
+            &mut context,
+            &test_rewards.admin,
+            PoolOperation::Deposit.flag(),
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            50,
+            LockupPeriod::Flex,
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .assert_on_chain_err(TrzRewardsError::PoolPaused);
+
+    test_rewards
+        .withdraw_mining(&mut context, &mining, &mining, 30, &user, &user)
+        .await
+        .unwrap();
+
+    test_rewards
+        .set_paused_operations(&mut context, &test_rewards.admin, 0)
+        .await
+        .unwrap();
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            60,
+            LockupPeriod::Flex,
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn guardian_pauses_withdrawals() {
+    let (mut context, test_rewards, user, mining) = setup().await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            100,
+            LockupPeriod::Flex,
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    let guardian = Keypair::new();
+    test_rewards
+        .propose_authority(
+            &mut context,
+            &test_rewards.admin,
+            AuthorityRole::Guardian,
+            &guardian.pubkey(),
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .accept_authority(&mut context, &guardian, AuthorityRole::Guardian)
+        .await
+        .unwrap();
+
+    test_rewards
+        .set_paused_operations(&mut context, &guardian, PoolOperation::Withdraw.flag())
+        .await
+        .unwrap();
+
+    test_rewards
+        .withdraw_mining(&mut context, &mining, &mining, 30, &user, &user)
+        .await
+        .assert_on_chain_err(TrzRewardsError::PoolPaused);
+}
+
+#[tokio::test]
+async fn paused_withdrawals_and_claims_block_penalties() {
+    let (mut context, test_rewards, user, mining) = setup().await;
+
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &mining,
+            100,
+            LockupPeriod::Flex,
+            &user,
+            &mining,
+            &user,
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .set_paused_operations(
+            &mut context,
+            &test_rewards.admin,
+            PoolOperation::Withdraw.flag() | PoolOperation::Claim.flag(),
+        )
+        .await
+        .unwrap();
+
+    test_rewards
+        .slash(&mut context, &mining, &user, 30, 30, None)
+        .await
+        .assert_on_chain_err(TrzRewardsError::PoolPaused);
+    test_rewards
+        .decrease_rewards(&mut context, &mining, &user, 30)
+        .await
+        .assert_on_chain_err(TrzRewardsError::PoolPaused);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn unauthorized_pause_fail() {
+    let (mut context, test_rewards, _, _) = setup().await;
+
+    let stranger = Keypair::new();
+    test_rewards
+        .set_paused_operations(&mut context, &stranger, PoolOperation::Deposit.flag())
+        .await
+        .unwrap();
+}
@@ -0,0 +1,295 @@
+use crate::utils::*;
+use trz_rewards::{
+    state::{CrankBounty, Delegate, WrappedMining, WrappedRewardPool},
+    utils::{find_delegate_program_address, LockupPeriod},
+};
+use trz_rewards_sim::{MiningId, PoolConfig, Simulator};
+use trezoa_program::{clock::SECONDS_PER_DAY, pubkey::Pubkey};
+use trezoa_program_test::*;
+use trezoa_sdk::{signature::Keypair, signer::Signer};
+
+/// Asserts the pool, the minings and the delegate the simulator holds
+/// are the very ones the program has
+async fn assert_simulated(
+    context: &mut ProgramTestContext,
+    test_rewards: &TestRewards,
+    simulator: &Simulator,
+    minings: &[(Pubkey, MiningId)],
+    delegate: (Pubkey, MiningId),
+) {
+    let mut reward_pool_account = get_account(context, &test_rewards.reward_pool.pubkey()).await;
+    let wrapped_reward_pool =
+        WrappedRewardPool::from_bytes_mut(&mut reward_pool_account.data).unwrap();
+    let pool = simulator.pool().unwrap();
+    assert_eq!(wrapped_reward_pool.pool.total_share, pool.total_share);
+    assert_eq!(
+        wrapped_reward_pool.pool.reward_tokens[0].index_with_precision,
+        pool.reward_tokens[0].index_with_precision
+    );
+    assert_eq!(
+        wrapped_reward_pool.pool.reward_tokens[0].tokens_available_for_distribution,
+        pool.reward_tokens[0].tokens_available_for_distribution
+    );
+
+    for (mining_pubkey, mining) in minings {
+        let mut mining_account = get_account(context, mining_pubkey).await;
+        let wrapped_mining = WrappedMining::from_bytes_mut(&mut mining_account.data).unwrap();
+        let simulated_mining = simulator.mining(*mining).unwrap();
+        assert_eq!(wrapped_mining.mining.share, simulated_mining.share);
+        assert_eq!(
+            wrapped_mining.mining.stake_from_others,
+            simulated_mining.stake_from_others
+        );
+        assert_eq!(
+            wrapped_mining.mining.index_with_precision,
+            simulated_mining.index_with_precision
+        );
+        assert_eq!(
+            wrapped_mining.mining.unclaimed_rewards,
+            simulated_mining.unclaimed_rewards
+        );
+
+        let pending_rewards = test_rewards
+            .get_pending_rewards(context, mining_pubkey)
+            .await;
+        assert_eq!(pending_rewards, simulator.pending_rewards(*mining).unwrap());
+    }
+
+    let (delegate_mining, delegate) = delegate;
+    let (delegate_pubkey, _) = find_delegate_program_address(&trz_rewards::id(), &delegate_mining);
+    let mut delegate_account = get_account(context, &delegate_pubkey).await;
+    let on_chain_delegate = *Delegate::from_bytes_mut(&mut delegate_account.data).unwrap();
+    let simulated_delegate = simulator.delegate(delegate).unwrap().unwrap();
+    assert_eq!(
+        on_chain_delegate.delegated_stake,
+        simulated_delegate.delegated_stake
+    );
+    assert_eq!(
+        on_chain_delegate.commission_bps,
+        simulated_delegate.commission_bps
+    );
+    assert_eq!(
+        on_chain_delegate.index_with_precision,
+        simulated_delegate.index_with_precision
+    );
+    assert_eq!(
+        on_chain_delegate.delegators_index_with_precision,
+        simulated_delegate.delegators_index_with_precision
+    );
+}
+
+async fn advance_a_day(context: &mut ProgramTestContext, simulator: &mut Simulator) {
+    let curr_ts = advance_clock_by_ts(context, SECONDS_PER_DAY as i64).await;
+    simulator.advance_to(curr_ts as u64);
+}
+
+#[tokio::test]
+async fn simulator_calculates_the_rewards_the_program_does() {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        1_000_000,
+    )
+    .await
+    .unwrap();
+
+    let start_ts = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    let crank_bounty = CrankBounty::BasisPoints(100);
+    let mut simulator = Simulator::new(
+        PoolConfig {
+            crank_bounty,
+            ..Default::default()
+        },
+        start_ts,
+    )
+    .unwrap();
+    test_rewards
+        .configure_distribution_crank(&mut context, &test_rewards.admin, false, crank_bounty)
+        .await
+        .unwrap();
+
+    let keeper_mining = test_rewards
+        .initialize_mining(&mut context, &test_rewards.distribution_authority)
+        .await;
+    let simulated_keeper = simulator.add_mining().unwrap();
+
+    let (delegate, _, delegate_mining) = create_end_user(&mut context, &test_rewards).await;
+    let simulated_delegate = simulator.add_mining().unwrap();
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &delegate_mining,
+            3_000_000,
+            LockupPeriod::OneYear,
+            &delegate.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    simulator
+        .deposit(simulated_delegate, 3_000_000, LockupPeriod::OneYear, None)
+        .unwrap();
+    test_rewards
+        .initialize_delegate(&mut context, &delegate, &delegate_mining, 1_000)
+        .await
+        .unwrap();
+    simulator
+        .initialize_delegate(simulated_delegate, 1_000)
+        .unwrap();
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    let simulated_user = simulator.add_mining().unwrap();
+    test_rewards
+        .initialize_delegation(&mut context, &delegate_mining, &user_mining)
+        .await
+        .unwrap();
+    simulator
+        .initialize_delegation(simulated_delegate, simulated_user, 0)
+        .unwrap();
+    test_rewards
+        .deposit_mining_with_delegation(
+            &mut context,
+            &user_mining,
+            1_000_000,
+            LockupPeriod::Flex,
+            &user.pubkey(),
+            &delegate_mining,
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    simulator
+        .deposit(
+            simulated_user,
+            1_000_000,
+            LockupPeriod::Flex,
+            Some(simulated_delegate),
+        )
+        .unwrap();
+
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder.pubkey(),
+            &test_rewards.fill_authority,
+            1_000_000,
+            start_ts + 3 * SECONDS_PER_DAY,
+        )
+        .await
+        .unwrap();
+    simulator
+        .fill(0, 1_000_000, start_ts + 3 * SECONDS_PER_DAY)
+        .unwrap();
+
+    let minings = [
+        (keeper_mining, simulated_keeper),
+        (delegate_mining, simulated_delegate),
+        (user_mining, simulated_user),
+    ];
+    for day in 0..3 {
+        advance_a_day(&mut context, &mut simulator).await;
+        test_rewards
+            .distribute_rewards_with_bounty(
+                &mut context,
+                &test_rewards.distribution_authority,
+                &keeper_mining,
+            )
+            .await
+            .unwrap();
+        simulator.distribute(Some(simulated_keeper)).unwrap();
+
+        // the rest is split with the new commission
+        if day == 1 {
+            test_rewards
+                .set_delegate_commission(&mut context, &delegate, &delegate_mining, 2_000)
+                .await
+                .unwrap();
+            simulator
+                .set_delegate_commission(simulated_delegate, 2_000)
+                .unwrap();
+        }
+
+        assert_simulated(
+            &mut context,
+            &test_rewards,
+            &simulator,
+            &minings,
+            (delegate_mining, simulated_delegate),
+        )
+        .await;
+    }
+
+    test_rewards
+        .settle_delegation_rewards(&mut context, &user_mining, &delegate_mining)
+        .await
+        .unwrap();
+    simulator
+        .settle_delegation_rewards(simulated_user, simulated_delegate)
+        .unwrap();
+    test_rewards
+        .withdraw_mining_with_delegation(
+            &mut context,
+            &user_mining,
+            &delegate_mining,
+            400_000,
+            &user.pubkey(),
+            &delegate.pubkey(),
+        )
+        .await
+        .unwrap();
+    simulator
+        .withdraw(simulated_user, 400_000, Some(simulated_delegate))
+        .unwrap();
+    assert_simulated(
+        &mut context,
+        &test_rewards,
+        &simulator,
+        &minings,
+        (delegate_mining, simulated_delegate),
+    )
+    .await;
+
+    test_rewards
+        .claim(&mut context, &user, &user_mining, &user_rewards.pubkey())
+        .await
+        .unwrap();
+    let claimed = simulator.claim(simulated_user, 0).unwrap();
+    assert!(claimed > 0);
+    assert_tokens(&mut context, &user_rewards.pubkey(), claimed).await;
+    assert_simulated(
+        &mut context,
+        &test_rewards,
+        &simulator,
+        &minings,
+        (delegate_mining, simulated_delegate),
+    )
+    .await;
+}
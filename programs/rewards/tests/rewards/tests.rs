@@ -1,15 +1,34 @@
+mod accept_authority;
+mod add_reward_mint;
 mod change_delegate;
 mod claim;
 mod close_mining;
+mod close_pool;
+mod compact_index_history;
+mod configure_emission_curve;
+mod configure_lockup_tier;
+mod defund_vault;
+mod delegate_commission;
 mod deposit_mining;
 mod distribute_rewards;
+mod events;
 mod fill_vault;
+mod full_modifiers_tree;
+mod get_pending_rewards;
 mod initialize_mining;
 mod initialize_pool;
 mod integration;
+mod migrate_mining;
+mod migrate_reward_pool;
+mod migrate_vault_authority;
 mod precision;
+mod resize_mining;
+mod set_paused_operations;
+mod simulator;
+mod token_2022;
 mod utils;
 mod withdraw_mining;
+mod withdraw_vested;
 
 mod extend_stake;
 
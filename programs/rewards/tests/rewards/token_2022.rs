@@ -0,0 +1,284 @@
+use crate::utils::*;
+use std::borrow::{Borrow, BorrowMut};
+use trz_rewards::{
+    state::WrappedRewardPool,
+    utils::{find_vault_authority_program_address, LockupPeriod},
+};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{signature::Keypair, signer::Signer, system_instruction, transaction::Transaction};
+use tpl_token_2022::{
+    extension::{transfer_fee, ExtensionType, StateWithExtensions},
+    state::{Account, Mint},
+};
+
+const TRANSFER_FEE_BASIS_POINTS: u16 = 100;
+
+async fn create_mint_with_transfer_fee(context: &mut ProgramTestContext, mint: &Keypair) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let space =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+            .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(space),
+                space as u64,
+                &tpl_token_2022::id(),
+            ),
+            transfer_fee::instruction::initialize_transfer_fee_config(
+                &tpl_token_2022::id(),
+                &mint.pubkey(),
+                None,
+                None,
+                TRANSFER_FEE_BASIS_POINTS,
+                u64::MAX,
+            )
+            .unwrap(),
+            tpl_token_2022::instruction::initialize_mint(
+                &tpl_token_2022::id(),
+                &mint.pubkey(),
+                &context.payer.pubkey(),
+                None,
+                6,
+            )
+            .unwrap(),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, mint],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn create_token_2022_account(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let space =
+        ExtensionType::try_calculate_account_len::<Account>(&[ExtensionType::TransferFeeAmount])
+            .unwrap();
+    let account = Keypair::new();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &account.pubkey(),
+                rent.minimum_balance(space),
+                space as u64,
+                &tpl_token_2022::id(),
+            ),
+            tpl_token_2022::instruction::initialize_account(
+                &tpl_token_2022::id(),
+                &account.pubkey(),
+                mint,
+                owner,
+            )
+            .unwrap(),
+            tpl_token_2022::instruction::mint_to(
+                &tpl_token_2022::id(),
+                mint,
+                &account.pubkey(),
+                &context.payer.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap(),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    account.pubkey()
+}
+
+async fn token_amount(context: &mut ProgramTestContext, token_account: &Pubkey) -> u64 {
+    let account = get_account(context, token_account).await;
+    StateWithExtensions::<Account>::unpack(account.data.borrow())
+        .unwrap()
+        .base
+        .amount
+}
+
+async fn setup() -> (ProgramTestContext, TestRewards, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let mint = Keypair::new();
+    create_mint_with_transfer_fee(&mut context, &mint).await;
+
+    let test_rewards = TestRewards::new_with_token_program(mint.pubkey(), tpl_token_2022::id());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+
+    let rewarder = create_token_2022_account(
+        &mut context,
+        &mint.pubkey(),
+        &test_rewards.fill_authority.pubkey(),
+        1_000_000,
+    )
+    .await;
+
+    (context, test_rewards, rewarder)
+}
+
+#[tokio::test]
+async fn vault_is_sized_for_the_mint_extensions() {
+    let (mut context, test_rewards, _) = setup().await;
+
+    let vault = get_account(&mut context, &test_rewards.vault_pubkey).await;
+    assert_eq!(vault.owner, tpl_token_2022::id());
+    assert_eq!(
+        vault.data.len(),
+        ExtensionType::try_calculate_account_len::<Account>(&[ExtensionType::TransferFeeAmount])
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn rewards_are_distributed_net_of_transfer_fees() {
+    let (mut context, test_rewards, rewarder) = setup().await;
+
+    let user = Keypair::new();
+    let user_mining = test_rewards.initialize_mining(&mut context, &user).await;
+    let user_rewards = create_token_2022_account(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &user.pubkey(),
+        0,
+    )
+    .await;
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::OneYear,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder,
+            &test_rewards.fill_authority,
+            1_000_000,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+
+    // 1% of the filled rewards is withheld as the transfer fee
+    let mut reward_pool_account =
+        get_account(&mut context, &test_rewards.reward_pool.pubkey()).await;
+    let reward_pool_data = &mut reward_pool_account.data.borrow_mut();
+    let wrapped_reward_pool = WrappedRewardPool::from_bytes_mut(reward_pool_data).unwrap();
+    assert_eq!(
+        wrapped_reward_pool.pool.reward_tokens[0].tokens_available_for_distribution,
+        990_000
+    );
+
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+    test_rewards
+        .claim(&mut context, &user, &user_mining, &user_rewards)
+        .await
+        .unwrap();
+
+    assert_eq!(token_amount(&mut context, &user_rewards).await, 980_100);
+    assert_eq!(
+        token_amount(&mut context, &test_rewards.vault_pubkey).await,
+        0
+    );
+}
+
+#[tokio::test]
+async fn vault_authority_is_migrated() {
+    let (mut context, test_rewards, _) = setup().await;
+
+    test_rewards
+        .migrate_vault_authority(&mut context)
+        .await
+        .unwrap();
+
+    let (vault_authority, _) = find_vault_authority_program_address(
+        &trz_rewards::id(),
+        &test_rewards.reward_pool.pubkey(),
+    );
+    let vault = get_account(&mut context, &test_rewards.vault_pubkey).await;
+    assert_eq!(
+        StateWithExtensions::<Account>::unpack(vault.data.borrow())
+            .unwrap()
+            .base
+            .owner,
+        vault_authority
+    );
+}
+
+#[tokio::test]
+async fn leftovers_are_swept_to_treasury_net_of_transfer_fees() {
+    let (mut context, test_rewards, _) = setup().await;
+
+    let treasury = create_token_2022_account(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.admin.pubkey(),
+        0,
+    )
+    .await;
+    // minted straight to the vault, so no transfer fee is withheld there
+    let tx = Transaction::new_signed_with_payer(
+        &[tpl_token_2022::instruction::mint_to(
+            &tpl_token_2022::id(),
+            &test_rewards.token_mint_pubkey,
+            &test_rewards.vault_pubkey,
+            &context.payer.pubkey(),
+            &[],
+            1_000,
+        )
+        .unwrap()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    test_rewards
+        .close_pool(
+            &mut context,
+            &test_rewards.admin,
+            &Keypair::new().pubkey(),
+            &treasury,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(token_amount(&mut context, &treasury).await, 990);
+    let vault = context
+        .banks_client
+        .get_account(test_rewards.vault_pubkey)
+        .await
+        .unwrap();
+    assert!(vault.is_none());
+}
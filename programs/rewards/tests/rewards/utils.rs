@@ -1,10 +1,24 @@
 use std::borrow::{Borrow, BorrowMut};
 
-use trz_rewards::{error::TrzRewardsError, state::WrappedRewardPool, utils::LockupPeriod};
-use trezoa_program::{instruction::InstructionError, pubkey::Pubkey};
+use borsh::BorshDeserialize;
+use trz_rewards::{
+    error::TrzRewardsError,
+    instruction::with_token_program,
+    state::{
+        AccountType, AuthorityRole, CrankBounty, DistributionMode, EmissionCurve,
+        LegacyCumulativeIndex, LegacyRewardPool, LockupTier, PendingRewards,
+        PoolWeightedStakeDiffs, WrappedRewardPool, PRECISION,
+    },
+    utils::{find_mining_program_address, find_vault_program_address, LockupPeriod},
+};
+use trezoa_program::{
+    entrypoint::MAX_PERMITTED_DATA_INCREASE, instruction::InstructionError, pubkey::Pubkey,
+};
+use sokoban::{NodeAllocatorMap, ZeroCopy};
 use trezoa_program_test::{BanksClientError, ProgramTestContext};
 use trezoa_sdk::{
-    account::Account,
+    account::{Account, AccountSharedData},
+    clock::SECONDS_PER_DAY,
     program_pack::Pack,
     signature::{Keypair, Signer},
     system_instruction::{self, create_account},
@@ -16,43 +30,75 @@ pub type BanksClientResult<T> = Result<T, BanksClientError>;
 
 const TOKEN_DECIMALS: u8 = 6;
 
+/// Total share of the pool set up in the legacy layout
+pub const LEGACY_POOL_TOTAL_SHARE: u64 = 1_000;
+/// Weighted stake modifier the pool set up in the legacy layout applies in five days
+pub const LEGACY_POOL_MODIFIER: u64 = 100;
+
 #[derive(Debug)]
 pub struct TestRewards {
     pub token_mint_pubkey: Pubkey,
     pub deposit_authority: Keypair,
     pub distribution_authority: Keypair,
     pub fill_authority: Keypair,
+    pub admin: Keypair,
     pub reward_pool: Keypair,
     pub vault_pubkey: Pubkey,
+    pub token_program: Pubkey,
 }
 
 itpl TestRewards {
     pub fn new(token_mint_pubkey: Pubkey) -> Self {
+        Self::new_with_token_program(token_mint_pubkey, tpl_token::id())
+    }
+
+    pub fn new_with_token_program(token_mint_pubkey: Pubkey, token_program: Pubkey) -> Self {
         let deposit_authority = Keypair::new();
         let fill_authority = Keypair::new();
         let distribution_authority = Keypair::new();
+        let admin = Keypair::new();
         let reward_pool = Keypair::new();
 
-        let (vault_pubkey, _vault_bump) = Pubkey::find_program_address(
-            &[
-                b"vault".as_ref(),
-                &reward_pool.pubkey().to_bytes(),
-                &token_mint_pubkey.to_bytes(),
-            ],
+        let (vault_pubkey, _vault_bump) = find_vault_program_address(
             &trz_rewards::id(),
+            &reward_pool.pubkey(),
+            &token_mint_pubkey,
         );
 
         Self {
             token_mint_pubkey,
             deposit_authority,
             fill_authority,
+            admin,
             reward_pool,
             vault_pubkey,
             distribution_authority,
+            token_program,
         }
     }
 
     pub async fn initialize_pool(&self, context: &mut ProgramTestContext) -> BanksClientResult<()> {
+        self.initialize_pool_with_lockup_tiers(context, vec![])
+            .await
+    }
+
+    pub async fn initialize_pool_with_lockup_tiers(
+        &self,
+        context: &mut ProgramTestContext,
+        lockup_tiers: Vec<LockupTier>,
+    ) -> BanksClientResult<()> {
+        self.initialize_pool_with_config(context, lockup_tiers, 0, 0, DistributionMode::Epochs)
+            .await
+    }
+
+    pub async fn initialize_pool_with_config(
+        &self,
+        context: &mut ProgramTestContext,
+        lockup_tiers: Vec<LockupTier>,
+        epoch_length_secs: u64,
+        epoch_offset_secs: u64,
+        distribution_mode: DistributionMode,
+    ) -> BanksClientResult<()> {
         let rent = context.banks_client.get_rent().await.unwrap();
         let lamports = rent.minimum_balance(WrappedRewardPool::LEN);
         let space = WrappedRewardPool::LEN as u64;
@@ -68,15 +114,23 @@ itpl TestRewards {
         let tx = Transaction::new_signed_with_payer(
             &[
                 create_reward_pool_ix,
-                trz_rewards::instruction::initialize_pool(
-                    &trz_rewards::id(),
-                    &self.reward_pool.pubkey(),
-                    &self.token_mint_pubkey,
-                    &self.vault_pubkey,
-                    &context.payer.pubkey(),
-                    &self.deposit_authority.pubkey(),
-                    &self.fill_authority.pubkey(),
-                    &self.distribution_authority.pubkey(),
+                with_token_program(
+                    trz_rewards::instruction::initialize_pool(
+                        &trz_rewards::id(),
+                        &self.reward_pool.pubkey(),
+                        &self.token_mint_pubkey,
+                        &self.vault_pubkey,
+                        &context.payer.pubkey(),
+                        &self.deposit_authority.pubkey(),
+                        &self.fill_authority.pubkey(),
+                        &self.distribution_authority.pubkey(),
+                        &self.admin.pubkey(),
+                        lockup_tiers,
+                        epoch_length_secs,
+                        epoch_offset_secs,
+                        distribution_mode,
+                    ),
+                    &self.token_program,
                 ),
             ],
             Some(&context.payer.pubkey()),
@@ -92,13 +146,10 @@ itpl TestRewards {
         context: &mut ProgramTestContext,
         mining_owner: &Keypair,
     ) -> Pubkey {
-        let (mining_account, _) = Pubkey::find_program_address(
-            &[
-                b"mining".as_ref(),
-                mining_owner.pubkey().as_ref(),
-                self.reward_pool.pubkey().as_ref(),
-            ],
+        let (mining_account, _) = find_mining_program_address(
             &trz_rewards::id(),
+            &mining_owner.pubkey(),
+            &self.reward_pool.pubkey(),
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -237,206 +288,1040 @@ itpl TestRewards {
         context.banks_client.process_transaction(tx).await
     }
 
-    pub async fn fill_vault(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn deposit_mining_with_delegation(
         &self,
         context: &mut ProgramTestContext,
-        from: &Pubkey,
-        fill_authority: &Keypair,
+        mining_account: &Pubkey,
         amount: u64,
-        distribution_ends_at: u64,
+        lockup_period: LockupPeriod,
+        owner: &Pubkey,
+        delegate_mining: &Pubkey,
+        delegate_wallet_addr: &Pubkey,
     ) -> BanksClientResult<()> {
-        let tx = Transaction::new_signed_with_payer(
-            &[trz_rewards::instruction::fill_vault(
+        let mut deposit_mining = trz_rewards::instruction::deposit_mining(
+            &trz_rewards::id(),
+            &self.reward_pool.pubkey(),
+            mining_account,
+            &self.deposit_authority.pubkey(),
+            delegate_mining,
+            amount,
+            lockup_period,
+            owner,
+            delegate_wallet_addr,
+        );
+        deposit_mining
+            .accounts
+            .extend(trz_rewards::instruction::delegation_accounts(
                 &trz_rewards::id(),
-                &self.reward_pool.pubkey(),
-                &self.token_mint_pubkey,
-                &self.vault_pubkey,
-                &fill_authority.pubkey(),
-                from,
-                amount,
-                distribution_ends_at,
-            )],
+                delegate_mining,
+                mining_account,
+            ));
+
+        let tx = Transaction::new_signed_with_payer(
+            &[deposit_mining],
             Some(&context.payer.pubkey()),
-            &[&context.payer, fill_authority],
+            &[&context.payer, &self.deposit_authority],
             context.last_blockhash,
         );
 
         context.banks_client.process_transaction(tx).await
     }
 
-    pub async fn claim(
+    pub async fn withdraw_mining_with_delegation(
         &self,
         context: &mut ProgramTestContext,
-        user: &Keypair,
         mining_account: &Pubkey,
-        user_reward_token: &Pubkey,
+        delegate_mining: &Pubkey,
+        amount: u64,
+        owner: &Pubkey,
+        delegate_wallet_addr: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let mut withdraw_mining = trz_rewards::instruction::withdraw_mining(
+            &trz_rewards::id(),
+            &self.reward_pool.pubkey(),
+            mining_account,
+            &self.deposit_authority.pubkey(),
+            delegate_mining,
+            amount,
+            owner,
+            delegate_wallet_addr,
+        );
+        withdraw_mining
+            .accounts
+            .extend(trz_rewards::instruction::delegation_accounts(
+                &trz_rewards::id(),
+                delegate_mining,
+                mining_account,
+            ));
+
+        let tx = Transaction::new_signed_with_payer(
+            &[withdraw_mining],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn initialize_delegate(
+        &self,
+        context: &mut ProgramTestContext,
+        delegate: &Keypair,
+        delegate_mining: &Pubkey,
+        commission_bps: u16,
     ) -> BanksClientResult<()> {
         let tx = Transaction::new_signed_with_payer(
-            &[trz_rewards::instruction::claim(
+            &[trz_rewards::instruction::initialize_delegate(
                 &trz_rewards::id(),
                 &self.reward_pool.pubkey(),
-                &self.token_mint_pubkey,
-                &self.vault_pubkey,
-                mining_account,
-                &user.pubkey(),
-                &self.deposit_authority.pubkey(),
-                user_reward_token,
+                delegate_mining,
+                &delegate.pubkey(),
+                &context.payer.pubkey(),
+                commission_bps,
             )],
             Some(&context.payer.pubkey()),
-            &[&context.payer, user, &self.deposit_authority],
+            &[&context.payer, delegate],
             context.last_blockhash,
         );
 
         context.banks_client.process_transaction(tx).await
     }
 
-    pub async fn distribute_rewards(
+    pub async fn set_delegate_commission(
         &self,
-        authority: &Keypair,
         context: &mut ProgramTestContext,
+        delegate: &Keypair,
+        delegate_mining: &Pubkey,
+        commission_bps: u16,
     ) -> BanksClientResult<()> {
         let tx = Transaction::new_signed_with_payer(
-            &[trz_rewards::instruction::distribute_rewards(
+            &[trz_rewards::instruction::set_delegate_commission(
                 &trz_rewards::id(),
                 &self.reward_pool.pubkey(),
-                &authority.pubkey(),
+                delegate_mining,
+                &delegate.pubkey(),
+                commission_bps,
             )],
             Some(&context.payer.pubkey()),
-            &[&context.payer, authority],
+            &[&context.payer, delegate],
             context.last_blockhash,
         );
 
         context.banks_client.process_transaction(tx).await
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub async fn extend_stake(
+    pub async fn initialize_delegation(
         &self,
         context: &mut ProgramTestContext,
-        mining_account: &Pubkey,
         delegate_mining: &Pubkey,
-        old_lockup_period: LockupPeriod,
-        new_lockup_period: LockupPeriod,
-        deposit_start_ts: u64,
-        base_amount: u64,
-        additional_amount: u64,
-        mining_owner: &Pubkey,
-        delegate_wallet_addr: &Pubkey,
+        mining_account: &Pubkey,
     ) -> BanksClientResult<()> {
         let tx = Transaction::new_signed_with_payer(
-            &[trz_rewards::instruction::extend_stake(
+            &[trz_rewards::instruction::initialize_delegation(
                 &trz_rewards::id(),
                 &self.reward_pool.pubkey(),
-                mining_account,
-                &self.deposit_authority.pubkey(),
                 delegate_mining,
-                old_lockup_period,
-                new_lockup_period,
-                deposit_start_ts,
-                base_amount,
-                additional_amount,
-                mining_owner,
-                delegate_wallet_addr,
+                mining_account,
+                &context.payer.pubkey(),
             )],
             Some(&context.payer.pubkey()),
-            &[&context.payer, &self.deposit_authority],
+            &[&context.payer],
             context.last_blockhash,
         );
 
         context.banks_client.process_transaction(tx).await
     }
 
-    pub async fn close_mining(
+    pub async fn initialize_delegation_with_stake(
         &self,
         context: &mut ProgramTestContext,
+        delegate_mining: &Pubkey,
         mining_account: &Pubkey,
-        mining_owner: &Keypair,
-        target_account: &Pubkey,
+        delegated_amount: u64,
     ) -> BanksClientResult<()> {
         let tx = Transaction::new_signed_with_payer(
-            &[trz_rewards::instruction::close_mining(
+            &[trz_rewards::instruction::initialize_delegation_with_stake(
                 &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                delegate_mining,
                 mining_account,
-                &mining_owner.pubkey(),
-                target_account,
+                &context.payer.pubkey(),
                 &self.deposit_authority.pubkey(),
-                &self.reward_pool.pubkey(),
+                delegated_amount,
             )],
             Some(&context.payer.pubkey()),
-            &[&context.payer, &self.deposit_authority, mining_owner],
+            &[&context.payer, &self.deposit_authority],
             context.last_blockhash,
         );
 
         context.banks_client.process_transaction(tx).await
     }
 
-    #[allow(dead_code)]
-    pub async fn decrease_rewards(
+    pub async fn settle_delegation_rewards(
         &self,
         context: &mut ProgramTestContext,
         mining_account: &Pubkey,
-        mining_owner: &Pubkey,
-        decreased_weighted_stake_number: u64,
+        delegate_mining: &Pubkey,
     ) -> BanksClientResult<()> {
         let tx = Transaction::new_signed_with_payer(
-            &[trz_rewards::instruction::decrease_rewards(
+            &[trz_rewards::instruction::settle_delegation_rewards(
                 &trz_rewards::id(),
-                &self.deposit_authority.pubkey(),
                 &self.reward_pool.pubkey(),
                 mining_account,
-                mining_owner,
-                decreased_weighted_stake_number,
+                delegate_mining,
             )],
             Some(&context.payer.pubkey()),
-            &[&context.payer, &self.deposit_authority],
+            &[&context.payer],
             context.last_blockhash,
         );
 
         context.banks_client.process_transaction(tx).await
     }
-}
-
-pub async fn create_token_account(
-    context: &mut ProgramTestContext,
-    account: &Keypair,
-    mint: &Pubkey,
-    manager: &Pubkey,
-    lamports: u64,
-) -> BanksClientResult<()> {
-    let rent = context.banks_client.get_rent().await.unwrap();
 
-    let tx = Transaction::new_signed_with_payer(
-        &[
-            system_instruction::create_account(
+    pub async fn add_reward_mint(
+        &self,
+        context: &mut ProgramTestContext,
+        admin: &Keypair,
+        reward_mint: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::add_reward_mint(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                reward_mint,
+                &self.vault_for_mint(reward_mint),
                 &context.payer.pubkey(),
-                &account.pubkey(),
-                rent.minimum_balance(tpl_token::state::Account::LEN) + lamports,
-                tpl_token::state::Account::LEN as u64,
-                &tpl_token::id(),
-            ),
-            tpl_token::instruction::initialize_account(
-                &tpl_token::id(),
-                &account.pubkey(),
-                mint,
-                manager,
-            )
-            .unwrap(),
-        ],
-        Some(&context.payer.pubkey()),
-        &[&context.payer, account],
-        context.last_blockhash,
-    );
+                &self.deposit_authority.pubkey(),
+                &admin.pubkey(),
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, admin],
+            context.last_blockhash,
+        );
 
-    context.banks_client.process_transaction(tx).await
-}
+        context.banks_client.process_transaction(tx).await
+    }
 
-pub async fn get_account(context: &mut ProgramTestContext, pubkey: &Pubkey) -> Account {
-    context
-        .banks_client
-        .get_account(*pubkey)
+    pub fn vault_for_mint(&self, reward_mint: &Pubkey) -> Pubkey {
+        find_vault_program_address(&trz_rewards::id(), &self.reward_pool.pubkey(), reward_mint).0
+    }
+
+    pub async fn fill_vault(
+        &self,
+        context: &mut ProgramTestContext,
+        from: &Pubkey,
+        fill_authority: &Keypair,
+        amount: u64,
+        distribution_ends_at: u64,
+    ) -> BanksClientResult<()> {
+        self.fill_vault_for_mint(
+            context,
+            &self.token_mint_pubkey,
+            from,
+            fill_authority,
+            amount,
+            distribution_ends_at,
+        )
         .await
-        .expect("account not found")
-        .expect("account empty")
+    }
+
+    pub async fn fill_vault_for_mint(
+        &self,
+        context: &mut ProgramTestContext,
+        reward_mint: &Pubkey,
+        from: &Pubkey,
+        fill_authority: &Keypair,
+        amount: u64,
+        distribution_ends_at: u64,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[with_token_program(
+                trz_rewards::instruction::fill_vault(
+                    &trz_rewards::id(),
+                    &self.reward_pool.pubkey(),
+                    reward_mint,
+                    &self.vault_for_mint(reward_mint),
+                    &fill_authority.pubkey(),
+                    from,
+                    amount,
+                    distribution_ends_at,
+                ),
+                &self.token_program,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, fill_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn defund_vault(
+        &self,
+        context: &mut ProgramTestContext,
+        fill_authority: &Keypair,
+        destination: &Pubkey,
+        amount: u64,
+        distribution_ends_at: Option<u64>,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::defund_vault(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &self.token_mint_pubkey,
+                &self.vault_pubkey,
+                &fill_authority.pubkey(),
+                &self.deposit_authority.pubkey(),
+                destination,
+                amount,
+                distribution_ends_at,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, fill_authority, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn claim(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        mining_account: &Pubkey,
+        user_reward_token: &Pubkey,
+    ) -> BanksClientResult<()> {
+        self.claim_for_mint(
+            context,
+            &self.token_mint_pubkey,
+            user,
+            mining_account,
+            user_reward_token,
+        )
+        .await
+    }
+
+    pub async fn claim_for_mint(
+        &self,
+        context: &mut ProgramTestContext,
+        reward_mint: &Pubkey,
+        user: &Keypair,
+        mining_account: &Pubkey,
+        user_reward_token: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[with_token_program(
+                trz_rewards::instruction::claim(
+                    &trz_rewards::id(),
+                    &self.reward_pool.pubkey(),
+                    reward_mint,
+                    &self.vault_for_mint(reward_mint),
+                    mining_account,
+                    &user.pubkey(),
+                    &self.deposit_authority.pubkey(),
+                    user_reward_token,
+                ),
+                &self.token_program,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, user, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn claim_amount(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        mining_account: &Pubkey,
+        user_reward_token: &Pubkey,
+        amount: u64,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::claim_amount(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &self.token_mint_pubkey,
+                &self.vault_pubkey,
+                mining_account,
+                &user.pubkey(),
+                &self.deposit_authority.pubkey(),
+                user_reward_token,
+                amount,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, user, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn claim_direct(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        mining_account: &Pubkey,
+        user_reward_token: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::claim_direct(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &self.token_mint_pubkey,
+                &self.vault_pubkey,
+                mining_account,
+                &user.pubkey(),
+                user_reward_token,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, user],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn claim_with_vesting(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        mining_account: &Pubkey,
+        user_reward_token: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::claim_with_vesting(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &self.token_mint_pubkey,
+                &self.vault_pubkey,
+                mining_account,
+                &user.pubkey(),
+                &self.deposit_authority.pubkey(),
+                user_reward_token,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, user, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn withdraw_vested(
+        &self,
+        context: &mut ProgramTestContext,
+        user: &Keypair,
+        mining_account: &Pubkey,
+        user_reward_token: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::withdraw_vested(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &self.token_mint_pubkey,
+                &self.vault_pubkey,
+                mining_account,
+                &user.pubkey(),
+                &self.deposit_authority.pubkey(),
+                user_reward_token,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, user, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn distribute_rewards(
+        &self,
+        authority: &Keypair,
+        context: &mut ProgramTestContext,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::distribute_rewards(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &authority.pubkey(),
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn distribute_rewards_with_bounty(
+        &self,
+        context: &mut ProgramTestContext,
+        keeper: &Keypair,
+        keeper_mining: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::distribute_rewards_with_bounty(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &keeper.pubkey(),
+                keeper_mining,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, keeper],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn get_pending_rewards(
+        &self,
+        context: &mut ProgramTestContext,
+        mining_account: &Pubkey,
+    ) -> PendingRewards {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::get_pending_rewards(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                mining_account,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+        let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+
+        PendingRewards::try_from_slice(&return_data.data).unwrap()
+    }
+
+    pub async fn configure_emission_curve(
+        &self,
+        context: &mut ProgramTestContext,
+        admin: &Keypair,
+        emission_curve: EmissionCurve,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::configure_emission_curve(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &admin.pubkey(),
+                emission_curve,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, admin],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn configure_distribution_crank(
+        &self,
+        context: &mut ProgramTestContext,
+        admin: &Keypair,
+        permissionless_distribution: bool,
+        crank_bounty: CrankBounty,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::configure_distribution_crank(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &admin.pubkey(),
+                permissionless_distribution,
+                crank_bounty,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, admin],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn propose_authority(
+        &self,
+        context: &mut ProgramTestContext,
+        admin: &Keypair,
+        role: AuthorityRole,
+        new_authority: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::propose_authority(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &admin.pubkey(),
+                role,
+                new_authority,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, admin],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn accept_authority(
+        &self,
+        context: &mut ProgramTestContext,
+        new_authority: &Keypair,
+        role: AuthorityRole,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::accept_authority(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &new_authority.pubkey(),
+                role,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, new_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn migrate_vault_authority(
+        &self,
+        context: &mut ProgramTestContext,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[with_token_program(
+                trz_rewards::instruction::migrate_vault_authority(
+                    &trz_rewards::id(),
+                    &self.reward_pool.pubkey(),
+                    &self.admin.pubkey(),
+                    &self.deposit_authority.pubkey(),
+                    &[self.vault_pubkey],
+                ),
+                &self.token_program,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.admin, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn configure_direct_claims(
+        &self,
+        context: &mut ProgramTestContext,
+        direct_claims: bool,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::configure_direct_claims(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &self.admin.pubkey(),
+                direct_claims,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.admin],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn configure_vesting(
+        &self,
+        context: &mut ProgramTestContext,
+        cliff_secs: u64,
+        duration_secs: u64,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::configure_vesting(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &self.admin.pubkey(),
+                cliff_secs,
+                duration_secs,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.admin],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn initialize_vesting(
+        &self,
+        context: &mut ProgramTestContext,
+        mining_account: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::initialize_vesting(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                mining_account,
+                &context.payer.pubkey(),
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn set_paused_operations(
+        &self,
+        context: &mut ProgramTestContext,
+        pauser: &Keypair,
+        paused_operations: u8,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::set_paused_operations(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &pauser.pubkey(),
+                paused_operations,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, pauser],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn extend_stake(
+        &self,
+        context: &mut ProgramTestContext,
+        mining_account: &Pubkey,
+        delegate_mining: &Pubkey,
+        old_lockup_period: LockupPeriod,
+        new_lockup_period: LockupPeriod,
+        deposit_start_ts: u64,
+        base_amount: u64,
+        additional_amount: u64,
+        mining_owner: &Pubkey,
+        delegate_wallet_addr: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::extend_stake(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                mining_account,
+                &self.deposit_authority.pubkey(),
+                delegate_mining,
+                old_lockup_period,
+                new_lockup_period,
+                deposit_start_ts,
+                base_amount,
+                additional_amount,
+                mining_owner,
+                delegate_wallet_addr,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn close_mining(
+        &self,
+        context: &mut ProgramTestContext,
+        mining_account: &Pubkey,
+        mining_owner: &Keypair,
+        target_account: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::close_mining(
+                &trz_rewards::id(),
+                mining_account,
+                &mining_owner.pubkey(),
+                target_account,
+                &self.deposit_authority.pubkey(),
+                &self.reward_pool.pubkey(),
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.deposit_authority, mining_owner],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn close_pool(
+        &self,
+        context: &mut ProgramTestContext,
+        admin: &Keypair,
+        target_account: &Pubkey,
+        treasury: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[with_token_program(
+                trz_rewards::instruction::close_pool(
+                    &trz_rewards::id(),
+                    &self.reward_pool.pubkey(),
+                    &admin.pubkey(),
+                    &self.deposit_authority.pubkey(),
+                    target_account,
+                    &[(self.token_mint_pubkey, self.vault_pubkey, *treasury)],
+                ),
+                &self.token_program,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, admin, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn compact_index_history(
+        &self,
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        compact_before: u64,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::compact_index_history(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &authority.pubkey(),
+                compact_before,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn resize_mining(
+        &self,
+        context: &mut ProgramTestContext,
+        mining_owner: &Keypair,
+        mining_account: &Pubkey,
+        modifiers_tree_capacity: u16,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::resize_mining(
+                &trz_rewards::id(),
+                mining_account,
+                &mining_owner.pubkey(),
+                &context.payer.pubkey(),
+                modifiers_tree_capacity,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, mining_owner],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    /// Replaces the pool with one stored in the legacy layout: the header, the weighted stake
+    /// diffs and the index history of the only reward token, distributed till yesterday
+    pub async fn set_legacy_reward_pool(&self, context: &mut ProgramTestContext, today: u64) {
+        let mut data = vec![0; LegacyRewardPool::ACCOUNT_LEN];
+        let (pool, trees) = data.split_at_mut(LegacyRewardPool::LEN);
+        let (weighted_stake_diffs, cumulative_index) =
+            trees.split_at_mut(std::mem::size_of::<PoolWeightedStakeDiffs>());
+
+        let (_, token_account_bump) = find_vault_program_address(
+            &trz_rewards::id(),
+            &self.reward_pool.pubkey(),
+            &self.token_mint_pubkey,
+        );
+        let mut legacy_data = [0; 7];
+        legacy_data[0] = AccountType::RewardPool.into();
+        *LegacyRewardPool::load_mut_bytes(pool).unwrap() = LegacyRewardPool {
+            deposit_authority: self.deposit_authority.pubkey(),
+            distribute_authority: self.distribution_authority.pubkey(),
+            fill_authority: self.fill_authority.pubkey(),
+            reward_mint: self.token_mint_pubkey,
+            index_with_precision: 3 * PRECISION,
+            total_share: LEGACY_POOL_TOTAL_SHARE,
+            distribution_ends_at: today + 10 * SECONDS_PER_DAY,
+            tokens_available_for_distribution: 500,
+            token_account_bump,
+            data: legacy_data,
+        };
+
+        PoolWeightedStakeDiffs::load_mut_bytes(weighted_stake_diffs)
+            .unwrap()
+            .insert(today + 5 * SECONDS_PER_DAY, LEGACY_POOL_MODIFIER);
+
+        let cumulative_index = LegacyCumulativeIndex::load_mut_bytes(cumulative_index).unwrap();
+        for (days_ago, index) in [(3, PRECISION), (2, 2 * PRECISION), (1, 3 * PRECISION)] {
+            cumulative_index.insert(today - days_ago * SECONDS_PER_DAY, index);
+        }
+
+        set_program_account(context, &self.reward_pool.pubkey(), &data).await;
+    }
+
+    /// Migrates the legacy pool, repeating the instruction until the account fits the current layout
+    pub async fn migrate_reward_pool(
+        &self,
+        context: &mut ProgramTestContext,
+        legacy_minings: u64,
+    ) -> BanksClientResult<()> {
+        let reward_pool_len = get_account(context, &self.reward_pool.pubkey())
+            .await
+            .data
+            .len();
+        let instructions_count = WrappedRewardPool::LEN
+            .saturating_sub(reward_pool_len)
+            .div_ceil(MAX_PERMITTED_DATA_INCREASE)
+            .max(1);
+        let migrate_reward_pool_ix = trz_rewards::instruction::migrate_reward_pool(
+            &trz_rewards::id(),
+            &self.reward_pool.pubkey(),
+            &self.distribution_authority.pubkey(),
+            &context.payer.pubkey(),
+            &self.admin.pubkey(),
+            legacy_minings,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &vec![migrate_reward_pool_ix; instructions_count],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.distribution_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn migrate_mining(
+        &self,
+        context: &mut ProgramTestContext,
+        mining_account: &Pubkey,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::migrate_mining(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                mining_account,
+                &context.payer.pubkey(),
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    pub async fn configure_lockup_tier(
+        &self,
+        context: &mut ProgramTestContext,
+        admin: &Keypair,
+        tier_index: u8,
+        lockup_tier: LockupTier,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::configure_lockup_tier(
+                &trz_rewards::id(),
+                &self.reward_pool.pubkey(),
+                &admin.pubkey(),
+                tier_index,
+                lockup_tier,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, admin],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    #[allow(dead_code)]
+    pub async fn decrease_rewards(
+        &self,
+        context: &mut ProgramTestContext,
+        mining_account: &Pubkey,
+        mining_owner: &Pubkey,
+        decreased_weighted_stake_number: u64,
+    ) -> BanksClientResult<()> {
+        let tx = Transaction::new_signed_with_payer(
+            &[trz_rewards::instruction::decrease_rewards(
+                &trz_rewards::id(),
+                &self.deposit_authority.pubkey(),
+                &self.reward_pool.pubkey(),
+                mining_account,
+                mining_owner,
+                decreased_weighted_stake_number,
+            )],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &self.deposit_authority],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+}
+
+pub async fn create_token_account(
+    context: &mut ProgramTestContext,
+    account: &Keypair,
+    mint: &Pubkey,
+    manager: &Pubkey,
+    lamports: u64,
+) -> BanksClientResult<()> {
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &account.pubkey(),
+                rent.minimum_balance(tpl_token::state::Account::LEN) + lamports,
+                tpl_token::state::Account::LEN as u64,
+                &tpl_token::id(),
+            ),
+            tpl_token::instruction::initialize_account(
+                &tpl_token::id(),
+                &account.pubkey(),
+                mint,
+                manager,
+            )
+            .unwrap(),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, account],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn get_account(context: &mut ProgramTestContext, pubkey: &Pubkey) -> Account {
+    context
+        .banks_client
+        .get_account(*pubkey)
+        .await
+        .expect("account not found")
+        .expect("account empty")
+}
+
+/// Stores the data in a rent exempt account owned by the program, e.g. to set up
+/// the accounts of the legacy layout
+pub async fn set_program_account(context: &mut ProgramTestContext, pubkey: &Pubkey, data: &[u8]) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut account = AccountSharedData::new(
+        rent.minimum_balance(data.len()),
+        data.len(),
+        &trz_rewards::id(),
+    );
+    account.set_data_from_slice(data);
+    context.set_account(pubkey, &account);
 }
 
 pub async fn create_mint(
@@ -0,0 +1,129 @@
+use crate::utils::{assert_custom_on_chain_error::AssertCustomOnChainErr, *};
+use trz_rewards::{error::TrzRewardsError, utils::LockupPeriod};
+use trezoa_program::pubkey::Pubkey;
+use trezoa_program_test::*;
+use trezoa_sdk::{clock::SECONDS_PER_DAY, signature::Keypair, signer::Signer};
+
+async fn setup() -> (ProgramTestContext, TestRewards, Keypair, Keypair, Pubkey) {
+    let test = ProgramTest::new("trz_rewards", trz_rewards::ID, None);
+    let mut context = test.start_with_context().await;
+
+    let owner = &context.payer.pubkey();
+
+    let mint = Keypair::new();
+    create_mint(&mut context, &mint, owner).await.unwrap();
+
+    let test_rewards = TestRewards::new(mint.pubkey());
+    test_rewards.initialize_pool(&mut context).await.unwrap();
+    test_rewards
+        .configure_vesting(&mut context, SECONDS_PER_DAY, 10 * SECONDS_PER_DAY)
+        .await
+        .unwrap();
+
+    let rewarder = Keypair::new();
+    create_token_account(
+        &mut context,
+        &rewarder,
+        &test_rewards.token_mint_pubkey,
+        &test_rewards.fill_authority.pubkey(),
+        0,
+    )
+    .await
+    .unwrap();
+    mint_tokens(
+        &mut context,
+        &test_rewards.token_mint_pubkey,
+        &rewarder.pubkey(),
+        100,
+    )
+    .await
+    .unwrap();
+
+    let (user, user_rewards, user_mining) = create_end_user(&mut context, &test_rewards).await;
+    test_rewards
+        .initialize_vesting(&mut context, &user_mining)
+        .await
+        .unwrap();
+    test_rewards
+        .deposit_mining(
+            &mut context,
+            &user_mining,
+            100,
+            LockupPeriod::ThreeMonths,
+            &user.pubkey(),
+            &user_mining,
+            &user.pubkey(),
+        )
+        .await
+        .unwrap();
+
+    let distribution_ends_at = context
+        .banks_client
+        .get_sysvar::<trezoa_program::clock::Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp as u64
+        + SECONDS_PER_DAY;
+    test_rewards
+        .fill_vault(
+            &mut context,
+            &rewarder.pubkey(),
+            &test_rewards.fill_authority,
+            100,
+            distribution_ends_at,
+        )
+        .await
+        .unwrap();
+    test_rewards
+        .distribute_rewards(&test_rewards.distribution_authority, &mut context)
+        .await
+        .unwrap();
+
+    (context, test_rewards, user, user_rewards, user_mining)
+}
+
+#[tokio::test]
+async fn claimed_rewards_vest_linearly() {
+    let (mut context, test_rewards, user, user_rewards, user_mining) = setup().await;
+
+    test_rewards
+        .claim_with_vesting(&mut context, &user, &user_mining, &user_rewards.pubkey())
+        .await
+        .unwrap();
+    assert_tokens(&mut context, &user_rewards.pubkey(), 0).await;
+
+    advance_clock_by_ts(&mut context, (5 * SECONDS_PER_DAY) as i64).await;
+    test_rewards
+        .withdraw_vested(&mut context, &user, &user_mining, &user_rewards.pubkey())
+        .await
+        .unwrap();
+    assert_tokens(&mut context, &user_rewards.pubkey(), 50).await;
+
+    advance_clock_by_ts(&mut context, (10 * SECONDS_PER_DAY) as i64).await;
+    test_rewards
+        .withdraw_vested(&mut context, &user, &user_mining, &user_rewards.pubkey())
+        .await
+        .unwrap();
+    assert_tokens(&mut context, &user_rewards.pubkey(), 100).await;
+}
+
+#[tokio::test]
+async fn cliff_longer_than_vesting_fails() {
+    let (mut context, test_rewards, _, _, _) = setup().await;
+
+    test_rewards
+        .configure_vesting(&mut context, 2 * SECONDS_PER_DAY, SECONDS_PER_DAY)
+        .await
+        .assert_on_chain_err(TrzRewardsError::InvalidVestingSchedule);
+}
+
+#[tokio::test]
+#[should_panic]
+async fn claim_without_vesting_account_fails() {
+    let (mut context, test_rewards, user, user_rewards, user_mining) = setup().await;
+
+    test_rewards
+        .claim(&mut context, &user, &user_mining, &user_rewards.pubkey())
+        .await
+        .unwrap();
+}